@@ -5,6 +5,8 @@ use crate::fs::CloneFlags;
 #[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
 use crate::fs::RenameFlags;
 use crate::io::{self, OwnedFd};
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+use crate::process::{Gid, Uid};
 use crate::{imp, path};
 #[cfg(not(any(
     target_os = "ios",
@@ -45,6 +47,54 @@ pub fn openat<P: path::Arg, Fd: AsFd>(
     path.into_with_c_str(|path| imp::syscalls::openat(dirfd, path, oflags, create_mode))
 }
 
+/// `open(path, oflags, mode)`—Opens a file, relative to the current working
+/// directory.
+///
+/// `mode` gives the permissions of a newly created file, and is ignored
+/// unless `oflags` contains [`OFlags::CREATE`] or, on Linux, `O_TMPFILE`.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/open.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/open.2.html
+#[inline]
+pub fn open<P: path::Arg>(path: P, oflags: OFlags, mode: Mode) -> io::Result<OwnedFd> {
+    openat(&crate::fs::cwd(), path, oflags, mode)
+}
+
+/// `openat(dir, ".", oflags | O_TMPFILE, create_mode)`—Creates an unnamed
+/// temporary file in the filesystem containing a directory.
+///
+/// `oflags` must include [`OFlags::WRONLY`] or [`OFlags::RDWR`]. The file is
+/// deleted when its last file descriptor is closed, unless it's given a name
+/// first with [`linkat_fd`]. Including [`OFlags::EXCL`] prevents that.
+///
+/// If `dir` isn't a directory, this fails with [`io::Error::NOTDIR`]. If the
+/// filesystem or kernel doesn't support `O_TMPFILE`, this fails with
+/// [`io::Error::OPNOTSUPP`]. Kernels before Linux 3.11 report this as
+/// `EISDIR`, which is mapped to `OPNOTSUPP` too.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/open.2.html
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub fn openat_tmpfile<Fd: AsFd>(
+    dir: &Fd,
+    oflags: OFlags,
+    create_mode: Mode,
+) -> io::Result<OwnedFd> {
+    let dir = dir.as_fd();
+    match imp::syscalls::openat(dir, cstr!("."), oflags | OFlags::TMPFILE, create_mode) {
+        // Kernels without `O_TMPFILE` see only the `O_DIRECTORY` bit in it,
+        // and refuse to open a directory for writing.
+        Err(err) if err == io::Error::ISDIR => Err(io::Error::OPNOTSUPP),
+        otherwise => otherwise,
+    }
+}
+
 /// `readlinkat(fd, path)`—Reads the contents of a symlink.
 ///
 /// If `reuse` is non-empty, reuse its buffer to store the result if possible.
@@ -66,20 +116,53 @@ pub fn readlinkat<P: path::Arg, Fd: AsFd>(
 }
 
 fn _readlinkat(dirfd: BorrowedFd<'_>, path: &CStr, reuse: OsString) -> io::Result<OsString> {
+    let mut buffer = reuse.into_vec();
+    _readlinkat_into(dirfd, path, &mut buffer)?;
+    Ok(OsString::from_vec(buffer))
+}
+
+/// `readlinkat(fd, path)`—Reads the contents of a symlink into `buf`.
+///
+/// On success, `buf` holds exactly the contents of the symlink, and their
+/// length is returned. `buf`'s existing capacity is used, and it only grows
+/// if the contents don't fit.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/readlinkat.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/readlinkat.2.html
+#[inline]
+#[doc(alias = "readlinkat")]
+pub fn readlinkat_into<P: path::Arg, Fd: AsFd>(
+    dirfd: &Fd,
+    path: P,
+    buf: &mut Vec<u8>,
+) -> io::Result<usize> {
+    let dirfd = dirfd.as_fd();
+    path.into_with_c_str(|path| _readlinkat_into(dirfd, path, buf))
+}
+
+fn _readlinkat_into(dirfd: BorrowedFd<'_>, path: &CStr, buffer: &mut Vec<u8>) -> io::Result<usize> {
     // This code would benefit from having a better way to read into
     // uninitialized memory, but that requires `unsafe`.
-    let mut buffer = reuse.into_vec();
     buffer.clear();
-    buffer.resize(256, 0_u8);
+    buffer.resize(buffer.capacity().max(256), 0_u8);
 
     loop {
-        let nread = imp::syscalls::readlinkat(dirfd, path, &mut buffer)?;
+        let nread = match imp::syscalls::readlinkat(dirfd, path, buffer) {
+            Ok(nread) => nread,
+            Err(err) => {
+                buffer.clear();
+                return Err(err);
+            }
+        };
 
-        let nread = nread as usize;
         assert!(nread <= buffer.len());
         if nread < buffer.len() {
-            buffer.resize(nread, 0_u8);
-            return Ok(OsString::from_vec(buffer));
+            buffer.truncate(nread);
+            return Ok(nread);
         }
         buffer.resize(buffer.len() * 2, 0_u8);
     }
@@ -125,6 +208,34 @@ pub fn linkat<P: path::Arg, Q: path::Arg, PFd: AsFd, QFd: AsFd>(
     })
 }
 
+/// `linkat(fd, "", new_dirfd, new_path, AT_EMPTY_PATH)`—Creates a hard link
+/// to an open file.
+///
+/// This gives a name to the file `fd` refers to, such as an anonymous file
+/// opened with [`OFlags::TMPFILE`], unless it was opened with
+/// [`OFlags::EXCL`] as well.
+///
+/// Linking by file descriptor requires the `CAP_DAC_READ_SEARCH`
+/// capability; without it, this fails with [`io::Error::NOENT`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/linkat.2.html
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[inline]
+pub fn linkat_fd<Q: path::Arg, Fd: AsFd, QFd: AsFd>(
+    fd: &Fd,
+    new_dirfd: &QFd,
+    new_path: Q,
+) -> io::Result<()> {
+    let fd = fd.as_fd();
+    let new_dirfd = new_dirfd.as_fd();
+    new_path.into_with_c_str(|new_path| {
+        imp::syscalls::linkat(fd, cstr!(""), new_dirfd, new_path, AtFlags::EMPTY_PATH)
+    })
+}
+
 /// `unlinkat(fd, path, flags)`—Unlinks a file or remove a directory.
 ///
 /// # References
@@ -190,6 +301,33 @@ pub fn renameat_with<P: path::Arg, Q: path::Arg, PFd: AsFd, QFd: AsFd>(
     })
 }
 
+/// `renameat2(AT_FDCWD, old_path, AT_FDCWD, new_path, flags)`—Renames a file
+/// or directory, relative to the current working directory.
+///
+/// With [`RenameFlags::NOREPLACE`], this fails with [`io::Error::EXIST`] if
+/// `new_path` already exists. With [`RenameFlags::EXCHANGE`], `old_path` and
+/// `new_path` are atomically swapped, and both must exist.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/renameat2.2.html
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[inline]
+pub fn rename_with<P: path::Arg, Q: path::Arg>(
+    old_path: P,
+    new_path: Q,
+    flags: RenameFlags,
+) -> io::Result<()> {
+    renameat_with(
+        &crate::fs::cwd(),
+        old_path,
+        &crate::fs::cwd(),
+        new_path,
+        flags,
+    )
+}
+
 /// `symlinkat(old_dirfd, old_path, new_dirfd, new_path)`—Creates a symlink.
 ///
 /// # References
@@ -228,6 +366,10 @@ pub fn statat<P: path::Arg, Fd: AsFd>(dirfd: &Fd, path: P, flags: AtFlags) -> io
 /// `faccessat(dirfd, path, access, flags)`—Tests permissions for a file or
 /// directory.
 ///
+/// On Linux, when `flags` includes [`AtFlags::EACCESS`] and the effective
+/// user or group ID differs from the real one, this may need `faccessat2`,
+/// from Linux 5.8, and fail with [`io::Error::NOSYS`] on older kernels.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -246,6 +388,23 @@ pub fn accessat<P: path::Arg, Fd: AsFd>(
     path.into_with_c_str(|path| imp::syscalls::accessat(dirfd, path, access, flags))
 }
 
+/// `access(path, access)`—Tests permissions for a file or directory.
+///
+/// Like `access` itself, this checks against the real user and group IDs,
+/// rather than the effective ones. To check against the effective IDs, use
+/// [`accessat`] with [`AtFlags::EACCESS`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/access.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/access.2.html
+#[inline]
+pub fn access<P: path::Arg>(path: P, access: Access) -> io::Result<()> {
+    accessat(&crate::fs::cwd(), path, access, AtFlags::empty())
+}
+
 /// `utimensat(dirfd, path, times, flags)`—Sets file or directory timestamps.
 ///
 /// # References
@@ -265,6 +424,45 @@ pub fn utimensat<P: path::Arg, Fd: AsFd>(
     path.into_with_c_str(|path| imp::syscalls::utimensat(dirfd, path, times, flags))
 }
 
+/// `utimensat(AT_FDCWD, path, times, 0)`—Sets file or directory timestamps.
+///
+/// `times[0]` is the last access time and `times[1]` is the last
+/// modification time. Setting a `tv_nsec` to [`UTIME_NOW`] sets that
+/// timestamp to the current time, and setting it to [`UTIME_OMIT`] leaves
+/// that timestamp unchanged.
+///
+/// Unlike POSIX's `utimes`, this takes nanosecond-resolution times.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [`UTIME_NOW`]: crate::fs::UTIME_NOW
+/// [`UTIME_OMIT`]: crate::fs::UTIME_OMIT
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/utimensat.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/utimensat.2.html
+#[inline]
+pub fn utimes<P: path::Arg>(path: P, times: &[Timespec; 2]) -> io::Result<()> {
+    utimensat(&crate::fs::cwd(), path, times, AtFlags::empty())
+}
+
+/// `utimensat(AT_FDCWD, path, times, AT_SYMLINK_NOFOLLOW)`—Sets file,
+/// directory, or symlink timestamps, without following symlinks in the last
+/// path component.
+///
+/// See [`utimes`] for the meaning of `times`.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/utimensat.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/utimensat.2.html
+#[inline]
+pub fn lutimes<P: path::Arg>(path: P, times: &[Timespec; 2]) -> io::Result<()> {
+    utimensat(&crate::fs::cwd(), path, times, AtFlags::SYMLINK_NOFOLLOW)
+}
+
 /// `fchmodat(dirfd, path, mode, 0)`—Sets file or directory permissions.
 ///
 /// The flags argument is fixed to 0, so `AT_SYMLINK_NOFOLLOW` is not
@@ -287,6 +485,23 @@ pub fn chmodat<P: path::Arg, Fd: AsFd>(dirfd: &Fd, path: P, mode: Mode) -> io::R
     path.into_with_c_str(|path| imp::syscalls::chmodat(dirfd, path, mode))
 }
 
+/// `chmod(path, mode)`—Sets file or directory permissions.
+///
+/// Like `chmod` itself, this follows symlinks, so it sets the permissions of
+/// the file a symlink points to rather than those of the symlink.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/chmod.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/chmod.2.html
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub fn chmod<P: path::Arg>(path: P, mode: Mode) -> io::Result<()> {
+    chmodat(&crate::fs::cwd(), path, mode)
+}
+
 /// `fclonefileat(src, dst_dir, dst, flags)`—Efficiently copies between files.
 ///
 /// # References
@@ -332,3 +547,150 @@ pub fn mknodat<P: path::Arg, Fd: AsFd>(
     let dirfd = dirfd.as_fd();
     path.into_with_c_str(|path| imp::syscalls::mknodat(dirfd, path, mode, dev))
 }
+
+/// `mkfifoat(dirfd, path, mode)`—Creates a FIFO.
+///
+/// This calls [`mknodat`] with [`Mode::IFIFO`] set in `mode`. If `mode`
+/// already contains a different file type, this fails with
+/// [`io::Error::INVAL`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/mkfifoat.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/mkfifoat.3.html
+#[cfg(not(any(
+    target_os = "ios",
+    target_os = "macos",
+    target_os = "redox",
+    target_os = "wasi",
+)))]
+#[inline]
+pub fn mkfifoat<P: path::Arg, Fd: AsFd>(dirfd: &Fd, path: P, mode: Mode) -> io::Result<()> {
+    mknodat(dirfd, path, fifo_mode(mode)?, 0)
+}
+
+/// `mkfifo(path, mode)`—Creates a FIFO.
+///
+/// This is [`mkfifoat`] relative to the current working directory.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/mkfifo.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/mkfifo.3.html
+#[cfg(not(any(
+    target_os = "ios",
+    target_os = "macos",
+    target_os = "redox",
+    target_os = "wasi",
+)))]
+#[inline]
+pub fn mkfifo<P: path::Arg>(path: P, mode: Mode) -> io::Result<()> {
+    mkfifoat(&crate::fs::cwd(), path, mode)
+}
+
+/// Sets the FIFO file type in `mode`, rejecting any other file type.
+#[cfg(not(any(
+    target_os = "ios",
+    target_os = "macos",
+    target_os = "redox",
+    target_os = "wasi",
+)))]
+fn fifo_mode(mode: Mode) -> io::Result<Mode> {
+    let file_type = mode & Mode::IFMT;
+    if !file_type.is_empty() && file_type != Mode::IFIFO {
+        return Err(io::Error::INVAL);
+    }
+    Ok((mode - Mode::IFMT) | Mode::IFIFO)
+}
+
+/// `fchownat(dirfd, path, owner, group, flags)`—Sets file or directory
+/// ownership.
+///
+/// `None` for `owner` or `group` leaves that ID unchanged.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/fchownat.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/fchownat.2.html
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[inline]
+#[doc(alias = "fchownat")]
+pub fn chownat<P: path::Arg, Fd: AsFd>(
+    dirfd: &Fd,
+    path: P,
+    owner: Option<Uid>,
+    group: Option<Gid>,
+    flags: AtFlags,
+) -> io::Result<()> {
+    let dirfd = dirfd.as_fd();
+    path.into_with_c_str(|path| imp::syscalls::chownat(dirfd, path, owner, group, flags))
+}
+
+/// `chown(path, owner, group)`—Sets file or directory ownership.
+///
+/// `None` for `owner` or `group` leaves that ID unchanged.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/chown.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/chown.2.html
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[inline]
+pub fn chown<P: path::Arg>(path: P, owner: Option<Uid>, group: Option<Gid>) -> io::Result<()> {
+    chownat(&crate::fs::cwd(), path, owner, group, AtFlags::empty())
+}
+
+/// `lchown(path, owner, group)`—Sets file, directory, or symlink ownership,
+/// without following symlinks in the last path component.
+///
+/// `None` for `owner` or `group` leaves that ID unchanged.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/lchown.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/lchown.2.html
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[inline]
+pub fn lchown<P: path::Arg>(path: P, owner: Option<Uid>, group: Option<Gid>) -> io::Result<()> {
+    chownat(
+        &crate::fs::cwd(),
+        path,
+        owner,
+        group,
+        AtFlags::SYMLINK_NOFOLLOW,
+    )
+}
+
+/// `truncate(path, length)`—Sets the length of a file.
+///
+/// This opens the file with `O_WRONLY | O_CLOEXEC` and calls [`ftruncate`]
+/// on it, so it requires write permission on the file. If `length` is
+/// greater than the current length, the file is extended with zeros.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [`ftruncate`]: crate::fs::ftruncate
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/truncate.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/truncate.2.html
+#[inline]
+pub fn truncate<P: path::Arg>(path: P, length: u64) -> io::Result<()> {
+    let file = openat(
+        &crate::fs::cwd(),
+        path,
+        OFlags::WRONLY | OFlags::CLOEXEC,
+        Mode::empty(),
+    )?;
+    crate::fs::ftruncate(&file, length)
+}
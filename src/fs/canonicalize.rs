@@ -0,0 +1,43 @@
+#[cfg(not(any(target_os = "android", target_os = "linux")))]
+use crate::imp;
+use crate::{io, path};
+use std::path::PathBuf;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use {
+    crate::fs::{cwd, openat, readlinkat, Mode, OFlags},
+    crate::io::proc_self_fd,
+    crate::path::DecInt,
+    std::ffi::OsString,
+};
+
+/// `realpath(path, NULL)`—Returns the absolute path of `path`, with all
+/// symlinks, `.`, and `..` components resolved.
+///
+/// On Linux, this opens `path` with `O_PATH` and reads the resulting file
+/// descriptor's link in `/proc/self/fd`. Elsewhere, it calls `realpath`.
+///
+/// Fails if `path`, or any symlink encountered while resolving it, doesn't
+/// exist.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/realpath.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/realpath.3.html
+#[doc(alias = "realpath")]
+pub fn canonicalize<P: path::Arg>(path: P) -> io::Result<PathBuf> {
+    _canonicalize(path)
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+fn _canonicalize<P: path::Arg>(path: P) -> io::Result<PathBuf> {
+    let fd = openat(&cwd(), path, OFlags::PATH | OFlags::CLOEXEC, Mode::empty())?;
+    let resolved = readlinkat(&proc_self_fd()?, DecInt::from_fd(&fd), OsString::new())?;
+    Ok(resolved.into())
+}
+
+#[cfg(not(any(target_os = "android", target_os = "linux")))]
+fn _canonicalize<P: path::Arg>(path: P) -> io::Result<PathBuf> {
+    path.into_with_c_str(imp::syscalls::realpath)
+}
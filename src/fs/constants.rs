@@ -1,6 +1,7 @@
 //! Filesystem API constants, translated into `bitflags` constants.
 
 use crate::imp;
+use imp::fs::{FileType, RawMode};
 
 pub use imp::fs::FdFlags;
 
@@ -11,6 +12,62 @@ pub use imp::fs::AtFlags;
 
 pub use imp::fs::Mode;
 
+impl Mode {
+    /// Constructs a `Mode` from a raw mode value, such as `0o644` or the
+    /// `st_mode` field of a `Stat`.
+    ///
+    /// Bits that `Mode` doesn't know about are dropped.
+    #[inline]
+    pub const fn from_raw_mode(st_mode: RawMode) -> Self {
+        Self::from_bits_truncate(st_mode)
+    }
+
+    /// Returns the raw mode value.
+    #[inline]
+    pub const fn as_raw_mode(self) -> RawMode {
+        self.bits()
+    }
+
+    /// Tests whether the set-user-ID bit, `S_ISUID`, is set.
+    #[cfg(not(target_os = "wasi"))]
+    #[inline]
+    pub const fn is_setuid(self) -> bool {
+        self.contains(Self::ISUID)
+    }
+
+    /// Tests whether the set-group-ID bit, `S_ISGID`, is set.
+    #[cfg(not(target_os = "wasi"))]
+    #[inline]
+    pub const fn is_setgid(self) -> bool {
+        self.contains(Self::ISGID)
+    }
+
+    /// Tests whether the sticky bit, `S_ISVTX`, is set.
+    #[cfg(not(target_os = "wasi"))]
+    #[inline]
+    pub const fn is_sticky(self) -> bool {
+        self.contains(Self::ISVTX)
+    }
+
+    /// Tests whether the file type bits, `S_IFMT`, indicate a directory.
+    #[inline]
+    pub const fn is_dir(self) -> bool {
+        matches!(FileType::from_mode(self), FileType::Directory)
+    }
+
+    /// Tests whether the file type bits, `S_IFMT`, indicate a regular file.
+    #[inline]
+    pub const fn is_reg(self) -> bool {
+        matches!(FileType::from_mode(self), FileType::RegularFile)
+    }
+
+    /// Tests whether the file type bits, `S_IFMT`, indicate a symlink.
+    #[inline]
+    pub const fn is_symlink(self) -> bool {
+        matches!(FileType::from_mode(self), FileType::Symlink)
+    }
+}
+
 pub use imp::fs::OFlags;
 
 #[cfg(any(target_os = "ios", target_os = "macos"))]
@@ -4,6 +4,11 @@ use io_lifetimes::AsFd;
 /// `copy_file_range(fd_in, off_in, fd_out, off_out, len, 0)`—Copies data
 /// from one file to another.
 ///
+/// If `off_in` or `off_out` is `Some`, data is read from or written at that
+/// offset, the offset is advanced by the number of bytes copied, and the
+/// corresponding file position is left unchanged. If it's `None`, the file
+/// position is used and advanced instead.
+///
 /// # References
 ///  - [Linux]
 ///
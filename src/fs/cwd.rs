@@ -2,28 +2,50 @@ use crate::imp;
 use crate::io::RawFd;
 use io_lifetimes::BorrowedFd;
 
+/// The raw file descriptor [`cwd`] and [`current_dir_fd`] return.
+const CWD_RAW_FD: RawFd = imp::io::AT_FDCWD;
+
+// Check at compile time that it's `AT_FDCWD`, which is negative, so it can't
+// be confused with a file descriptor returned by the OS.
+const _: [(); 1] = [(); (CWD_RAW_FD == imp::io::AT_FDCWD && CWD_RAW_FD < 0) as usize];
+
 /// `AT_FDCWD`—Returns a handle representing the current working directory.
 ///
-/// This returns a file descriptor which refers to the process current
-/// directory which can be used as the directory argument in `*at`
-/// functions such as [`openat`].
+/// This returns a pseudo file descriptor which can be used as the directory
+/// argument in `*at` functions such as [`openat`] and [`statat`], which then
+/// resolve relative paths against the process' current directory, exactly as
+/// the corresponding functions without a directory argument do.
+///
+/// It isn't an open directory, and the kernel only recognizes it in the
+/// directory arguments of `*at` functions. Passing it to other functions,
+/// such as [`fstat`], fails with [`io::Error::BADF`]. It doesn't need to be
+/// closed, and it follows changes to the current directory.
 ///
 /// # References
 ///  - [POSIX]
 ///
 /// [`openat`]: crate::fs::openat
+/// [`statat`]: crate::fs::statat
+/// [`fstat`]: crate::fs::fstat
+/// [`io::Error::BADF`]: crate::io::Error::BADF
 /// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/fcntl.h.html
 #[inline]
 #[doc(alias = "AT_FDCWD")]
 pub fn cwd() -> BorrowedFd<'static> {
-    let at_fdcwd = imp::io::AT_FDCWD as RawFd;
-
     // # Safety
     //
     // `AT_FDCWD` is a reserved value that is never dynamically allocated, so
     // it'll remain valid for the duration of 'static.
     #[allow(unsafe_code)]
     unsafe {
-        BorrowedFd::<'static>::borrow_raw_fd(at_fdcwd)
+        BorrowedFd::<'static>::borrow_raw_fd(CWD_RAW_FD)
     }
 }
+
+/// `AT_FDCWD`—Returns a handle representing the current working directory.
+///
+/// This is another name for [`cwd`].
+#[inline]
+pub fn current_dir_fd() -> BorrowedFd<'static> {
+    cwd()
+}
@@ -6,6 +6,11 @@ pub use imp::fs::Advice;
 /// `posix_fadvise(fd, offset, len, advice)`—Declares an expected access
 /// pattern for a file.
 ///
+/// The advice applies to the `len` bytes starting at `offset`, or to the
+/// rest of the file if `len` is zero. For example, [`Advice::WillNeed`]
+/// starts readahead of the region in the background, ahead of a sequential
+/// scan. The advice is only a hint, and may be ignored.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -0,0 +1,29 @@
+use crate::{imp, io};
+use io_lifetimes::AsFd;
+
+pub use imp::fs::FallocateFlags;
+
+/// `fallocate(fd, mode, offset, len)`—Adjusts file allocation.
+///
+/// This is a more general form of `posix_fallocate`, adding a `mode` argument
+/// which modifies the behavior. On platforms which only support
+/// `posix_fallocate` and not the more general form, no `FallocateFlags` values
+/// are defined so it will always be empty.
+///
+/// `mode` is passed to the OS as-is, so invalid combinations, such as
+/// `PUNCH_HOLE` without `KEEP_SIZE`, fail with [`io::Error::OPNOTSUPP`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux `fallocate`]
+///  - [Linux `posix_fallocate`]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/posix_fallocate.html
+/// [Linux `fallocate`]: https://man7.org/linux/man-pages/man2/fallocate.2.html
+/// [Linux `posix_fallocate`]: https://man7.org/linux/man-pages/man3/posix_fallocate.3.html
+#[inline]
+#[doc(alias = "posix_fallocate")]
+pub fn fallocate<Fd: AsFd>(fd: &Fd, mode: FallocateFlags, offset: u64, len: u64) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::fallocate(fd, mode, offset, len)
+}
@@ -1,7 +1,32 @@
 use crate::imp;
+#[cfg(not(target_os = "wasi"))]
+use crate::io::RawFd;
 use crate::io::{self, OwnedFd};
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+use crate::process::Pid;
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+use imp::fs::FlockType;
 use imp::fs::{FdFlags, OFlags};
 use io_lifetimes::AsFd;
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+use std::io::SeekFrom;
+
+#[cfg(any(
+    linux_raw,
+    all(
+        libc,
+        not(any(
+            target_os = "freebsd",
+            target_os = "ios",
+            target_os = "macos",
+            target_os = "netbsd",
+            target_os = "openbsd",
+            target_os = "redox",
+            target_os = "wasi",
+        ))
+    )
+))]
+pub use imp::fs::SealFlags;
 
 /// `fcntl(fd, F_GETFD)`—Returns a file descriptor's flags.
 ///
@@ -47,6 +72,22 @@ pub fn fcntl_getfl<Fd: AsFd>(fd: &Fd) -> io::Result<OFlags> {
 
 /// `fcntl(fd, F_SETFL, flags)`—Sets a file descriptor's status.
 ///
+/// Only some status flags can be changed this way; on Linux, these are
+/// [`OFlags::APPEND`], [`OFlags::NONBLOCK`], `O_ASYNC`, `O_DIRECT`, and
+/// `O_NOATIME`. The access mode and file creation flags in `flags` are
+/// ignored. To change one flag, get the current flags with [`fcntl_getfl`],
+/// modify them, and set them, as in:
+///
+/// ```rust,no_run
+/// # fn main() -> std::io::Result<()> {
+/// use rsix::fs::{fcntl_getfl, fcntl_setfl, OFlags};
+///
+/// let (reader, _writer) = rsix::io::pipe()?;
+/// fcntl_setfl(&reader, fcntl_getfl(&reader)? | OFlags::NONBLOCK)?;
+/// # Ok(())
+/// # }
+/// ```
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -59,7 +100,7 @@ pub fn fcntl_setfl<Fd: AsFd>(fd: &Fd, flags: OFlags) -> io::Result<()> {
     imp::syscalls::fcntl_setfl(fd, flags)
 }
 
-/// `fcntl(fd, F_GET_SEALS)`
+/// `fcntl(fd, F_GET_SEALS)`—Returns the seals on a file.
 ///
 /// # References
 ///  - [Linux]
@@ -81,11 +122,50 @@ pub fn fcntl_setfl<Fd: AsFd>(fd: &Fd, flags: OFlags) -> io::Result<()> {
     )
 ))]
 #[inline]
-pub fn fcntl_get_seals<Fd: AsFd>(fd: &Fd) -> io::Result<u32> {
+pub fn fcntl_get_seals<Fd: AsFd>(fd: &Fd) -> io::Result<SealFlags> {
     let fd = fd.as_fd();
     imp::syscalls::fcntl_get_seals(fd)
 }
 
+/// `fcntl(fd, F_ADD_SEALS, seals)`—Adds seals to a file, restricting the
+/// ways it can be modified.
+///
+/// Seals can only be added to files that support them, such as ones
+/// created by [`memfd_create`] with [`MemfdFlags::ALLOW_SEALING`], and
+/// never removed. Once [`SealFlags::WRITE`] is added, writes to the file
+/// fail with [`io::Error::PERM`]. Once [`SealFlags::SEAL`] is added, adding
+/// more seals fails with [`io::Error::PERM`].
+///
+/// Adding [`SealFlags::WRITE`] fails with [`io::Error::BUSY`] if the file has
+/// any shared writable memory mappings.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
+/// [`memfd_create`]: crate::fs::memfd_create
+/// [`MemfdFlags::ALLOW_SEALING`]: crate::fs::MemfdFlags::ALLOW_SEALING
+#[cfg(any(
+    linux_raw,
+    all(
+        libc,
+        not(any(
+            target_os = "freebsd",
+            target_os = "ios",
+            target_os = "macos",
+            target_os = "netbsd",
+            target_os = "openbsd",
+            target_os = "redox",
+            target_os = "wasi",
+        ))
+    )
+))]
+#[inline]
+pub fn fcntl_add_seals<Fd: AsFd>(fd: &Fd, seals: SealFlags) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::fcntl_add_seals(fd, seals)
+}
+
 /// `fcntl(fd, F_DUPFD_CLOEXEC)`—Creates a new `OwnedFd` instance that has
 /// `O_CLOEXEC` set and that shares the same underlying [file description] as
 /// `fd`.
@@ -104,5 +184,123 @@ pub fn fcntl_get_seals<Fd: AsFd>(fd: &Fd) -> io::Result<u32> {
 #[inline]
 pub fn fcntl_dupfd_cloexec<Fd: AsFd>(fd: &Fd) -> io::Result<OwnedFd> {
     let fd = fd.as_fd();
-    imp::syscalls::fcntl_dupfd_cloexec(fd)
+    imp::syscalls::fcntl_dupfd_cloexec(fd, 0)
+}
+
+/// `fcntl(fd, F_DUPFD, min)`—Creates a new `OwnedFd` instance that shares
+/// the same underlying [file description] as `fd`, using the lowest unused
+/// file descriptor number greater than or equal to `min`.
+///
+/// This is useful for keeping duplicates out of the range used for stdio
+/// and other well-known file descriptor numbers.
+///
+/// Note that this function does not set the `O_CLOEXEC` flag. To do a dup
+/// that does set `O_CLOEXEC`, use [`fcntl_dupfd_cloexec_from`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [file description]: https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/V1_chap03.html#tag_03_258
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/fcntl.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+#[doc(alias = "F_DUPFD")]
+pub fn fcntl_dupfd_from<Fd: AsFd>(fd: &Fd, min: RawFd) -> io::Result<OwnedFd> {
+    let fd = fd.as_fd();
+    imp::syscalls::fcntl_dupfd(fd, min)
+}
+
+/// `fcntl(fd, F_DUPFD_CLOEXEC, min)`—Creates a new `OwnedFd` instance that
+/// has `O_CLOEXEC` set and that shares the same underlying
+/// [file description] as `fd`, using the lowest unused file descriptor
+/// number greater than or equal to `min`.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [file description]: https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/V1_chap03.html#tag_03_258
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/fcntl.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+#[doc(alias = "F_DUPFD_CLOEXEC")]
+pub fn fcntl_dupfd_cloexec_from<Fd: AsFd>(fd: &Fd, min: RawFd) -> io::Result<OwnedFd> {
+    let fd = fd.as_fd();
+    imp::syscalls::fcntl_dupfd_cloexec(fd, min)
+}
+
+/// `struct flock`—A byte-range lock, for use with [`fcntl_getlk`],
+/// [`fcntl_setlk`], and [`fcntl_setlkw`].
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Flock {
+    /// `l_type`—The type of lock.
+    pub typ: FlockType,
+
+    /// `l_whence` and `l_start`—The start of the locked region.
+    pub start: SeekFrom,
+
+    /// `l_len`—The length of the locked region, where 0 means the region
+    /// extends to the end of the file, however large it grows.
+    pub len: i64,
+
+    /// `l_pid`—The process holding a conflicting lock, as reported by
+    /// [`fcntl_getlk`]. This is ignored when setting a lock.
+    pub pid: Pid,
+}
+
+/// `fcntl(fd, F_GETLK, lock)`—Tests whether a byte-range lock could be
+/// placed.
+///
+/// If `lock` could be placed, this returns it with its `typ` set to
+/// [`FlockType::Unlock`]. Otherwise, it returns a description of one of the
+/// conflicting locks, including the `pid` of its holder.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/fcntl.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[inline]
+pub fn fcntl_getlk<Fd: AsFd>(fd: &Fd, lock: &Flock) -> io::Result<Flock> {
+    let fd = fd.as_fd();
+    imp::syscalls::fcntl_getlk(fd, lock)
+}
+
+/// `fcntl(fd, F_SETLK, lock)`—Acquires or releases a byte-range lock,
+/// failing with [`io::Error::ACCES`] or [`io::Error::AGAIN`] if a
+/// conflicting lock is held.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/fcntl.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[inline]
+pub fn fcntl_setlk<Fd: AsFd>(fd: &Fd, lock: &Flock) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::fcntl_setlk(fd, lock)
+}
+
+/// `fcntl(fd, F_SETLKW, lock)`—Acquires or releases a byte-range lock,
+/// waiting for any conflicting locks to be released.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/fcntl.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[inline]
+pub fn fcntl_setlkw<Fd: AsFd>(fd: &Fd, lock: &Flock) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::fcntl_setlkw(fd, lock)
 }
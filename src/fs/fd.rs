@@ -1,8 +1,8 @@
 //! Functions which operate on file descriptors.
 
+#[cfg(not(target_os = "wasi"))]
+use crate::process::{Gid, Uid};
 use crate::{imp, io};
-#[cfg(not(any(target_os = "netbsd", target_os = "openbsd", target_os = "redox")))]
-use imp::fs::FallocateFlags;
 use imp::fs::Stat;
 #[cfg(not(any(target_os = "netbsd", target_os = "redox", target_os = "wasi")))]
 // not implemented in libc for netbsd yet
@@ -63,6 +63,23 @@ pub fn fchmod<Fd: AsFd>(fd: &Fd, mode: Mode) -> io::Result<()> {
     imp::syscalls::fchmod(fd, mode)
 }
 
+/// `fchown(fd, owner, group)`—Sets open file or directory ownership.
+///
+/// `None` for `owner` or `group` leaves that ID unchanged.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/fchown.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/fchown.2.html
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub fn fchown<Fd: AsFd>(fd: &Fd, owner: Option<Uid>, group: Option<Gid>) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::fchown(fd, owner, group)
+}
+
 /// `fstat(fd)`—Queries metadata for an open file or directory.
 ///
 /// # References
@@ -92,10 +109,17 @@ pub fn fstatfs<Fd: AsFd>(fd: &Fd) -> io::Result<StatFs> {
 
 /// `futimens(fd, times)`—Sets timestamps for an open file or directory.
 ///
+/// `times[0]` is the last access time and `times[1]` is the last
+/// modification time. Setting a `tv_nsec` to [`UTIME_NOW`] sets that
+/// timestamp to the current time, and setting it to [`UTIME_OMIT`] leaves
+/// that timestamp unchanged.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
 ///
+/// [`UTIME_NOW`]: crate::fs::UTIME_NOW
+/// [`UTIME_OMIT`]: crate::fs::UTIME_OMIT
 /// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/futimens.html
 /// [Linux]: https://man7.org/linux/man-pages/man2/futimens.2.html
 #[inline]
@@ -104,29 +128,6 @@ pub fn futimens<Fd: AsFd>(fd: &Fd, times: &[Timespec; 2]) -> io::Result<()> {
     imp::syscalls::futimens(fd, times)
 }
 
-/// `fallocate(fd, mode, offset, len)`—Adjusts file allocation.
-///
-/// This is a more general form of `posix_fallocate`, adding a `mode` argument
-/// which modifies the behavior. On platforms which only support
-/// `posix_fallocate` and not the more general form, no `FallocateFlags` values
-/// are defined so it will always be empty.
-///
-/// # References
-///  - [POSIX]
-///  - [Linux `fallocate`]
-///  - [Linux `posix_fallocate`]
-///
-/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/posix_fallocate.html
-/// [Linux `fallocate`]: https://man7.org/linux/man-pages/man2/fallocate.2.html
-/// [Linux `posix_fallocate`]: https://man7.org/linux/man-pages/man3/posix_fallocate.3.html
-#[cfg(not(any(target_os = "netbsd", target_os = "openbsd", target_os = "redox")))] // not implemented in libc for netbsd yet
-#[inline]
-#[doc(alias = "posix_fallocate")]
-pub fn fallocate<Fd: AsFd>(fd: &Fd, mode: FallocateFlags, offset: u64, len: u64) -> io::Result<()> {
-    let fd = fd.as_fd();
-    imp::syscalls::fallocate(fd, mode, offset, len)
-}
-
 /// `fcntl(fd, F_GETFL) & O_ACCMODE`
 ///
 /// Returns a pair of booleans indicating whether the file descriptor is
@@ -194,6 +195,24 @@ pub fn fdatasync<Fd: AsFd>(fd: &Fd) -> io::Result<()> {
     imp::syscalls::fdatasync(fd)
 }
 
+/// `syncfs(fd)`—Ensures that the data and metadata of all files on the
+/// filesystem containing `fd` is written to the underlying storage device.
+///
+/// `fd` may be any file descriptor open on the filesystem. To flush all
+/// filesystems, use [`sync`].
+///
+/// # References
+///  - [Linux]
+///
+/// [`sync`]: crate::io::sync
+/// [Linux]: https://man7.org/linux/man-pages/man2/syncfs.2.html
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[inline]
+pub fn syncfs<Fd: AsFd>(fd: &Fd) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::syncfs(fd)
+}
+
 /// `ftruncate(fd, length)`—Sets the length of a file.
 ///
 /// # References
@@ -1,7 +1,12 @@
 use crate::imp;
 use imp::fs::Dev;
 
-/// `makedev(maj, min)`
+/// `makedev(maj, min)`—Combines major and minor device numbers into a
+/// `Dev`.
+///
+/// On Linux, major and minor numbers are up to 32 bits each, and are
+/// interleaved in the 64-bit `Dev` for compatibility with the old 16-bit
+/// encoding, rather than simply concatenated.
 ///
 /// # References
 ///  - [Linux]
@@ -12,7 +17,7 @@ pub fn makedev(maj: u32, min: u32) -> Dev {
     imp::fs::makedev(maj, min)
 }
 
-/// `minor(dev)`
+/// `minor(dev)`—Extracts the minor device number from a `Dev`.
 ///
 /// # References
 ///  - [Linux]
@@ -23,7 +28,7 @@ pub fn minor(dev: Dev) -> u32 {
     imp::fs::minor(dev)
 }
 
-/// `major(dev)`
+/// `major(dev)`—Extracts the major device number from a `Dev`.
 ///
 /// # References
 ///  - [Linux]
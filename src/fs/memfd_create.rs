@@ -3,13 +3,19 @@ use crate::{imp, path};
 
 pub use imp::fs::MemfdFlags;
 
-/// `memfd_create(path, flags)`
+/// `memfd_create(name, flags)`—Creates an anonymous file that lives in
+/// memory.
+///
+/// The file starts out empty; give it a size with [`ftruncate`]. `name` is
+/// only used for debugging, as the target of the `/proc/self/fd` symlink for
+/// the file, so it needn't be unique.
 ///
 /// # References
 ///  - [Linux]
 ///
 /// [Linux]: https://man7.org/linux/man-pages/man2/memfd_create.2.html
+/// [`ftruncate`]: crate::fs::ftruncate
 #[inline]
-pub fn memfd_create<P: path::Arg>(path: P, flags: MemfdFlags) -> io::Result<OwnedFd> {
-    path.into_with_c_str(|path| imp::syscalls::memfd_create(path, flags))
+pub fn memfd_create<P: path::Arg>(name: P, flags: MemfdFlags) -> io::Result<OwnedFd> {
+    name.into_with_c_str(|name| imp::syscalls::memfd_create(name, flags))
 }
@@ -5,6 +5,8 @@ use imp::time::Nsecs;
 
 #[cfg(not(target_os = "redox"))]
 mod at;
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+mod canonicalize;
 mod constants;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 mod copy_file_range;
@@ -20,6 +22,9 @@ mod dir;
     target_os = "redox"
 )))]
 mod fadvise;
+// not implemented in libc for netbsd yet
+#[cfg(not(any(target_os = "netbsd", target_os = "openbsd", target_os = "redox")))]
+mod fallocate;
 pub(crate) mod fcntl;
 #[cfg(any(target_os = "ios", target_os = "macos"))]
 mod fcntl_rdadvise;
@@ -45,26 +50,36 @@ mod memfd_create;
 mod openat2;
 #[cfg(target_os = "linux")]
 mod sendfile;
-#[cfg(all(target_os = "linux", target_env = "gnu"))]
+mod stat;
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+mod statvfs;
+#[cfg(any(linux_raw, all(libc, target_os = "linux", target_env = "gnu")))]
 mod statx;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod sync_file_range;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod xattr;
 
-#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
-pub use at::chmodat;
 #[cfg(any(target_os = "ios", target_os = "macos"))]
 pub use at::fclonefileat;
+#[cfg(not(target_os = "redox"))]
+pub use at::{
+    access, accessat, linkat, lutimes, mkdirat, open, openat, readlinkat, readlinkat_into,
+    renameat, statat, symlinkat, truncate, unlinkat, utimensat, utimes,
+};
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+pub use at::{chmod, chmodat, chown, chownat, lchown};
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub use at::{linkat_fd, openat_tmpfile, rename_with, renameat_with};
 #[cfg(not(any(
     target_os = "ios",
     target_os = "macos",
     target_os = "redox",
     target_os = "wasi",
 )))]
-pub use at::mknodat;
-#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
-pub use at::renameat_with;
-#[cfg(not(target_os = "redox"))]
-pub use at::{
-    accessat, linkat, mkdirat, openat, readlinkat, renameat, statat, symlinkat, unlinkat, utimensat,
-};
+pub use at::{mkfifo, mkfifoat, mknodat};
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+pub use canonicalize::canonicalize;
 #[cfg(not(target_os = "redox"))]
 pub use constants::AtFlags;
 #[cfg(any(target_os = "ios", target_os = "macos"))]
@@ -80,7 +95,7 @@ pub use constants::{Access, FdFlags, Mode, OFlags};
 #[cfg(any(target_os = "android", target_os = "linux"))]
 pub use copy_file_range::copy_file_range;
 #[cfg(not(target_os = "redox"))]
-pub use cwd::cwd;
+pub use cwd::{current_dir_fd, cwd};
 #[cfg(not(target_os = "redox"))]
 pub use dir::{Dir, DirEntry};
 #[cfg(not(any(
@@ -91,8 +106,8 @@ pub use dir::{Dir, DirEntry};
     target_os = "redox"
 )))]
 pub use fadvise::{fadvise, Advice};
-#[cfg(not(target_os = "wasi"))]
-pub use fcntl::fcntl_dupfd_cloexec;
+#[cfg(not(any(target_os = "netbsd", target_os = "openbsd", target_os = "redox")))]
+pub use fallocate::{fallocate, FallocateFlags};
 #[cfg(not(any(
     target_os = "freebsd",
     target_os = "ios",
@@ -102,8 +117,12 @@ pub use fcntl::fcntl_dupfd_cloexec;
     target_os = "redox",
     target_os = "wasi",
 )))]
-pub use fcntl::fcntl_get_seals;
+pub use fcntl::{fcntl_add_seals, fcntl_get_seals, SealFlags};
+#[cfg(not(target_os = "wasi"))]
+pub use fcntl::{fcntl_dupfd_cloexec, fcntl_dupfd_cloexec_from, fcntl_dupfd_from};
 pub use fcntl::{fcntl_getfd, fcntl_getfl, fcntl_setfd, fcntl_setfl};
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+pub use fcntl::{fcntl_getlk, fcntl_setlk, fcntl_setlkw, Flock};
 #[cfg(any(target_os = "ios", target_os = "macos"))]
 pub use fcntl_rdadvise::fcntl_rdadvise;
 #[cfg(any(target_os = "ios", target_os = "macos"))]
@@ -111,15 +130,15 @@ pub use fcopyfile::{
     copyfile_state_alloc, copyfile_state_free, copyfile_state_get, copyfile_state_get_copied,
     copyfile_state_t, fcopyfile,
 };
-#[cfg(not(any(target_os = "netbsd", target_os = "openbsd", target_os = "redox")))]
-pub use fd::fallocate;
 #[cfg(not(any(target_os = "ios", target_os = "macos", target_os = "redox")))]
 pub use fd::fdatasync;
 #[cfg(not(any(target_os = "netbsd", target_os = "redox", target_os = "wasi")))]
 // not implemented in libc for netbsd yet
 pub use fd::fstatfs;
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub use fd::syncfs;
 #[cfg(not(target_os = "wasi"))]
-pub use fd::{fchmod, flock};
+pub use fd::{fchmod, fchown, flock};
 pub use fd::{fstat, fsync, ftruncate, futimens, is_file_read_write, seek, tell};
 pub use file_type::FileType;
 #[cfg(any(target_os = "ios", target_os = "macos"))]
@@ -140,8 +159,18 @@ pub use memfd_create::{memfd_create, MemfdFlags};
 pub use openat2::openat2;
 #[cfg(target_os = "linux")]
 pub use sendfile::sendfile;
-#[cfg(all(target_os = "linux", target_env = "gnu"))]
+pub use stat::StatExt;
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+pub use statvfs::{fstatvfs, statvfs, StatVfs, StatVfsMountFlags};
+#[cfg(any(linux_raw, all(libc, target_os = "linux", target_env = "gnu")))]
 pub use statx::{statx, StatxFlags};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use sync_file_range::{sync_file_range, SyncFileRangeFlags};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use xattr::{
+    fgetxattr, flistxattr, fremovexattr, fsetxattr, getxattr, lgetxattr, listxattr, llistxattr,
+    lremovexattr, lsetxattr, removexattr, setxattr, XattrFlags,
+};
 
 pub use imp::fs::Stat;
 
@@ -151,9 +180,6 @@ pub use imp::fs::StatFs;
 #[cfg(any(linux_raw, all(libc, target_os = "linux", target_env = "gnu")))]
 pub use imp::fs::Statx;
 
-#[cfg(not(any(target_os = "netbsd", target_os = "openbsd", target_os = "redox")))]
-pub use imp::fs::FallocateFlags;
-
 /// `UTIME_NOW` for use with [`utimensat`].
 ///
 /// [`utimensat`]: crate::fs::utimensat
@@ -179,6 +205,8 @@ pub const PROC_SUPER_MAGIC: FsWord = imp::fs::PROC_SUPER_MAGIC;
 
 #[cfg(not(target_os = "wasi"))]
 pub use imp::fs::FlockOperation;
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+pub use imp::fs::FlockType;
 pub use imp::fs::{Dev, RawMode};
 
 /// Re-export types common to POSIX-ish platforms.
@@ -5,6 +5,10 @@ use io_lifetimes::AsFd;
 
 /// `openat2(dirfd, path, OpenHow { oflags, mode, resolve }, sizeof(OpenHow))`
 ///
+/// On kernels which don't support `openat2`, this fails with
+/// [`io::Error::NOSYS`]; it doesn't fall back to `openat`, because that
+/// wouldn't honor the `resolve` restrictions.
+///
 /// # References
 ///  - [Linux]
 ///
@@ -1,7 +1,13 @@
 use crate::{imp, io};
 use io_lifetimes::AsFd;
 
-/// `sendfile(out_fd, in_fd, offset, count)`
+/// `sendfile(out_fd, in_fd, offset, count)`—Transfers data between file
+/// descriptors within the kernel.
+///
+/// If `offset` is `Some`, data is read from `in_fd` starting at that offset,
+/// the offset is advanced by the number of bytes transferred, and the file
+/// position of `in_fd` is left unchanged. If it's `None`, the file position
+/// is used and advanced instead.
 ///
 /// # References
 ///  - [Linux]
@@ -0,0 +1,102 @@
+//! Portable accessors for `Stat`.
+
+use crate::fs::{FileType, Mode, Stat};
+use crate::imp::fs::RawMode;
+use crate::time::Timespec;
+
+/// Portable accessors for the fields of a [`Stat`].
+///
+/// `Stat` is the platform's `struct stat`, so the names and types of its
+/// fields vary between platforms, and between 32-bit and 64-bit targets.
+/// These methods hide those differences.
+pub trait StatExt: private::Sealed {
+    /// `st_atime` and `st_atime_nsec`—The time of last access.
+    fn atime(&self) -> Timespec;
+
+    /// `st_mtime` and `st_mtime_nsec`—The time of last modification.
+    fn mtime(&self) -> Timespec;
+
+    /// `st_ctime` and `st_ctime_nsec`—The time of last status change.
+    fn ctime(&self) -> Timespec;
+
+    /// The file type, from the `S_IFMT` bits of `st_mode`.
+    fn file_type(&self) -> FileType;
+
+    /// `st_mode`—The file type and permission bits.
+    fn mode(&self) -> Mode;
+}
+
+#[cfg(not(target_os = "wasi"))]
+impl StatExt for Stat {
+    #[inline]
+    fn atime(&self) -> Timespec {
+        Timespec {
+            tv_sec: self.st_atime as _,
+            tv_nsec: self.st_atime_nsec as _,
+        }
+    }
+
+    #[inline]
+    fn mtime(&self) -> Timespec {
+        Timespec {
+            tv_sec: self.st_mtime as _,
+            tv_nsec: self.st_mtime_nsec as _,
+        }
+    }
+
+    #[inline]
+    fn ctime(&self) -> Timespec {
+        Timespec {
+            tv_sec: self.st_ctime as _,
+            tv_nsec: self.st_ctime_nsec as _,
+        }
+    }
+
+    #[inline]
+    fn file_type(&self) -> FileType {
+        FileType::from_raw_mode(self.st_mode as RawMode)
+    }
+
+    #[inline]
+    fn mode(&self) -> Mode {
+        Mode::from_raw_mode(self.st_mode as RawMode)
+    }
+}
+
+// WASI's `struct stat` has `struct timespec` fields instead.
+#[cfg(target_os = "wasi")]
+impl StatExt for Stat {
+    #[inline]
+    fn atime(&self) -> Timespec {
+        self.st_atim
+    }
+
+    #[inline]
+    fn mtime(&self) -> Timespec {
+        self.st_mtim
+    }
+
+    #[inline]
+    fn ctime(&self) -> Timespec {
+        self.st_ctim
+    }
+
+    #[inline]
+    fn file_type(&self) -> FileType {
+        FileType::from_raw_mode(self.st_mode)
+    }
+
+    #[inline]
+    fn mode(&self) -> Mode {
+        Mode::from_raw_mode(self.st_mode)
+    }
+}
+
+/// Seal `StatExt` using the technique documented [here].
+///
+/// [here]: https://rust-lang.github.io/api-guidelines/future-proofing.html
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for super::Stat {}
+}
@@ -0,0 +1,69 @@
+use crate::{imp, io, path};
+use io_lifetimes::AsFd;
+
+pub use imp::fs::StatVfsMountFlags;
+
+/// `struct statvfs`—Filesystem statistics, as returned by [`statvfs`] and
+/// [`fstatvfs`].
+///
+/// Unlike [`StatFs`], this has the same fields on all platforms. Block
+/// counts are in units of `f_frsize`.
+///
+/// [`StatFs`]: crate::fs::StatFs
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct StatVfs {
+    /// `f_bsize`—The preferred block size for I/O.
+    pub f_bsize: u64,
+    /// `f_frsize`—The fundamental block size.
+    pub f_frsize: u64,
+    /// `f_blocks`—The total number of blocks.
+    pub f_blocks: u64,
+    /// `f_bfree`—The number of free blocks.
+    pub f_bfree: u64,
+    /// `f_bavail`—The number of free blocks available to unprivileged
+    /// users.
+    pub f_bavail: u64,
+    /// `f_files`—The total number of file serial numbers, or inodes.
+    pub f_files: u64,
+    /// `f_ffree`—The number of free file serial numbers.
+    pub f_ffree: u64,
+    /// `f_favail`—The number of free file serial numbers available to
+    /// unprivileged users.
+    pub f_favail: u64,
+    /// `f_fsid`—The filesystem ID.
+    pub f_fsid: u64,
+    /// `f_flag`—The mount flags.
+    pub f_flag: StatVfsMountFlags,
+    /// `f_namemax`—The maximum length of a filename.
+    pub f_namemax: u64,
+}
+
+/// `statvfs(path)`—Queries filesystem statistics for the filesystem
+/// containing `path`.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/statvfs.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/statvfs.3.html
+#[inline]
+pub fn statvfs<P: path::Arg>(path: P) -> io::Result<StatVfs> {
+    path.into_with_c_str(imp::syscalls::statvfs)
+}
+
+/// `fstatvfs(fd)`—Queries filesystem statistics for the filesystem
+/// containing an open file or directory.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/fstatvfs.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/fstatvfs.3.html
+#[inline]
+pub fn fstatvfs<Fd: AsFd>(fd: &Fd) -> io::Result<StatVfs> {
+    let fd = fd.as_fd();
+    imp::syscalls::fstatvfs(fd)
+}
@@ -4,7 +4,6 @@ use crate::{imp, io, path};
 use imp::fs::{AtFlags, Statx};
 use io_lifetimes::AsFd;
 
-/// `STATX_*` constants.
 pub use imp::fs::StatxFlags;
 
 /// `statx(dirfd, path, flags, mask, statxbuf)`
@@ -0,0 +1,35 @@
+use crate::{imp, io};
+use io_lifetimes::AsFd;
+
+pub use imp::fs::SyncFileRangeFlags;
+
+/// `sync_file_range(fd, offset, nbytes, flags)`—Controls writeback of a
+/// range of a file's data to the underlying storage device.
+///
+/// This applies to the `nbytes` bytes starting at `offset`, or to the rest of
+/// the file if `nbytes` is zero. [`SyncFileRangeFlags::WRITE`] starts
+/// writeback of any dirty pages in the range, and
+/// [`SyncFileRangeFlags::WAIT_BEFORE`] and [`SyncFileRangeFlags::WAIT_AFTER`]
+/// wait for writeback already in progress before and after that.
+///
+/// Unlike [`fsync`] and [`fdatasync`], this doesn't write out any of the
+/// file's metadata, including metadata needed to read the data back, such as
+/// block allocations, and doesn't flush the device's write cache, so it
+/// doesn't make the data durable by itself.
+///
+/// # References
+///  - [Linux]
+///
+/// [`fsync`]: crate::fs::fsync
+/// [`fdatasync`]: crate::fs::fdatasync
+/// [Linux]: https://man7.org/linux/man-pages/man2/sync_file_range.2.html
+#[inline]
+pub fn sync_file_range<Fd: AsFd>(
+    fd: &Fd,
+    offset: u64,
+    nbytes: u64,
+    flags: SyncFileRangeFlags,
+) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::sync_file_range(fd, offset, nbytes, flags)
+}
@@ -0,0 +1,217 @@
+use crate::{imp, io, path};
+use io_lifetimes::AsFd;
+use std::ffi::CString;
+
+pub use imp::fs::XattrFlags;
+
+/// `getxattr(path, name, value, size)`—Returns the value of an extended
+/// attribute.
+///
+/// If the attribute doesn't exist, this fails with [`io::Error::NODATA`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/getxattr.2.html
+#[inline]
+pub fn getxattr<P: path::Arg, N: path::Arg>(path: P, name: N) -> io::Result<Vec<u8>> {
+    path.into_with_c_str(|path| {
+        name.into_with_c_str(|name| read_sized(|value| imp::syscalls::getxattr(path, name, value)))
+    })
+}
+
+/// `lgetxattr(path, name, value, size)`—Returns the value of an extended
+/// attribute, without following symlinks in the last path component.
+///
+/// If the attribute doesn't exist, this fails with [`io::Error::NODATA`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/getxattr.2.html
+#[inline]
+pub fn lgetxattr<P: path::Arg, N: path::Arg>(path: P, name: N) -> io::Result<Vec<u8>> {
+    path.into_with_c_str(|path| {
+        name.into_with_c_str(|name| read_sized(|value| imp::syscalls::lgetxattr(path, name, value)))
+    })
+}
+
+/// `fgetxattr(fd, name, value, size)`—Returns the value of an extended
+/// attribute.
+///
+/// If the attribute doesn't exist, this fails with [`io::Error::NODATA`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/getxattr.2.html
+#[inline]
+pub fn fgetxattr<Fd: AsFd, N: path::Arg>(fd: &Fd, name: N) -> io::Result<Vec<u8>> {
+    let fd = fd.as_fd();
+    name.into_with_c_str(|name| read_sized(|value| imp::syscalls::fgetxattr(fd, name, value)))
+}
+
+/// `setxattr(path, name, value, size, flags)`—Sets the value of an
+/// extended attribute.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/setxattr.2.html
+#[inline]
+pub fn setxattr<P: path::Arg, N: path::Arg>(
+    path: P,
+    name: N,
+    value: &[u8],
+    flags: XattrFlags,
+) -> io::Result<()> {
+    path.into_with_c_str(|path| {
+        name.into_with_c_str(|name| imp::syscalls::setxattr(path, name, value, flags))
+    })
+}
+
+/// `lsetxattr(path, name, value, size, flags)`—Sets the value of an
+/// extended attribute, without following symlinks in the last path
+/// component.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/setxattr.2.html
+#[inline]
+pub fn lsetxattr<P: path::Arg, N: path::Arg>(
+    path: P,
+    name: N,
+    value: &[u8],
+    flags: XattrFlags,
+) -> io::Result<()> {
+    path.into_with_c_str(|path| {
+        name.into_with_c_str(|name| imp::syscalls::lsetxattr(path, name, value, flags))
+    })
+}
+
+/// `fsetxattr(fd, name, value, size, flags)`—Sets the value of an
+/// extended attribute.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/setxattr.2.html
+#[inline]
+pub fn fsetxattr<Fd: AsFd, N: path::Arg>(
+    fd: &Fd,
+    name: N,
+    value: &[u8],
+    flags: XattrFlags,
+) -> io::Result<()> {
+    let fd = fd.as_fd();
+    name.into_with_c_str(|name| imp::syscalls::fsetxattr(fd, name, value, flags))
+}
+
+/// `listxattr(path, list, size)`—Returns the names of all extended
+/// attributes.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/listxattr.2.html
+#[inline]
+pub fn listxattr<P: path::Arg>(path: P) -> io::Result<Vec<CString>> {
+    path.into_with_c_str(|path| {
+        read_sized(|list| imp::syscalls::listxattr(path, list)).map(split_names)
+    })
+}
+
+/// `llistxattr(path, list, size)`—Returns the names of all extended
+/// attributes, without following symlinks in the last path component.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/listxattr.2.html
+#[inline]
+pub fn llistxattr<P: path::Arg>(path: P) -> io::Result<Vec<CString>> {
+    path.into_with_c_str(|path| {
+        read_sized(|list| imp::syscalls::llistxattr(path, list)).map(split_names)
+    })
+}
+
+/// `flistxattr(fd, list, size)`—Returns the names of all extended
+/// attributes.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/listxattr.2.html
+#[inline]
+pub fn flistxattr<Fd: AsFd>(fd: &Fd) -> io::Result<Vec<CString>> {
+    let fd = fd.as_fd();
+    read_sized(|list| imp::syscalls::flistxattr(fd, list)).map(split_names)
+}
+
+/// `removexattr(path, name)`—Removes an extended attribute.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/removexattr.2.html
+#[inline]
+pub fn removexattr<P: path::Arg, N: path::Arg>(path: P, name: N) -> io::Result<()> {
+    path.into_with_c_str(|path| name.into_with_c_str(|name| imp::syscalls::removexattr(path, name)))
+}
+
+/// `lremovexattr(path, name)`—Removes an extended attribute, without
+/// following symlinks in the last path component.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/removexattr.2.html
+#[inline]
+pub fn lremovexattr<P: path::Arg, N: path::Arg>(path: P, name: N) -> io::Result<()> {
+    path.into_with_c_str(|path| {
+        name.into_with_c_str(|name| imp::syscalls::lremovexattr(path, name))
+    })
+}
+
+/// `fremovexattr(fd, name)`—Removes an extended attribute.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/removexattr.2.html
+#[inline]
+pub fn fremovexattr<Fd: AsFd, N: path::Arg>(fd: &Fd, name: N) -> io::Result<()> {
+    let fd = fd.as_fd();
+    name.into_with_c_str(|name| imp::syscalls::fremovexattr(fd, name))
+}
+
+/// Call `f` with an empty buffer to query the size of the data, and then
+/// again with a buffer of that size to read it.
+fn read_sized<F: FnMut(&mut [u8]) -> io::Result<usize>>(mut f: F) -> io::Result<Vec<u8>> {
+    let mut buffer = Vec::new();
+
+    loop {
+        let len = f(&mut [])?;
+        buffer.resize(len, 0_u8);
+
+        match f(&mut buffer) {
+            Ok(nread) => {
+                assert!(nread <= buffer.len());
+                buffer.truncate(nread);
+                return Ok(buffer);
+            }
+            // The data grew between the two calls; query the size again.
+            Err(io::Error::RANGE) => continue,
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+/// Split a NUL-separated list of names, as returned by `listxattr`.
+fn split_names(list: Vec<u8>) -> Vec<CString> {
+    list.split(|byte| *byte == b'\0')
+        .filter(|name| !name.is_empty())
+        .map(|name| CString::new(name).unwrap())
+        .collect()
+}
@@ -36,14 +36,30 @@ pub use types::Advice;
 pub use types::FallocateFlags;
 #[cfg(not(target_os = "wasi"))]
 pub use types::FlockOperation;
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+pub use types::FlockType;
+#[cfg(not(any(
+    target_os = "freebsd",
+    target_os = "ios",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "redox",
+    target_os = "wasi",
+)))]
+pub use types::SealFlags;
 #[cfg(not(any(target_os = "netbsd", target_os = "redox", target_os = "wasi")))]
 pub use types::StatFs;
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+pub use types::StatVfsMountFlags;
 #[cfg(any(target_os = "ios", target_os = "macos"))]
 pub use types::{copyfile_state_t, CloneFlags, CopyfileFlags};
 pub use types::{Access, Dev, FdFlags, FileType, Mode, OFlags, RawMode, Stat};
 #[cfg(not(target_os = "redox"))]
 pub use types::{AtFlags, UTIME_NOW, UTIME_OMIT};
 #[cfg(any(target_os = "android", target_os = "linux"))]
-pub use types::{FsWord, MemfdFlags, RenameFlags, ResolveFlags, PROC_SUPER_MAGIC};
+pub use types::{
+    FsWord, MemfdFlags, RenameFlags, ResolveFlags, SyncFileRangeFlags, XattrFlags, PROC_SUPER_MAGIC,
+};
 #[cfg(all(target_os = "linux", target_env = "gnu"))]
 pub use types::{Statx, StatxFlags};
@@ -420,7 +420,7 @@ impl FileType {
 
     /// Construct a `FileType` from the `d_type` field of a `libc::dirent`.
     #[cfg(not(target_os = "redox"))]
-    pub(crate) const fn from_dirent_d_type(d_type: u8) -> Self {
+    pub const fn from_dirent_d_type(d_type: u8) -> Self {
         match d_type {
             libc::DT_REG => Self::RegularFile,
             libc::DT_DIR => Self::Directory,
@@ -480,11 +480,89 @@ bitflags! {
 
         /// `MFD_ALLOW_SEALING`
         const ALLOW_SEALING = libc::MFD_ALLOW_SEALING;
+
+        /// `MFD_HUGETLB` (since Linux 4.14)
+        const HUGETLB = libc::MFD_HUGETLB;
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+bitflags! {
+    /// `SYNC_FILE_RANGE_*` constants for use with [`sync_file_range`].
+    ///
+    /// [`sync_file_range`]: crate::fs::sync_file_range
+    pub struct SyncFileRangeFlags: libc::c_uint {
+        /// `SYNC_FILE_RANGE_WAIT_BEFORE`
+        const WAIT_BEFORE = libc::SYNC_FILE_RANGE_WAIT_BEFORE;
+
+        /// `SYNC_FILE_RANGE_WRITE`
+        const WRITE = libc::SYNC_FILE_RANGE_WRITE;
+
+        /// `SYNC_FILE_RANGE_WAIT_AFTER`
+        const WAIT_AFTER = libc::SYNC_FILE_RANGE_WAIT_AFTER;
+    }
+}
+
+#[cfg(not(any(
+    target_os = "freebsd",
+    target_os = "ios",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "redox",
+    target_os = "wasi",
+)))]
+bitflags! {
+    /// `F_SEAL_*` constants for use with [`fcntl_add_seals`] and
+    /// [`fcntl_get_seals`].
+    ///
+    /// [`fcntl_add_seals`]: crate::fs::fcntl_add_seals
+    /// [`fcntl_get_seals`]: crate::fs::fcntl_get_seals
+    pub struct SealFlags: u32 {
+        /// `F_SEAL_SEAL`
+        const SEAL = libc::F_SEAL_SEAL as u32;
+        /// `F_SEAL_SHRINK`
+        const SHRINK = libc::F_SEAL_SHRINK as u32;
+        /// `F_SEAL_GROW`
+        const GROW = libc::F_SEAL_GROW as u32;
+        /// `F_SEAL_WRITE`
+        const WRITE = libc::F_SEAL_WRITE as u32;
+        /// `F_SEAL_FUTURE_WRITE` (since Linux 5.1)
+        const FUTURE_WRITE = F_SEAL_FUTURE_WRITE;
+    }
+}
+
+// The libc crate doesn't yet define `F_SEAL_FUTURE_WRITE`.
+#[cfg(not(any(
+    target_os = "freebsd",
+    target_os = "ios",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "redox",
+    target_os = "wasi",
+)))]
+const F_SEAL_FUTURE_WRITE: u32 = 0x10;
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+bitflags! {
+    /// `XATTR_*` constants for use with [`setxattr`].
+    ///
+    /// [`setxattr`]: crate::fs::setxattr
+    pub struct XattrFlags: libc::c_int {
+        /// `XATTR_CREATE`
+        const CREATE = libc::XATTR_CREATE;
+
+        /// `XATTR_REPLACE`
+        const REPLACE = libc::XATTR_REPLACE;
     }
 }
 
 #[cfg(all(target_os = "linux", target_env = "gnu"))]
 bitflags! {
+    /// `STATX_*` constants for use with [`statx`].
+    ///
+    /// [`statx`]: crate::fs::statx
     pub struct StatxFlags: u32 {
         /// `STATX_TYPE`
         const TYPE = libc::STATX_TYPE;
@@ -630,6 +708,38 @@ pub enum FlockOperation {
     NonBlockingUnlock = libc::LOCK_UN | libc::LOCK_NB,
 }
 
+/// `F_*LCK` constants for use with [`Flock`].
+///
+/// [`Flock`]: crate::fs::Flock
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(i32)]
+pub enum FlockType {
+    /// `F_RDLCK`
+    ReadLock = libc::F_RDLCK as i32,
+    /// `F_WRLCK`
+    WriteLock = libc::F_WRLCK as i32,
+    /// `F_UNLCK`
+    Unlock = libc::F_UNLCK as i32,
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+impl FlockType {
+    /// Construct a `FlockType` from the `l_type` field of a `flock`.
+    #[inline]
+    pub(crate) const fn from_raw(l_type: libc::c_short) -> Self {
+        // The type of the `F_*LCK` constants varies between platforms, so
+        // compare with casts rather than matching.
+        if l_type == libc::F_RDLCK as libc::c_short {
+            Self::ReadLock
+        } else if l_type == libc::F_WRLCK as libc::c_short {
+            Self::WriteLock
+        } else {
+            Self::Unlock
+        }
+    }
+}
+
 /// `struct stat` for use with [`statat`] and [`fstat`].
 ///
 /// [`statat`]: crate::fs::statat
@@ -680,11 +790,59 @@ pub type StatFs = libc::statfs;
 ))]
 pub type StatFs = libc::statfs64;
 
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+bitflags! {
+    /// `ST_*` constants for use with [`StatVfs`].
+    ///
+    /// [`StatVfs`]: crate::fs::StatVfs
+    pub struct StatVfsMountFlags: u64 {
+        /// `ST_RDONLY`
+        const RDONLY = libc::ST_RDONLY as u64;
+
+        /// `ST_NOSUID`
+        const NOSUID = libc::ST_NOSUID as u64;
+
+        /// `ST_NODEV`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const NODEV = libc::ST_NODEV as u64;
+
+        /// `ST_NOEXEC`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const NOEXEC = libc::ST_NOEXEC as u64;
+
+        /// `ST_SYNCHRONOUS`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const SYNCHRONOUS = libc::ST_SYNCHRONOUS as u64;
+
+        /// `ST_MANDLOCK`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const MANDLOCK = libc::ST_MANDLOCK as u64;
+
+        /// `ST_NOATIME`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const NOATIME = libc::ST_NOATIME as u64;
+
+        /// `ST_NODIRATIME`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const NODIRATIME = libc::ST_NODIRATIME as u64;
+
+        /// `ST_RELATIME`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const RELATIME = libc::ST_RELATIME as u64;
+    }
+}
+
 /// `struct statx` for use with [`statx`].
 ///
+/// Only the fields requested in the `mask` argument to `statx`, and reported
+/// in `stx_mask`, are meaningful. In addition to the fields of [`Stat`], this
+/// provides the file's birth time, in `stx_btime`, when the filesystem
+/// supports it. The device is split into `stx_dev_major` and `stx_dev_minor`.
+///
 /// Only available on Linux with GLIBC for now.
 ///
 /// [`statx`]: crate::fs::statx
+/// [`Stat`]: crate::fs::Stat
 #[cfg(all(target_os = "linux", target_env = "gnu"))]
 pub type Statx = libc::statx;
 
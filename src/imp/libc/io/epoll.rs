@@ -425,12 +425,12 @@ impl<'context, Context: self::Context> Iterator for Iter<'context, Context> {
     ),
     repr(packed)
 )]
-struct Event {
+pub(crate) struct Event {
     // Match the layout of `libc::epoll_event`. We just use a `u64` instead of
     // the full union; `Context` implementations will simply need to deal with
     // casting the value into and out of the `u64` themselves.
-    event_flags: EventFlags,
-    encoded: u64,
+    pub(crate) event_flags: EventFlags,
+    pub(crate) encoded: u64,
 }
 
 pub struct EventVec<'context, Context: self::Context> {
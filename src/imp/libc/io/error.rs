@@ -713,6 +713,22 @@ impl Error {
         self.0
     }
 
+    /// Convert from a raw OS error number, such as a `SO_ERROR` value, to an
+    /// `Error`.
+    ///
+    /// Error numbers are in `1..4096` on all supported platforms. Values
+    /// outside that range can't be OS error numbers, and are converted to
+    /// [`Error::INVAL`].
+    #[inline]
+    pub const fn from_raw_os_error(raw: i32) -> Self {
+        // TODO: Use Range::contains, once that's `const`.
+        if raw >= 1 && raw < 4096 {
+            Self(raw)
+        } else {
+            Self::INVAL
+        }
+    }
+
     pub(crate) fn last_os_error() -> Self {
         Self(errno().0)
     }
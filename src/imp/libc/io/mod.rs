@@ -15,10 +15,18 @@ pub use types::Advice;
 pub use types::PipeFlags;
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 pub use types::PIPE_BUF;
-#[cfg(not(target_os = "wasi"))]
-pub use types::{DupFlags, MapFlags, MprotectFlags, ProtFlags, Tcflag, Termios, Winsize, ICANON};
 #[cfg(any(target_os = "android", target_os = "linux"))]
-pub use types::{EventfdFlags, MlockFlags, ReadWriteFlags, UserfaultfdFlags};
+pub use types::{
+    CloseRangeFlags, EventfdFlags, InotifyFlags, MlockFlags, ReadWriteFlags, SignalfdFlags,
+    SpliceFlags, UserfaultfdFlags, WatchFlags,
+};
+#[cfg(not(target_os = "wasi"))]
+pub use types::{
+    ControlFlags, DupFlags, InputFlags, LocalFlags, MapFlags, MprotectFlags, OptionalActions,
+    OutputFlags, ProtFlags, QueueSelector, Tcflag, Termios, Winsize, ICANON,
+};
+#[cfg(not(target_os = "wasi"))]
+pub(crate) use types::{VMIN, VTIME};
 
 use libc::c_int;
 
@@ -71,7 +71,7 @@ impl<'fd> PollFd<'fd> {
         }
     }
 
-    /// Return the ready events.
+    /// Returns the ready events.
     #[inline]
     pub fn revents(self) -> PollFlags {
         // Use `unwrap()` here because in theory we know we know all the bits
@@ -264,6 +264,41 @@ bitflags! {
     }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+bitflags! {
+    /// `SPLICE_F_*` constants for use with [`splice`], [`tee`], and
+    /// [`vmsplice`].
+    ///
+    /// [`splice`]: crate::io::splice
+    /// [`tee`]: crate::io::tee
+    /// [`vmsplice`]: crate::io::vmsplice
+    pub struct SpliceFlags: libc::c_uint {
+        /// `SPLICE_F_MOVE`
+        const MOVE = libc::SPLICE_F_MOVE;
+        /// `SPLICE_F_NONBLOCK`
+        const NONBLOCK = libc::SPLICE_F_NONBLOCK;
+        /// `SPLICE_F_MORE`
+        const MORE = libc::SPLICE_F_MORE;
+        /// `SPLICE_F_GIFT`
+        const GIFT = libc::SPLICE_F_GIFT;
+    }
+}
+
+// The libc crate doesn't yet include the `CLOSE_RANGE_*` constants, so we
+// define them ourselves.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+bitflags! {
+    /// `CLOSE_RANGE_*` flags for use with [`close_range`].
+    ///
+    /// [`close_range`]: crate::io::close_range
+    pub struct CloseRangeFlags: libc::c_uint {
+        /// `CLOSE_RANGE_UNSHARE`
+        const UNSHARE = 1 << 1;
+        /// `CLOSE_RANGE_CLOEXEC`
+        const CLOEXEC = 1 << 2;
+    }
+}
+
 #[cfg(any(target_os = "android", target_os = "linux"))]
 bitflags! {
     /// The `O_*` flags accepted by [`userfaultfd`].
@@ -292,6 +327,19 @@ bitflags! {
     }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+bitflags! {
+    /// The `SFD_*` flags accepted by [`signalfd`].
+    ///
+    /// [`signalfd`]: crate::io::signalfd
+    pub struct SignalfdFlags: libc::c_int {
+        /// `SFD_CLOEXEC`
+        const CLOEXEC = libc::SFD_CLOEXEC;
+        /// `SFD_NONBLOCK`
+        const NONBLOCK = libc::SFD_NONBLOCK;
+    }
+}
+
 /// `POSIX_MADV_*` constants for use with [`madvise`].
 ///
 /// [`madvise`]: crate::io::madvise
@@ -398,13 +446,202 @@ impl Advice {
     pub const DontNeed: Self = Self::Normal;
 }
 
-/// `struct termios`, for use with [`ioctl_tcgets`].
+/// `struct termios`, for use with [`tcgetattr`] and [`tcsetattr`].
 ///
-/// [`ioctl_tcgets`]: crate::io::ioctl_tcgets
+/// [`tcgetattr`]: crate::io::tcgetattr
+/// [`tcsetattr`]: crate::io::tcsetattr
 #[cfg(not(target_os = "wasi"))]
 pub type Termios = libc::termios;
 
-/// `struct winsize`
+/// `TCSA*` values for use with [`tcsetattr`].
+///
+/// [`tcsetattr`]: crate::io::tcsetattr
+#[cfg(not(target_os = "wasi"))]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(i32)]
+pub enum OptionalActions {
+    /// `TCSANOW`—Make the change immediately.
+    Now = libc::TCSANOW,
+
+    /// `TCSADRAIN`—Make the change after all queued output has been
+    /// transmitted.
+    Drain = libc::TCSADRAIN,
+
+    /// `TCSAFLUSH`—Make the change after all queued output has been
+    /// transmitted, and discard any unread input.
+    Flush = libc::TCSAFLUSH,
+}
+
+/// `TC*FLUSH` values for use with [`tcflush`].
+///
+/// [`tcflush`]: crate::io::tcflush
+#[cfg(not(target_os = "wasi"))]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(i32)]
+pub enum QueueSelector {
+    /// `TCIFLUSH`—Discard data received but not yet read.
+    IFlush = libc::TCIFLUSH,
+
+    /// `TCOFLUSH`—Discard data written but not yet transmitted.
+    OFlush = libc::TCOFLUSH,
+
+    /// `TCIOFLUSH`—Discard both data received but not yet read, and data
+    /// written but not yet transmitted.
+    IOFlush = libc::TCIOFLUSH,
+}
+
+#[cfg(not(target_os = "wasi"))]
+bitflags! {
+    /// Input modes, in the `c_iflag` field of a [`Termios`].
+    pub struct InputFlags: Tcflag {
+        /// `IGNBRK`
+        const IGNBRK = libc::IGNBRK;
+
+        /// `BRKINT`
+        const BRKINT = libc::BRKINT;
+
+        /// `IGNPAR`
+        const IGNPAR = libc::IGNPAR;
+
+        /// `PARMRK`
+        const PARMRK = libc::PARMRK;
+
+        /// `INPCK`
+        const INPCK = libc::INPCK;
+
+        /// `ISTRIP`
+        const ISTRIP = libc::ISTRIP;
+
+        /// `INLCR`
+        const INLCR = libc::INLCR;
+
+        /// `IGNCR`
+        const IGNCR = libc::IGNCR;
+
+        /// `ICRNL`
+        const ICRNL = libc::ICRNL;
+
+        /// `IXON`
+        const IXON = libc::IXON;
+
+        /// `IXANY`
+        const IXANY = libc::IXANY;
+
+        /// `IXOFF`
+        const IXOFF = libc::IXOFF;
+    }
+}
+
+#[cfg(not(target_os = "wasi"))]
+bitflags! {
+    /// Output modes, in the `c_oflag` field of a [`Termios`].
+    pub struct OutputFlags: Tcflag {
+        /// `OPOST`
+        const OPOST = libc::OPOST;
+
+        /// `ONLCR`
+        const ONLCR = libc::ONLCR;
+
+        /// `OCRNL`
+        const OCRNL = libc::OCRNL;
+
+        /// `ONOCR`
+        const ONOCR = libc::ONOCR;
+
+        /// `ONLRET`
+        const ONLRET = libc::ONLRET;
+    }
+}
+
+#[cfg(not(target_os = "wasi"))]
+bitflags! {
+    /// Control modes, in the `c_cflag` field of a [`Termios`].
+    pub struct ControlFlags: Tcflag {
+        /// `CSIZE`—The mask of the character size bits.
+        const CSIZE = libc::CSIZE;
+
+        /// `CS5`
+        const CS5 = libc::CS5;
+
+        /// `CS6`
+        const CS6 = libc::CS6;
+
+        /// `CS7`
+        const CS7 = libc::CS7;
+
+        /// `CS8`
+        const CS8 = libc::CS8;
+
+        /// `CSTOPB`
+        const CSTOPB = libc::CSTOPB;
+
+        /// `CREAD`
+        const CREAD = libc::CREAD;
+
+        /// `PARENB`
+        const PARENB = libc::PARENB;
+
+        /// `PARODD`
+        const PARODD = libc::PARODD;
+
+        /// `HUPCL`
+        const HUPCL = libc::HUPCL;
+
+        /// `CLOCAL`
+        const CLOCAL = libc::CLOCAL;
+    }
+}
+
+#[cfg(not(target_os = "wasi"))]
+bitflags! {
+    /// Local modes, in the `c_lflag` field of a [`Termios`].
+    pub struct LocalFlags: Tcflag {
+        /// `ISIG`
+        const ISIG = libc::ISIG;
+
+        /// `ICANON`
+        const ICANON = libc::ICANON;
+
+        /// `ECHO`
+        const ECHO = libc::ECHO;
+
+        /// `ECHOE`
+        const ECHOE = libc::ECHOE;
+
+        /// `ECHOK`
+        const ECHOK = libc::ECHOK;
+
+        /// `ECHONL`
+        const ECHONL = libc::ECHONL;
+
+        /// `NOFLSH`
+        const NOFLSH = libc::NOFLSH;
+
+        /// `TOSTOP`
+        const TOSTOP = libc::TOSTOP;
+
+        /// `IEXTEN`
+        const IEXTEN = libc::IEXTEN;
+    }
+}
+
+/// `VMIN`—The index of the minimum number of bytes for a non-canonical read
+/// in `c_cc`.
+#[cfg(not(target_os = "wasi"))]
+pub(crate) const VMIN: usize = libc::VMIN as usize;
+
+/// `VTIME`—The index of the timeout for a non-canonical read in `c_cc`.
+#[cfg(not(target_os = "wasi"))]
+pub(crate) const VTIME: usize = libc::VTIME as usize;
+
+/// `struct winsize`, for use with [`ioctl_tiocgwinsz`] and
+/// [`ioctl_tiocswinsz`].
+///
+/// Its `ws_row`, `ws_col`, `ws_xpixel`, and `ws_ypixel` fields are public,
+/// so it can be constructed directly.
+///
+/// [`ioctl_tiocgwinsz`]: crate::io::ioctl_tiocgwinsz
+/// [`ioctl_tiocswinsz`]: crate::io::ioctl_tiocswinsz
 #[cfg(not(target_os = "wasi"))]
 pub type Winsize = libc::winsize;
 
@@ -416,3 +653,71 @@ pub const ICANON: Tcflag = libc::ICANON;
 
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 pub const PIPE_BUF: usize = libc::PIPE_BUF;
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+bitflags! {
+    /// The `IN_*` flags accepted by [`inotify_init1`].
+    ///
+    /// [`inotify_init1`]: crate::io::inotify::inotify_init1
+    pub struct InotifyFlags: c_int {
+        /// `IN_CLOEXEC`
+        const CLOEXEC = libc::IN_CLOEXEC;
+        /// `IN_NONBLOCK`
+        const NONBLOCK = libc::IN_NONBLOCK;
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+bitflags! {
+    /// The `IN_*` event mask bits accepted by [`inotify_add_watch`] and
+    /// reported in [`InotifyEvent`].
+    ///
+    /// [`inotify_add_watch`]: crate::io::inotify::inotify_add_watch
+    /// [`InotifyEvent`]: crate::io::inotify::InotifyEvent
+    pub struct WatchFlags: u32 {
+        /// `IN_ACCESS`
+        const ACCESS = libc::IN_ACCESS;
+        /// `IN_MODIFY`
+        const MODIFY = libc::IN_MODIFY;
+        /// `IN_ATTRIB`
+        const ATTRIB = libc::IN_ATTRIB;
+        /// `IN_CLOSE_WRITE`
+        const CLOSE_WRITE = libc::IN_CLOSE_WRITE;
+        /// `IN_CLOSE_NOWRITE`
+        const CLOSE_NOWRITE = libc::IN_CLOSE_NOWRITE;
+        /// `IN_OPEN`
+        const OPEN = libc::IN_OPEN;
+        /// `IN_MOVED_FROM`
+        const MOVED_FROM = libc::IN_MOVED_FROM;
+        /// `IN_MOVED_TO`
+        const MOVED_TO = libc::IN_MOVED_TO;
+        /// `IN_CREATE`
+        const CREATE = libc::IN_CREATE;
+        /// `IN_DELETE`
+        const DELETE = libc::IN_DELETE;
+        /// `IN_DELETE_SELF`
+        const DELETE_SELF = libc::IN_DELETE_SELF;
+        /// `IN_MOVE_SELF`
+        const MOVE_SELF = libc::IN_MOVE_SELF;
+        /// `IN_UNMOUNT`
+        const UNMOUNT = libc::IN_UNMOUNT;
+        /// `IN_Q_OVERFLOW`
+        const Q_OVERFLOW = libc::IN_Q_OVERFLOW;
+        /// `IN_IGNORED`
+        const IGNORED = libc::IN_IGNORED;
+        /// `IN_ONLYDIR`
+        const ONLYDIR = libc::IN_ONLYDIR;
+        /// `IN_DONT_FOLLOW`
+        const DONT_FOLLOW = libc::IN_DONT_FOLLOW;
+        /// `IN_EXCL_UNLINK`
+        const EXCL_UNLINK = libc::IN_EXCL_UNLINK;
+        /// `IN_MASK_CREATE`
+        const MASK_CREATE = libc::IN_MASK_CREATE;
+        /// `IN_MASK_ADD`
+        const MASK_ADD = libc::IN_MASK_ADD;
+        /// `IN_ISDIR`
+        const ISDIR = libc::IN_ISDIR;
+        /// `IN_ONESHOT`
+        const ONESHOT = libc::IN_ONESHOT;
+    }
+}
@@ -1,8 +1,9 @@
 //! IPv4, IPv6, and Socket addresses.
 
 use super::AddressFamily;
-use crate::{io, path};
-use std::ffi::CString;
+use crate::{as_ptr, io, path};
+use std::cmp::min;
+use std::ffi::{CStr, CString};
 use std::fmt;
 #[cfg(any(
     target_os = "netbsd",
@@ -376,7 +377,19 @@ impl fmt::Debug for SocketAddrV6 {
 #[derive(Clone)]
 #[doc(alias = "sockaddr_un")]
 pub struct SocketAddrUnix {
-    path: CString,
+    name: UnixName,
+}
+
+/// The forms of name a Unix-domain socket can have.
+#[derive(Clone)]
+enum UnixName {
+    /// No name, as for a socket that was never bound.
+    Unnamed,
+    /// A filesystem path.
+    Path(CString),
+    /// A name in the abstract namespace, without its leading NUL.
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Abstract(Vec<u8>),
 }
 
 impl SocketAddrUnix {
@@ -419,10 +432,78 @@ impl SocketAddrUnix {
             )))]
             sun_path: [0; 108],
         };
-        if bytes.len() + 1 > z.sun_path.len() {
+        // A path that fills `sun_path` is passed without a NUL terminator.
+        if bytes.len() > z.sun_path.len() {
+            return Err(io::Error::NAMETOOLONG);
+        }
+        Ok(Self {
+            name: UnixName::Path(path),
+        })
+    }
+
+    /// Construct an unnamed Unix-domain address, as reported for a peer
+    /// socket that was never bound.
+    #[inline]
+    pub(crate) const fn new_unnamed() -> Self {
+        Self {
+            name: UnixName::Unnamed,
+        }
+    }
+
+    /// Construct a new Unix-domain address in the abstract namespace.
+    ///
+    /// `name` is the name without the leading NUL byte that marks it as
+    /// abstract. It may contain arbitrary bytes, including NULs.
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub fn new_abstract(name: &[u8]) -> io::Result<Self> {
+        let z = libc::sockaddr_un {
+            sun_family: 0,
+            sun_path: [0; 108],
+        };
+        if name.len() + 1 > z.sun_path.len() {
             return Err(io::Error::NAMETOOLONG);
         }
-        Ok(Self { path })
+        Ok(Self {
+            name: UnixName::Abstract(name.to_vec()),
+        })
+    }
+
+    /// Return the filesystem path of this address, or `None` if it isn't a
+    /// filesystem path.
+    #[inline]
+    pub fn path(&self) -> Option<&CStr> {
+        match &self.name {
+            UnixName::Path(path) => Some(path),
+            UnixName::Unnamed => None,
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            UnixName::Abstract(_) => None,
+        }
+    }
+
+    /// Test whether this address is unnamed, as reported for a peer socket
+    /// that was never bound.
+    #[inline]
+    pub fn is_unnamed(&self) -> bool {
+        matches!(self.name, UnixName::Unnamed)
+    }
+
+    /// Test whether this address is in the abstract namespace.
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub fn is_abstract(&self) -> bool {
+        matches!(self.name, UnixName::Abstract(_))
+    }
+
+    /// Return the name of this address in the abstract namespace, without
+    /// its leading NUL byte, or `None` if it isn't an abstract address.
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub fn abstract_name(&self) -> Option<&[u8]> {
+        match &self.name {
+            UnixName::Unnamed | UnixName::Path(_) => None,
+            UnixName::Abstract(name) => Some(name),
+        }
     }
 
     /// Encode this socket address in the host format.
@@ -455,18 +536,56 @@ impl SocketAddrUnix {
             )))]
             sun_path: [0; 108],
         };
-        let bytes = self.path.as_bytes();
-        for (i, b) in bytes.iter().enumerate() {
-            encoded.sun_path[i] = *b as libc::c_char;
+        match &self.name {
+            UnixName::Unnamed => (),
+            UnixName::Path(path) => {
+                let bytes = path.as_bytes();
+                for (i, b) in bytes.iter().enumerate() {
+                    encoded.sun_path[i] = *b as libc::c_char;
+                }
+                if bytes.len() < encoded.sun_path.len() {
+                    encoded.sun_path[bytes.len()] = b'\0' as libc::c_char;
+                }
+            }
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            UnixName::Abstract(name) => {
+                // The leading NUL is already there; abstract names aren't
+                // NUL-terminated.
+                for (i, b) in name.iter().enumerate() {
+                    encoded.sun_path[1 + i] = *b as libc::c_char;
+                }
+            }
         }
-        encoded.sun_path[bytes.len()] = b'\0' as libc::c_char;
         encoded
     }
+
+    /// Return the length of the encoded address, to pass as the `addrlen`
+    /// argument along with it.
+    #[inline]
+    pub(crate) fn addr_len(&self) -> libc::socklen_t {
+        let encoded = self.encode();
+        let offsetof_sun_path = (as_ptr(&encoded.sun_path) as usize) - (as_ptr(&encoded) as usize);
+        let sun_path_len = match &self.name {
+            UnixName::Unnamed => 0,
+            UnixName::Path(path) => min(path.as_bytes().len() + 1, encoded.sun_path.len()),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            UnixName::Abstract(name) => 1 + name.len(),
+        };
+        (offsetof_sun_path + sun_path_len) as _
+    }
 }
 
 impl fmt::Debug for SocketAddrUnix {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.path.fmt(fmt)
+        match &self.name {
+            UnixName::Unnamed => fmt.write_str("Unnamed"),
+            UnixName::Path(path) => path.fmt(fmt),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            UnixName::Abstract(name) => fmt
+                .debug_tuple("Abstract")
+                .field(&String::from_utf8_lossy(name))
+                .finish(),
+        }
     }
 }
 
@@ -134,25 +134,42 @@ pub(crate) unsafe fn decode_sockaddr(storage: *const sockaddr_storage, len: u32)
         libc::AF_UNIX => {
             assert!(len as usize >= offsetof_sun_path);
             if len as usize == offsetof_sun_path {
-                SocketAddr::Unix(SocketAddrUnix::new(&[][..]).unwrap())
+                SocketAddr::Unix(SocketAddrUnix::new_unnamed())
             } else {
                 let decode = *storage.cast::<libc::sockaddr_un>();
-                assert_eq!(
-                    decode.sun_path[len as usize - 1 - offsetof_sun_path],
-                    b'\0' as libc::c_char
-                );
-                let path_bytes = &decode.sun_path[..len as usize - 1 - offsetof_sun_path];
+                let sun_path_len = (len as usize - offsetof_sun_path).min(decode.sun_path.len());
+                let path_bytes = decode.sun_path[..sun_path_len]
+                    .iter()
+                    .map(|c| *c as u8)
+                    .collect::<Vec<u8>>();
 
-                // FreeBSD sometimes sets the length to longer than the length
-                // of the NUL-terminated string. Find the NUL and truncate the
-                // string accordingly.
-                #[cfg(target_os = "freebsd")]
-                let path_bytes = &path_bytes[..path_bytes.iter().position(|b| *b == 0).unwrap()];
+                // An abstract name, which follows the leading NUL and isn't
+                // NUL-terminated.
+                #[cfg(any(target_os = "android", target_os = "linux"))]
+                if path_bytes[0] == b'\0' {
+                    return SocketAddr::Unix(
+                        SocketAddrUnix::new_abstract(&path_bytes[1..]).unwrap(),
+                    );
+                }
 
-                SocketAddr::Unix(
-                    SocketAddrUnix::new(path_bytes.iter().map(|c| *c as u8).collect::<Vec<u8>>())
-                        .unwrap(),
-                )
+                // A filesystem path. Linux may report it with its NUL
+                // terminator included in `len`, and FreeBSD sometimes sets
+                // `len` to longer than the length of the NUL-terminated
+                // string, so find the NUL and truncate the string there.
+                let path_len = path_bytes
+                    .iter()
+                    .position(|b| *b == b'\0')
+                    .unwrap_or(path_bytes.len());
+
+                // BSDs report unnamed peers with an empty path rather than
+                // with a short `len`.
+                if path_len == 0 {
+                    return SocketAddr::Unix(SocketAddrUnix::new_unnamed());
+                }
+
+                // This can't fail: the path has no NULs, and it fits in
+                // `sun_path`, even if it fills it with no NUL terminator.
+                SocketAddr::Unix(SocketAddrUnix::new(&path_bytes[..path_len]).unwrap())
             }
         }
         other => unimplemented!("{:?}", other),
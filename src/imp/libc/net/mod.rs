@@ -6,5 +6,9 @@ mod types;
 pub(crate) use decode_sockaddr::decode_sockaddr;
 
 pub use addr::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrUnix, SocketAddrV4, SocketAddrV6};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use send_recv::ReturnFlags;
 pub use send_recv::{RecvFlags, SendFlags};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) use send_recv::{SCM_RIGHTS, SOL_SOCKET};
 pub use types::{AcceptFlags, AddressFamily, Protocol, Shutdown, SocketType};
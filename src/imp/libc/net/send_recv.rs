@@ -44,3 +44,32 @@ bitflags! {
         const WAITALL = libc::MSG_WAITALL;
     }
 }
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+bitflags! {
+    /// `MSG_*` flags returned by [`recvmsg`] in `msg_flags`.
+    ///
+    /// [`recvmsg`]: crate::net::recvmsg
+    pub struct ReturnFlags: i32 {
+        /// `MSG_CMSG_CLOEXEC`
+        const CMSG_CLOEXEC = libc::MSG_CMSG_CLOEXEC;
+        /// `MSG_CTRUNC`
+        const CTRUNC = libc::MSG_CTRUNC;
+        /// `MSG_EOR`
+        const EOR = libc::MSG_EOR;
+        /// `MSG_ERRQUEUE`
+        const ERRQUEUE = libc::MSG_ERRQUEUE;
+        /// `MSG_OOB`
+        const OOB = libc::MSG_OOB;
+        /// `MSG_TRUNC`
+        const TRUNC = libc::MSG_TRUNC;
+    }
+}
+
+/// `SOL_SOCKET`, as a `cmsg_level`.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const SOL_SOCKET: libc::c_int = libc::SOL_SOCKET;
+
+/// `SCM_RIGHTS`, as a `cmsg_type`.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const SCM_RIGHTS: libc::c_int = libc::SCM_RIGHTS;
@@ -4,7 +4,7 @@ use libc::c_int;
 /// `SOCK_*` constants for [`socket`].
 ///
 /// [`socket`]: crate::net::socket
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
 #[repr(transparent)]
 pub struct SocketType(pub(crate) u32);
 
@@ -27,7 +27,7 @@ impl SocketType {
 }
 
 /// `AF_*` constants.
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
 #[repr(transparent)]
 pub struct AddressFamily(pub(crate) libc::sa_family_t);
 
@@ -144,6 +144,8 @@ pub enum Protocol {
 }
 
 /// `SHUT_*` constants for [`shutdown`].
+///
+/// [`shutdown`]: crate::net::shutdown
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 #[repr(i32)]
 pub enum Shutdown {
@@ -156,9 +158,10 @@ pub enum Shutdown {
 }
 
 bitflags! {
-    /// `SOCK_*` constants for [`accept`].
+    /// `SOCK_*` constants for [`accept_with`] and [`acceptfrom_with`].
     ///
-    /// [`accept`]: crate::net::accept
+    /// [`accept_with`]: crate::net::accept_with
+    /// [`acceptfrom_with`]: crate::net::acceptfrom_with
     pub struct AcceptFlags: c_int {
         /// `SOCK_NONBLOCK`
         #[cfg(not(any(target_os = "ios", target_os = "macos")))]
@@ -141,6 +141,15 @@ pub(super) use libc::fstatfs as libc_fstatfs;
 pub(super) use libc::posix_fallocate as libc_posix_fallocate;
 #[cfg(any(target_os = "l4re",))]
 pub(super) use libc::posix_fallocate64 as libc_posix_fallocate;
+#[cfg(not(any(
+    target_os = "android",
+    target_os = "linux",
+    target_os = "emscripten",
+    target_os = "l4re",
+    target_os = "redox",
+    target_os = "wasi",
+)))]
+pub(super) use libc::{fstatvfs as libc_fstatvfs, statvfs as libc_statvfs};
 
 #[cfg(any(
     target_os = "android",
@@ -149,3 +158,10 @@ pub(super) use libc::posix_fallocate64 as libc_posix_fallocate;
     target_os = "l4re",
 ))]
 pub(super) use libc::fstatfs64 as libc_fstatfs;
+#[cfg(any(
+    target_os = "android",
+    target_os = "linux",
+    target_os = "emscripten",
+    target_os = "l4re",
+))]
+pub(super) use libc::{fstatvfs64 as libc_fstatvfs, statvfs64 as libc_statvfs};
@@ -1,9 +1,26 @@
 mod auxv;
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+mod sigset;
 mod types;
+#[cfg(not(target_os = "wasi"))]
+mod wait;
 
 #[cfg(any(target_os = "android", target_os = "linux"))]
 pub(crate) use auxv::linux_hwcap;
 pub(crate) use auxv::page_size;
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+pub use sigset::SigSet;
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+pub use types::SigmaskHow;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use types::{MountFlags, RawSysinfo, SchedFlags, SchedPolicy, UnmountFlags};
 #[cfg(not(target_os = "wasi"))]
-pub use types::{RawGid, RawPid, RawUid, RawUname, EXIT_SIGNALED_SIGABRT};
+pub use types::{
+    RawGid, RawPid, RawRusage, RawUid, RawUname, RusageWho, Signal, WaitOptions,
+    EXIT_SIGNALED_SIGABRT,
+};
 pub use types::{EXIT_FAILURE, EXIT_SUCCESS};
+#[cfg(not(target_os = "wasi"))]
+pub(crate) use wait::{
+    WCOREDUMP, WEXITSTATUS, WIFCONTINUED, WIFEXITED, WIFSIGNALED, WIFSTOPPED, WSTOPSIG, WTERMSIG,
+};
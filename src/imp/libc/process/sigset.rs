@@ -0,0 +1,89 @@
+use super::types::Signal;
+use crate::io;
+use libc::c_int;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::mem::MaybeUninit;
+
+/// `sigset_t`—A set of signals.
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+pub struct SigSet(pub(crate) libc::sigset_t);
+
+impl SigSet {
+    /// Construct a new empty `SigSet`.
+    #[inline]
+    pub fn new() -> Self {
+        let mut set = MaybeUninit::<libc::sigset_t>::uninit();
+        unsafe {
+            libc::sigemptyset(set.as_mut_ptr());
+            Self(set.assume_init())
+        }
+    }
+
+    /// Add the signal `sig` to this set.
+    #[inline]
+    pub fn add(&mut self, sig: Signal) -> io::Result<()> {
+        match unsafe { libc::sigaddset(&mut self.0, sig.as_raw()) } {
+            0 => Ok(()),
+            _ => Err(io::Error::INVAL),
+        }
+    }
+
+    /// Remove the signal `sig` from this set.
+    #[inline]
+    pub fn remove(&mut self, sig: Signal) -> io::Result<()> {
+        match unsafe { libc::sigdelset(&mut self.0, sig.as_raw()) } {
+            0 => Ok(()),
+            _ => Err(io::Error::INVAL),
+        }
+    }
+
+    /// Test whether the signal `sig` is in this set.
+    #[inline]
+    pub fn contains(&self, sig: Signal) -> bool {
+        unsafe { libc::sigismember(&self.0, sig.as_raw()) > 0 }
+    }
+
+    /// Returns an iterator over the signals in this set.
+    fn members(&self) -> impl Iterator<Item = c_int> + '_ {
+        // `sigismember` fails for numbers past the end of the set, which
+        // varies between platforms, so stop at the first failure.
+        (1..)
+            .map(move |sig| (sig, unsafe { libc::sigismember(&self.0, sig) }))
+            .take_while(|(_sig, r)| *r >= 0)
+            .filter(|(_sig, r)| *r != 0)
+            .map(|(sig, _r)| sig)
+    }
+}
+
+impl Default for SigSet {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// `sigset_t` may have padding or bits that aren't signals, so compare and
+// hash the signals in the sets rather than their bytes.
+impl PartialEq for SigSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.members().eq(other.members())
+    }
+}
+
+impl Eq for SigSet {}
+
+impl Hash for SigSet {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for sig in self.members() {
+            sig.hash(state);
+        }
+    }
+}
+
+impl fmt::Debug for SigSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.members()).finish()
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(not(target_os = "wasi"))]
+use bitflags::bitflags;
 use libc::c_int;
 
 pub const EXIT_SUCCESS: c_int = libc::EXIT_SUCCESS;
@@ -14,3 +16,275 @@ pub type RawUid = libc::uid_t;
 
 #[cfg(not(target_os = "wasi"))]
 pub type RawUname = libc::utsname;
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub type RawSysinfo = libc::sysinfo;
+
+#[cfg(not(target_os = "wasi"))]
+pub type RawRusage = libc::rusage;
+
+/// `RUSAGE_*` constants for use with [`getrusage`].
+///
+/// [`getrusage`]: crate::process::getrusage
+#[cfg(not(target_os = "wasi"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(i32)]
+pub enum RusageWho {
+    /// `RUSAGE_SELF`
+    Self_ = libc::RUSAGE_SELF,
+    /// `RUSAGE_CHILDREN`
+    Children = libc::RUSAGE_CHILDREN,
+    /// `RUSAGE_THREAD`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Thread = libc::RUSAGE_THREAD,
+}
+
+/// `SIG_*` constants for use with [`sigprocmask`].
+///
+/// [`sigprocmask`]: crate::process::sigprocmask
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(i32)]
+pub enum SigmaskHow {
+    /// `SIG_BLOCK`
+    Block = libc::SIG_BLOCK,
+    /// `SIG_UNBLOCK`
+    Unblock = libc::SIG_UNBLOCK,
+    /// `SIG_SETMASK`
+    SetMask = libc::SIG_SETMASK,
+}
+
+#[cfg(not(target_os = "wasi"))]
+bitflags! {
+    /// `W*` constants for use with [`waitpid`].
+    ///
+    /// [`waitpid`]: crate::process::waitpid
+    pub struct WaitOptions: c_int {
+        /// `WNOHANG`
+        const NOHANG = libc::WNOHANG;
+        /// `WUNTRACED`
+        const UNTRACED = libc::WUNTRACED;
+        /// `WCONTINUED`
+        const CONTINUED = libc::WCONTINUED;
+    }
+}
+
+/// A signal number for use with [`kill_process`] and related functions.
+///
+/// [`kill_process`]: crate::process::kill_process
+#[cfg(not(target_os = "wasi"))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[repr(i32)]
+pub enum Signal {
+    /// `SIGHUP`
+    Hup = libc::SIGHUP,
+    /// `SIGINT`
+    Int = libc::SIGINT,
+    /// `SIGQUIT`
+    Quit = libc::SIGQUIT,
+    /// `SIGILL`
+    Ill = libc::SIGILL,
+    /// `SIGTRAP`
+    Trap = libc::SIGTRAP,
+    /// `SIGABRT`
+    Abort = libc::SIGABRT,
+    /// `SIGBUS`
+    Bus = libc::SIGBUS,
+    /// `SIGFPE`
+    Fpe = libc::SIGFPE,
+    /// `SIGKILL`
+    Kill = libc::SIGKILL,
+    /// `SIGUSR1`
+    Usr1 = libc::SIGUSR1,
+    /// `SIGSEGV`
+    Segv = libc::SIGSEGV,
+    /// `SIGUSR2`
+    Usr2 = libc::SIGUSR2,
+    /// `SIGPIPE`
+    Pipe = libc::SIGPIPE,
+    /// `SIGALRM`
+    Alarm = libc::SIGALRM,
+    /// `SIGTERM`
+    Term = libc::SIGTERM,
+    /// `SIGSTKFLT`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Stkflt = libc::SIGSTKFLT,
+    /// `SIGCHLD`
+    Child = libc::SIGCHLD,
+    /// `SIGCONT`
+    Cont = libc::SIGCONT,
+    /// `SIGSTOP`
+    Stop = libc::SIGSTOP,
+    /// `SIGTSTP`
+    Tstp = libc::SIGTSTP,
+    /// `SIGTTIN`
+    Ttin = libc::SIGTTIN,
+    /// `SIGTTOU`
+    Ttou = libc::SIGTTOU,
+    /// `SIGURG`
+    Urg = libc::SIGURG,
+    /// `SIGXCPU`
+    Xcpu = libc::SIGXCPU,
+    /// `SIGXFSZ`
+    Xfsz = libc::SIGXFSZ,
+    /// `SIGVTALRM`
+    Vtalarm = libc::SIGVTALRM,
+    /// `SIGPROF`
+    Prof = libc::SIGPROF,
+    /// `SIGWINCH`
+    Winch = libc::SIGWINCH,
+    /// `SIGIO`
+    Io = libc::SIGIO,
+    /// `SIGPWR`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Power = libc::SIGPWR,
+    /// `SIGSYS`
+    Sys = libc::SIGSYS,
+}
+
+#[cfg(not(target_os = "wasi"))]
+impl Signal {
+    /// Convert a raw signal number into a `Signal`, if it's one of the
+    /// signals listed here.
+    pub fn from_raw(sig: c_int) -> Option<Self> {
+        match sig {
+            libc::SIGHUP => Some(Self::Hup),
+            libc::SIGINT => Some(Self::Int),
+            libc::SIGQUIT => Some(Self::Quit),
+            libc::SIGILL => Some(Self::Ill),
+            libc::SIGTRAP => Some(Self::Trap),
+            libc::SIGABRT => Some(Self::Abort),
+            libc::SIGBUS => Some(Self::Bus),
+            libc::SIGFPE => Some(Self::Fpe),
+            libc::SIGKILL => Some(Self::Kill),
+            libc::SIGUSR1 => Some(Self::Usr1),
+            libc::SIGSEGV => Some(Self::Segv),
+            libc::SIGUSR2 => Some(Self::Usr2),
+            libc::SIGPIPE => Some(Self::Pipe),
+            libc::SIGALRM => Some(Self::Alarm),
+            libc::SIGTERM => Some(Self::Term),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            libc::SIGSTKFLT => Some(Self::Stkflt),
+            libc::SIGCHLD => Some(Self::Child),
+            libc::SIGCONT => Some(Self::Cont),
+            libc::SIGSTOP => Some(Self::Stop),
+            libc::SIGTSTP => Some(Self::Tstp),
+            libc::SIGTTIN => Some(Self::Ttin),
+            libc::SIGTTOU => Some(Self::Ttou),
+            libc::SIGURG => Some(Self::Urg),
+            libc::SIGXCPU => Some(Self::Xcpu),
+            libc::SIGXFSZ => Some(Self::Xfsz),
+            libc::SIGVTALRM => Some(Self::Vtalarm),
+            libc::SIGPROF => Some(Self::Prof),
+            libc::SIGWINCH => Some(Self::Winch),
+            libc::SIGIO => Some(Self::Io),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            libc::SIGPWR => Some(Self::Power),
+            libc::SIGSYS => Some(Self::Sys),
+            _ => None,
+        }
+    }
+
+    /// Convert a `Signal` into a raw signal number.
+    #[inline]
+    pub const fn as_raw(self) -> c_int {
+        self as c_int
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+bitflags! {
+    /// `MS_*` constants for use with [`mount`].
+    ///
+    /// [`mount`]: crate::process::mount
+    pub struct MountFlags: libc::c_ulong {
+        /// `MS_RDONLY`
+        const RDONLY = libc::MS_RDONLY;
+        /// `MS_NOSUID`
+        const NOSUID = libc::MS_NOSUID;
+        /// `MS_NODEV`
+        const NODEV = libc::MS_NODEV;
+        /// `MS_NOEXEC`
+        const NOEXEC = libc::MS_NOEXEC;
+        /// `MS_REMOUNT`
+        const REMOUNT = libc::MS_REMOUNT;
+        /// `MS_BIND`
+        const BIND = libc::MS_BIND;
+        /// `MS_REC`
+        const REC = libc::MS_REC;
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+bitflags! {
+    /// `MNT_*` constants for use with [`unmount`].
+    ///
+    /// [`unmount`]: crate::process::unmount
+    pub struct UnmountFlags: c_int {
+        /// `MNT_FORCE`
+        const FORCE = libc::MNT_FORCE;
+        /// `MNT_DETACH`
+        const DETACH = libc::MNT_DETACH;
+    }
+}
+
+// The libc crate doesn't define `SCHED_DEADLINE` on Linux, or `SCHED_OTHER`
+// and `SCHED_RESET_ON_FORK` on Android, so define them here.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const SCHED_OTHER: c_int = 0;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const SCHED_DEADLINE: c_int = 6;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const SCHED_RESET_ON_FORK: c_int = 0x4000_0000;
+
+/// `SCHED_*` constants for use with [`sched_getscheduler`] and
+/// [`sched_setscheduler`].
+///
+/// [`sched_getscheduler`]: crate::process::sched_getscheduler
+/// [`sched_setscheduler`]: crate::process::sched_setscheduler
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(i32)]
+pub enum SchedPolicy {
+    /// `SCHED_OTHER`, also known as `SCHED_NORMAL`
+    #[doc(alias = "Normal")]
+    Other = SCHED_OTHER,
+    /// `SCHED_FIFO`
+    Fifo = libc::SCHED_FIFO,
+    /// `SCHED_RR`
+    RoundRobin = libc::SCHED_RR,
+    /// `SCHED_BATCH`
+    Batch = libc::SCHED_BATCH,
+    /// `SCHED_IDLE`
+    Idle = libc::SCHED_IDLE,
+    /// `SCHED_DEADLINE`
+    Deadline = SCHED_DEADLINE,
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+impl SchedPolicy {
+    /// Convert a raw policy number into a `SchedPolicy`, if it's one of the
+    /// policies listed here.
+    pub fn from_raw(policy: c_int) -> Option<Self> {
+        match policy {
+            SCHED_OTHER => Some(Self::Other),
+            libc::SCHED_FIFO => Some(Self::Fifo),
+            libc::SCHED_RR => Some(Self::RoundRobin),
+            libc::SCHED_BATCH => Some(Self::Batch),
+            libc::SCHED_IDLE => Some(Self::Idle),
+            SCHED_DEADLINE => Some(Self::Deadline),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+bitflags! {
+    /// Flags which may be or'd into a policy for [`sched_setscheduler_with`].
+    ///
+    /// [`sched_setscheduler_with`]: crate::process::sched_setscheduler_with
+    pub struct SchedFlags: c_int {
+        /// `SCHED_RESET_ON_FORK`
+        const RESET_ON_FORK = SCHED_RESET_ON_FORK;
+    }
+}
@@ -1,13 +1,3 @@
-#[cfg(not(any(
-    target_os = "freebsd",
-    target_os = "ios",
-    target_os = "macos",
-    target_os = "netbsd",
-    target_os = "openbsd",
-    target_os = "redox",
-    target_os = "wasi",
-)))]
-use super::conv::ret_u32;
 use super::conv::{
     borrowed_fd, no_fd, ret, ret_c_int, ret_discarded_fd, ret_off_t, ret_owned_fd, ret_ssize_t,
 };
@@ -32,12 +22,26 @@ use super::fs::Dev;
 use super::fs::FallocateFlags;
 #[cfg(not(target_os = "wasi"))]
 use super::fs::FlockOperation;
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+use super::fs::FlockType;
+#[cfg(not(any(
+    target_os = "freebsd",
+    target_os = "ios",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "redox",
+    target_os = "wasi",
+)))]
+use super::fs::SealFlags;
 #[cfg(not(any(target_os = "netbsd", target_os = "redox", target_os = "wasi")))]
 // not implemented in libc for netbsd yet
 use super::fs::StatFs;
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+use super::fs::StatVfsMountFlags;
 use super::fs::{Access, FdFlags, Mode, OFlags, Stat};
 #[cfg(any(target_os = "android", target_os = "linux"))]
-use super::fs::{RenameFlags, ResolveFlags};
+use super::fs::{RenameFlags, ResolveFlags, SyncFileRangeFlags};
 #[cfg(all(target_os = "linux", target_env = "gnu"))]
 use super::fs::{Statx, StatxFlags};
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
@@ -49,10 +53,12 @@ use super::io::PipeFlags;
 use super::io::PollFd;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 use super::io::ReadWriteFlags;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use super::net::ReturnFlags;
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 use super::net::{
-    decode_sockaddr, AcceptFlags, AddressFamily, Protocol, RecvFlags, SendFlags, Shutdown,
-    SocketAddr, SocketAddrUnix, SocketAddrV4, SocketAddrV6, SocketType,
+    decode_sockaddr, AcceptFlags, AddressFamily, Ipv4Addr, Ipv6Addr, Protocol, RecvFlags,
+    SendFlags, Shutdown, SocketAddr, SocketAddrUnix, SocketAddrV4, SocketAddrV6, SocketType,
 };
 #[cfg(any(target_os = "android", target_os = "fuchsia", target_os = "linux"))]
 use super::offset::libc_fallocate;
@@ -80,27 +86,57 @@ use super::offset::libc_posix_fadvise;
 )))]
 use super::offset::libc_posix_fallocate;
 use super::offset::{libc_fstat, libc_fstatat, libc_lseek, libc_off_t, libc_pread, libc_pwrite};
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+use super::offset::{libc_fstatvfs, libc_statvfs};
 #[cfg(all(target_os = "linux", target_env = "gnu"))]
 use super::offset::{libc_preadv2, libc_pwritev2};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use super::process::{MountFlags, RawSysinfo, SchedFlags, SchedPolicy, UnmountFlags};
 #[cfg(not(target_os = "wasi"))]
-use super::process::RawUname;
+use super::process::{RawRusage, RawUname, RusageWho, WaitOptions};
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+use super::process::{SigSet, SigmaskHow};
 #[cfg(target_os = "linux")]
 use super::rand::GetRandomFlags;
-use super::time::Timespec;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use super::thread::{FutexFlags, FUTEX_WAIT, FUTEX_WAKE};
+#[cfg(target_os = "linux")]
+use super::time::{
+    AdjtimeState, Itimerspec, RawTimer, TimerFlags, TimerfdFlags, TimerfdTimerFlags, TimexModes,
+    TimexStatus,
+};
+use super::time::{ClockId, Timespec};
 use crate::as_ptr;
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+use crate::fs::{Flock, StatVfs};
 use crate::io::{self, OwnedFd, RawFd};
 #[cfg(not(target_os = "wasi"))]
 use crate::process::{Gid, Pid, Uid};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::process::{ResGid, ResUid};
+#[cfg(target_os = "linux")]
+use crate::time::{Sigevent, Timex};
 use errno::errno;
 use io_lifetimes::{AsFd, BorrowedFd};
 use libc::{c_int, c_void};
+// Darwin and the BSDs call these `IPV6_JOIN_GROUP` and `IPV6_LEAVE_GROUP`.
+#[cfg(any(target_os = "android", target_os = "fuchsia", target_os = "linux"))]
+use libc::{IPV6_ADD_MEMBERSHIP, IPV6_DROP_MEMBERSHIP};
+#[cfg(any(
+    target_os = "freebsd",
+    target_os = "ios",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+use libc::{IPV6_JOIN_GROUP as IPV6_ADD_MEMBERSHIP, IPV6_LEAVE_GROUP as IPV6_DROP_MEMBERSHIP};
 use std::cmp::min;
 use std::convert::TryInto;
 use std::ffi::CStr;
 #[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))]
 use std::ffi::OsString;
 use std::io::{IoSlice, IoSliceMut, SeekFrom};
-#[cfg(target_os = "linux")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
 use std::mem::transmute;
 use std::mem::{size_of, MaybeUninit};
 #[cfg(all(unix, not(target_os = "fuchsia")))]
@@ -108,9 +144,13 @@ use std::os::unix::ffi::OsStringExt;
 #[cfg(target_os = "wasi")]
 use std::os::wasi::ffi::OsStringExt;
 #[cfg(not(any(target_os = "redox", target_os = "wasi",)))]
-use std::ptr::null_mut;
+use std::ptr::{null, null_mut};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use std::sync::atomic::AtomicU32;
 #[cfg(not(any(target_os = "redox", target_env = "newlib")))]
 use std::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+use std::time::Duration;
 #[cfg(not(target_os = "redox"))]
 use {
     super::conv::c_str,
@@ -126,13 +166,19 @@ use {
 };
 #[cfg(any(target_os = "android", target_os = "linux"))]
 use {
-    super::fs::MemfdFlags,
-    super::io::{EventfdFlags, UserfaultfdFlags},
+    super::fs::{MemfdFlags, XattrFlags},
+    super::io::{
+        epoll, CloseRangeFlags, EventfdFlags, InotifyFlags, SignalfdFlags, SpliceFlags,
+        UserfaultfdFlags, WatchFlags,
+    },
 };
 #[cfg(not(target_os = "wasi"))]
 use {
-    super::io::{DupFlags, MapFlags, MprotectFlags, ProtFlags, Termios, Winsize},
-    super::time::{ClockId, DynamicClockId},
+    super::io::{
+        DupFlags, MapFlags, MprotectFlags, OptionalActions, ProtFlags, QueueSelector, Termios,
+        Winsize,
+    },
+    super::time::DynamicClockId,
 };
 
 pub(crate) fn read(fd: BorrowedFd<'_>, buf: &mut [u8]) -> io::Result<usize> {
@@ -146,6 +192,22 @@ pub(crate) fn read(fd: BorrowedFd<'_>, buf: &mut [u8]) -> io::Result<usize> {
     Ok(nread as usize)
 }
 
+pub(crate) fn read_uninit<'buf>(
+    fd: BorrowedFd<'_>,
+    buf: &'buf mut [MaybeUninit<u8>],
+) -> io::Result<(&'buf mut [u8], &'buf mut [MaybeUninit<u8>])> {
+    let nread = unsafe {
+        ret_ssize_t(libc::read(
+            borrowed_fd(fd),
+            buf.as_mut_ptr().cast::<_>(),
+            buf.len(),
+        ))?
+    };
+
+    // Safety: `read` initialized the first `nread` bytes of `buf`.
+    Ok(unsafe { io::split_init(buf, nread as usize) })
+}
+
 pub(crate) fn write(fd: BorrowedFd<'_>, buf: &[u8]) -> io::Result<usize> {
     let nwritten = unsafe {
         ret_ssize_t(libc::write(
@@ -241,11 +303,12 @@ pub(crate) fn pwritev(fd: BorrowedFd<'_>, bufs: &[IoSlice], offset: u64) -> io::
 pub(crate) fn preadv2(
     fd: BorrowedFd<'_>,
     bufs: &[IoSliceMut],
-    offset: u64,
+    offset: Option<u64>,
     flags: ReadWriteFlags,
 ) -> io::Result<usize> {
-    // Silently cast; we'll get `EINVAL` if the value is negative.
-    let offset = offset as i64;
+    // An offset of -1 means the current file position. Otherwise, silently
+    // cast; we'll get `EINVAL` if the value is negative.
+    let offset = offset.map_or(-1, |offset| offset as i64);
     let nread = unsafe {
         ret_ssize_t(libc_preadv2(
             borrowed_fd(fd),
@@ -259,7 +322,8 @@ pub(crate) fn preadv2(
 }
 
 /// At present, `libc` only has `preadv2` defined for glibc. On other
-/// ABIs, `ReadWriteFlags` has no flags defined, and we use plain `preadv`.
+/// ABIs, `ReadWriteFlags` has no flags defined, and we use plain `preadv`, or
+/// `readv` if there's no offset.
 #[cfg(any(
     target_os = "android",
     all(target_os = "linux", not(target_env = "gnu"))
@@ -268,22 +332,26 @@ pub(crate) fn preadv2(
 pub(crate) fn preadv2(
     fd: BorrowedFd<'_>,
     bufs: &[IoSliceMut],
-    offset: u64,
+    offset: Option<u64>,
     flags: ReadWriteFlags,
 ) -> io::Result<usize> {
     assert!(flags.is_empty());
-    preadv(fd, bufs, offset)
+    match offset {
+        Some(offset) => preadv(fd, bufs, offset),
+        None => readv(fd, bufs),
+    }
 }
 
 #[cfg(all(target_os = "linux", target_env = "gnu"))]
 pub(crate) fn pwritev2(
     fd: BorrowedFd<'_>,
     bufs: &[IoSlice],
-    offset: u64,
+    offset: Option<u64>,
     flags: ReadWriteFlags,
 ) -> io::Result<usize> {
-    // Silently cast; we'll get `EINVAL` if the value is negative.
-    let offset = offset as i64;
+    // An offset of -1 means the current file position. Otherwise, silently
+    // cast; we'll get `EINVAL` if the value is negative.
+    let offset = offset.map_or(-1, |offset| offset as i64);
     let nwritten = unsafe {
         ret_ssize_t(libc_pwritev2(
             borrowed_fd(fd),
@@ -297,7 +365,8 @@ pub(crate) fn pwritev2(
 }
 
 /// At present, `libc` only has `pwritev2` defined for glibc. On other
-/// ABIs, `ReadWriteFlags` has no flags defined, and we use plain `pwritev`.
+/// ABIs, `ReadWriteFlags` has no flags defined, and we use plain `pwritev`, or
+/// `writev` if there's no offset.
 #[cfg(any(
     target_os = "android",
     all(target_os = "linux", not(target_env = "gnu"))
@@ -306,11 +375,14 @@ pub(crate) fn pwritev2(
 pub(crate) fn pwritev2(
     fd: BorrowedFd<'_>,
     bufs: &[IoSlice],
-    offset: u64,
+    offset: Option<u64>,
     flags: ReadWriteFlags,
 ) -> io::Result<usize> {
     assert!(flags.is_empty());
-    pwritev(fd, bufs, offset)
+    match offset {
+        Some(offset) => pwritev(fd, bufs, offset),
+        None => writev(fd, bufs),
+    }
 }
 
 // These functions are derived from Rust's library/std/src/sys/unix/fd.rs at
@@ -356,6 +428,29 @@ pub(crate) unsafe fn close(raw_fd: RawFd) {
     let _ = libc::close(raw_fd as c_int);
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) unsafe fn close_range(
+    first: RawFd,
+    last: RawFd,
+    flags: CloseRangeFlags,
+) -> io::Result<()> {
+    syscall_ret(libc::syscall(
+        SYS_close_range,
+        first as libc::c_uint,
+        last as libc::c_uint,
+        flags.bits(),
+    ))
+}
+
+#[cfg(target_os = "linux")]
+use libc::SYS_close_range;
+
+// The libc crate doesn't yet define `SYS_close_range` for Android; its number
+// is the same on all architectures.
+#[cfg(target_os = "android")]
+#[allow(non_upper_case_globals)]
+const SYS_close_range: libc::c_long = 436;
+
 #[cfg(not(target_os = "redox"))]
 pub(crate) fn openat(
     dirfd: BorrowedFd<'_>,
@@ -615,6 +710,129 @@ pub(crate) fn mknodat(dirfd: BorrowedFd<'_>, path: &CStr, mode: Mode, dev: Dev)
     }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn splice(
+    fd_in: BorrowedFd<'_>,
+    off_in: Option<&mut u64>,
+    fd_out: BorrowedFd<'_>,
+    off_out: Option<&mut u64>,
+    len: usize,
+    flags: SpliceFlags,
+) -> io::Result<usize> {
+    assert_eq!(size_of::<libc::loff_t>(), size_of::<u64>());
+
+    unsafe {
+        ret_ssize_t(libc::splice(
+            borrowed_fd(fd_in),
+            transmute(off_in),
+            borrowed_fd(fd_out),
+            transmute(off_out),
+            len,
+            flags.bits(),
+        ))
+        .map(|nspliced| nspliced as usize)
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn tee(
+    fd_in: BorrowedFd<'_>,
+    fd_out: BorrowedFd<'_>,
+    len: usize,
+    flags: SpliceFlags,
+) -> io::Result<usize> {
+    unsafe {
+        ret_ssize_t(libc::tee(
+            borrowed_fd(fd_in),
+            borrowed_fd(fd_out),
+            len,
+            flags.bits(),
+        ))
+        .map(|ntee| ntee as usize)
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) unsafe fn vmsplice(
+    fd: BorrowedFd<'_>,
+    bufs: &[IoSlice],
+    flags: SpliceFlags,
+) -> io::Result<usize> {
+    ret_ssize_t(libc::vmsplice(
+        borrowed_fd(fd),
+        bufs.as_ptr().cast::<libc::iovec>(),
+        min(bufs.len(), max_iov()),
+        flags.bits(),
+    ))
+    .map(|nspliced| nspliced as usize)
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn epoll_create(flags: epoll::CreateFlags) -> io::Result<OwnedFd> {
+    unsafe { ret_owned_fd(libc::epoll_create1(flags.bits())) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) unsafe fn epoll_add(
+    epfd: BorrowedFd<'_>,
+    fd: c_int,
+    event: &libc::epoll_event,
+) -> io::Result<()> {
+    ret(libc::epoll_ctl(
+        borrowed_fd(epfd),
+        libc::EPOLL_CTL_ADD,
+        fd,
+        as_ptr(event) as *mut libc::epoll_event,
+    ))
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) unsafe fn epoll_mod(
+    epfd: BorrowedFd<'_>,
+    fd: c_int,
+    event: &libc::epoll_event,
+) -> io::Result<()> {
+    ret(libc::epoll_ctl(
+        borrowed_fd(epfd),
+        libc::EPOLL_CTL_MOD,
+        fd,
+        as_ptr(event) as *mut libc::epoll_event,
+    ))
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) unsafe fn epoll_del(epfd: BorrowedFd<'_>, fd: c_int) -> io::Result<()> {
+    ret(libc::epoll_ctl(
+        borrowed_fd(epfd),
+        libc::EPOLL_CTL_DEL,
+        fd,
+        null_mut(),
+    ))
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn epoll_wait(
+    epfd: BorrowedFd<'_>,
+    events: *mut libc::epoll_event,
+    num_events: usize,
+    timeout: c_int,
+) -> io::Result<usize> {
+    unsafe {
+        ret_c_int(libc::epoll_wait(
+            borrowed_fd(epfd),
+            events,
+            num_events.try_into().unwrap_or(c_int::MAX),
+            timeout,
+        ))
+        .map(|nfds| nfds as usize)
+    }
+}
+
 #[cfg(any(target_os = "android", target_os = "linux"))]
 pub(crate) fn copy_file_range(
     fd_in: BorrowedFd<'_>,
@@ -766,22 +984,116 @@ pub(crate) fn fcntl_setfl(fd: BorrowedFd<'_>, flags: OFlags) -> io::Result<()> {
     target_os = "redox",
     target_os = "wasi",
 )))]
-pub(crate) fn fcntl_get_seals(fd: BorrowedFd<'_>) -> io::Result<u32> {
-    unsafe { ret_u32(libc::fcntl(borrowed_fd(fd), libc::F_GET_SEALS)) }
+pub(crate) fn fcntl_get_seals(fd: BorrowedFd<'_>) -> io::Result<SealFlags> {
+    unsafe {
+        ret_c_int(libc::fcntl(borrowed_fd(fd), libc::F_GET_SEALS))
+            .map(|seals| SealFlags::from_bits_truncate(seals as u32))
+    }
+}
+
+#[cfg(not(any(
+    target_os = "freebsd",
+    target_os = "ios",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "redox",
+    target_os = "wasi",
+)))]
+pub(crate) fn fcntl_add_seals(fd: BorrowedFd<'_>, seals: SealFlags) -> io::Result<()> {
+    unsafe {
+        ret(libc::fcntl(
+            borrowed_fd(fd),
+            libc::F_ADD_SEALS,
+            seals.bits() as c_int,
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fcntl_getpipe_sz(fd: BorrowedFd<'_>) -> io::Result<usize> {
+    unsafe { ret_c_int(libc::fcntl(borrowed_fd(fd), libc::F_GETPIPE_SZ)).map(|size| size as usize) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fcntl_setpipe_sz(fd: BorrowedFd<'_>, size: c_int) -> io::Result<usize> {
+    unsafe {
+        ret_c_int(libc::fcntl(borrowed_fd(fd), libc::F_SETPIPE_SZ, size)).map(|size| size as usize)
+    }
+}
+
+#[cfg(not(target_os = "wasi"))]
+pub(crate) fn fcntl_dupfd(fd: BorrowedFd<'_>, min: RawFd) -> io::Result<OwnedFd> {
+    unsafe { ret_owned_fd(libc::fcntl(borrowed_fd(fd), libc::F_DUPFD, min)) }
 }
 
 #[cfg(not(target_os = "wasi"))]
-pub(crate) fn fcntl_dupfd_cloexec(fd: BorrowedFd<'_>) -> io::Result<OwnedFd> {
-    unsafe { ret_owned_fd(libc::fcntl(borrowed_fd(fd), libc::F_DUPFD_CLOEXEC)) }
+pub(crate) fn fcntl_dupfd_cloexec(fd: BorrowedFd<'_>, min: RawFd) -> io::Result<OwnedFd> {
+    unsafe { ret_owned_fd(libc::fcntl(borrowed_fd(fd), libc::F_DUPFD_CLOEXEC, min)) }
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+pub(crate) fn fcntl_getlk(fd: BorrowedFd<'_>, lock: &Flock) -> io::Result<Flock> {
+    let mut raw = flock_to_raw(lock)?;
+    unsafe { ret(libc::fcntl(borrowed_fd(fd), libc::F_GETLK, &mut raw))? };
+    Ok(flock_from_raw(&raw))
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+pub(crate) fn fcntl_setlk(fd: BorrowedFd<'_>, lock: &Flock) -> io::Result<()> {
+    let raw = flock_to_raw(lock)?;
+    unsafe { ret(libc::fcntl(borrowed_fd(fd), libc::F_SETLK, &raw)) }
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+pub(crate) fn fcntl_setlkw(fd: BorrowedFd<'_>, lock: &Flock) -> io::Result<()> {
+    let raw = flock_to_raw(lock)?;
+    unsafe { ret(libc::fcntl(borrowed_fd(fd), libc::F_SETLKW, &raw)) }
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+fn flock_to_raw(lock: &Flock) -> io::Result<libc::flock> {
+    let (whence, start) = match lock.start {
+        SeekFrom::Start(pos) => (
+            libc::SEEK_SET,
+            pos.try_into().map_err(|_| io::Error::OVERFLOW)?,
+        ),
+        SeekFrom::End(offset) => (libc::SEEK_END, offset),
+        SeekFrom::Current(offset) => (libc::SEEK_CUR, offset),
+    };
+
+    // `struct flock` has platform-specific extra fields, so start from zero
+    // and fill in the portable ones.
+    let mut raw: libc::flock = unsafe { std::mem::zeroed() };
+    raw.l_type = lock.typ as libc::c_short;
+    raw.l_whence = whence as libc::c_short;
+    raw.l_start = start.try_into().map_err(|_| io::Error::OVERFLOW)?;
+    raw.l_len = lock.len.try_into().map_err(|_| io::Error::OVERFLOW)?;
+    raw.l_pid = lock.pid.as_raw();
+    Ok(raw)
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+fn flock_from_raw(raw: &libc::flock) -> Flock {
+    let start = match raw.l_whence as libc::c_int {
+        libc::SEEK_END => SeekFrom::End(raw.l_start as i64),
+        libc::SEEK_CUR => SeekFrom::Current(raw.l_start as i64),
+        _ => SeekFrom::Start(raw.l_start as u64),
+    };
+    Flock {
+        typ: FlockType::from_raw(raw.l_type),
+        start,
+        len: raw.l_len as i64,
+        pid: unsafe { Pid::from_raw(raw.l_pid) },
+    }
 }
 
 pub(crate) fn seek(fd: BorrowedFd<'_>, pos: SeekFrom) -> io::Result<u64> {
     let (whence, offset): (libc::c_int, libc_off_t) = match pos {
-        SeekFrom::Start(pos) => {
-            let pos: u64 = pos;
-            // Silently cast; we'll get `EINVAL` if the value is negative.
-            (libc::SEEK_SET, pos as i64)
-        }
+        SeekFrom::Start(pos) => (
+            libc::SEEK_SET,
+            pos.try_into().map_err(|_| io::Error::OVERFLOW)?,
+        ),
         SeekFrom::End(offset) => (libc::SEEK_END, offset),
         SeekFrom::Current(offset) => (libc::SEEK_CUR, offset),
     };
@@ -794,6 +1106,36 @@ pub(crate) fn tell(fd: BorrowedFd<'_>) -> io::Result<u64> {
     Ok(offset as u64)
 }
 
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+pub(crate) fn chownat(
+    dirfd: BorrowedFd<'_>,
+    path: &CStr,
+    owner: Option<Uid>,
+    group: Option<Gid>,
+    flags: AtFlags,
+) -> io::Result<()> {
+    // `-1` means "unchanged".
+    let owner = owner.map_or(!0, Uid::as_raw);
+    let group = group.map_or(!0, Gid::as_raw);
+    unsafe {
+        ret(libc::fchownat(
+            borrowed_fd(dirfd),
+            c_str(path),
+            owner,
+            group,
+            flags.bits(),
+        ))
+    }
+}
+
+#[cfg(not(target_os = "wasi"))]
+pub(crate) fn fchown(fd: BorrowedFd<'_>, owner: Option<Uid>, group: Option<Gid>) -> io::Result<()> {
+    // `-1` means "unchanged".
+    let owner = owner.map_or(!0, Uid::as_raw);
+    let group = group.map_or(!0, Gid::as_raw);
+    unsafe { ret(libc::fchown(borrowed_fd(fd), owner, group)) }
+}
+
 #[cfg(not(any(target_os = "android", target_os = "linux", target_os = "wasi")))]
 pub(crate) fn fchmod(fd: BorrowedFd<'_>, mode: Mode) -> io::Result<()> {
     unsafe { ret(libc::fchmod(borrowed_fd(fd), mode.bits())) }
@@ -836,6 +1178,41 @@ pub(crate) fn fstatfs(fd: BorrowedFd<'_>) -> io::Result<StatFs> {
     }
 }
 
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+pub(crate) fn statvfs(path: &CStr) -> io::Result<StatVfs> {
+    let mut statvfs = MaybeUninit::<libc_statvfs>::uninit();
+    unsafe {
+        ret(libc_statvfs(c_str(path), statvfs.as_mut_ptr()))?;
+        Ok(libc_statvfs_to_statvfs(statvfs.assume_init()))
+    }
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+pub(crate) fn fstatvfs(fd: BorrowedFd<'_>) -> io::Result<StatVfs> {
+    let mut statvfs = MaybeUninit::<libc_statvfs>::uninit();
+    unsafe {
+        ret(libc_fstatvfs(borrowed_fd(fd), statvfs.as_mut_ptr()))?;
+        Ok(libc_statvfs_to_statvfs(statvfs.assume_init()))
+    }
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+fn libc_statvfs_to_statvfs(from: libc_statvfs) -> StatVfs {
+    StatVfs {
+        f_bsize: from.f_bsize as u64,
+        f_frsize: from.f_frsize as u64,
+        f_blocks: from.f_blocks as u64,
+        f_bfree: from.f_bfree as u64,
+        f_bavail: from.f_bavail as u64,
+        f_files: from.f_files as u64,
+        f_ffree: from.f_ffree as u64,
+        f_favail: from.f_favail as u64,
+        f_fsid: from.f_fsid as u64,
+        f_flag: StatVfsMountFlags::from_bits_truncate(from.f_flag as u64),
+        f_namemax: from.f_namemax as u64,
+    }
+}
+
 pub(crate) fn futimens(fd: BorrowedFd<'_>, times: &[Timespec; 2]) -> io::Result<()> {
     unsafe { ret(libc::futimens(borrowed_fd(fd), times.as_ptr())) }
 }
@@ -915,32 +1292,240 @@ pub(crate) fn fdatasync(fd: BorrowedFd<'_>) -> io::Result<()> {
     unsafe { ret(libc::fdatasync(borrowed_fd(fd))) }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn sync_file_range(
+    fd: BorrowedFd<'_>,
+    offset: u64,
+    nbytes: u64,
+    flags: SyncFileRangeFlags,
+) -> io::Result<()> {
+    // Silently cast; we'll get `EINVAL` if the value is negative.
+    unsafe {
+        ret(libc::sync_file_range(
+            borrowed_fd(fd),
+            offset as i64,
+            nbytes as i64,
+            flags.bits(),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn syncfs(fd: BorrowedFd<'_>) -> io::Result<()> {
+    unsafe { syscall_ret(libc::syscall(libc::SYS_syncfs, borrowed_fd(fd))) }
+}
+
+#[cfg(not(target_os = "wasi"))]
+pub(crate) fn sync() {
+    // `sync` always succeeds.
+    unsafe { libc::sync() }
+}
+
 pub(crate) fn ftruncate(fd: BorrowedFd<'_>, length: u64) -> io::Result<()> {
     let length = length.try_into().map_err(|_overflow_err| io::Error::FBIG)?;
     unsafe { ret(libc::ftruncate(borrowed_fd(fd), length)) }
 }
 
 #[cfg(any(target_os = "android", target_os = "linux"))]
-pub(crate) fn memfd_create(path: &CStr, flags: MemfdFlags) -> io::Result<OwnedFd> {
+pub(crate) fn getxattr(path: &CStr, name: &CStr, value: &mut [u8]) -> io::Result<usize> {
     unsafe {
-        syscall_ret_owned_fd(libc::syscall(
-            libc::SYS_memfd_create,
+        ret_ssize_t(libc::getxattr(
             c_str(path),
-            flags.bits(),
+            c_str(name),
+            value.as_mut_ptr().cast::<libc::c_void>(),
+            value.len(),
         ))
+        .map(|nread| nread as usize)
     }
 }
 
 #[cfg(any(target_os = "android", target_os = "linux"))]
-pub(crate) fn eventfd(initval: u32, flags: EventfdFlags) -> io::Result<OwnedFd> {
-    unsafe { syscall_ret_owned_fd(libc::syscall(libc::SYS_eventfd2, initval, flags.bits())) }
+pub(crate) fn lgetxattr(path: &CStr, name: &CStr, value: &mut [u8]) -> io::Result<usize> {
+    unsafe {
+        ret_ssize_t(libc::lgetxattr(
+            c_str(path),
+            c_str(name),
+            value.as_mut_ptr().cast::<libc::c_void>(),
+            value.len(),
+        ))
+        .map(|nread| nread as usize)
+    }
 }
 
 #[cfg(any(target_os = "android", target_os = "linux"))]
-pub(crate) fn openat2(
-    dirfd: BorrowedFd<'_>,
-    path: &CStr,
-    oflags: OFlags,
+pub(crate) fn fgetxattr(fd: BorrowedFd<'_>, name: &CStr, value: &mut [u8]) -> io::Result<usize> {
+    unsafe {
+        ret_ssize_t(libc::fgetxattr(
+            borrowed_fd(fd),
+            c_str(name),
+            value.as_mut_ptr().cast::<libc::c_void>(),
+            value.len(),
+        ))
+        .map(|nread| nread as usize)
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn setxattr(
+    path: &CStr,
+    name: &CStr,
+    value: &[u8],
+    flags: XattrFlags,
+) -> io::Result<()> {
+    unsafe {
+        ret(libc::setxattr(
+            c_str(path),
+            c_str(name),
+            value.as_ptr().cast::<libc::c_void>(),
+            value.len(),
+            flags.bits(),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn lsetxattr(
+    path: &CStr,
+    name: &CStr,
+    value: &[u8],
+    flags: XattrFlags,
+) -> io::Result<()> {
+    unsafe {
+        ret(libc::lsetxattr(
+            c_str(path),
+            c_str(name),
+            value.as_ptr().cast::<libc::c_void>(),
+            value.len(),
+            flags.bits(),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fsetxattr(
+    fd: BorrowedFd<'_>,
+    name: &CStr,
+    value: &[u8],
+    flags: XattrFlags,
+) -> io::Result<()> {
+    unsafe {
+        ret(libc::fsetxattr(
+            borrowed_fd(fd),
+            c_str(name),
+            value.as_ptr().cast::<libc::c_void>(),
+            value.len(),
+            flags.bits(),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn listxattr(path: &CStr, list: &mut [u8]) -> io::Result<usize> {
+    unsafe {
+        ret_ssize_t(libc::listxattr(
+            c_str(path),
+            list.as_mut_ptr().cast::<libc::c_char>(),
+            list.len(),
+        ))
+        .map(|nread| nread as usize)
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn llistxattr(path: &CStr, list: &mut [u8]) -> io::Result<usize> {
+    unsafe {
+        ret_ssize_t(libc::llistxattr(
+            c_str(path),
+            list.as_mut_ptr().cast::<libc::c_char>(),
+            list.len(),
+        ))
+        .map(|nread| nread as usize)
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn flistxattr(fd: BorrowedFd<'_>, list: &mut [u8]) -> io::Result<usize> {
+    unsafe {
+        ret_ssize_t(libc::flistxattr(
+            borrowed_fd(fd),
+            list.as_mut_ptr().cast::<libc::c_char>(),
+            list.len(),
+        ))
+        .map(|nread| nread as usize)
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn removexattr(path: &CStr, name: &CStr) -> io::Result<()> {
+    unsafe { ret(libc::removexattr(c_str(path), c_str(name))) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn lremovexattr(path: &CStr, name: &CStr) -> io::Result<()> {
+    unsafe { ret(libc::lremovexattr(c_str(path), c_str(name))) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fremovexattr(fd: BorrowedFd<'_>, name: &CStr) -> io::Result<()> {
+    unsafe { ret(libc::fremovexattr(borrowed_fd(fd), c_str(name))) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn memfd_create(path: &CStr, flags: MemfdFlags) -> io::Result<OwnedFd> {
+    unsafe {
+        syscall_ret_owned_fd(libc::syscall(
+            libc::SYS_memfd_create,
+            c_str(path),
+            flags.bits(),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn eventfd(initval: u32, flags: EventfdFlags) -> io::Result<OwnedFd> {
+    unsafe { syscall_ret_owned_fd(libc::syscall(libc::SYS_eventfd2, initval, flags.bits())) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn signalfd(mask: &SigSet, flags: SignalfdFlags) -> io::Result<OwnedFd> {
+    unsafe { ret_owned_fd(libc::signalfd(-1, &mask.0, flags.bits())) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn signalfd_set_mask(fd: BorrowedFd<'_>, mask: &SigSet) -> io::Result<()> {
+    unsafe { ret_discarded_fd(libc::signalfd(borrowed_fd(fd), &mask.0, 0)) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn inotify_init1(flags: InotifyFlags) -> io::Result<OwnedFd> {
+    unsafe { ret_owned_fd(libc::inotify_init1(flags.bits())) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn inotify_add_watch(
+    fd: BorrowedFd<'_>,
+    path: &CStr,
+    mask: WatchFlags,
+) -> io::Result<c_int> {
+    unsafe {
+        ret_c_int(libc::inotify_add_watch(
+            borrowed_fd(fd),
+            c_str(path),
+            mask.bits(),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn inotify_rm_watch(fd: BorrowedFd<'_>, wd: c_int) -> io::Result<()> {
+    unsafe { ret(libc::inotify_rm_watch(borrowed_fd(fd), wd)) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn openat2(
+    dirfd: BorrowedFd<'_>,
+    path: &CStr,
+    oflags: OFlags,
     mode: Mode,
     resolve: ResolveFlags,
 ) -> io::Result<OwnedFd> {
@@ -1141,6 +1726,11 @@ pub(crate) fn dup2(fd: BorrowedFd<'_>, new: &OwnedFd) -> io::Result<()> {
     unsafe { ret_discarded_fd(libc::dup2(borrowed_fd(fd), borrowed_fd(new.as_fd()))) }
 }
 
+#[cfg(not(target_os = "wasi"))]
+pub(crate) unsafe fn dup2_to(fd: BorrowedFd<'_>, new: RawFd) -> io::Result<OwnedFd> {
+    ret_owned_fd(libc::dup2(borrowed_fd(fd), new as c_int))
+}
+
 #[cfg(not(any(
     target_os = "android",
     target_os = "macos",
@@ -1230,11 +1820,69 @@ pub(crate) fn ioctl_tcgets(fd: BorrowedFd<'_>) -> io::Result<Termios> {
     }
 }
 
-#[cfg(any(target_os = "ios", target_os = "macos"))]
+#[cfg(not(target_os = "wasi"))]
+pub(crate) fn tcsetattr(
+    fd: BorrowedFd<'_>,
+    optional_actions: OptionalActions,
+    termios: &Termios,
+) -> io::Result<()> {
+    unsafe {
+        ret(libc::tcsetattr(
+            borrowed_fd(fd),
+            optional_actions as c_int,
+            termios,
+        ))
+    }
+}
+
+#[cfg(not(target_os = "wasi"))]
+pub(crate) fn tcflush(fd: BorrowedFd<'_>, queue_selector: QueueSelector) -> io::Result<()> {
+    unsafe { ret(libc::tcflush(borrowed_fd(fd), queue_selector as c_int)) }
+}
+
+#[cfg(not(target_os = "wasi"))]
+pub(crate) fn tcdrain(fd: BorrowedFd<'_>) -> io::Result<()> {
+    unsafe { ret(libc::tcdrain(borrowed_fd(fd))) }
+}
+
+#[cfg(not(target_os = "wasi"))]
+pub(crate) fn tcsendbreak(fd: BorrowedFd<'_>) -> io::Result<()> {
+    unsafe { ret(libc::tcsendbreak(borrowed_fd(fd), 0)) }
+}
+
+#[cfg(not(target_os = "wasi"))]
+pub(crate) fn tcgetpgrp(fd: BorrowedFd<'_>) -> io::Result<Pid> {
+    unsafe {
+        let pgid = ret_c_int(libc::tcgetpgrp(borrowed_fd(fd)))?;
+        Ok(Pid::from_raw(pgid))
+    }
+}
+
+#[cfg(not(target_os = "wasi"))]
+pub(crate) fn tcsetpgrp(fd: BorrowedFd<'_>, pid: Pid) -> io::Result<()> {
+    unsafe { ret(libc::tcsetpgrp(borrowed_fd(fd), pid.as_raw())) }
+}
+
+#[cfg(not(any(
+    target_os = "emscripten",
+    target_os = "fuchsia",
+    target_os = "redox",
+    target_os = "wasi"
+)))]
 pub(crate) fn ioctl_fioclex(fd: BorrowedFd<'_>) -> io::Result<()> {
     unsafe { ret(libc::ioctl(borrowed_fd(fd), libc::FIOCLEX)) }
 }
 
+#[cfg(not(any(
+    target_os = "emscripten",
+    target_os = "fuchsia",
+    target_os = "redox",
+    target_os = "wasi"
+)))]
+pub(crate) fn ioctl_fionclex(fd: BorrowedFd<'_>) -> io::Result<()> {
+    unsafe { ret(libc::ioctl(borrowed_fd(fd), libc::FIONCLEX)) }
+}
+
 #[cfg(not(target_os = "wasi"))]
 pub(crate) fn ioctl_tiocgwinsz(fd: BorrowedFd) -> io::Result<Winsize> {
     unsafe {
@@ -1248,6 +1896,17 @@ pub(crate) fn ioctl_tiocgwinsz(fd: BorrowedFd) -> io::Result<Winsize> {
     }
 }
 
+#[cfg(not(target_os = "wasi"))]
+pub(crate) fn ioctl_tiocswinsz(fd: BorrowedFd, size: &Winsize) -> io::Result<()> {
+    unsafe {
+        ret(libc::ioctl(
+            borrowed_fd(fd),
+            libc::TIOCSWINSZ.into(),
+            size as *const Winsize,
+        ))
+    }
+}
+
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 pub(crate) fn ioctl_tiocexcl(fd: BorrowedFd) -> io::Result<()> {
     unsafe { ret(libc::ioctl(borrowed_fd(fd), libc::TIOCEXCL as _)) }
@@ -1392,6 +2051,29 @@ pub(crate) fn poll(fds: &mut [PollFd<'_>], timeout: c_int) -> io::Result<usize>
         .map(|nready| nready as usize)
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn ppoll(
+    fds: &mut [PollFd<'_>],
+    timeout: Option<&Timespec>,
+    sigmask: Option<&SigSet>,
+) -> io::Result<usize> {
+    let nfds = fds
+        .len()
+        .try_into()
+        .map_err(|_convert_err| io::Error::INVAL)?;
+
+    ret_c_int(unsafe {
+        libc::ppoll(
+            fds.as_mut_ptr().cast::<_>(),
+            nfds,
+            timeout.map_or_else(null, |timeout| timeout as *const Timespec),
+            sigmask.map_or_else(null, |sigmask| &sigmask.0),
+        )
+    })
+    .map(|nready| nready as usize)
+}
+
 #[cfg(any(target_os = "android", target_os = "linux"))]
 pub(crate) unsafe fn userfaultfd(flags: UserfaultfdFlags) -> io::Result<OwnedFd> {
     syscall_ret_owned_fd(libc::syscall(libc::SYS_userfaultfd, flags.bits()))
@@ -1410,6 +2092,27 @@ pub(crate) fn recv(fd: BorrowedFd<'_>, buf: &mut [u8], flags: RecvFlags) -> io::
     Ok(nrecv as usize)
 }
 
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+pub(crate) fn recv_uninit<'buf>(
+    fd: BorrowedFd<'_>,
+    buf: &'buf mut [MaybeUninit<u8>],
+    flags: RecvFlags,
+) -> io::Result<(&'buf mut [u8], &'buf mut [MaybeUninit<u8>])> {
+    let nrecv = unsafe {
+        ret_ssize_t(libc::recv(
+            borrowed_fd(fd),
+            buf.as_mut_ptr().cast::<_>(),
+            buf.len(),
+            flags.bits(),
+        ))?
+    };
+
+    // With `MSG_TRUNC`, the OS may report more bytes than it wrote.
+    let nrecv = min(nrecv as usize, buf.len());
+    // Safety: `recv` initialized the first `nrecv` bytes of `buf`.
+    Ok(unsafe { io::split_init(buf, nrecv) })
+}
+
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 pub(crate) fn send(fd: BorrowedFd<'_>, buf: &[u8], flags: SendFlags) -> io::Result<usize> {
     let nwritten = unsafe {
@@ -1444,6 +2147,74 @@ pub(crate) fn recvfrom(
     }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn sendmsg(
+    fd: BorrowedFd<'_>,
+    iov: &[IoSlice<'_>],
+    control: &[u8],
+    flags: SendFlags,
+) -> io::Result<usize> {
+    let mut msg: libc::msghdr = unsafe { std::mem::zeroed() };
+    msg.msg_iov = iov.as_ptr() as *mut libc::iovec;
+    msg.msg_iovlen = iov.len().try_into().map_err(|_| io::Error::OVERFLOW)?;
+    msg.msg_control = control.as_ptr() as *mut c_void;
+    msg.msg_controllen = control.len().try_into().map_err(|_| io::Error::OVERFLOW)?;
+
+    let nwritten = unsafe { ret_ssize_t(libc::sendmsg(borrowed_fd(fd), &msg, flags.bits()))? };
+    Ok(nwritten as usize)
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn recvmsg(
+    fd: BorrowedFd<'_>,
+    iov: &mut [IoSliceMut<'_>],
+    control: &mut [u8],
+    flags: RecvFlags,
+) -> io::Result<(usize, usize, ReturnFlags)> {
+    let mut msg: libc::msghdr = unsafe { std::mem::zeroed() };
+    msg.msg_iov = iov.as_mut_ptr().cast::<libc::iovec>();
+    msg.msg_iovlen = iov.len().try_into().map_err(|_| io::Error::OVERFLOW)?;
+    msg.msg_control = control.as_mut_ptr().cast::<c_void>();
+    msg.msg_controllen = control.len().try_into().map_err(|_| io::Error::OVERFLOW)?;
+
+    let nread = unsafe { ret_ssize_t(libc::recvmsg(borrowed_fd(fd), &mut msg, flags.bits()))? };
+    Ok((
+        nread as usize,
+        msg.msg_controllen as usize,
+        ReturnFlags::from_bits_truncate(msg.msg_flags),
+    ))
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn recvfrom_flags(
+    fd: BorrowedFd<'_>,
+    buf: &mut [u8],
+    flags: RecvFlags,
+) -> io::Result<(usize, ReturnFlags, Option<SocketAddr>)> {
+    let mut storage = MaybeUninit::<libc::sockaddr_storage>::uninit();
+    let mut iov = [IoSliceMut::new(buf)];
+    let mut msg: libc::msghdr = unsafe { std::mem::zeroed() };
+    msg.msg_name = storage.as_mut_ptr().cast::<c_void>();
+    msg.msg_namelen = size_of::<libc::sockaddr_storage>() as libc::socklen_t;
+    msg.msg_iov = iov.as_mut_ptr().cast::<libc::iovec>();
+    msg.msg_iovlen = 1;
+
+    unsafe {
+        let nread = ret_ssize_t(libc::recvmsg(borrowed_fd(fd), &mut msg, flags.bits()))?;
+        // Connected sockets may not report an address.
+        let addr = if msg.msg_namelen == 0 {
+            None
+        } else {
+            Some(decode_sockaddr(storage.as_ptr(), msg.msg_namelen))
+        };
+        Ok((
+            nread as usize,
+            ReturnFlags::from_bits_truncate(msg.msg_flags),
+            addr,
+        ))
+    }
+}
+
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 pub(crate) fn sendto_v4(
     fd: BorrowedFd<'_>,
@@ -1458,7 +2229,7 @@ pub(crate) fn sendto_v4(
             buf.len(),
             flags.bits(),
             as_ptr(&addr.encode()).cast::<libc::sockaddr>(),
-            size_of::<SocketAddrV4>() as u32,
+            size_of::<libc::sockaddr_in>() as libc::socklen_t,
         ))?
     };
     Ok(nwritten as usize)
@@ -1478,7 +2249,7 @@ pub(crate) fn sendto_v6(
             buf.len(),
             flags.bits(),
             as_ptr(&addr.encode()).cast::<libc::sockaddr>(),
-            size_of::<SocketAddrV6>() as u32,
+            size_of::<libc::sockaddr_in6>() as libc::socklen_t,
         ))?
     };
     Ok(nwritten as usize)
@@ -1498,7 +2269,7 @@ pub(crate) fn sendto_unix(
             buf.len(),
             flags.bits(),
             as_ptr(&addr.encode()).cast::<libc::sockaddr>(),
-            size_of::<SocketAddrUnix>() as u32,
+            addr.addr_len(),
         ))?
     };
     Ok(nwritten as usize)
@@ -1547,7 +2318,7 @@ pub(crate) fn bind_unix(sockfd: BorrowedFd<'_>, addr: &SocketAddrUnix) -> io::Re
         ret(libc::bind(
             borrowed_fd(sockfd),
             as_ptr(&addr.encode()).cast::<_>(),
-            size_of::<libc::sockaddr_un>() as libc::socklen_t,
+            addr.addr_len(),
         ))
     }
 }
@@ -1580,7 +2351,7 @@ pub(crate) fn connect_unix(sockfd: BorrowedFd<'_>, addr: &SocketAddrUnix) -> io:
         ret(libc::connect(
             borrowed_fd(sockfd),
             as_ptr(&addr.encode()).cast::<_>(),
-            size_of::<libc::sockaddr_un>() as libc::socklen_t,
+            addr.addr_len(),
         ))
     }
 }
@@ -1697,49 +2468,344 @@ pub(crate) fn getsockopt_socket_type(fd: BorrowedFd<'_>) -> io::Result<SocketTyp
 }
 
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
-pub(crate) fn getsockname(sockfd: BorrowedFd<'_>) -> io::Result<SocketAddr> {
+#[inline]
+fn getsockopt<T>(fd: BorrowedFd<'_>, level: c_int, optname: c_int) -> io::Result<T> {
+    let mut optlen = size_of::<T>() as libc::socklen_t;
+    let mut value = MaybeUninit::<T>::uninit();
     unsafe {
-        let mut storage = MaybeUninit::<libc::sockaddr_storage>::uninit();
-        let mut len = size_of::<libc::sockaddr_storage>() as libc::socklen_t;
-        ret(libc::getsockname(
-            borrowed_fd(sockfd),
-            storage.as_mut_ptr().cast::<_>(),
-            &mut len,
+        ret(libc::getsockopt(
+            borrowed_fd(fd),
+            level,
+            optname,
+            value.as_mut_ptr().cast::<c_void>(),
+            &mut optlen,
         ))?;
-        Ok(decode_sockaddr(storage.as_ptr(), len))
+        assert_eq!(
+            optlen as usize,
+            size_of::<T>(),
+            "unexpected getsockopt size"
+        );
+        Ok(value.assume_init())
     }
 }
 
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
-pub(crate) fn getpeername(sockfd: BorrowedFd<'_>) -> io::Result<SocketAddr> {
+#[inline]
+fn setsockopt<T>(fd: BorrowedFd<'_>, level: c_int, optname: c_int, value: T) -> io::Result<()> {
+    let optlen = size_of::<T>() as libc::socklen_t;
     unsafe {
-        let mut storage = MaybeUninit::<libc::sockaddr_storage>::uninit();
-        let mut len = size_of::<libc::sockaddr_storage>() as libc::socklen_t;
-        ret(libc::getpeername(
-            borrowed_fd(sockfd),
-            storage.as_mut_ptr().cast::<_>(),
-            &mut len,
-        ))?;
-        Ok(decode_sockaddr(storage.as_ptr(), len))
+        ret(libc::setsockopt(
+            borrowed_fd(fd),
+            level,
+            optname,
+            as_ptr(&value).cast::<c_void>(),
+            optlen,
+        ))
     }
 }
 
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
-pub(crate) fn socketpair(
-    domain: AddressFamily,
-    type_: SocketType,
-    accept_flags: AcceptFlags,
-    protocol: Protocol,
-) -> io::Result<(OwnedFd, OwnedFd)> {
-    unsafe {
-        let mut fds = MaybeUninit::<[OwnedFd; 2]>::uninit();
-        ret(libc::socketpair(
-            domain.0 as c_int,
-            type_.0 as c_int | accept_flags.bits(),
-            protocol as c_int,
-            fds.as_mut_ptr().cast::<c_int>(),
-        ))?;
-
+#[inline]
+pub(crate) fn set_reuseaddr(fd: BorrowedFd<'_>, reuseaddr: bool) -> io::Result<()> {
+    setsockopt(
+        fd,
+        libc::SOL_SOCKET,
+        libc::SO_REUSEADDR,
+        c_int::from(reuseaddr),
+    )
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[inline]
+pub(crate) fn get_reuseaddr(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt::<c_int>(fd, libc::SOL_SOCKET, libc::SO_REUSEADDR).map(|reuseaddr| reuseaddr != 0)
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[inline]
+pub(crate) fn set_tcp_nodelay(fd: BorrowedFd<'_>, nodelay: bool) -> io::Result<()> {
+    setsockopt(
+        fd,
+        libc::IPPROTO_TCP,
+        libc::TCP_NODELAY,
+        c_int::from(nodelay),
+    )
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[inline]
+pub(crate) fn get_tcp_nodelay(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt::<c_int>(fd, libc::IPPROTO_TCP, libc::TCP_NODELAY).map(|nodelay| nodelay != 0)
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[inline]
+pub(crate) fn set_recv_buffer_size(fd: BorrowedFd<'_>, size: usize) -> io::Result<()> {
+    let size: c_int = size.try_into().map_err(|_| io::Error::OVERFLOW)?;
+    setsockopt(fd, libc::SOL_SOCKET, libc::SO_RCVBUF, size)
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[inline]
+pub(crate) fn get_recv_buffer_size(fd: BorrowedFd<'_>) -> io::Result<usize> {
+    getsockopt::<c_int>(fd, libc::SOL_SOCKET, libc::SO_RCVBUF).map(|size| size as usize)
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[inline]
+pub(crate) fn set_send_buffer_size(fd: BorrowedFd<'_>, size: usize) -> io::Result<()> {
+    let size: c_int = size.try_into().map_err(|_| io::Error::OVERFLOW)?;
+    setsockopt(fd, libc::SOL_SOCKET, libc::SO_SNDBUF, size)
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[inline]
+pub(crate) fn get_send_buffer_size(fd: BorrowedFd<'_>) -> io::Result<usize> {
+    getsockopt::<c_int>(fd, libc::SOL_SOCKET, libc::SO_SNDBUF).map(|size| size as usize)
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[inline]
+pub(crate) fn set_socket_linger(fd: BorrowedFd<'_>, linger: Option<Duration>) -> io::Result<()> {
+    let linger = match linger {
+        // Round up, so that a sub-second linger doesn't become zero, which
+        // would make `close` reset the connection.
+        Some(linger) => libc::linger {
+            l_onoff: 1,
+            l_linger: (linger.as_secs() + u64::from(linger.subsec_nanos() != 0))
+                .try_into()
+                .map_err(|_| io::Error::OVERFLOW)?,
+        },
+        None => libc::linger {
+            l_onoff: 0,
+            l_linger: 0,
+        },
+    };
+    setsockopt(fd, libc::SOL_SOCKET, libc::SO_LINGER, linger)
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[inline]
+pub(crate) fn get_socket_linger(fd: BorrowedFd<'_>) -> io::Result<Option<Duration>> {
+    let linger: libc::linger = getsockopt(fd, libc::SOL_SOCKET, libc::SO_LINGER)?;
+    Ok(if linger.l_onoff != 0 {
+        Some(Duration::from_secs(linger.l_linger as u64))
+    } else {
+        None
+    })
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[inline]
+pub(crate) fn set_keepalive(fd: BorrowedFd<'_>, keepalive: bool) -> io::Result<()> {
+    setsockopt(
+        fd,
+        libc::SOL_SOCKET,
+        libc::SO_KEEPALIVE,
+        c_int::from(keepalive),
+    )
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[inline]
+pub(crate) fn get_keepalive(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt::<c_int>(fd, libc::SOL_SOCKET, libc::SO_KEEPALIVE).map(|keepalive| keepalive != 0)
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[inline]
+pub(crate) fn get_socket_error(fd: BorrowedFd<'_>) -> io::Result<Result<(), io::Error>> {
+    let err: c_int = getsockopt(fd, libc::SOL_SOCKET, libc::SO_ERROR)?;
+    Ok(if err == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::from_raw_os_error(err))
+    })
+}
+
+/// Convert a `Duration` to the whole number of seconds the TCP keepalive
+/// options expect, rounding up, and rejecting zero.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+fn keepalive_secs(duration: Duration) -> io::Result<c_int> {
+    let secs = duration.as_secs() + u64::from(duration.subsec_nanos() != 0);
+    if secs == 0 {
+        return Err(io::Error::INVAL);
+    }
+    secs.try_into().map_err(|_| io::Error::OVERFLOW)
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn set_tcp_keepidle(fd: BorrowedFd<'_>, idle: Duration) -> io::Result<()> {
+    let idle = keepalive_secs(idle)?;
+    setsockopt(fd, libc::IPPROTO_TCP, libc::TCP_KEEPIDLE, idle)
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn get_tcp_keepidle(fd: BorrowedFd<'_>) -> io::Result<Duration> {
+    getsockopt::<c_int>(fd, libc::IPPROTO_TCP, libc::TCP_KEEPIDLE)
+        .map(|idle| Duration::from_secs(idle as u64))
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn set_tcp_keepintvl(fd: BorrowedFd<'_>, interval: Duration) -> io::Result<()> {
+    let interval = keepalive_secs(interval)?;
+    setsockopt(fd, libc::IPPROTO_TCP, libc::TCP_KEEPINTVL, interval)
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn get_tcp_keepintvl(fd: BorrowedFd<'_>) -> io::Result<Duration> {
+    getsockopt::<c_int>(fd, libc::IPPROTO_TCP, libc::TCP_KEEPINTVL)
+        .map(|interval| Duration::from_secs(interval as u64))
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn set_tcp_keepcnt(fd: BorrowedFd<'_>, count: u32) -> io::Result<()> {
+    let count: c_int = count.try_into().map_err(|_| io::Error::OVERFLOW)?;
+    setsockopt(fd, libc::IPPROTO_TCP, libc::TCP_KEEPCNT, count)
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn get_tcp_keepcnt(fd: BorrowedFd<'_>) -> io::Result<u32> {
+    getsockopt::<c_int>(fd, libc::IPPROTO_TCP, libc::TCP_KEEPCNT).map(|count| count as u32)
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[inline]
+pub(crate) fn set_ip_multicast_loop(fd: BorrowedFd<'_>, multicast_loop: bool) -> io::Result<()> {
+    setsockopt(
+        fd,
+        libc::IPPROTO_IP,
+        libc::IP_MULTICAST_LOOP,
+        c_int::from(multicast_loop),
+    )
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[inline]
+pub(crate) fn get_ip_multicast_loop(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt::<c_int>(fd, libc::IPPROTO_IP, libc::IP_MULTICAST_LOOP)
+        .map(|multicast_loop| multicast_loop != 0)
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[inline]
+pub(crate) fn set_ip_multicast_ttl(fd: BorrowedFd<'_>, ttl: u32) -> io::Result<()> {
+    let ttl: c_int = ttl.try_into().map_err(|_| io::Error::OVERFLOW)?;
+    setsockopt(fd, libc::IPPROTO_IP, libc::IP_MULTICAST_TTL, ttl)
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[inline]
+pub(crate) fn get_ip_multicast_ttl(fd: BorrowedFd<'_>) -> io::Result<u32> {
+    getsockopt::<c_int>(fd, libc::IPPROTO_IP, libc::IP_MULTICAST_TTL).map(|ttl| ttl as u32)
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[inline]
+pub(crate) fn join_multicast_v4(
+    fd: BorrowedFd<'_>,
+    multiaddr: &Ipv4Addr,
+    interface: &Ipv4Addr,
+) -> io::Result<()> {
+    let mreq = libc::ip_mreq {
+        imr_multiaddr: multiaddr.0,
+        imr_interface: interface.0,
+    };
+    setsockopt(fd, libc::IPPROTO_IP, libc::IP_ADD_MEMBERSHIP, mreq)
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[inline]
+pub(crate) fn leave_multicast_v4(
+    fd: BorrowedFd<'_>,
+    multiaddr: &Ipv4Addr,
+    interface: &Ipv4Addr,
+) -> io::Result<()> {
+    let mreq = libc::ip_mreq {
+        imr_multiaddr: multiaddr.0,
+        imr_interface: interface.0,
+    };
+    setsockopt(fd, libc::IPPROTO_IP, libc::IP_DROP_MEMBERSHIP, mreq)
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[inline]
+pub(crate) fn join_multicast_v6(
+    fd: BorrowedFd<'_>,
+    multiaddr: &Ipv6Addr,
+    interface: u32,
+) -> io::Result<()> {
+    let mreq = libc::ipv6_mreq {
+        ipv6mr_multiaddr: multiaddr.0,
+        ipv6mr_interface: interface.try_into().map_err(|_| io::Error::OVERFLOW)?,
+    };
+    setsockopt(fd, libc::IPPROTO_IPV6, IPV6_ADD_MEMBERSHIP, mreq)
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[inline]
+pub(crate) fn leave_multicast_v6(
+    fd: BorrowedFd<'_>,
+    multiaddr: &Ipv6Addr,
+    interface: u32,
+) -> io::Result<()> {
+    let mreq = libc::ipv6_mreq {
+        ipv6mr_multiaddr: multiaddr.0,
+        ipv6mr_interface: interface.try_into().map_err(|_| io::Error::OVERFLOW)?,
+    };
+    setsockopt(fd, libc::IPPROTO_IPV6, IPV6_DROP_MEMBERSHIP, mreq)
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+pub(crate) fn getsockname(sockfd: BorrowedFd<'_>) -> io::Result<SocketAddr> {
+    unsafe {
+        let mut storage = MaybeUninit::<libc::sockaddr_storage>::uninit();
+        let mut len = size_of::<libc::sockaddr_storage>() as libc::socklen_t;
+        ret(libc::getsockname(
+            borrowed_fd(sockfd),
+            storage.as_mut_ptr().cast::<_>(),
+            &mut len,
+        ))?;
+        Ok(decode_sockaddr(storage.as_ptr(), len))
+    }
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+pub(crate) fn getpeername(sockfd: BorrowedFd<'_>) -> io::Result<SocketAddr> {
+    unsafe {
+        let mut storage = MaybeUninit::<libc::sockaddr_storage>::uninit();
+        let mut len = size_of::<libc::sockaddr_storage>() as libc::socklen_t;
+        ret(libc::getpeername(
+            borrowed_fd(sockfd),
+            storage.as_mut_ptr().cast::<_>(),
+            &mut len,
+        ))?;
+        Ok(decode_sockaddr(storage.as_ptr(), len))
+    }
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+pub(crate) fn socketpair(
+    domain: AddressFamily,
+    type_: SocketType,
+    accept_flags: AcceptFlags,
+    protocol: Protocol,
+) -> io::Result<(OwnedFd, OwnedFd)> {
+    unsafe {
+        let mut fds = MaybeUninit::<[OwnedFd; 2]>::uninit();
+        ret(libc::socketpair(
+            domain.0 as c_int,
+            type_.0 as c_int | accept_flags.bits(),
+            protocol as c_int,
+            fds.as_mut_ptr().cast::<c_int>(),
+        ))?;
+
         let [fd0, fd1] = fds.assume_init();
         Ok((fd0, fd1))
     }
@@ -1757,6 +2823,188 @@ pub(crate) fn getrandom(buf: &mut [u8], flags: GetRandomFlags) -> io::Result<usi
     Ok(nread as usize)
 }
 
+#[cfg(target_os = "linux")]
+fn sigevent_to_raw(event: &Sigevent) -> libc::sigevent {
+    // `struct sigevent` has a union and padding, so start from zero and fill
+    // in the fields we use.
+    let mut raw: libc::sigevent = unsafe { std::mem::zeroed() };
+    match *event {
+        Sigevent::None => raw.sigev_notify = libc::SIGEV_NONE,
+        Sigevent::Signal { signo, value } => {
+            raw.sigev_notify = libc::SIGEV_SIGNAL;
+            raw.sigev_signo = signo.as_raw();
+            raw.sigev_value.sival_ptr = value as *mut c_void;
+        }
+        Sigevent::ThreadSignal { signo, value, tid } => {
+            raw.sigev_notify = libc::SIGEV_THREAD_ID;
+            raw.sigev_signo = signo.as_raw();
+            raw.sigev_value.sival_ptr = value as *mut c_void;
+            raw.sigev_notify_thread_id = tid.as_raw();
+        }
+    }
+    raw
+}
+
+#[cfg(target_os = "linux")]
+#[inline]
+pub(crate) fn timer_create(id: ClockId, event: &Sigevent) -> io::Result<RawTimer> {
+    let mut event = sigevent_to_raw(event);
+    let mut timer = MaybeUninit::<RawTimer>::uninit();
+    unsafe {
+        ret(libc::timer_create(
+            id as libc::clockid_t,
+            &mut event,
+            timer.as_mut_ptr(),
+        ))?;
+        Ok(timer.assume_init())
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[inline]
+pub(crate) fn timer_settime(
+    timer: RawTimer,
+    flags: TimerFlags,
+    new_value: &Itimerspec,
+) -> io::Result<Itimerspec> {
+    let mut old_value = MaybeUninit::<Itimerspec>::uninit();
+    unsafe {
+        ret(libc::timer_settime(
+            timer,
+            flags.bits(),
+            new_value,
+            old_value.as_mut_ptr(),
+        ))?;
+        Ok(old_value.assume_init())
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[inline]
+pub(crate) fn timer_gettime(timer: RawTimer) -> io::Result<Itimerspec> {
+    let mut curr_value = MaybeUninit::<Itimerspec>::uninit();
+    unsafe {
+        ret(libc::timer_gettime(timer, curr_value.as_mut_ptr()))?;
+        Ok(curr_value.assume_init())
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[inline]
+pub(crate) fn timer_getoverrun(timer: RawTimer) -> io::Result<c_int> {
+    unsafe { ret_c_int(libc::timer_getoverrun(timer)) }
+}
+
+#[cfg(target_os = "linux")]
+#[inline]
+pub(crate) fn timer_delete(timer: RawTimer) -> io::Result<()> {
+    unsafe { ret(libc::timer_delete(timer)) }
+}
+
+#[cfg(target_os = "linux")]
+#[inline]
+pub(crate) fn timerfd_create(id: ClockId, flags: TimerfdFlags) -> io::Result<OwnedFd> {
+    unsafe { ret_owned_fd(libc::timerfd_create(id as libc::clockid_t, flags.bits())) }
+}
+
+#[cfg(target_os = "linux")]
+#[inline]
+pub(crate) fn timerfd_settime(
+    fd: BorrowedFd<'_>,
+    flags: TimerfdTimerFlags,
+    new_value: &Itimerspec,
+) -> io::Result<Itimerspec> {
+    let mut old_value = MaybeUninit::<Itimerspec>::uninit();
+    unsafe {
+        ret(libc::timerfd_settime(
+            borrowed_fd(fd),
+            flags.bits(),
+            new_value,
+            old_value.as_mut_ptr(),
+        ))?;
+        Ok(old_value.assume_init())
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[inline]
+pub(crate) fn timerfd_gettime(fd: BorrowedFd<'_>) -> io::Result<Itimerspec> {
+    let mut curr_value = MaybeUninit::<Itimerspec>::uninit();
+    unsafe {
+        ret(libc::timerfd_gettime(
+            borrowed_fd(fd),
+            curr_value.as_mut_ptr(),
+        ))?;
+        Ok(curr_value.assume_init())
+    }
+}
+
+#[cfg(not(any(
+    target_os = "ios",
+    target_os = "macos",
+    target_os = "redox",
+    target_os = "wasi"
+)))]
+#[inline]
+pub(crate) fn clock_settime(id: ClockId, timespec: Timespec) -> io::Result<()> {
+    unsafe { ret(libc::clock_settime(id as libc::clockid_t, &timespec)) }
+}
+
+/// Converts a `Timex` field to the type `struct timex` uses for it, which is
+/// `c_long` on most platforms and so narrower than `i64` on 32-bit ones.
+#[cfg(target_os = "linux")]
+#[inline]
+fn timex_field_to_raw<T: std::convert::TryFrom<i64>>(value: i64) -> io::Result<T> {
+    T::try_from(value).map_err(|_| io::Error::OVERFLOW)
+}
+
+/// Converts a `struct timex` field to the `i64` `Timex` uses for it.
+#[cfg(target_os = "linux")]
+#[inline]
+fn timex_field_from_raw<T: Into<i64>>(value: T) -> i64 {
+    value.into()
+}
+
+#[cfg(target_os = "linux")]
+fn timex_to_raw(timex: &Timex) -> io::Result<libc::timex> {
+    // `struct timex` has padding fields, so start from zero and fill in the
+    // ones `Timex` exposes; the kernel ignores the others on input.
+    let mut raw: libc::timex = unsafe { std::mem::zeroed() };
+    raw.modes = timex.modes.bits();
+    raw.offset = timex_field_to_raw(timex.offset)?;
+    raw.freq = timex_field_to_raw(timex.freq)?;
+    raw.maxerror = timex_field_to_raw(timex.maxerror)?;
+    raw.esterror = timex_field_to_raw(timex.esterror)?;
+    raw.status = timex.status.bits();
+    raw.constant = timex_field_to_raw(timex.constant)?;
+    raw.tick = timex_field_to_raw(timex.tick)?;
+    Ok(raw)
+}
+
+#[cfg(target_os = "linux")]
+fn timex_from_raw(raw: &libc::timex, timex: &mut Timex) {
+    timex.modes = TimexModes::from_bits_truncate(raw.modes);
+    timex.offset = timex_field_from_raw(raw.offset);
+    timex.freq = timex_field_from_raw(raw.freq);
+    timex.maxerror = timex_field_from_raw(raw.maxerror);
+    timex.esterror = timex_field_from_raw(raw.esterror);
+    timex.status = TimexStatus::from_bits_truncate(raw.status);
+    timex.constant = timex_field_from_raw(raw.constant);
+    timex.precision = timex_field_from_raw(raw.precision);
+    timex.tolerance = timex_field_from_raw(raw.tolerance);
+    timex.tick = timex_field_from_raw(raw.tick);
+    timex.tai = raw.tai;
+}
+
+#[cfg(target_os = "linux")]
+#[inline]
+pub(crate) fn clock_adjtime(id: ClockId, timex: &mut Timex) -> io::Result<AdjtimeState> {
+    let mut raw = timex_to_raw(timex)?;
+    let state = unsafe { ret_c_int(libc::clock_adjtime(id as libc::clockid_t, &mut raw))? };
+    timex_from_raw(&raw, timex);
+    AdjtimeState::from_raw(state).ok_or(io::Error::INVAL)
+}
+
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 #[inline]
 #[must_use]
@@ -1787,6 +3035,36 @@ pub(crate) fn clock_gettime(id: ClockId) -> Timespec {
     }
 }
 
+#[cfg(target_os = "wasi")]
+#[inline]
+#[must_use]
+pub(crate) fn clock_gettime(id: ClockId) -> Timespec {
+    // wasi-libc's `clockid_t` is a pointer rather than an integer, so the
+    // libc crate doesn't declare its `clock_gettime`. Call WASI's
+    // `clock_time_get` directly instead.
+    #[link(wasm_import_module = "wasi_snapshot_preview1")]
+    extern "C" {
+        fn clock_time_get(id: u32, precision: u64, time: *mut u64) -> u16;
+    }
+
+    let mut time = MaybeUninit::<u64>::uninit();
+    // As above, use `unwrap()`; the realtime and monotonic clocks can't
+    // reasonably fail. WASI hosts aren't required to support the CPU-time
+    // clocks, but `clock_time_get` reports that with an errno, which is
+    // handled the same way.
+    let time = unsafe {
+        match clock_time_get(id as u32, 1, time.as_mut_ptr()) {
+            0 => Ok(time.assume_init()),
+            errno => Err(io::Error::from_raw_os_error(errno.into())),
+        }
+        .unwrap()
+    };
+    Timespec {
+        tv_sec: (time / 1_000_000_000) as _,
+        tv_nsec: (time % 1_000_000_000) as _,
+    }
+}
+
 #[cfg(not(target_os = "wasi"))]
 #[inline]
 #[must_use]
@@ -1990,6 +3268,24 @@ pub(crate) fn getpath(fd: BorrowedFd<'_>) -> io::Result<PathBuf> {
     Ok(PathBuf::from(std::ffi::OsString::from_vec(buf)))
 }
 
+#[cfg(not(any(
+    target_os = "android",
+    target_os = "linux",
+    target_os = "redox",
+    target_os = "wasi"
+)))]
+pub(crate) fn realpath(path: &CStr) -> io::Result<std::path::PathBuf> {
+    unsafe {
+        let resolved = libc::realpath(c_str(path), null_mut());
+        if resolved.is_null() {
+            return Err(io::Error::last_os_error());
+        }
+        let buf = CStr::from_ptr(resolved).to_bytes().to_vec();
+        libc::free(resolved.cast::<c_void>());
+        Ok(std::path::PathBuf::from(OsString::from_vec(buf)))
+    }
+}
+
 #[cfg(any(target_os = "ios", target_os = "macos"))]
 pub(crate) fn fcntl_rdadvise(fd: BorrowedFd<'_>, offset: u64, len: u64) -> io::Result<()> {
     // From the macOS `fcntl` man page:
@@ -2027,6 +3323,107 @@ pub(crate) fn fcntl_rdadvise(fd: BorrowedFd<'_>, offset: u64, len: u64) -> io::R
     }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn getresuid() -> ResUid {
+    let mut real = MaybeUninit::<libc::uid_t>::uninit();
+    let mut effective = MaybeUninit::<libc::uid_t>::uninit();
+    let mut saved = MaybeUninit::<libc::uid_t>::uninit();
+    unsafe {
+        let _ = libc::getresuid(
+            real.as_mut_ptr(),
+            effective.as_mut_ptr(),
+            saved.as_mut_ptr(),
+        );
+        ResUid {
+            real: Uid::from_raw(real.assume_init()),
+            effective: Uid::from_raw(effective.assume_init()),
+            saved: Uid::from_raw(saved.assume_init()),
+        }
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn getresgid() -> ResGid {
+    let mut real = MaybeUninit::<libc::gid_t>::uninit();
+    let mut effective = MaybeUninit::<libc::gid_t>::uninit();
+    let mut saved = MaybeUninit::<libc::gid_t>::uninit();
+    unsafe {
+        let _ = libc::getresgid(
+            real.as_mut_ptr(),
+            effective.as_mut_ptr(),
+            saved.as_mut_ptr(),
+        );
+        ResGid {
+            real: Gid::from_raw(real.assume_init()),
+            effective: Gid::from_raw(effective.assume_init()),
+            saved: Gid::from_raw(saved.assume_init()),
+        }
+    }
+}
+
+// libc's `setresuid`, `setresgid`, and `setgroups` make every thread in the
+// process change its IDs, so make the system calls directly, which only
+// affect the calling thread, as the `linux_raw` backend does.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn setresuid_thread(real: Uid, effective: Uid, saved: Uid) -> io::Result<()> {
+    unsafe {
+        syscall_ret(libc::syscall(
+            SYS_setresuid,
+            real.as_raw(),
+            effective.as_raw(),
+            saved.as_raw(),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn setresgid_thread(real: Gid, effective: Gid, saved: Gid) -> io::Result<()> {
+    unsafe {
+        syscall_ret(libc::syscall(
+            SYS_setresgid,
+            real.as_raw(),
+            effective.as_raw(),
+            saved.as_raw(),
+        ))
+    }
+}
+
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    not(any(target_arch = "x86", target_arch = "sparc", target_arch = "arm"))
+))]
+use libc::{SYS_setgroups, SYS_setresgid, SYS_setresuid};
+
+// On these architectures, the syscalls without the `32` suffix use 16-bit
+// IDs.
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    any(target_arch = "x86", target_arch = "sparc", target_arch = "arm")
+))]
+use libc::{
+    SYS_setgroups32 as SYS_setgroups, SYS_setresgid32 as SYS_setresgid,
+    SYS_setresuid32 as SYS_setresuid,
+};
+
+#[cfg(not(target_os = "wasi"))]
+pub(crate) fn getgroups(buf: &mut [Gid]) -> io::Result<usize> {
+    let len = buf.len().try_into().map_err(|_| io::Error::INVAL)?;
+    unsafe {
+        ret_c_int(libc::getgroups(len, buf.as_mut_ptr().cast::<libc::gid_t>())).map(|n| n as usize)
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn setgroups_thread(groups: &[Gid]) -> io::Result<()> {
+    unsafe {
+        syscall_ret(libc::syscall(
+            SYS_setgroups,
+            groups.len(),
+            groups.as_ptr().cast::<libc::gid_t>(),
+        ))
+    }
+}
+
 #[cfg(not(target_os = "wasi"))]
 #[inline]
 #[must_use]
@@ -2087,6 +3484,54 @@ pub(crate) fn getppid() -> Pid {
     }
 }
 
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub(crate) fn setsid() -> io::Result<Pid> {
+    unsafe {
+        let pid = ret_c_int(libc::setsid())?;
+        Ok(Pid::from_raw(pid))
+    }
+}
+
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub(crate) fn setpgid(pid: Option<Pid>, pgid: Option<Pid>) -> io::Result<()> {
+    unsafe {
+        ret(libc::setpgid(
+            pid.map_or(0, Pid::as_raw),
+            pgid.map_or(0, Pid::as_raw),
+        ))
+    }
+}
+
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub(crate) fn getpgid(pid: Option<Pid>) -> io::Result<Pid> {
+    unsafe {
+        let pgid = ret_c_int(libc::getpgid(pid.map_or(0, Pid::as_raw)))?;
+        Ok(Pid::from_raw(pgid))
+    }
+}
+
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+#[must_use]
+pub(crate) fn getpgrp() -> Pid {
+    unsafe {
+        let pgid: i32 = libc::getpgrp();
+        Pid::from_raw(pgid)
+    }
+}
+
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub(crate) fn getsid(pid: Option<Pid>) -> io::Result<Pid> {
+    unsafe {
+        let sid = ret_c_int(libc::getsid(pid.map_or(0, Pid::as_raw)))?;
+        Ok(Pid::from_raw(sid))
+    }
+}
+
 #[cfg(any(target_os = "android", target_os = "linux"))]
 #[inline]
 #[must_use]
@@ -2097,6 +3542,130 @@ pub(crate) fn gettid() -> Pid {
     }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn set_thread_name(name: &CStr) -> io::Result<()> {
+    unsafe { ret(libc::prctl(libc::PR_SET_NAME, c_str(name))) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn thread_name(buf: &mut [u8; 16]) -> io::Result<()> {
+    unsafe { ret(libc::prctl(libc::PR_GET_NAME, buf.as_mut_ptr())) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn futex_wait(
+    uaddr: &AtomicU32,
+    flags: FutexFlags,
+    val: u32,
+    timeout: Option<&Timespec>,
+) -> io::Result<()> {
+    unsafe {
+        syscall_ret(libc::syscall(
+            libc::SYS_futex,
+            as_ptr(uaddr),
+            FUTEX_WAIT | flags.bits(),
+            val,
+            timeout.map_or_else(null, |timeout| timeout as *const Timespec),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn futex_wake(uaddr: &AtomicU32, flags: FutexFlags, count: u32) -> io::Result<usize> {
+    unsafe {
+        syscall_ret_ssize_t(libc::syscall(
+            libc::SYS_futex,
+            as_ptr(uaddr),
+            FUTEX_WAKE | flags.bits(),
+            count,
+        ))
+        .map(|nwoken| nwoken as usize)
+    }
+}
+
+// musl's `sched_getscheduler` and `sched_setscheduler` always fail with
+// `ENOSYS`, so make the syscalls directly.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn sched_getscheduler(pid: Option<Pid>) -> io::Result<c_int> {
+    unsafe {
+        syscall_ret_ssize_t(libc::syscall(
+            libc::SYS_sched_getscheduler,
+            pid.map_or(0, Pid::as_raw),
+        ))
+        .map(|policy| policy as c_int)
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn sched_setscheduler(
+    pid: Option<Pid>,
+    policy: SchedPolicy,
+    flags: SchedFlags,
+    priority: c_int,
+) -> io::Result<()> {
+    // The kernel's `struct sched_param` holds just the priority; musl's
+    // `sched_param` has extra fields, so don't use it here.
+    unsafe {
+        syscall_ret(libc::syscall(
+            libc::SYS_sched_setscheduler,
+            pid.map_or(0, Pid::as_raw),
+            policy as c_int | flags.bits(),
+            as_ptr(&priority),
+        ))
+    }
+}
+
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub(crate) fn kill_process(pid: Pid, sig: c_int) -> io::Result<()> {
+    unsafe { ret(libc::kill(pid.as_raw(), sig)) }
+}
+
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub(crate) fn kill_process_group(pid: Pid, sig: c_int) -> io::Result<()> {
+    unsafe { ret(libc::kill(-pid.as_raw(), sig)) }
+}
+
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub(crate) fn kill_current_process_group(sig: c_int) -> io::Result<()> {
+    unsafe { ret(libc::kill(0, sig)) }
+}
+
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub(crate) fn waitpid(pid: Option<Pid>, waitopts: WaitOptions) -> io::Result<Option<(Pid, c_int)>> {
+    let pid = pid.map_or(-1, Pid::as_raw);
+    let mut status: c_int = 0;
+    unsafe {
+        let pid = ret_c_int(libc::waitpid(pid, &mut status, waitopts.bits()))?;
+        Ok(if pid == 0 {
+            None
+        } else {
+            Some((Pid::from_raw(pid), status))
+        })
+    }
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[inline]
+pub(crate) fn sigprocmask(how: SigmaskHow, set: Option<&SigSet>) -> io::Result<SigSet> {
+    let mut old = SigSet::new();
+    unsafe {
+        ret(libc::sigprocmask(
+            how as c_int,
+            set.map_or_else(null, |set| &set.0),
+            &mut old.0,
+        ))?;
+    }
+    Ok(old)
+}
+
 #[inline]
 pub(crate) fn sched_yield() {
     unsafe {
@@ -2104,6 +3673,111 @@ pub(crate) fn sched_yield() {
     }
 }
 
+#[cfg(not(target_os = "wasi"))]
+pub(crate) fn getrusage(who: RusageWho) -> io::Result<RawRusage> {
+    let mut usage = MaybeUninit::<RawRusage>::uninit();
+    unsafe { ret(libc::getrusage(who as c_int, usage.as_mut_ptr())).map(|()| usage.assume_init()) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn sysinfo() -> io::Result<RawSysinfo> {
+    let mut info = MaybeUninit::<RawSysinfo>::uninit();
+    unsafe { ret(libc::sysinfo(info.as_mut_ptr())).map(|()| info.assume_init()) }
+}
+
+#[cfg(not(any(target_os = "android", target_os = "linux", target_os = "wasi")))]
+pub(crate) fn gethostname(buf: &mut [u8]) -> io::Result<()> {
+    unsafe {
+        ret(libc::gethostname(
+            buf.as_mut_ptr().cast::<libc::c_char>(),
+            buf.len(),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn sethostname(name: &[u8]) -> io::Result<()> {
+    unsafe {
+        ret(libc::sethostname(
+            name.as_ptr().cast::<libc::c_char>(),
+            name.len(),
+        ))
+    }
+}
+
+#[cfg(not(target_os = "wasi"))]
+pub(crate) fn getcwd(buf: &mut [u8]) -> io::Result<()> {
+    unsafe {
+        if libc::getcwd(buf.as_mut_ptr().cast::<_>(), buf.len()).is_null() {
+            Err(io::Error::last_os_error())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub(crate) fn chdir(path: &CStr) -> io::Result<()> {
+    unsafe { ret(libc::chdir(c_str(path))) }
+}
+
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub(crate) fn fchdir(fd: BorrowedFd<'_>) -> io::Result<()> {
+    unsafe { ret(libc::fchdir(borrowed_fd(fd))) }
+}
+
+#[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))]
+#[inline]
+pub(crate) fn chroot(path: &CStr) -> io::Result<()> {
+    unsafe { ret(libc::chroot(c_str(path))) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn pivot_root(new_root: &CStr, put_old: &CStr) -> io::Result<()> {
+    unsafe {
+        syscall_ret(libc::syscall(
+            libc::SYS_pivot_root,
+            c_str(new_root),
+            c_str(put_old),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn mount(
+    source: &CStr,
+    target: &CStr,
+    fstype: &CStr,
+    flags: MountFlags,
+    data: Option<&CStr>,
+) -> io::Result<()> {
+    unsafe {
+        ret(libc::mount(
+            c_str(source),
+            c_str(target),
+            c_str(fstype),
+            flags.bits(),
+            data.map_or_else(null, |data| data.as_ptr().cast()),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn unmount(target: &CStr, flags: UnmountFlags) -> io::Result<()> {
+    unsafe { ret(libc::umount2(c_str(target), flags.bits())) }
+}
+
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub(crate) fn umask(mask: Mode) -> Mode {
+    unsafe { Mode::from_bits_truncate(libc::umask(mask.bits())) }
+}
+
 #[cfg(not(target_os = "wasi"))]
 #[inline]
 pub(crate) fn uname() -> RawUname {
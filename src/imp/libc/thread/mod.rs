@@ -0,0 +1,7 @@
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod types;
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use types::FutexFlags;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) use types::{FUTEX_WAIT, FUTEX_WAKE};
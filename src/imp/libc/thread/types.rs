@@ -0,0 +1,17 @@
+use bitflags::bitflags;
+
+bitflags! {
+    /// `FUTEX_*` flags for use with [`futex_wait`] and [`futex_wake`].
+    ///
+    /// [`futex_wait`]: crate::thread::futex_wait
+    /// [`futex_wake`]: crate::thread::futex_wake
+    pub struct FutexFlags: u32 {
+        /// `FUTEX_PRIVATE_FLAG`
+        const PRIVATE = libc::FUTEX_PRIVATE_FLAG as u32;
+        /// `FUTEX_CLOCK_REALTIME`
+        const REALTIME = libc::FUTEX_CLOCK_REALTIME as u32;
+    }
+}
+
+pub(crate) const FUTEX_WAIT: u32 = libc::FUTEX_WAIT as u32;
+pub(crate) const FUTEX_WAKE: u32 = libc::FUTEX_WAKE as u32;
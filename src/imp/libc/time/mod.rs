@@ -1,5 +1,11 @@
 mod types;
 
+pub use types::ClockId;
 #[cfg(not(target_os = "wasi"))]
-pub use types::{ClockId, DynamicClockId};
+pub use types::DynamicClockId;
+#[cfg(target_os = "linux")]
+pub use types::{
+    AdjtimeState, Itimerspec, RawTimer, TimerFlags, TimerfdFlags, TimerfdTimerFlags, TimexModes,
+    TimexStatus,
+};
 pub use types::{Nsecs, Secs, Timespec};
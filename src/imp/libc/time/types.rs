@@ -1,3 +1,5 @@
+#[cfg(target_os = "linux")]
+use bitflags::bitflags;
 #[cfg(not(target_os = "wasi"))]
 use io_lifetimes::BorrowedFd;
 
@@ -75,6 +77,32 @@ pub enum ClockId {
     ThreadCPUTime = libc::CLOCK_THREAD_CPUTIME_ID,
 }
 
+/// `CLOCK_*` constants for use with [`clock_gettime`].
+///
+/// On WASI, these are WASI's own clock IDs, passed to its `clock_time_get`.
+/// WASI hosts are only required to support the realtime and monotonic
+/// clocks, and [`clock_gettime`] panics if the CPU-time clocks are
+/// unsupported.
+///
+/// [`clock_gettime`]: crate::time::clock_gettime
+#[cfg(target_os = "wasi")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(u32)]
+#[non_exhaustive]
+pub enum ClockId {
+    /// `CLOCK_REALTIME`
+    Realtime = 0,
+
+    /// `CLOCK_MONOTONIC`
+    Monotonic = 1,
+
+    /// `CLOCK_PROCESS_CPUTIME_ID`
+    ProcessCPUTime = 2,
+
+    /// `CLOCK_THREAD_CPUTIME_ID`
+    ThreadCPUTime = 3,
+}
+
 /// `CLOCK_*` constants for use with [`clock_gettime_dynamic`].
 ///
 /// These constants may be unsupported at runtime, depending on the OS version,
@@ -108,3 +136,166 @@ pub enum DynamicClockId<'a> {
     #[cfg(any(target_os = "android", target_os = "linux"))]
     BoottimeAlarm,
 }
+
+#[cfg(target_os = "linux")]
+bitflags! {
+    /// `ADJ_*` flags for use in [`Timex::modes`].
+    ///
+    /// [`Timex::modes`]: crate::time::Timex::modes
+    pub struct TimexModes: u32 {
+        /// `ADJ_OFFSET`
+        const OFFSET = libc::ADJ_OFFSET as u32;
+        /// `ADJ_FREQUENCY`
+        const FREQUENCY = libc::ADJ_FREQUENCY as u32;
+        /// `ADJ_MAXERROR`
+        const MAXERROR = libc::ADJ_MAXERROR as u32;
+        /// `ADJ_ESTERROR`
+        const ESTERROR = libc::ADJ_ESTERROR as u32;
+        /// `ADJ_STATUS`
+        const STATUS = libc::ADJ_STATUS as u32;
+        /// `ADJ_TIMECONST`
+        const TIMECONST = libc::ADJ_TIMECONST as u32;
+        /// `ADJ_TAI`
+        const TAI = libc::ADJ_TAI as u32;
+        /// `ADJ_SETOFFSET`
+        const SETOFFSET = libc::ADJ_SETOFFSET as u32;
+        /// `ADJ_MICRO`
+        const MICRO = libc::ADJ_MICRO as u32;
+        /// `ADJ_NANO`
+        const NANO = libc::ADJ_NANO as u32;
+        /// `ADJ_TICK`
+        const TICK = libc::ADJ_TICK as u32;
+    }
+}
+
+#[cfg(target_os = "linux")]
+bitflags! {
+    /// `STA_*` flags for use in [`Timex::status`].
+    ///
+    /// [`Timex::status`]: crate::time::Timex::status
+    pub struct TimexStatus: i32 {
+        /// `STA_PLL`
+        const PLL = libc::STA_PLL;
+        /// `STA_PPSFREQ`
+        const PPSFREQ = libc::STA_PPSFREQ;
+        /// `STA_PPSTIME`
+        const PPSTIME = libc::STA_PPSTIME;
+        /// `STA_FLL`
+        const FLL = libc::STA_FLL;
+        /// `STA_INS`
+        const INS = libc::STA_INS;
+        /// `STA_DEL`
+        const DEL = libc::STA_DEL;
+        /// `STA_UNSYNC`
+        const UNSYNC = libc::STA_UNSYNC;
+        /// `STA_FREQHOLD`
+        const FREQHOLD = libc::STA_FREQHOLD;
+        /// `STA_PPSSIGNAL`
+        const PPSSIGNAL = libc::STA_PPSSIGNAL;
+        /// `STA_PPSJITTER`
+        const PPSJITTER = libc::STA_PPSJITTER;
+        /// `STA_PPSWANDER`
+        const PPSWANDER = libc::STA_PPSWANDER;
+        /// `STA_PPSERROR`
+        const PPSERROR = libc::STA_PPSERROR;
+        /// `STA_CLOCKERR`
+        const CLOCKERR = libc::STA_CLOCKERR;
+        /// `STA_NANO`
+        const NANO = libc::STA_NANO;
+        /// `STA_MODE`
+        const MODE = libc::STA_MODE;
+        /// `STA_CLK`
+        const CLK = libc::STA_CLK;
+    }
+}
+
+/// `TIME_*` clock states returned by [`clock_adjtime`].
+///
+/// [`clock_adjtime`]: crate::time::clock_adjtime
+#[cfg(target_os = "linux")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(i32)]
+pub enum AdjtimeState {
+    /// `TIME_OK`
+    Ok = libc::TIME_OK,
+
+    /// `TIME_INS`
+    Insert = libc::TIME_INS,
+
+    /// `TIME_DEL`
+    Delete = libc::TIME_DEL,
+
+    /// `TIME_OOP`
+    InProgress = libc::TIME_OOP,
+
+    /// `TIME_WAIT`
+    Wait = libc::TIME_WAIT,
+
+    /// `TIME_ERROR`
+    Error = libc::TIME_ERROR,
+}
+
+#[cfg(target_os = "linux")]
+impl AdjtimeState {
+    pub(crate) fn from_raw(raw: i32) -> Option<Self> {
+        match raw {
+            libc::TIME_OK => Some(Self::Ok),
+            libc::TIME_INS => Some(Self::Insert),
+            libc::TIME_DEL => Some(Self::Delete),
+            libc::TIME_OOP => Some(Self::InProgress),
+            libc::TIME_WAIT => Some(Self::Wait),
+            libc::TIME_ERROR => Some(Self::Error),
+            _ => None,
+        }
+    }
+}
+
+/// `struct itimerspec`
+#[cfg(target_os = "linux")]
+pub type Itimerspec = libc::itimerspec;
+
+/// The raw `timer_t` identifier of a POSIX timer.
+#[cfg(target_os = "linux")]
+pub type RawTimer = libc::timer_t;
+
+#[cfg(target_os = "linux")]
+bitflags! {
+    /// `TIMER_*` flags for use with [`Timer::set_time`].
+    ///
+    /// [`Timer::set_time`]: crate::time::Timer::set_time
+    pub struct TimerFlags: libc::c_int {
+        /// `TIMER_ABSTIME`
+        const ABSTIME = libc::TIMER_ABSTIME;
+    }
+}
+
+#[cfg(target_os = "linux")]
+bitflags! {
+    /// `TFD_*` flags for use with [`timerfd_create`].
+    ///
+    /// [`timerfd_create`]: crate::time::timerfd_create
+    pub struct TimerfdFlags: libc::c_int {
+        /// `TFD_CLOEXEC`
+        const CLOEXEC = libc::TFD_CLOEXEC;
+        /// `TFD_NONBLOCK`
+        const NONBLOCK = libc::TFD_NONBLOCK;
+    }
+}
+
+#[cfg(target_os = "linux")]
+bitflags! {
+    /// `TFD_TIMER_*` flags for use with [`timerfd_settime`].
+    ///
+    /// [`timerfd_settime`]: crate::time::timerfd_settime
+    pub struct TimerfdTimerFlags: libc::c_int {
+        /// `TFD_TIMER_ABSTIME`
+        const ABSTIME = libc::TFD_TIMER_ABSTIME;
+        /// `TFD_TIMER_CANCEL_ON_SET`
+        const CANCEL_ON_SET = TFD_TIMER_CANCEL_ON_SET;
+    }
+}
+
+// The libc crate doesn't define `TFD_TIMER_CANCEL_ON_SET` on Linux, so define
+// it here.
+#[cfg(target_os = "linux")]
+const TFD_TIMER_CANCEL_ON_SET: libc::c_int = 0x2;
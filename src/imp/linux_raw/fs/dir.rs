@@ -142,10 +142,17 @@ impl Dir {
         self.buf
             .resize(self.buf.capacity() + 32 * size_of::<linux_dirent64>(), 0);
         self.pos = 0;
-        let nread = match crate::imp::linux_raw::syscalls::getdents(self.fd.as_fd(), &mut self.buf)
-        {
-            Ok(nread) => nread,
-            Err(err) => return Some(Err(err)),
+        let nread = loop {
+            match crate::imp::linux_raw::syscalls::getdents(self.fd.as_fd(), &mut self.buf) {
+                Ok(nread) => break nread,
+                // `EINVAL` means the next entry doesn't fit in the buffer;
+                // grow the buffer and try again.
+                Err(io::Error::INVAL) => {
+                    let len = self.buf.len();
+                    self.buf.resize(len * 2, 0);
+                }
+                Err(err) => return Some(Err(err)),
+            }
         };
         self.buf.resize(nread, 0);
         if nread == 0 {
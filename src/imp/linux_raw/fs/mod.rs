@@ -5,7 +5,8 @@ mod types;
 pub use dir::{Dir, DirEntry};
 pub use makedev::{major, makedev, minor};
 pub use types::{
-    Access, Advice, AtFlags, Dev, FallocateFlags, FdFlags, FileType, FlockOperation, FsWord,
-    MemfdFlags, Mode, OFlags, RawMode, RenameFlags, ResolveFlags, Stat, StatFs, Statx, StatxFlags,
-    PROC_SUPER_MAGIC, UTIME_NOW, UTIME_OMIT,
+    Access, Advice, AtFlags, Dev, FallocateFlags, FdFlags, FileType, FlockOperation, FlockType,
+    FsWord, MemfdFlags, Mode, OFlags, RawMode, RenameFlags, ResolveFlags, SealFlags, Stat, StatFs,
+    StatVfsMountFlags, Statx, StatxFlags, SyncFileRangeFlags, XattrFlags, PROC_SUPER_MAGIC,
+    UTIME_NOW, UTIME_OMIT,
 };
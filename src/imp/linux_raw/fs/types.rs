@@ -298,7 +298,7 @@ impl FileType {
 
     /// Construct a `FileType` from the `d_type` field of a `dirent`.
     #[inline]
-    pub(crate) const fn from_dirent_d_type(d_type: u8) -> Self {
+    pub const fn from_dirent_d_type(d_type: u8) -> Self {
         match d_type as u32 {
             linux_raw_sys::general::DT_REG => Self::RegularFile,
             linux_raw_sys::general::DT_DIR => Self::Directory,
@@ -348,10 +348,54 @@ bitflags! {
 
         /// `MFD_ALLOW_SEALING`
         const ALLOW_SEALING = linux_raw_sys::v5_4::general::MFD_ALLOW_SEALING;
+
+        /// `MFD_HUGETLB` (since Linux 4.14)
+        const HUGETLB = linux_raw_sys::v5_4::general::MFD_HUGETLB;
     }
 }
 
+// linux_raw_sys doesn't define the `XATTR_*` constants, which are in the
+// kernel's `linux/xattr.h`, so define them here.
+const XATTR_CREATE: std::os::raw::c_uint = 0x1;
+const XATTR_REPLACE: std::os::raw::c_uint = 0x2;
+
 bitflags! {
+    /// `F_SEAL_*` constants for use with [`fcntl_add_seals`] and
+    /// [`fcntl_get_seals`].
+    ///
+    /// [`fcntl_add_seals`]: crate::fs::fcntl_add_seals
+    /// [`fcntl_get_seals`]: crate::fs::fcntl_get_seals
+    pub struct SealFlags: u32 {
+        /// `F_SEAL_SEAL`
+        const SEAL = linux_raw_sys::v5_4::general::F_SEAL_SEAL;
+        /// `F_SEAL_SHRINK`
+        const SHRINK = linux_raw_sys::v5_4::general::F_SEAL_SHRINK;
+        /// `F_SEAL_GROW`
+        const GROW = linux_raw_sys::v5_4::general::F_SEAL_GROW;
+        /// `F_SEAL_WRITE`
+        const WRITE = linux_raw_sys::v5_4::general::F_SEAL_WRITE;
+        /// `F_SEAL_FUTURE_WRITE` (since Linux 5.1)
+        const FUTURE_WRITE = linux_raw_sys::v5_4::general::F_SEAL_FUTURE_WRITE;
+    }
+}
+
+bitflags! {
+    /// `XATTR_*` constants for use with [`setxattr`].
+    ///
+    /// [`setxattr`]: crate::fs::setxattr
+    pub struct XattrFlags: std::os::raw::c_uint {
+        /// `XATTR_CREATE`
+        const CREATE = XATTR_CREATE;
+
+        /// `XATTR_REPLACE`
+        const REPLACE = XATTR_REPLACE;
+    }
+}
+
+bitflags! {
+    /// `STATX_*` constants for use with [`statx`].
+    ///
+    /// [`statx`]: crate::fs::statx
     pub struct StatxFlags: u32 {
         /// `STATX_TYPE`
         const TYPE = linux_raw_sys::v5_4::general::STATX_TYPE;
@@ -419,6 +463,20 @@ bitflags! {
     }
 }
 
+bitflags! {
+    /// `SYNC_FILE_RANGE_*` constants for use with [`sync_file_range`].
+    ///
+    /// [`sync_file_range`]: crate::fs::sync_file_range
+    pub struct SyncFileRangeFlags: u32 {
+        /// `SYNC_FILE_RANGE_WAIT_BEFORE`
+        const WAIT_BEFORE = linux_raw_sys::general::SYNC_FILE_RANGE_WAIT_BEFORE;
+        /// `SYNC_FILE_RANGE_WRITE`
+        const WRITE = linux_raw_sys::general::SYNC_FILE_RANGE_WRITE;
+        /// `SYNC_FILE_RANGE_WAIT_AFTER`
+        const WAIT_AFTER = linux_raw_sys::general::SYNC_FILE_RANGE_WAIT_AFTER;
+    }
+}
+
 /// `LOCK_*` constants for use with [`flock`]
 ///
 /// [`flock`]: crate::fs::flock
@@ -439,6 +497,33 @@ pub enum FlockOperation {
     NonBlockingUnlock = linux_raw_sys::general::LOCK_UN | linux_raw_sys::general::LOCK_NB,
 }
 
+/// `F_*LCK` constants for use with [`Flock`].
+///
+/// [`Flock`]: crate::fs::Flock
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum FlockType {
+    /// `F_RDLCK`
+    ReadLock = linux_raw_sys::general::F_RDLCK,
+    /// `F_WRLCK`
+    WriteLock = linux_raw_sys::general::F_WRLCK,
+    /// `F_UNLCK`
+    Unlock = linux_raw_sys::general::F_UNLCK,
+}
+
+impl FlockType {
+    /// Construct a `FlockType` from the `l_type` field of a `flock`.
+    #[inline]
+    pub(crate) const fn from_raw(l_type: u32) -> Self {
+        match l_type {
+            linux_raw_sys::general::F_RDLCK => Self::ReadLock,
+            linux_raw_sys::general::F_WRLCK => Self::WriteLock,
+            // linux_raw_sys::general::F_UNLCK |
+            _ => Self::Unlock,
+        }
+    }
+}
+
 /// `struct stat` for use with [`statat`] and [`fstat`].
 ///
 /// [`fstat`]: crate::fs::fstat
@@ -456,20 +541,62 @@ pub type Stat = linux_raw_sys::general::stat;
 /// [`fstatfs`]: crate::fs::fstatfs
 #[cfg(target_pointer_width = "32")]
 #[allow(clippy::module_name_repetitions)]
-pub type StatFs = linux_raw_sys::general::statfs64;
+pub type StatFs = linux_raw_sys::v5_4::general::statfs64;
 
 /// `struct statfs` for use with [`fstatfs`].
 ///
 /// [`fstatfs`]: crate::fs::fstatfs
 #[cfg(target_pointer_width = "64")]
 #[allow(clippy::module_name_repetitions)]
-pub type StatFs = linux_raw_sys::general::statfs64;
+pub type StatFs = linux_raw_sys::v5_4::general::statfs64;
+
+// linux_raw_sys doesn't define the `ST_*` constants, which are what the
+// kernel reports in `statfs`'s `f_flags`, so define them here.
+const ST_RDONLY: u64 = 0x0001;
+const ST_NOSUID: u64 = 0x0002;
+const ST_NODEV: u64 = 0x0004;
+const ST_NOEXEC: u64 = 0x0008;
+const ST_SYNCHRONOUS: u64 = 0x0010;
+const ST_MANDLOCK: u64 = 0x0040;
+const ST_NOATIME: u64 = 0x0400;
+const ST_NODIRATIME: u64 = 0x0800;
+const ST_RELATIME: u64 = 0x1000;
+
+bitflags! {
+    /// `ST_*` constants for use with [`StatVfs`].
+    ///
+    /// [`StatVfs`]: crate::fs::StatVfs
+    pub struct StatVfsMountFlags: u64 {
+        /// `ST_RDONLY`
+        const RDONLY = ST_RDONLY;
+        /// `ST_NOSUID`
+        const NOSUID = ST_NOSUID;
+        /// `ST_NODEV`
+        const NODEV = ST_NODEV;
+        /// `ST_NOEXEC`
+        const NOEXEC = ST_NOEXEC;
+        /// `ST_SYNCHRONOUS`
+        const SYNCHRONOUS = ST_SYNCHRONOUS;
+        /// `ST_MANDLOCK`
+        const MANDLOCK = ST_MANDLOCK;
+        /// `ST_NOATIME`
+        const NOATIME = ST_NOATIME;
+        /// `ST_NODIRATIME`
+        const NODIRATIME = ST_NODIRATIME;
+        /// `ST_RELATIME`
+        const RELATIME = ST_RELATIME;
+    }
+}
 
 /// `struct statx` for use with [`statx`].
 ///
-/// Only available on Linux with GLIBC for now.
+/// Only the fields requested in the `mask` argument to `statx`, and reported
+/// in `stx_mask`, are meaningful. In addition to the fields of [`Stat`], this
+/// provides the file's birth time, in `stx_btime`, when the filesystem
+/// supports it. The device is split into `stx_dev_major` and `stx_dev_minor`.
 ///
 /// [`statx`]: crate::fs::statx
+/// [`Stat`]: crate::fs::Stat
 pub type Statx = linux_raw_sys::v5_4::general::statx;
 
 /// `mode_t`
@@ -52,6 +52,22 @@ impl Error {
         (self.0 as i16 as i32).wrapping_neg()
     }
 
+    /// Convert from a raw OS error number, such as a `SO_ERROR` value, to an
+    /// `Error`.
+    ///
+    /// Error numbers are in `1..4096` on all supported platforms. Values
+    /// outside that range can't be OS error numbers, and are converted to
+    /// [`Error::INVAL`].
+    #[inline]
+    pub const fn from_raw_os_error(raw: i32) -> Self {
+        // TODO: Use Range::contains, once that's `const`.
+        if raw >= 1 && raw < 4096 {
+            Self::from_errno(raw as u32)
+        } else {
+            Self::INVAL
+        }
+    }
+
     /// Convert from a C errno value (which is positive) to an `Error`.
     const fn from_errno(raw: u32) -> Self {
         // We store error values in negated form, so that we don't have to negate
@@ -6,9 +6,12 @@ mod types;
 pub use error::Error;
 pub use poll_fd::{PollFd, PollFlags};
 pub use types::{
-    Advice, DupFlags, EventfdFlags, MapFlags, MlockFlags, MprotectFlags, PipeFlags, ProtFlags,
-    ReadWriteFlags, Tcflag, Termios, UserfaultfdFlags, Winsize, ICANON, PIPE_BUF,
+    Advice, CloseRangeFlags, ControlFlags, DupFlags, EventfdFlags, InotifyFlags, InputFlags,
+    LocalFlags, MapFlags, MlockFlags, MprotectFlags, OptionalActions, OutputFlags, PipeFlags,
+    ProtFlags, QueueSelector, ReadWriteFlags, SignalfdFlags, SpliceFlags, Tcflag, Termios,
+    UserfaultfdFlags, WatchFlags, Winsize, ICANON, PIPE_BUF,
 };
+pub(crate) use types::{VMIN, VTIME};
 
 use std::os::raw::{c_int, c_uint};
 
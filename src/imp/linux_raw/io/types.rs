@@ -19,6 +19,20 @@ bitflags! {
     }
 }
 
+// `linux_raw_sys` doesn't include the `CLOSE_RANGE_*` constants from
+// <linux/close_range.h>, so we define them ourselves.
+bitflags! {
+    /// `CLOSE_RANGE_*` flags for use with [`close_range`].
+    ///
+    /// [`close_range`]: crate::io::close_range
+    pub struct CloseRangeFlags: std::os::raw::c_uint {
+        /// `CLOSE_RANGE_UNSHARE`
+        const UNSHARE = 1 << 1;
+        /// `CLOSE_RANGE_CLOEXEC`
+        const CLOEXEC = 1 << 2;
+    }
+}
+
 bitflags! {
     /// `O_*` constants for use with [`dup2`].
     ///
@@ -132,6 +146,27 @@ bitflags! {
     }
 }
 
+bitflags! {
+    /// `SPLICE_F_*` constants for use with [`splice`], [`tee`], and
+    /// [`vmsplice`].
+    ///
+    /// [`splice`]: crate::io::splice
+    /// [`tee`]: crate::io::tee
+    /// [`vmsplice`]: crate::io::vmsplice
+    pub struct SpliceFlags: std::os::raw::c_uint {
+        // These aren't in the UAPI headers, so define them here.
+
+        /// `SPLICE_F_MOVE`
+        const MOVE = 1;
+        /// `SPLICE_F_NONBLOCK`
+        const NONBLOCK = 2;
+        /// `SPLICE_F_MORE`
+        const MORE = 4;
+        /// `SPLICE_F_GIFT`
+        const GIFT = 8;
+    }
+}
+
 bitflags! {
     /// The `O_*` flags accepted by [`userfaultfd`].
     ///
@@ -158,6 +193,18 @@ bitflags! {
     }
 }
 
+bitflags! {
+    /// The `SFD_*` flags accepted by [`signalfd`].
+    ///
+    /// [`signalfd`]: crate::io::signalfd
+    pub struct SignalfdFlags: std::os::raw::c_uint {
+        /// `SFD_CLOEXEC`
+        const CLOEXEC = linux_raw_sys::general::O_CLOEXEC;
+        /// `SFD_NONBLOCK`
+        const NONBLOCK = linux_raw_sys::general::O_NONBLOCK;
+    }
+}
+
 /// `POSIX_MADV_*` constants for use with [`madvise`].
 ///
 /// [`madvise`]: crate::io::madvise
@@ -223,12 +270,193 @@ impl Advice {
     pub const DontNeed: Self = Self::Normal;
 }
 
-/// `struct termios`, for use with [`ioctl_tcgets`].
+/// `struct termios`, for use with [`tcgetattr`] and [`tcsetattr`].
 ///
-/// [`ioctl_tcgets`]: crate::io::ioctl_tcgets
+/// [`tcgetattr`]: crate::io::tcgetattr
+/// [`tcsetattr`]: crate::io::tcsetattr
 pub type Termios = linux_raw_sys::general::termios;
 
-/// `struct winsize`
+/// `TCSA*` values for use with [`tcsetattr`].
+///
+/// [`tcsetattr`]: crate::io::tcsetattr
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(u32)]
+pub enum OptionalActions {
+    /// `TCSANOW`—Make the change immediately.
+    Now = linux_raw_sys::general::TCSANOW,
+
+    /// `TCSADRAIN`—Make the change after all queued output has been
+    /// transmitted.
+    Drain = linux_raw_sys::general::TCSADRAIN,
+
+    /// `TCSAFLUSH`—Make the change after all queued output has been
+    /// transmitted, and discard any unread input.
+    Flush = linux_raw_sys::general::TCSAFLUSH,
+}
+
+/// `TC*FLUSH` values for use with [`tcflush`].
+///
+/// [`tcflush`]: crate::io::tcflush
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(u32)]
+pub enum QueueSelector {
+    /// `TCIFLUSH`—Discard data received but not yet read.
+    IFlush = linux_raw_sys::general::TCIFLUSH,
+
+    /// `TCOFLUSH`—Discard data written but not yet transmitted.
+    OFlush = linux_raw_sys::general::TCOFLUSH,
+
+    /// `TCIOFLUSH`—Discard both data received but not yet read, and data
+    /// written but not yet transmitted.
+    IOFlush = linux_raw_sys::general::TCIOFLUSH,
+}
+
+bitflags! {
+    /// Input modes, in the `c_iflag` field of a [`Termios`].
+    pub struct InputFlags: Tcflag {
+        /// `IGNBRK`
+        const IGNBRK = linux_raw_sys::general::IGNBRK;
+
+        /// `BRKINT`
+        const BRKINT = linux_raw_sys::general::BRKINT;
+
+        /// `IGNPAR`
+        const IGNPAR = linux_raw_sys::general::IGNPAR;
+
+        /// `PARMRK`
+        const PARMRK = linux_raw_sys::general::PARMRK;
+
+        /// `INPCK`
+        const INPCK = linux_raw_sys::general::INPCK;
+
+        /// `ISTRIP`
+        const ISTRIP = linux_raw_sys::general::ISTRIP;
+
+        /// `INLCR`
+        const INLCR = linux_raw_sys::general::INLCR;
+
+        /// `IGNCR`
+        const IGNCR = linux_raw_sys::general::IGNCR;
+
+        /// `ICRNL`
+        const ICRNL = linux_raw_sys::general::ICRNL;
+
+        /// `IXON`
+        const IXON = linux_raw_sys::general::IXON;
+
+        /// `IXANY`
+        const IXANY = linux_raw_sys::general::IXANY;
+
+        /// `IXOFF`
+        const IXOFF = linux_raw_sys::general::IXOFF;
+    }
+}
+
+bitflags! {
+    /// Output modes, in the `c_oflag` field of a [`Termios`].
+    pub struct OutputFlags: Tcflag {
+        /// `OPOST`
+        const OPOST = linux_raw_sys::general::OPOST;
+
+        /// `ONLCR`
+        const ONLCR = linux_raw_sys::general::ONLCR;
+
+        /// `OCRNL`
+        const OCRNL = linux_raw_sys::general::OCRNL;
+
+        /// `ONOCR`
+        const ONOCR = linux_raw_sys::general::ONOCR;
+
+        /// `ONLRET`
+        const ONLRET = linux_raw_sys::general::ONLRET;
+    }
+}
+
+bitflags! {
+    /// Control modes, in the `c_cflag` field of a [`Termios`].
+    pub struct ControlFlags: Tcflag {
+        /// `CSIZE`—The mask of the character size bits.
+        const CSIZE = linux_raw_sys::general::CSIZE;
+
+        /// `CS5`
+        const CS5 = linux_raw_sys::general::CS5;
+
+        /// `CS6`
+        const CS6 = linux_raw_sys::general::CS6;
+
+        /// `CS7`
+        const CS7 = linux_raw_sys::general::CS7;
+
+        /// `CS8`
+        const CS8 = linux_raw_sys::general::CS8;
+
+        /// `CSTOPB`
+        const CSTOPB = linux_raw_sys::general::CSTOPB;
+
+        /// `CREAD`
+        const CREAD = linux_raw_sys::general::CREAD;
+
+        /// `PARENB`
+        const PARENB = linux_raw_sys::general::PARENB;
+
+        /// `PARODD`
+        const PARODD = linux_raw_sys::general::PARODD;
+
+        /// `HUPCL`
+        const HUPCL = linux_raw_sys::general::HUPCL;
+
+        /// `CLOCAL`
+        const CLOCAL = linux_raw_sys::general::CLOCAL;
+    }
+}
+
+bitflags! {
+    /// Local modes, in the `c_lflag` field of a [`Termios`].
+    pub struct LocalFlags: Tcflag {
+        /// `ISIG`
+        const ISIG = linux_raw_sys::general::ISIG;
+
+        /// `ICANON`
+        const ICANON = linux_raw_sys::general::ICANON;
+
+        /// `ECHO`
+        const ECHO = linux_raw_sys::general::ECHO;
+
+        /// `ECHOE`
+        const ECHOE = linux_raw_sys::general::ECHOE;
+
+        /// `ECHOK`
+        const ECHOK = linux_raw_sys::general::ECHOK;
+
+        /// `ECHONL`
+        const ECHONL = linux_raw_sys::general::ECHONL;
+
+        /// `NOFLSH`
+        const NOFLSH = linux_raw_sys::general::NOFLSH;
+
+        /// `TOSTOP`
+        const TOSTOP = linux_raw_sys::general::TOSTOP;
+
+        /// `IEXTEN`
+        const IEXTEN = linux_raw_sys::general::IEXTEN;
+    }
+}
+
+/// `VMIN`—The index of the minimum number of bytes for a non-canonical read
+/// in `c_cc`.
+pub(crate) const VMIN: usize = linux_raw_sys::general::VMIN as usize;
+
+/// `VTIME`—The index of the timeout for a non-canonical read in `c_cc`.
+pub(crate) const VTIME: usize = linux_raw_sys::general::VTIME as usize;
+
+/// `struct winsize`, for use with [`ioctl_tiocgwinsz`] and
+/// [`ioctl_tiocswinsz`].
+///
+/// Its `ws_row`, `ws_col`, `ws_xpixel`, and `ws_ypixel` fields are public,
+/// so it can be constructed directly.
+///
+/// [`ioctl_tiocgwinsz`]: crate::io::ioctl_tiocgwinsz
+/// [`ioctl_tiocswinsz`]: crate::io::ioctl_tiocswinsz
 pub type Winsize = linux_raw_sys::general::winsize;
 
 pub type Tcflag = linux_raw_sys::general::tcflag_t;
@@ -236,3 +464,94 @@ pub type Tcflag = linux_raw_sys::general::tcflag_t;
 pub const ICANON: std::os::raw::c_uint = linux_raw_sys::general::ICANON;
 
 pub const PIPE_BUF: usize = linux_raw_sys::general::PIPE_BUF as usize;
+
+bitflags! {
+    /// The `IN_*` flags accepted by [`inotify_init1`].
+    ///
+    /// [`inotify_init1`]: crate::io::inotify::inotify_init1
+    pub struct InotifyFlags: std::os::raw::c_uint {
+        /// `IN_CLOEXEC`
+        const CLOEXEC = linux_raw_sys::general::O_CLOEXEC;
+        /// `IN_NONBLOCK`
+        const NONBLOCK = linux_raw_sys::general::O_NONBLOCK;
+    }
+}
+
+// linux_raw_sys doesn't define the `IN_*` constants, which are in the
+// kernel's `linux/inotify.h`, so define them here.
+const IN_ACCESS: u32 = 0x1;
+const IN_MODIFY: u32 = 0x2;
+const IN_ATTRIB: u32 = 0x4;
+const IN_CLOSE_WRITE: u32 = 0x8;
+const IN_CLOSE_NOWRITE: u32 = 0x10;
+const IN_OPEN: u32 = 0x20;
+const IN_MOVED_FROM: u32 = 0x40;
+const IN_MOVED_TO: u32 = 0x80;
+const IN_CREATE: u32 = 0x100;
+const IN_DELETE: u32 = 0x200;
+const IN_DELETE_SELF: u32 = 0x400;
+const IN_MOVE_SELF: u32 = 0x800;
+const IN_UNMOUNT: u32 = 0x2000;
+const IN_Q_OVERFLOW: u32 = 0x4000;
+const IN_IGNORED: u32 = 0x8000;
+const IN_ONLYDIR: u32 = 0x0100_0000;
+const IN_DONT_FOLLOW: u32 = 0x0200_0000;
+const IN_EXCL_UNLINK: u32 = 0x0400_0000;
+const IN_MASK_CREATE: u32 = 0x1000_0000;
+const IN_MASK_ADD: u32 = 0x2000_0000;
+const IN_ISDIR: u32 = 0x4000_0000;
+const IN_ONESHOT: u32 = 0x8000_0000;
+
+bitflags! {
+    /// The `IN_*` event mask bits accepted by [`inotify_add_watch`] and
+    /// reported in [`InotifyEvent`].
+    ///
+    /// [`inotify_add_watch`]: crate::io::inotify::inotify_add_watch
+    /// [`InotifyEvent`]: crate::io::inotify::InotifyEvent
+    pub struct WatchFlags: u32 {
+        /// `IN_ACCESS`
+        const ACCESS = IN_ACCESS;
+        /// `IN_MODIFY`
+        const MODIFY = IN_MODIFY;
+        /// `IN_ATTRIB`
+        const ATTRIB = IN_ATTRIB;
+        /// `IN_CLOSE_WRITE`
+        const CLOSE_WRITE = IN_CLOSE_WRITE;
+        /// `IN_CLOSE_NOWRITE`
+        const CLOSE_NOWRITE = IN_CLOSE_NOWRITE;
+        /// `IN_OPEN`
+        const OPEN = IN_OPEN;
+        /// `IN_MOVED_FROM`
+        const MOVED_FROM = IN_MOVED_FROM;
+        /// `IN_MOVED_TO`
+        const MOVED_TO = IN_MOVED_TO;
+        /// `IN_CREATE`
+        const CREATE = IN_CREATE;
+        /// `IN_DELETE`
+        const DELETE = IN_DELETE;
+        /// `IN_DELETE_SELF`
+        const DELETE_SELF = IN_DELETE_SELF;
+        /// `IN_MOVE_SELF`
+        const MOVE_SELF = IN_MOVE_SELF;
+        /// `IN_UNMOUNT`
+        const UNMOUNT = IN_UNMOUNT;
+        /// `IN_Q_OVERFLOW`
+        const Q_OVERFLOW = IN_Q_OVERFLOW;
+        /// `IN_IGNORED`
+        const IGNORED = IN_IGNORED;
+        /// `IN_ONLYDIR`
+        const ONLYDIR = IN_ONLYDIR;
+        /// `IN_DONT_FOLLOW`
+        const DONT_FOLLOW = IN_DONT_FOLLOW;
+        /// `IN_EXCL_UNLINK`
+        const EXCL_UNLINK = IN_EXCL_UNLINK;
+        /// `IN_MASK_CREATE`
+        const MASK_CREATE = IN_MASK_CREATE;
+        /// `IN_MASK_ADD`
+        const MASK_ADD = IN_MASK_ADD;
+        /// `IN_ISDIR`
+        const ISDIR = IN_ISDIR;
+        /// `IN_ONESHOT`
+        const ONESHOT = IN_ONESHOT;
+    }
+}
@@ -10,4 +10,5 @@ pub(crate) mod net;
 pub(crate) mod process;
 pub(crate) mod rand;
 pub(crate) mod syscalls;
+pub(crate) mod thread;
 pub(crate) mod time;
@@ -6,8 +6,9 @@
 #![allow(unsafe_code)]
 
 use super::AddressFamily;
-use crate::{io, path};
-use std::ffi::CString;
+use crate::{as_ptr, io, path};
+use std::cmp::min;
+use std::ffi::{CStr, CString};
 use std::fmt;
 
 /// `struct in_addr`
@@ -243,14 +244,38 @@ impl Ipv6Addr {
     #[inline]
     pub const fn segments(&self) -> [u16; 8] {
         // Safety: self.0.in6_u is a union of plain data.
-        unsafe { self.0.in6_u.u6_addr16 }
+        let segments = unsafe { self.0.in6_u.u6_addr16 };
+
+        // `in6_addr` holds the segments in network byte order.
+        [
+            u16::from_be(segments[0]),
+            u16::from_be(segments[1]),
+            u16::from_be(segments[2]),
+            u16::from_be(segments[3]),
+            u16::from_be(segments[4]),
+            u16::from_be(segments[5]),
+            u16::from_be(segments[6]),
+            u16::from_be(segments[7]),
+        ]
     }
 
     #[cfg(not(const_fn_union))]
     #[inline]
     pub fn segments(&self) -> [u16; 8] {
         // Safety: self.0.in6_u is a union of plain data.
-        unsafe { self.0.in6_u.u6_addr16 }
+        let segments = unsafe { self.0.in6_u.u6_addr16 };
+
+        // `in6_addr` holds the segments in network byte order.
+        [
+            u16::from_be(segments[0]),
+            u16::from_be(segments[1]),
+            u16::from_be(segments[2]),
+            u16::from_be(segments[3]),
+            u16::from_be(segments[4]),
+            u16::from_be(segments[5]),
+            u16::from_be(segments[6]),
+            u16::from_be(segments[7]),
+        ]
     }
 
     #[inline]
@@ -404,7 +429,18 @@ impl fmt::Debug for SocketAddrV6 {
 #[derive(Clone)]
 #[doc(alias = "sockaddr_un")]
 pub struct SocketAddrUnix {
-    path: CString,
+    name: UnixName,
+}
+
+/// The forms of name a Unix-domain socket can have.
+#[derive(Clone)]
+enum UnixName {
+    /// No name, as for a socket that was never bound.
+    Unnamed,
+    /// A filesystem path.
+    Path(CString),
+    /// A name in the abstract namespace, without its leading NUL.
+    Abstract(Vec<u8>),
 }
 
 impl SocketAddrUnix {
@@ -423,10 +459,74 @@ impl SocketAddrUnix {
             sun_family: 0,
             sun_path: [0; 108_usize],
         };
-        if bytes.len() + 1 > z.sun_path.len() {
+        // A path that fills `sun_path` is passed without a NUL terminator.
+        if bytes.len() > z.sun_path.len() {
             return Err(io::Error::NAMETOOLONG);
         }
-        Ok(Self { path })
+        Ok(Self {
+            name: UnixName::Path(path),
+        })
+    }
+
+    /// Construct an unnamed Unix-domain address, as reported for a peer
+    /// socket that was never bound.
+    #[inline]
+    pub(crate) const fn new_unnamed() -> Self {
+        Self {
+            name: UnixName::Unnamed,
+        }
+    }
+
+    /// Construct a new Unix-domain address in the abstract namespace.
+    ///
+    /// `name` is the name without the leading NUL byte that marks it as
+    /// abstract. It may contain arbitrary bytes, including NULs.
+    #[inline]
+    pub fn new_abstract(name: &[u8]) -> io::Result<Self> {
+        let z = linux_raw_sys::general::sockaddr_un {
+            sun_family: 0,
+            sun_path: [0; 108_usize],
+        };
+        if name.len() + 1 > z.sun_path.len() {
+            return Err(io::Error::NAMETOOLONG);
+        }
+        Ok(Self {
+            name: UnixName::Abstract(name.to_vec()),
+        })
+    }
+
+    /// Return the filesystem path of this address, or `None` if it isn't a
+    /// filesystem path.
+    #[inline]
+    pub fn path(&self) -> Option<&CStr> {
+        match &self.name {
+            UnixName::Path(path) => Some(path),
+            UnixName::Unnamed => None,
+            UnixName::Abstract(_) => None,
+        }
+    }
+
+    /// Test whether this address is unnamed, as reported for a peer socket
+    /// that was never bound.
+    #[inline]
+    pub fn is_unnamed(&self) -> bool {
+        matches!(self.name, UnixName::Unnamed)
+    }
+
+    /// Test whether this address is in the abstract namespace.
+    #[inline]
+    pub fn is_abstract(&self) -> bool {
+        matches!(self.name, UnixName::Abstract(_))
+    }
+
+    /// Return the name of this address in the abstract namespace, without
+    /// its leading NUL byte, or `None` if it isn't an abstract address.
+    #[inline]
+    pub fn abstract_name(&self) -> Option<&[u8]> {
+        match &self.name {
+            UnixName::Unnamed | UnixName::Path(_) => None,
+            UnixName::Abstract(name) => Some(name),
+        }
     }
 
     /// Encode this socket address in the host format.
@@ -436,18 +536,56 @@ impl SocketAddrUnix {
             sun_family: linux_raw_sys::general::AF_UNIX as _,
             sun_path: [0; 108_usize],
         };
-        let bytes = self.path.as_bytes();
-        for (i, b) in bytes.iter().enumerate() {
-            encoded.sun_path[i] = *b as std::os::raw::c_char;
+        match &self.name {
+            UnixName::Unnamed => (),
+            UnixName::Path(path) => {
+                let bytes = path.as_bytes();
+                for (i, b) in bytes.iter().enumerate() {
+                    encoded.sun_path[i] = *b as std::os::raw::c_char;
+                }
+                if bytes.len() < encoded.sun_path.len() {
+                    encoded.sun_path[bytes.len()] = b'\0' as std::os::raw::c_char;
+                }
+            }
+            UnixName::Abstract(name) => {
+                // The leading NUL is already there; abstract names aren't
+                // NUL-terminated.
+                for (i, b) in name.iter().enumerate() {
+                    encoded.sun_path[1 + i] = *b as std::os::raw::c_char;
+                }
+            }
         }
-        encoded.sun_path[bytes.len()] = b'\0' as std::os::raw::c_char;
         encoded
     }
+
+    /// Return the length of the encoded address, to pass as the `addrlen`
+    /// argument along with it.
+    #[inline]
+    pub(crate) fn addr_len(&self) -> linux_raw_sys::general::socklen_t {
+        let z = linux_raw_sys::general::sockaddr_un {
+            sun_family: 0,
+            sun_path: [0; 108_usize],
+        };
+        let offsetof_sun_path = (as_ptr(&z.sun_path) as usize) - (as_ptr(&z) as usize);
+        let sun_path_len = match &self.name {
+            UnixName::Unnamed => 0,
+            UnixName::Path(path) => min(path.as_bytes().len() + 1, z.sun_path.len()),
+            UnixName::Abstract(name) => 1 + name.len(),
+        };
+        (offsetof_sun_path + sun_path_len) as _
+    }
 }
 
 impl fmt::Debug for SocketAddrUnix {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.path.fmt(fmt)
+        match &self.name {
+            UnixName::Unnamed => fmt.write_str("Unnamed"),
+            UnixName::Path(path) => path.fmt(fmt),
+            UnixName::Abstract(name) => fmt
+                .debug_tuple("Abstract")
+                .field(&String::from_utf8_lossy(name))
+                .finish(),
+        }
     }
 }
 
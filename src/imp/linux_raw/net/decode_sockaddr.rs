@@ -66,22 +66,30 @@ pub(crate) unsafe fn decode_sockaddr(storage: *const sockaddr, len: u32) -> Sock
         linux_raw_sys::general::AF_UNIX => {
             assert!(len as usize >= offsetof_sun_path);
             if len as usize == offsetof_sun_path {
-                SocketAddr::Unix(SocketAddrUnix::new(&[][..]).unwrap())
+                SocketAddr::Unix(SocketAddrUnix::new_unnamed())
             } else {
                 let decode = *storage.cast::<linux_raw_sys::general::sockaddr_un>();
-                assert_eq!(
-                    decode.sun_path[len as usize - 1 - offsetof_sun_path],
-                    b'\0' as std::os::raw::c_char
-                );
-                SocketAddr::Unix(
-                    SocketAddrUnix::new(
-                        decode.sun_path[..len as usize - 1 - offsetof_sun_path]
-                            .iter()
-                            .map(|c| *c as u8)
-                            .collect::<Vec<u8>>(),
-                    )
-                    .unwrap(),
-                )
+                let sun_path_len = (len as usize - offsetof_sun_path).min(decode.sun_path.len());
+                let path_bytes = decode.sun_path[..sun_path_len]
+                    .iter()
+                    .map(|c| *c as u8)
+                    .collect::<Vec<u8>>();
+
+                if path_bytes[0] == b'\0' {
+                    // An abstract name, which follows the leading NUL and
+                    // isn't NUL-terminated.
+                    SocketAddr::Unix(SocketAddrUnix::new_abstract(&path_bytes[1..]).unwrap())
+                } else {
+                    // A filesystem path, which the kernel may report with
+                    // its NUL terminator included in `len`. A path that
+                    // fills `sun_path` has no NUL terminator, but it still
+                    // fits, so `SocketAddrUnix::new` can't fail.
+                    let path_len = path_bytes
+                        .iter()
+                        .position(|b| *b == b'\0')
+                        .unwrap_or(path_bytes.len());
+                    SocketAddr::Unix(SocketAddrUnix::new(&path_bytes[..path_len]).unwrap())
+                }
             }
         }
         other => unimplemented!("{:?}", other),
@@ -6,5 +6,7 @@ mod types;
 pub(crate) use decode_sockaddr::decode_sockaddr;
 
 pub use addr::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrUnix, SocketAddrV4, SocketAddrV6};
-pub use send_recv::{RecvFlags, SendFlags};
+pub(crate) use send_recv::{MsgHdr, SCM_RIGHTS, SOL_SOCKET};
+pub use send_recv::{RecvFlags, ReturnFlags, SendFlags};
+pub(crate) use types::Linger;
 pub use types::{AcceptFlags, AddressFamily, Protocol, Shutdown, SocketType};
@@ -1,4 +1,5 @@
 use bitflags::bitflags;
+use std::os::raw::{c_int, c_uint, c_void};
 
 bitflags! {
     /// `MSG_*`
@@ -39,3 +40,42 @@ bitflags! {
         const WAITALL = linux_raw_sys::general::MSG_WAITALL;
     }
 }
+
+bitflags! {
+    /// `MSG_*` flags returned by [`recvmsg`] in `msg_flags`.
+    ///
+    /// [`recvmsg`]: crate::net::recvmsg
+    pub struct ReturnFlags: u32 {
+        /// `MSG_CMSG_CLOEXEC`
+        const CMSG_CLOEXEC = linux_raw_sys::general::MSG_CMSG_CLOEXEC;
+        /// `MSG_CTRUNC`
+        const CTRUNC = linux_raw_sys::general::MSG_CTRUNC;
+        /// `MSG_EOR`
+        const EOR = linux_raw_sys::general::MSG_EOR;
+        /// `MSG_ERRQUEUE`
+        const ERRQUEUE = linux_raw_sys::general::MSG_ERRQUEUE;
+        /// `MSG_OOB`
+        const OOB = linux_raw_sys::general::MSG_OOB;
+        /// `MSG_TRUNC`
+        const TRUNC = linux_raw_sys::general::MSG_TRUNC;
+    }
+}
+
+/// `SOL_SOCKET`, as a `cmsg_level`.
+pub(crate) const SOL_SOCKET: c_int = linux_raw_sys::general::SOL_SOCKET as c_int;
+
+/// `SCM_RIGHTS`, as a `cmsg_type`. This is defined in the kernel's internal
+/// `linux/socket.h` rather than in its uapi headers, so we define it here.
+pub(crate) const SCM_RIGHTS: c_int = 1;
+
+/// `struct msghdr`, as the kernel's `struct user_msghdr` defines it.
+#[repr(C)]
+pub(crate) struct MsgHdr {
+    pub(crate) msg_name: *mut c_void,
+    pub(crate) msg_namelen: c_int,
+    pub(crate) msg_iov: *mut c_void,
+    pub(crate) msg_iovlen: usize,
+    pub(crate) msg_control: *mut c_void,
+    pub(crate) msg_controllen: usize,
+    pub(crate) msg_flags: c_uint,
+}
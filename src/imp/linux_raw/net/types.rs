@@ -1,10 +1,10 @@
 use bitflags::bitflags;
-use std::os::raw::c_uint;
+use std::os::raw::{c_int, c_uint};
 
 /// `SOCK_*` constants for [`socket`].
 ///
 /// [`socket`]: crate::net::socket
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
 #[repr(transparent)]
 pub struct SocketType(pub(crate) u32);
 
@@ -27,7 +27,7 @@ impl SocketType {
 }
 
 /// `AF_*` constants.
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
 #[repr(transparent)]
 pub struct AddressFamily(pub(crate) linux_raw_sys::general::__kernel_sa_family_t);
 
@@ -105,6 +105,8 @@ pub enum Protocol {
 }
 
 /// `SHUT_*` constants for [`shutdown`].
+///
+/// [`shutdown`]: crate::net::shutdown
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 #[repr(u32)]
 pub enum Shutdown {
@@ -117,9 +119,10 @@ pub enum Shutdown {
 }
 
 bitflags! {
-    /// `SOCK_*` constants for [`accept`].
+    /// `SOCK_*` constants for [`accept_with`] and [`acceptfrom_with`].
     ///
-    /// [`accept`]: crate::net::accept
+    /// [`accept_with`]: crate::net::accept_with
+    /// [`acceptfrom_with`]: crate::net::acceptfrom_with
     pub struct AcceptFlags: c_uint {
         /// `SOCK_NONBLOCK`
         const NONBLOCK = linux_raw_sys::general::O_NONBLOCK;
@@ -127,3 +130,11 @@ bitflags! {
         const CLOEXEC = linux_raw_sys::general::O_CLOEXEC;
     }
 }
+
+/// `struct linger`, which is defined in the kernel's internal
+/// `linux/socket.h` rather than in its uapi headers.
+#[repr(C)]
+pub(crate) struct Linger {
+    pub(crate) l_onoff: c_int,
+    pub(crate) l_linger: c_int,
+}
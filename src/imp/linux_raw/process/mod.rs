@@ -1,8 +1,16 @@
 mod auxv;
+mod sigset;
 mod types;
+mod wait;
 
 pub(super) use auxv::sysinfo_ehdr;
 pub(crate) use auxv::{linux_hwcap, page_size};
+pub use sigset::SigSet;
 pub use types::{
-    RawGid, RawPid, RawUid, RawUname, EXIT_FAILURE, EXIT_SIGNALED_SIGABRT, EXIT_SUCCESS,
+    MountFlags, RawGid, RawPid, RawRusage, RawSysinfo, RawUid, RawUname, RusageWho, SchedFlags,
+    SchedPolicy, SigmaskHow, Signal, UnmountFlags, WaitOptions, EXIT_FAILURE,
+    EXIT_SIGNALED_SIGABRT, EXIT_SUCCESS,
+};
+pub(crate) use wait::{
+    WCOREDUMP, WEXITSTATUS, WIFCONTINUED, WIFEXITED, WIFSIGNALED, WIFSTOPPED, WSTOPSIG, WTERMSIG,
 };
@@ -0,0 +1,60 @@
+use super::types::Signal;
+use crate::io;
+use std::fmt;
+use std::os::raw::c_int;
+
+/// The number of signals the kernel's `sigset_t` has room for.
+const NSIG: c_int = 64;
+
+/// `sigset_t`—A set of signals.
+///
+/// This is the kernel's signal set, which has a bit for each of the
+/// signals numbered 1 through 64.
+#[repr(transparent)]
+#[derive(Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct SigSet(pub(crate) u64);
+
+impl SigSet {
+    /// Construct a new empty `SigSet`.
+    #[inline]
+    pub fn new() -> Self {
+        Self(0)
+    }
+
+    /// Add the signal `sig` to this set.
+    #[inline]
+    pub fn add(&mut self, sig: Signal) -> io::Result<()> {
+        self.0 |= Self::bit(sig.as_raw())?;
+        Ok(())
+    }
+
+    /// Remove the signal `sig` from this set.
+    #[inline]
+    pub fn remove(&mut self, sig: Signal) -> io::Result<()> {
+        self.0 &= !Self::bit(sig.as_raw())?;
+        Ok(())
+    }
+
+    /// Test whether the signal `sig` is in this set.
+    #[inline]
+    pub fn contains(&self, sig: Signal) -> bool {
+        matches!(Self::bit(sig.as_raw()), Ok(bit) if self.0 & bit != 0)
+    }
+
+    #[inline]
+    fn bit(sig: c_int) -> io::Result<u64> {
+        if (1..=NSIG).contains(&sig) {
+            Ok(1 << (sig - 1))
+        } else {
+            Err(io::Error::INVAL)
+        }
+    }
+}
+
+impl fmt::Debug for SigSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set()
+            .entries((1..=NSIG).filter(|sig| self.0 & (1 << (sig - 1)) != 0))
+            .finish()
+    }
+}
@@ -1,4 +1,5 @@
-use std::os::raw::c_int;
+use bitflags::bitflags;
+use std::os::raw::{c_int, c_uint};
 
 pub const EXIT_SUCCESS: c_int = 0;
 pub const EXIT_FAILURE: c_int = 1;
@@ -9,3 +10,284 @@ pub type RawGid = u32;
 pub type RawUid = u32;
 
 pub type RawUname = linux_raw_sys::general::new_utsname;
+
+// linux_raw_sys doesn't define `struct sysinfo`, so define it here, following
+// the layout in linux/sysinfo.h.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct RawSysinfo {
+    pub uptime: linux_raw_sys::v5_4::general::__kernel_long_t,
+    pub loads: [linux_raw_sys::v5_4::general::__kernel_ulong_t; 3],
+    pub totalram: linux_raw_sys::v5_4::general::__kernel_ulong_t,
+    pub freeram: linux_raw_sys::v5_4::general::__kernel_ulong_t,
+    pub sharedram: linux_raw_sys::v5_4::general::__kernel_ulong_t,
+    pub bufferram: linux_raw_sys::v5_4::general::__kernel_ulong_t,
+    pub totalswap: linux_raw_sys::v5_4::general::__kernel_ulong_t,
+    pub freeswap: linux_raw_sys::v5_4::general::__kernel_ulong_t,
+    pub procs: u16,
+    pub pad: u16,
+    pub totalhigh: linux_raw_sys::v5_4::general::__kernel_ulong_t,
+    pub freehigh: linux_raw_sys::v5_4::general::__kernel_ulong_t,
+    pub mem_unit: u32,
+    #[cfg(target_pointer_width = "32")]
+    pub _f: [u8; 8],
+}
+
+pub type RawRusage = linux_raw_sys::general::rusage;
+
+/// `RUSAGE_*` constants for use with [`getrusage`].
+///
+/// [`getrusage`]: crate::process::getrusage
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(i32)]
+pub enum RusageWho {
+    /// `RUSAGE_SELF`
+    Self_ = linux_raw_sys::general::RUSAGE_SELF as i32,
+    /// `RUSAGE_CHILDREN`
+    Children = linux_raw_sys::general::RUSAGE_CHILDREN,
+    /// `RUSAGE_THREAD`
+    Thread = linux_raw_sys::general::RUSAGE_THREAD as i32,
+}
+
+/// `SIG_*` constants for use with [`sigprocmask`].
+///
+/// [`sigprocmask`]: crate::process::sigprocmask
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum SigmaskHow {
+    /// `SIG_BLOCK`
+    Block = linux_raw_sys::general::SIG_BLOCK,
+    /// `SIG_UNBLOCK`
+    Unblock = linux_raw_sys::general::SIG_UNBLOCK,
+    /// `SIG_SETMASK`
+    SetMask = linux_raw_sys::general::SIG_SETMASK,
+}
+
+bitflags! {
+    /// `W*` constants for use with [`waitpid`].
+    ///
+    /// [`waitpid`]: crate::process::waitpid
+    pub struct WaitOptions: c_uint {
+        /// `WNOHANG`
+        const NOHANG = linux_raw_sys::general::WNOHANG;
+        /// `WUNTRACED`
+        const UNTRACED = linux_raw_sys::general::WUNTRACED;
+        /// `WCONTINUED`
+        const CONTINUED = linux_raw_sys::general::WCONTINUED;
+    }
+}
+
+/// A signal number for use with [`kill_process`] and related functions.
+///
+/// [`kill_process`]: crate::process::kill_process
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[repr(u32)]
+pub enum Signal {
+    /// `SIGHUP`
+    Hup = linux_raw_sys::general::SIGHUP,
+    /// `SIGINT`
+    Int = linux_raw_sys::general::SIGINT,
+    /// `SIGQUIT`
+    Quit = linux_raw_sys::general::SIGQUIT,
+    /// `SIGILL`
+    Ill = linux_raw_sys::general::SIGILL,
+    /// `SIGTRAP`
+    Trap = linux_raw_sys::general::SIGTRAP,
+    /// `SIGABRT`
+    Abort = linux_raw_sys::general::SIGABRT,
+    /// `SIGBUS`
+    Bus = linux_raw_sys::general::SIGBUS,
+    /// `SIGFPE`
+    Fpe = linux_raw_sys::general::SIGFPE,
+    /// `SIGKILL`
+    Kill = linux_raw_sys::general::SIGKILL,
+    /// `SIGUSR1`
+    Usr1 = linux_raw_sys::general::SIGUSR1,
+    /// `SIGSEGV`
+    Segv = linux_raw_sys::general::SIGSEGV,
+    /// `SIGUSR2`
+    Usr2 = linux_raw_sys::general::SIGUSR2,
+    /// `SIGPIPE`
+    Pipe = linux_raw_sys::general::SIGPIPE,
+    /// `SIGALRM`
+    Alarm = linux_raw_sys::general::SIGALRM,
+    /// `SIGTERM`
+    Term = linux_raw_sys::general::SIGTERM,
+    /// `SIGSTKFLT`
+    Stkflt = linux_raw_sys::general::SIGSTKFLT,
+    /// `SIGCHLD`
+    Child = linux_raw_sys::general::SIGCHLD,
+    /// `SIGCONT`
+    Cont = linux_raw_sys::general::SIGCONT,
+    /// `SIGSTOP`
+    Stop = linux_raw_sys::general::SIGSTOP,
+    /// `SIGTSTP`
+    Tstp = linux_raw_sys::general::SIGTSTP,
+    /// `SIGTTIN`
+    Ttin = linux_raw_sys::general::SIGTTIN,
+    /// `SIGTTOU`
+    Ttou = linux_raw_sys::general::SIGTTOU,
+    /// `SIGURG`
+    Urg = linux_raw_sys::general::SIGURG,
+    /// `SIGXCPU`
+    Xcpu = linux_raw_sys::general::SIGXCPU,
+    /// `SIGXFSZ`
+    Xfsz = linux_raw_sys::general::SIGXFSZ,
+    /// `SIGVTALRM`
+    Vtalarm = linux_raw_sys::general::SIGVTALRM,
+    /// `SIGPROF`
+    Prof = linux_raw_sys::general::SIGPROF,
+    /// `SIGWINCH`
+    Winch = linux_raw_sys::general::SIGWINCH,
+    /// `SIGIO`
+    Io = linux_raw_sys::general::SIGIO,
+    /// `SIGPWR`
+    Power = linux_raw_sys::general::SIGPWR,
+    /// `SIGSYS`
+    Sys = linux_raw_sys::general::SIGSYS,
+}
+
+impl Signal {
+    /// Convert a raw signal number into a `Signal`, if it's one of the
+    /// signals listed here.
+    pub fn from_raw(sig: c_int) -> Option<Self> {
+        match sig as u32 {
+            linux_raw_sys::general::SIGHUP => Some(Self::Hup),
+            linux_raw_sys::general::SIGINT => Some(Self::Int),
+            linux_raw_sys::general::SIGQUIT => Some(Self::Quit),
+            linux_raw_sys::general::SIGILL => Some(Self::Ill),
+            linux_raw_sys::general::SIGTRAP => Some(Self::Trap),
+            linux_raw_sys::general::SIGABRT => Some(Self::Abort),
+            linux_raw_sys::general::SIGBUS => Some(Self::Bus),
+            linux_raw_sys::general::SIGFPE => Some(Self::Fpe),
+            linux_raw_sys::general::SIGKILL => Some(Self::Kill),
+            linux_raw_sys::general::SIGUSR1 => Some(Self::Usr1),
+            linux_raw_sys::general::SIGSEGV => Some(Self::Segv),
+            linux_raw_sys::general::SIGUSR2 => Some(Self::Usr2),
+            linux_raw_sys::general::SIGPIPE => Some(Self::Pipe),
+            linux_raw_sys::general::SIGALRM => Some(Self::Alarm),
+            linux_raw_sys::general::SIGTERM => Some(Self::Term),
+            linux_raw_sys::general::SIGSTKFLT => Some(Self::Stkflt),
+            linux_raw_sys::general::SIGCHLD => Some(Self::Child),
+            linux_raw_sys::general::SIGCONT => Some(Self::Cont),
+            linux_raw_sys::general::SIGSTOP => Some(Self::Stop),
+            linux_raw_sys::general::SIGTSTP => Some(Self::Tstp),
+            linux_raw_sys::general::SIGTTIN => Some(Self::Ttin),
+            linux_raw_sys::general::SIGTTOU => Some(Self::Ttou),
+            linux_raw_sys::general::SIGURG => Some(Self::Urg),
+            linux_raw_sys::general::SIGXCPU => Some(Self::Xcpu),
+            linux_raw_sys::general::SIGXFSZ => Some(Self::Xfsz),
+            linux_raw_sys::general::SIGVTALRM => Some(Self::Vtalarm),
+            linux_raw_sys::general::SIGPROF => Some(Self::Prof),
+            linux_raw_sys::general::SIGWINCH => Some(Self::Winch),
+            linux_raw_sys::general::SIGIO => Some(Self::Io),
+            linux_raw_sys::general::SIGPWR => Some(Self::Power),
+            linux_raw_sys::general::SIGSYS => Some(Self::Sys),
+            _ => None,
+        }
+    }
+
+    /// Convert a `Signal` into a raw signal number.
+    #[inline]
+    pub const fn as_raw(self) -> c_int {
+        self as c_int
+    }
+}
+
+bitflags! {
+    /// `MS_*` constants for use with [`mount`].
+    ///
+    /// [`mount`]: crate::process::mount
+    pub struct MountFlags: c_uint {
+        /// `MS_RDONLY`
+        const RDONLY = linux_raw_sys::general::MS_RDONLY;
+        /// `MS_NOSUID`
+        const NOSUID = linux_raw_sys::general::MS_NOSUID;
+        /// `MS_NODEV`
+        const NODEV = linux_raw_sys::general::MS_NODEV;
+        /// `MS_NOEXEC`
+        const NOEXEC = linux_raw_sys::general::MS_NOEXEC;
+        /// `MS_REMOUNT`
+        const REMOUNT = linux_raw_sys::general::MS_REMOUNT;
+        /// `MS_BIND`
+        const BIND = linux_raw_sys::general::MS_BIND;
+        /// `MS_REC`
+        const REC = linux_raw_sys::general::MS_REC;
+    }
+}
+
+// linux_raw_sys doesn't define the `MNT_*` constants for `umount2`, which
+// are in the kernel's internal `linux/fs.h`, so define them here.
+const MNT_FORCE: c_uint = 0x1;
+const MNT_DETACH: c_uint = 0x2;
+
+bitflags! {
+    /// `MNT_*` constants for use with [`unmount`].
+    ///
+    /// [`unmount`]: crate::process::unmount
+    pub struct UnmountFlags: c_uint {
+        /// `MNT_FORCE`
+        const FORCE = MNT_FORCE;
+        /// `MNT_DETACH`
+        const DETACH = MNT_DETACH;
+    }
+}
+
+// linux_raw_sys doesn't define the `SCHED_*` constants, so define them here,
+// following the values in linux/sched.h.
+const SCHED_NORMAL: u32 = 0;
+const SCHED_FIFO: u32 = 1;
+const SCHED_RR: u32 = 2;
+const SCHED_BATCH: u32 = 3;
+const SCHED_IDLE: u32 = 5;
+const SCHED_DEADLINE: u32 = 6;
+const SCHED_RESET_ON_FORK: u32 = 0x4000_0000;
+
+/// `SCHED_*` constants for use with [`sched_getscheduler`] and
+/// [`sched_setscheduler`].
+///
+/// [`sched_getscheduler`]: crate::process::sched_getscheduler
+/// [`sched_setscheduler`]: crate::process::sched_setscheduler
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(i32)]
+pub enum SchedPolicy {
+    /// `SCHED_OTHER`, also known as `SCHED_NORMAL`
+    #[doc(alias = "Normal")]
+    Other = SCHED_NORMAL as i32,
+    /// `SCHED_FIFO`
+    Fifo = SCHED_FIFO as i32,
+    /// `SCHED_RR`
+    RoundRobin = SCHED_RR as i32,
+    /// `SCHED_BATCH`
+    Batch = SCHED_BATCH as i32,
+    /// `SCHED_IDLE`
+    Idle = SCHED_IDLE as i32,
+    /// `SCHED_DEADLINE`
+    Deadline = SCHED_DEADLINE as i32,
+}
+
+impl SchedPolicy {
+    /// Convert a raw policy number into a `SchedPolicy`, if it's one of the
+    /// policies listed here.
+    pub fn from_raw(policy: c_int) -> Option<Self> {
+        match policy as u32 {
+            SCHED_NORMAL => Some(Self::Other),
+            SCHED_FIFO => Some(Self::Fifo),
+            SCHED_RR => Some(Self::RoundRobin),
+            SCHED_BATCH => Some(Self::Batch),
+            SCHED_IDLE => Some(Self::Idle),
+            SCHED_DEADLINE => Some(Self::Deadline),
+            _ => None,
+        }
+    }
+}
+
+bitflags! {
+    /// Flags which may be or'd into a policy for [`sched_setscheduler_with`].
+    ///
+    /// [`sched_setscheduler_with`]: crate::process::sched_setscheduler_with
+    pub struct SchedFlags: c_int {
+        /// `SCHED_RESET_ON_FORK`
+        const RESET_ON_FORK = SCHED_RESET_ON_FORK as c_int;
+    }
+}
@@ -0,0 +1,44 @@
+// The functions replacing the C macros use the same names as in libc.
+#![allow(non_snake_case)]
+
+use std::os::raw::c_int;
+
+#[inline]
+pub(crate) fn WIFSTOPPED(status: c_int) -> bool {
+    (status & 0xff) == 0x7f
+}
+
+#[inline]
+pub(crate) fn WSTOPSIG(status: c_int) -> c_int {
+    (status >> 8) & 0xff
+}
+
+#[inline]
+pub(crate) fn WIFCONTINUED(status: c_int) -> bool {
+    status == 0xffff
+}
+
+#[inline]
+pub(crate) fn WIFSIGNALED(status: c_int) -> bool {
+    ((status & 0x7f) + 1) as i8 >= 2
+}
+
+#[inline]
+pub(crate) fn WTERMSIG(status: c_int) -> c_int {
+    status & 0x7f
+}
+
+#[inline]
+pub(crate) fn WCOREDUMP(status: c_int) -> bool {
+    (status & 0x80) != 0
+}
+
+#[inline]
+pub(crate) fn WIFEXITED(status: c_int) -> bool {
+    (status & 0x7f) == 0
+}
+
+#[inline]
+pub(crate) fn WEXITSTATUS(status: c_int) -> c_int {
+    (status >> 8) & 0xff
+}
@@ -21,38 +21,47 @@ use super::arch::choose::{
     syscall3, syscall3_readonly, syscall4, syscall4_readonly, syscall5, syscall5_readonly,
     syscall6, syscall6_readonly,
 };
-#[cfg(any(target_arch = "aarch64", target_arch = "riscv64"))]
-use super::conv::opt_ref;
 use super::conv::{
     borrowed_fd, by_mut, by_ref, c_int, c_str, c_uint, clockid_t, dev_t, mode_as, no_fd, oflags,
-    oflags_for_open_how, opt_c_str, opt_mut, out, pass_usize, raw_fd, ret, ret_c_int, ret_c_uint,
-    ret_discarded_fd, ret_owned_fd, ret_usize, ret_usize_infallible, ret_void_star, size_of, slice,
-    slice_just_addr, slice_mut, socklen_t, void_star, zero,
+    oflags_for_open_how, opt_c_str, opt_mut, opt_ref, out, pass_usize, raw_fd, ret, ret_c_int,
+    ret_c_uint, ret_discarded_fd, ret_owned_fd, ret_usize, ret_usize_infallible, ret_void_star,
+    size_of, slice, slice_just_addr, slice_mut, socklen_t, void_star, zero,
 };
 use super::fs::{
-    Access, Advice as FsAdvice, AtFlags, FallocateFlags, FdFlags, FlockOperation, MemfdFlags, Mode,
-    OFlags, RenameFlags, ResolveFlags, Stat, StatFs, StatxFlags,
+    Access, Advice as FsAdvice, AtFlags, FallocateFlags, FdFlags, FlockOperation, FlockType,
+    MemfdFlags, Mode, OFlags, RenameFlags, ResolveFlags, SealFlags, Stat, StatFs,
+    StatVfsMountFlags, StatxFlags, SyncFileRangeFlags, XattrFlags,
 };
 use super::io::{
-    epoll, Advice as IoAdvice, DupFlags, EventfdFlags, MapFlags, MlockFlags, MprotectFlags,
-    PipeFlags, PollFd, ProtFlags, ReadWriteFlags, UserfaultfdFlags,
+    epoll, Advice as IoAdvice, CloseRangeFlags, DupFlags, EventfdFlags, InotifyFlags, MapFlags,
+    MlockFlags, MprotectFlags, PipeFlags, PollFd, ProtFlags, ReadWriteFlags, SignalfdFlags,
+    SpliceFlags, UserfaultfdFlags, WatchFlags,
 };
 #[cfg(not(target_os = "wasi"))]
-use super::io::{Termios, Winsize};
+use super::io::{OptionalActions, QueueSelector, Termios, Winsize};
 use super::net::{
-    decode_sockaddr, AcceptFlags, AddressFamily, Protocol, RecvFlags, SendFlags, Shutdown,
-    SocketAddr, SocketAddrUnix, SocketAddrV4, SocketAddrV6, SocketType,
+    decode_sockaddr, AcceptFlags, AddressFamily, Ipv4Addr, Ipv6Addr, Linger, MsgHdr, Protocol,
+    RecvFlags, ReturnFlags, SendFlags, Shutdown, SocketAddr, SocketAddrUnix, SocketAddrV4,
+    SocketAddrV6, SocketType,
+};
+use super::process::{
+    MountFlags, RawRusage, RawSysinfo, RawUname, RusageWho, SchedFlags, SchedPolicy, SigSet,
+    SigmaskHow, UnmountFlags, WaitOptions,
 };
-use super::process::RawUname;
 use super::rand::GetRandomFlags;
 use super::reg::nr;
 #[cfg(target_arch = "x86")]
 use super::reg::{ArgReg, SocketArg};
-use super::time::{ClockId, Timespec};
+use super::thread::{FutexFlags, FUTEX_WAIT, FUTEX_WAKE};
+use super::time::{
+    AdjtimeState, ClockId, Itimerspec, KernelSigevent, KernelTimex, RawTimer, TimerFlags,
+    TimerfdFlags, TimerfdTimerFlags, Timespec, TimexModes, TimexStatus,
+};
+use crate::fs::{Flock, StatVfs};
 use crate::io;
 use crate::io::{OwnedFd, RawFd};
-use crate::process::{Gid, Pid, Uid};
-use crate::time::NanosleepRelativeResult;
+use crate::process::{Gid, Pid, ResGid, ResUid, Uid};
+use crate::time::{NanosleepRelativeResult, Sigevent, Timex};
 use io_lifetimes::{AsFd, BorrowedFd};
 #[cfg(any(target_arch = "aarch64", target_arch = "riscv64"))]
 use linux_raw_sys::general::__NR_epoll_pwait;
@@ -60,35 +69,66 @@ use linux_raw_sys::general::__NR_epoll_pwait;
 use linux_raw_sys::general::__NR_epoll_wait;
 #[cfg(not(any(target_arch = "riscv64")))]
 use linux_raw_sys::general::__NR_renameat;
+#[cfg(not(any(target_arch = "arm", target_arch = "powerpc")))]
+use linux_raw_sys::general::__NR_sync_file_range;
+// On arm and powerpc, only the variant with the reordered arguments exists.
+#[cfg(any(target_arch = "arm", target_arch = "powerpc"))]
+use linux_raw_sys::general::__NR_sync_file_range2;
 #[cfg(not(target_arch = "x86"))]
 use linux_raw_sys::general::{
     __NR_accept, __NR_accept4, __NR_bind, __NR_connect, __NR_getpeername, __NR_getsockname,
-    __NR_getsockopt, __NR_listen, __NR_recvfrom, __NR_sendto, __NR_setsockopt, __NR_shutdown,
-    __NR_socket, __NR_socketpair,
+    __NR_getsockopt, __NR_listen, __NR_recvfrom, __NR_recvmsg, __NR_sendmsg, __NR_sendto,
+    __NR_setsockopt, __NR_shutdown, __NR_socket, __NR_socketpair,
 };
 use linux_raw_sys::general::{
-    __NR_chdir, __NR_clock_getres, __NR_clock_nanosleep, __NR_close, __NR_dup, __NR_dup3,
-    __NR_epoll_create1, __NR_epoll_ctl, __NR_exit_group, __NR_faccessat, __NR_fallocate,
-    __NR_fchmod, __NR_fchmodat, __NR_fdatasync, __NR_flock, __NR_fsync, __NR_getcwd,
-    __NR_getdents64, __NR_getpid, __NR_getppid, __NR_getpriority, __NR_gettid, __NR_ioctl,
-    __NR_linkat, __NR_madvise, __NR_mkdirat, __NR_mknodat, __NR_mlock, __NR_mprotect, __NR_munlock,
-    __NR_munmap, __NR_nanosleep, __NR_openat, __NR_pipe2, __NR_pread64, __NR_preadv, __NR_pwrite64,
-    __NR_pwritev, __NR_read, __NR_readlinkat, __NR_readv, __NR_sched_yield, __NR_setpriority,
-    __NR_symlinkat, __NR_uname, __NR_unlinkat, __NR_utimensat, __NR_write, __NR_writev,
-    __kernel_gid_t, __kernel_pid_t, __kernel_timespec, __kernel_uid_t, epoll_event, sockaddr,
-    sockaddr_in, sockaddr_in6, sockaddr_un, socklen_t, AT_FDCWD, AT_REMOVEDIR, AT_SYMLINK_NOFOLLOW,
-    EPOLL_CTL_ADD, EPOLL_CTL_DEL, EPOLL_CTL_MOD, FIONBIO, FIONREAD, F_DUPFD, F_DUPFD_CLOEXEC,
-    F_GETFD, F_GETFL, F_GETLEASE, F_GETOWN, F_GETSIG, F_SETFD, F_SETFL, TCGETS, TIMER_ABSTIME,
-    TIOCEXCL, TIOCGWINSZ, TIOCNXCL,
+    __NR_chdir, __NR_chroot, __NR_clock_getres, __NR_clock_nanosleep, __NR_clock_settime,
+    __NR_close, __NR_dup, __NR_dup3, __NR_epoll_create1, __NR_epoll_ctl, __NR_exit_group,
+    __NR_faccessat, __NR_fallocate, __NR_fchdir, __NR_fchmod, __NR_fchmodat, __NR_fchownat,
+    __NR_fdatasync, __NR_flock, __NR_fsync, __NR_futex, __NR_getcwd, __NR_getdents64, __NR_getpgid,
+    __NR_getpid, __NR_getppid, __NR_getpriority, __NR_getrusage, __NR_getsid, __NR_gettid,
+    __NR_ioctl, __NR_kill, __NR_linkat, __NR_madvise, __NR_mkdirat, __NR_mknodat, __NR_mlock,
+    __NR_mount, __NR_mprotect, __NR_munlock, __NR_munmap, __NR_nanosleep, __NR_openat, __NR_pipe2,
+    __NR_pivot_root, __NR_prctl, __NR_pread64, __NR_preadv, __NR_pwrite64, __NR_pwritev, __NR_read,
+    __NR_readlinkat, __NR_readv, __NR_sched_getscheduler, __NR_sched_setscheduler,
+    __NR_sched_yield, __NR_sethostname, __NR_setpgid, __NR_setpriority, __NR_setsid,
+    __NR_symlinkat, __NR_sync, __NR_sysinfo, __NR_umask, __NR_umount2, __NR_uname, __NR_unlinkat,
+    __NR_utimensat, __NR_wait4, __NR_write, __NR_writev, __kernel_gid_t, __kernel_pid_t,
+    __kernel_sockaddr_storage, __kernel_timespec, __kernel_uid_t, epoll_event, sockaddr,
+    sockaddr_in, sockaddr_in6, socklen_t, AT_FDCWD, AT_REMOVEDIR, AT_SYMLINK_NOFOLLOW,
+    EPOLL_CTL_ADD, EPOLL_CTL_DEL, EPOLL_CTL_MOD, FIOCLEX, FIONBIO, FIONCLEX, FIONREAD, F_DUPFD,
+    F_DUPFD_CLOEXEC, F_GETFD, F_GETFL, F_GETLEASE, F_GETOWN, F_GETSIG, F_SETFD, F_SETFL, TCFLSH,
+    TCGETS, TCSBRK, TCSETS, TCSETSF, TCSETSW, TIMER_ABSTIME, TIOCEXCL, TIOCGPGRP, TIOCGWINSZ,
+    TIOCNXCL, TIOCSPGRP, TIOCSWINSZ, UIO_MAXIOV,
 };
 #[cfg(not(any(target_arch = "aarch64", target_arch = "riscv64")))]
 use linux_raw_sys::general::{__NR_dup2, __NR_open, __NR_pipe, __NR_poll};
 #[cfg(not(any(target_arch = "x86", target_arch = "sparc", target_arch = "arm")))]
+use linux_raw_sys::general::{
+    __NR_fchown, __NR_getgroups, __NR_getresgid, __NR_getresuid, __NR_setgroups, __NR_setresgid,
+    __NR_setresuid,
+};
+use linux_raw_sys::general::{
+    __NR_fgetxattr, __NR_flistxattr, __NR_fremovexattr, __NR_fsetxattr, __NR_getxattr,
+    __NR_lgetxattr, __NR_listxattr, __NR_llistxattr, __NR_lremovexattr, __NR_lsetxattr,
+    __NR_removexattr, __NR_setxattr,
+};
+#[cfg(not(any(target_arch = "x86", target_arch = "sparc", target_arch = "arm")))]
 use linux_raw_sys::general::{__NR_getegid, __NR_geteuid, __NR_getgid, __NR_getuid};
 #[cfg(any(target_arch = "x86", target_arch = "sparc", target_arch = "arm"))]
 use linux_raw_sys::general::{__NR_getegid32, __NR_geteuid32, __NR_getgid32, __NR_getuid32};
-#[cfg(any(target_arch = "aarch64", target_arch = "riscv64"))]
-use linux_raw_sys::general::{__NR_ppoll, sigset_t};
+// On these architectures, the syscalls without the `32` suffix use 16-bit
+// IDs.
+#[cfg(any(target_arch = "x86", target_arch = "sparc", target_arch = "arm"))]
+use linux_raw_sys::general::{
+    __NR_fchown32 as __NR_fchown, __NR_getgroups32 as __NR_getgroups,
+    __NR_getresgid32 as __NR_getresgid, __NR_getresuid32 as __NR_getresuid,
+    __NR_setgroups32 as __NR_setgroups, __NR_setresgid32 as __NR_setresgid,
+    __NR_setresuid32 as __NR_setresuid,
+};
+use linux_raw_sys::general::{
+    __NR_inotify_add_watch, __NR_inotify_init1, __NR_inotify_rm_watch, __NR_ppoll,
+    __NR_rt_sigprocmask, __NR_signalfd4, __NR_splice, __NR_tee, __NR_vmsplice,
+};
 #[cfg(not(any(
     target_arch = "x86",
     target_arch = "x86_64",
@@ -96,46 +136,65 @@ use linux_raw_sys::general::{__NR_ppoll, sigset_t};
     target_arch = "riscv64"
 )))]
 use linux_raw_sys::general::{__NR_recv, __NR_send};
-use linux_raw_sys::v5_11::general::{__NR_openat2, open_how};
+use linux_raw_sys::general::{
+    __NR_timer_create, __NR_timer_delete, __NR_timer_getoverrun, __NR_timerfd_create, SIGEV_NONE,
+    SIGEV_SIGNAL, SIGEV_THREAD_ID,
+};
+use linux_raw_sys::v5_11::general::{__NR_close_range, __NR_faccessat2, __NR_openat2, open_how};
 use linux_raw_sys::v5_4::general::{
     __NR_copy_file_range, __NR_eventfd2, __NR_getrandom, __NR_memfd_create, __NR_mlock2,
-    __NR_preadv2, __NR_pwritev2, __NR_renameat2, __NR_statx, __NR_userfaultfd, statx, F_GETPIPE_SZ,
-    F_GET_SEALS, F_SETPIPE_SZ,
+    __NR_preadv2, __NR_pwritev2, __NR_renameat2, __NR_statx, __NR_syncfs, __NR_userfaultfd, statx,
+    F_ADD_SEALS, F_GETPIPE_SZ, F_GET_SEALS, F_SETPIPE_SZ,
 };
+use std::cmp::min;
 use std::convert::TryInto;
 use std::ffi::CStr;
 use std::io::{IoSlice, IoSliceMut, SeekFrom};
 use std::mem::MaybeUninit;
-use std::os::raw::{c_char, c_int, c_uint, c_void};
+use std::os::raw::{c_int, c_uint, c_void};
+use std::ptr::null_mut;
+use std::sync::atomic::AtomicU32;
+use std::time::Duration;
 #[cfg(target_arch = "x86")]
 use {
     super::conv::x86_sys,
     linux_raw_sys::general::{
         __NR_mmap2, __NR_socketcall, SYS_ACCEPT, SYS_ACCEPT4, SYS_BIND, SYS_CONNECT,
         SYS_GETPEERNAME, SYS_GETSOCKNAME, SYS_GETSOCKOPT, SYS_LISTEN, SYS_RECV, SYS_RECVFROM,
-        SYS_SEND, SYS_SENDTO, SYS_SETSOCKOPT, SYS_SHUTDOWN, SYS_SOCKET, SYS_SOCKETPAIR,
+        SYS_RECVMSG, SYS_SEND, SYS_SENDMSG, SYS_SENDTO, SYS_SETSOCKOPT, SYS_SHUTDOWN, SYS_SOCKET,
+        SYS_SOCKETPAIR,
     },
 };
 #[cfg(target_pointer_width = "32")]
 use {
     super::conv::{hi, lo},
     linux_raw_sys::{
+        general::flock64 as __kernel_flock,
         general::timespec as __kernel_old_timespec,
         general::{
             __NR__llseek, __NR_fadvise64_64, __NR_fcntl64, __NR_fstat64, __NR_fstatat64,
-            __NR_fstatfs64, __NR_ftruncate64, __NR_sendfile64,
+            __NR_fstatfs64, __NR_ftruncate64, __NR_sendfile64, __NR_statfs64, F_GETLK64, F_SETLK64,
+            F_SETLKW64,
         },
         v5_4::general::{
-            __NR_clock_getres_time64, __NR_clock_nanosleep_time64, __NR_utimensat_time64,
+            __NR_clock_adjtime64, __NR_clock_getres_time64, __NR_clock_nanosleep_time64,
+            __NR_clock_settime64, __NR_futex_time64, __NR_ppoll_time64, __NR_timer_gettime64,
+            __NR_timer_settime64, __NR_timerfd_gettime64, __NR_timerfd_settime64,
+            __NR_utimensat_time64,
         },
     },
 };
 #[cfg(target_pointer_width = "64")]
 use {
     super::conv::{loff_t, loff_t_from_u64, ret_u64},
-    linux_raw_sys::general::{
-        __NR_fadvise64, __NR_fcntl, __NR_fstat, __NR_fstatfs, __NR_ftruncate, __NR_lseek,
-        __NR_mmap, __NR_newfstatat, __NR_sendfile,
+    linux_raw_sys::{
+        general::{
+            __NR_fadvise64, __NR_fcntl, __NR_fstat, __NR_fstatfs, __NR_ftruncate, __NR_lseek,
+            __NR_mmap, __NR_newfstatat, __NR_sendfile, __NR_statfs, __NR_timer_gettime,
+            __NR_timer_settime, __NR_timerfd_gettime, __NR_timerfd_settime,
+            flock as __kernel_flock, F_GETLK, F_SETLK, F_SETLKW,
+        },
+        v5_4::general::__NR_clock_adjtime,
     },
 };
 
@@ -152,6 +211,20 @@ pub(crate) unsafe fn close(fd: RawFd) {
     let _ = syscall1_readonly(nr(__NR_close), raw_fd(fd));
 }
 
+#[inline]
+pub(crate) unsafe fn close_range(
+    first: RawFd,
+    last: RawFd,
+    flags: CloseRangeFlags,
+) -> io::Result<()> {
+    ret(syscall3(
+        nr(__NR_close_range),
+        c_uint(first as c_uint),
+        c_uint(last as c_uint),
+        c_uint(flags.bits()),
+    ))
+}
+
 #[inline]
 pub(crate) fn open(filename: &CStr, flags: OFlags, mode: Mode) -> io::Result<OwnedFd> {
     #[cfg(any(target_arch = "aarch64", target_arch = "riscv64"))]
@@ -309,6 +382,26 @@ pub(crate) fn read(fd: BorrowedFd<'_>, buf: &mut [u8]) -> io::Result<usize> {
     }
 }
 
+#[inline]
+pub(crate) fn read_uninit<'buf>(
+    fd: BorrowedFd<'_>,
+    buf: &'buf mut [MaybeUninit<u8>],
+) -> io::Result<(&'buf mut [u8], &'buf mut [MaybeUninit<u8>])> {
+    let (buf_addr_mut, buf_len) = slice_mut(buf);
+
+    let nread = unsafe {
+        ret_usize(syscall3(
+            nr(__NR_read),
+            borrowed_fd(fd),
+            buf_addr_mut,
+            buf_len,
+        ))?
+    };
+
+    // Safety: `read` initialized the first `nread` bytes of `buf`.
+    Ok(unsafe { io::split_init(buf, nread) })
+}
+
 #[inline]
 pub(crate) fn pread(fd: BorrowedFd<'_>, buf: &mut [u8], pos: u64) -> io::Result<usize> {
     let (buf_addr_mut, buf_len) = slice_mut(buf);
@@ -378,10 +471,12 @@ pub(crate) fn preadv(fd: BorrowedFd<'_>, bufs: &[IoSliceMut], pos: u64) -> io::R
 pub(crate) fn preadv2(
     fd: BorrowedFd<'_>,
     bufs: &[IoSliceMut],
-    pos: u64,
+    pos: Option<u64>,
     flags: ReadWriteFlags,
 ) -> io::Result<usize> {
     let (bufs_addr, bufs_len) = slice(bufs);
+    // An offset of -1 means the current file position.
+    let pos = pos.unwrap_or(-1_i64 as u64);
 
     #[cfg(target_pointer_width = "32")]
     unsafe {
@@ -395,14 +490,17 @@ pub(crate) fn preadv2(
             c_uint(flags.bits()),
         ))
     }
+    // The offset is split into two registers even on 64-bit targets, where
+    // the high half is ignored.
     #[cfg(target_pointer_width = "64")]
     unsafe {
-        ret_usize(syscall5(
+        ret_usize(syscall6(
             nr(__NR_preadv2),
             borrowed_fd(fd),
             bufs_addr,
             bufs_len,
             loff_t_from_u64(pos),
+            zero(),
             c_uint(flags.bits()),
         ))
     }
@@ -494,10 +592,12 @@ pub(crate) fn pwritev(fd: BorrowedFd<'_>, bufs: &[IoSlice], pos: u64) -> io::Res
 pub(crate) fn pwritev2(
     fd: BorrowedFd<'_>,
     bufs: &[IoSlice],
-    pos: u64,
+    pos: Option<u64>,
     flags: ReadWriteFlags,
 ) -> io::Result<usize> {
     let (bufs_addr, bufs_len) = slice(bufs);
+    // An offset of -1 means the current file position.
+    let pos = pos.unwrap_or(-1_i64 as u64);
 
     #[cfg(target_pointer_width = "32")]
     unsafe {
@@ -511,14 +611,17 @@ pub(crate) fn pwritev2(
             c_uint(flags.bits()),
         ))
     }
+    // The offset is split into two registers even on 64-bit targets, where
+    // the high half is ignored.
     #[cfg(target_pointer_width = "64")]
     unsafe {
-        ret_usize(syscall5_readonly(
+        ret_usize(syscall6_readonly(
             nr(__NR_pwritev2),
             borrowed_fd(fd),
             bufs_addr,
             bufs_len,
             loff_t_from_u64(pos),
+            zero(),
             c_uint(flags.bits()),
         ))
     }
@@ -559,6 +662,44 @@ pub(crate) fn fchmod(fd: BorrowedFd<'_>, mode: Mode) -> io::Result<()> {
     }
 }
 
+#[inline]
+pub(crate) fn chownat(
+    dirfd: BorrowedFd<'_>,
+    filename: &CStr,
+    owner: Option<Uid>,
+    group: Option<Gid>,
+    flags: AtFlags,
+) -> io::Result<()> {
+    // `-1` means "unchanged".
+    let owner = owner.map_or(!0, Uid::as_raw);
+    let group = group.map_or(!0, Gid::as_raw);
+    unsafe {
+        ret(syscall5_readonly(
+            nr(__NR_fchownat),
+            borrowed_fd(dirfd),
+            c_str(filename),
+            c_uint(owner),
+            c_uint(group),
+            c_uint(flags.bits()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn fchown(fd: BorrowedFd<'_>, owner: Option<Uid>, group: Option<Gid>) -> io::Result<()> {
+    // `-1` means "unchanged".
+    let owner = owner.map_or(!0, Uid::as_raw);
+    let group = group.map_or(!0, Gid::as_raw);
+    unsafe {
+        ret(syscall3_readonly(
+            nr(__NR_fchown),
+            borrowed_fd(fd),
+            c_uint(owner),
+            c_uint(group),
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn mknodat(
     dirfd: BorrowedFd<'_>,
@@ -752,6 +893,67 @@ pub(crate) fn fdatasync(fd: BorrowedFd<'_>) -> io::Result<()> {
     unsafe { ret(syscall1_readonly(nr(__NR_fdatasync), borrowed_fd(fd))) }
 }
 
+#[inline]
+pub(crate) fn sync_file_range(
+    fd: BorrowedFd<'_>,
+    offset: u64,
+    nbytes: u64,
+    flags: SyncFileRangeFlags,
+) -> io::Result<()> {
+    // On arm and powerpc, the flags argument is moved ahead of the offset and
+    // nbytes argument pairs so that they're aligned.
+    #[cfg(any(target_arch = "arm", target_arch = "powerpc"))]
+    unsafe {
+        ret(syscall6_readonly(
+            nr(__NR_sync_file_range2),
+            borrowed_fd(fd),
+            c_uint(flags.bits()),
+            hi(offset),
+            lo(offset),
+            hi(nbytes),
+            lo(nbytes),
+        ))
+    }
+    #[cfg(all(
+        target_pointer_width = "32",
+        not(any(target_arch = "arm", target_arch = "powerpc"))
+    ))]
+    unsafe {
+        ret(syscall6_readonly(
+            nr(__NR_sync_file_range),
+            borrowed_fd(fd),
+            hi(offset),
+            lo(offset),
+            hi(nbytes),
+            lo(nbytes),
+            c_uint(flags.bits()),
+        ))
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall4_readonly(
+            nr(__NR_sync_file_range),
+            borrowed_fd(fd),
+            loff_t_from_u64(offset),
+            loff_t_from_u64(nbytes),
+            c_uint(flags.bits()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn syncfs(fd: BorrowedFd<'_>) -> io::Result<()> {
+    unsafe { ret(syscall1_readonly(nr(__NR_syncfs), borrowed_fd(fd))) }
+}
+
+#[inline]
+pub(crate) fn sync() {
+    // `sync` always succeeds.
+    unsafe {
+        let _ = syscall0_readonly(nr(__NR_sync));
+    }
+}
+
 #[inline]
 pub(crate) fn flock(fd: BorrowedFd<'_>, operation: FlockOperation) -> io::Result<()> {
     unsafe {
@@ -913,6 +1115,61 @@ pub(crate) fn fstatfs(fd: BorrowedFd<'_>) -> io::Result<StatFs> {
     }
 }
 
+#[inline]
+fn statfs(path: &CStr) -> io::Result<StatFs> {
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        let mut result = MaybeUninit::<StatFs>::uninit();
+        ret(syscall3(
+            nr(__NR_statfs64),
+            c_str(path),
+            size_of::<StatFs, _>(),
+            out(&mut result),
+        ))
+        .map(|()| result.assume_init())
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        let mut result = MaybeUninit::<StatFs>::uninit();
+        ret(syscall2(nr(__NR_statfs), c_str(path), out(&mut result))).map(|()| result.assume_init())
+    }
+}
+
+#[inline]
+pub(crate) fn statvfs(path: &CStr) -> io::Result<StatVfs> {
+    statfs(path).map(statfs_to_statvfs)
+}
+
+#[inline]
+pub(crate) fn fstatvfs(fd: BorrowedFd<'_>) -> io::Result<StatVfs> {
+    fstatfs(fd).map(statfs_to_statvfs)
+}
+
+/// Derives the POSIX `statvfs` fields from a `statfs`, the way libc
+/// implementations do.
+fn statfs_to_statvfs(statfs: StatFs) -> StatVfs {
+    let [fsid_lo, fsid_hi] = statfs.f_fsid.val;
+
+    StatVfs {
+        f_bsize: statfs.f_bsize as u64,
+        // Kernels before 2.6 don't report `f_frsize`.
+        f_frsize: if statfs.f_frsize != 0 {
+            statfs.f_frsize
+        } else {
+            statfs.f_bsize
+        } as u64,
+        f_blocks: statfs.f_blocks,
+        f_bfree: statfs.f_bfree,
+        f_bavail: statfs.f_bavail,
+        f_files: statfs.f_files,
+        f_ffree: statfs.f_ffree,
+        f_favail: statfs.f_ffree,
+        f_fsid: u64::from(fsid_lo as u32) | (u64::from(fsid_hi as u32) << 32),
+        f_flag: StatVfsMountFlags::from_bits_truncate(statfs.f_flags as u64),
+        f_namemax: statfs.f_namelen as u64,
+    }
+}
+
 #[inline]
 pub(crate) fn readlink(path: &CStr, buf: &mut [u8]) -> io::Result<usize> {
     let (buf_addr_mut, buf_len) = slice_mut(buf);
@@ -942,41 +1199,45 @@ pub(crate) fn readlinkat(dirfd: BorrowedFd<'_>, path: &CStr, buf: &mut [u8]) ->
 }
 
 #[inline]
-pub(crate) fn fcntl_dupfd(fd: BorrowedFd<'_>) -> io::Result<OwnedFd> {
+pub(crate) fn fcntl_dupfd(fd: BorrowedFd<'_>, min: RawFd) -> io::Result<OwnedFd> {
     #[cfg(target_pointer_width = "32")]
     unsafe {
-        ret_owned_fd(syscall2_readonly(
+        ret_owned_fd(syscall3_readonly(
             nr(__NR_fcntl64),
             borrowed_fd(fd),
             c_uint(F_DUPFD),
+            raw_fd(min),
         ))
     }
     #[cfg(target_pointer_width = "64")]
     unsafe {
-        ret_owned_fd(syscall2_readonly(
+        ret_owned_fd(syscall3_readonly(
             nr(__NR_fcntl),
             borrowed_fd(fd),
             c_uint(F_DUPFD),
+            raw_fd(min),
         ))
     }
 }
 
 #[inline]
-pub(crate) fn fcntl_dupfd_cloexec(fd: BorrowedFd<'_>) -> io::Result<OwnedFd> {
+pub(crate) fn fcntl_dupfd_cloexec(fd: BorrowedFd<'_>, min: RawFd) -> io::Result<OwnedFd> {
     #[cfg(target_pointer_width = "32")]
     unsafe {
-        ret_owned_fd(syscall2_readonly(
+        ret_owned_fd(syscall3_readonly(
             nr(__NR_fcntl64),
             borrowed_fd(fd),
             c_uint(F_DUPFD_CLOEXEC),
+            raw_fd(min),
         ))
     }
     #[cfg(target_pointer_width = "64")]
     unsafe {
-        ret_owned_fd(syscall2_readonly(
+        ret_owned_fd(syscall3_readonly(
             nr(__NR_fcntl),
             borrowed_fd(fd),
             c_uint(F_DUPFD_CLOEXEC),
+            raw_fd(min),
         ))
     }
 }
@@ -1172,7 +1433,7 @@ pub(crate) fn fcntl_setpipe_sz(fd: BorrowedFd<'_>, size: c_int) -> io::Result<us
 }
 
 #[inline]
-pub(crate) fn fcntl_get_seals(fd: BorrowedFd<'_>) -> io::Result<u32> {
+pub(crate) fn fcntl_get_seals(fd: BorrowedFd<'_>) -> io::Result<SealFlags> {
     #[cfg(target_pointer_width = "32")]
     unsafe {
         ret_c_int(syscall2_readonly(
@@ -1180,7 +1441,7 @@ pub(crate) fn fcntl_get_seals(fd: BorrowedFd<'_>) -> io::Result<u32> {
             borrowed_fd(fd),
             c_uint(F_GET_SEALS),
         ))
-        .map(|seals| seals as u32)
+        .map(|seals| SealFlags::from_bits_truncate(seals as u32))
     }
     #[cfg(target_pointer_width = "64")]
     unsafe {
@@ -1189,7 +1450,133 @@ pub(crate) fn fcntl_get_seals(fd: BorrowedFd<'_>) -> io::Result<u32> {
             borrowed_fd(fd),
             c_uint(F_GET_SEALS),
         ))
-        .map(|seals| seals as u32)
+        .map(|seals| SealFlags::from_bits_truncate(seals as u32))
+    }
+}
+
+#[inline]
+pub(crate) fn fcntl_add_seals(fd: BorrowedFd<'_>, seals: SealFlags) -> io::Result<()> {
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        ret(syscall3(
+            nr(__NR_fcntl64),
+            borrowed_fd(fd),
+            c_uint(F_ADD_SEALS),
+            c_uint(seals.bits()),
+        ))
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall3(
+            nr(__NR_fcntl),
+            borrowed_fd(fd),
+            c_uint(F_ADD_SEALS),
+            c_uint(seals.bits()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn fcntl_getlk(fd: BorrowedFd<'_>, lock: &Flock) -> io::Result<Flock> {
+    let mut raw = flock_to_raw(lock)?;
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        ret(syscall3(
+            nr(__NR_fcntl64),
+            borrowed_fd(fd),
+            c_uint(F_GETLK64),
+            by_mut(&mut raw),
+        ))?;
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall3(
+            nr(__NR_fcntl),
+            borrowed_fd(fd),
+            c_uint(F_GETLK),
+            by_mut(&mut raw),
+        ))?;
+    }
+    Ok(flock_from_raw(&raw))
+}
+
+#[inline]
+pub(crate) fn fcntl_setlk(fd: BorrowedFd<'_>, lock: &Flock) -> io::Result<()> {
+    let raw = flock_to_raw(lock)?;
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        ret(syscall3_readonly(
+            nr(__NR_fcntl64),
+            borrowed_fd(fd),
+            c_uint(F_SETLK64),
+            by_ref(&raw),
+        ))
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall3_readonly(
+            nr(__NR_fcntl),
+            borrowed_fd(fd),
+            c_uint(F_SETLK),
+            by_ref(&raw),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn fcntl_setlkw(fd: BorrowedFd<'_>, lock: &Flock) -> io::Result<()> {
+    let raw = flock_to_raw(lock)?;
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        ret(syscall3_readonly(
+            nr(__NR_fcntl64),
+            borrowed_fd(fd),
+            c_uint(F_SETLKW64),
+            by_ref(&raw),
+        ))
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall3_readonly(
+            nr(__NR_fcntl),
+            borrowed_fd(fd),
+            c_uint(F_SETLKW),
+            by_ref(&raw),
+        ))
+    }
+}
+
+fn flock_to_raw(lock: &Flock) -> io::Result<__kernel_flock> {
+    let (whence, start) = match lock.start {
+        SeekFrom::Start(pos) => (
+            linux_raw_sys::general::SEEK_SET,
+            pos.try_into().map_err(|_| io::Error::OVERFLOW)?,
+        ),
+        SeekFrom::End(offset) => (linux_raw_sys::general::SEEK_END, offset),
+        SeekFrom::Current(offset) => (linux_raw_sys::general::SEEK_CUR, offset),
+    };
+    Ok(__kernel_flock {
+        l_type: lock.typ as _,
+        l_whence: whence as _,
+        l_start: start,
+        l_len: lock.len,
+        l_pid: lock.pid.as_raw() as _,
+    })
+}
+
+fn flock_from_raw(raw: &__kernel_flock) -> Flock {
+    // The kernel reports conflicting locks with `SEEK_SET`, but decode
+    // `l_whence` in full rather than assuming that.
+    let start = match raw.l_whence as c_uint {
+        linux_raw_sys::general::SEEK_END => SeekFrom::End(raw.l_start),
+        linux_raw_sys::general::SEEK_CUR => SeekFrom::Current(raw.l_start),
+        _ => SeekFrom::Start(raw.l_start as u64),
+    };
+    Flock {
+        typ: FlockType::from_raw(raw.l_type as u32),
+        start,
+        len: raw.l_len,
+        pid: unsafe { Pid::from_raw(raw.l_pid as _) },
     }
 }
 
@@ -1662,49 +2049,414 @@ pub(crate) fn shutdown(fd: BorrowedFd<'_>, how: Shutdown) -> io::Result<()> {
 }
 
 #[inline]
-pub(crate) fn setsockopt(
-    fd: BorrowedFd<'_>,
-    level: c_int,
-    name: c_int,
-    value: &[u8],
-    optlen: socklen_t,
-) -> io::Result<c_int> {
-    assert!((optlen as usize) <= value.len());
+fn getsockopt<T>(fd: BorrowedFd<'_>, level: u32, optname: u32) -> io::Result<T> {
+    let mut optlen = std::mem::size_of::<T>() as socklen_t;
+    let mut value = MaybeUninit::<T>::uninit();
+
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret(syscall5(
+            nr(__NR_getsockopt),
+            borrowed_fd(fd),
+            c_uint(level),
+            c_uint(optname),
+            out(&mut value),
+            by_mut(&mut optlen),
+        ))?;
+    }
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret(syscall2(
+            nr(__NR_socketcall),
+            x86_sys(SYS_GETSOCKOPT),
+            slice_just_addr::<ArgReg<SocketArg>, _>(&[
+                borrowed_fd(fd),
+                c_uint(level),
+                c_uint(optname),
+                out(&mut value),
+                by_mut(&mut optlen),
+            ]),
+        ))?;
+    }
+
+    assert_eq!(
+        optlen as usize,
+        std::mem::size_of::<T>(),
+        "unexpected getsockopt size"
+    );
+    unsafe { Ok(value.assume_init()) }
+}
 
-    // The length is not passed to the syscall; we checked the buffer size above.
-    let value_addr = slice_just_addr(value);
+#[inline]
+fn setsockopt<T>(fd: BorrowedFd<'_>, level: u32, optname: u32, value: T) -> io::Result<()> {
+    let optlen = std::mem::size_of::<T>() as socklen_t;
 
     #[cfg(not(target_arch = "x86"))]
     unsafe {
-        ret_c_int(syscall5_readonly(
+        ret(syscall5_readonly(
             nr(__NR_setsockopt),
             borrowed_fd(fd),
-            c_int(level),
-            c_int(name),
-            value_addr,
+            c_uint(level),
+            c_uint(optname),
+            by_ref(&value),
             socklen_t(optlen),
         ))
     }
     #[cfg(target_arch = "x86")]
     unsafe {
-        ret_c_int(syscall2_readonly(
+        ret(syscall2_readonly(
             nr(__NR_socketcall),
             x86_sys(SYS_SETSOCKOPT),
             slice_just_addr::<ArgReg<SocketArg>, _>(&[
                 borrowed_fd(fd),
-                c_int(level),
-                c_int(name),
-                value_addr,
+                c_uint(level),
+                c_uint(optname),
+                by_ref(&value),
                 socklen_t(optlen),
             ]),
         ))
     }
 }
 
-#[inline]
-pub(crate) fn getsockopt_socket_type(fd: BorrowedFd<'_>) -> io::Result<SocketType> {
-    #[cfg(not(target_arch = "x86"))]
-    unsafe {
+// These are defined in `linux/tcp.h` rather than in the headers
+// linux_raw_sys is generated from.
+const TCP_NODELAY: u32 = 1;
+const TCP_KEEPIDLE: u32 = 4;
+const TCP_KEEPINTVL: u32 = 5;
+const TCP_KEEPCNT: u32 = 6;
+
+/// Convert a `Duration` to the whole number of seconds the TCP keepalive
+/// options expect, rounding up, and rejecting zero.
+fn keepalive_secs(duration: Duration) -> io::Result<c_int> {
+    let secs = duration.as_secs() + u64::from(duration.subsec_nanos() != 0);
+    if secs == 0 {
+        return Err(io::Error::INVAL);
+    }
+    secs.try_into().map_err(|_| io::Error::OVERFLOW)
+}
+
+#[inline]
+pub(crate) fn set_reuseaddr(fd: BorrowedFd<'_>, reuseaddr: bool) -> io::Result<()> {
+    setsockopt(
+        fd,
+        linux_raw_sys::general::SOL_SOCKET,
+        linux_raw_sys::general::SO_REUSEADDR,
+        c_int::from(reuseaddr),
+    )
+}
+
+#[inline]
+pub(crate) fn get_reuseaddr(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt::<c_int>(
+        fd,
+        linux_raw_sys::general::SOL_SOCKET,
+        linux_raw_sys::general::SO_REUSEADDR,
+    )
+    .map(|reuseaddr| reuseaddr != 0)
+}
+
+#[inline]
+pub(crate) fn set_tcp_nodelay(fd: BorrowedFd<'_>, nodelay: bool) -> io::Result<()> {
+    setsockopt(
+        fd,
+        linux_raw_sys::general::IPPROTO_TCP as u32,
+        TCP_NODELAY,
+        c_int::from(nodelay),
+    )
+}
+
+#[inline]
+pub(crate) fn get_tcp_nodelay(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt::<c_int>(fd, linux_raw_sys::general::IPPROTO_TCP as u32, TCP_NODELAY)
+        .map(|nodelay| nodelay != 0)
+}
+
+#[inline]
+pub(crate) fn set_recv_buffer_size(fd: BorrowedFd<'_>, size: usize) -> io::Result<()> {
+    let size: c_int = size.try_into().map_err(|_| io::Error::OVERFLOW)?;
+    setsockopt(
+        fd,
+        linux_raw_sys::general::SOL_SOCKET,
+        linux_raw_sys::general::SO_RCVBUF,
+        size,
+    )
+}
+
+#[inline]
+pub(crate) fn get_recv_buffer_size(fd: BorrowedFd<'_>) -> io::Result<usize> {
+    getsockopt::<c_int>(
+        fd,
+        linux_raw_sys::general::SOL_SOCKET,
+        linux_raw_sys::general::SO_RCVBUF,
+    )
+    .map(|size| size as usize)
+}
+
+#[inline]
+pub(crate) fn set_send_buffer_size(fd: BorrowedFd<'_>, size: usize) -> io::Result<()> {
+    let size: c_int = size.try_into().map_err(|_| io::Error::OVERFLOW)?;
+    setsockopt(
+        fd,
+        linux_raw_sys::general::SOL_SOCKET,
+        linux_raw_sys::general::SO_SNDBUF,
+        size,
+    )
+}
+
+#[inline]
+pub(crate) fn get_send_buffer_size(fd: BorrowedFd<'_>) -> io::Result<usize> {
+    getsockopt::<c_int>(
+        fd,
+        linux_raw_sys::general::SOL_SOCKET,
+        linux_raw_sys::general::SO_SNDBUF,
+    )
+    .map(|size| size as usize)
+}
+
+#[inline]
+pub(crate) fn set_socket_linger(fd: BorrowedFd<'_>, linger: Option<Duration>) -> io::Result<()> {
+    let linger = match linger {
+        // Round up, so that a sub-second linger doesn't become zero, which
+        // would make `close` reset the connection.
+        Some(linger) => Linger {
+            l_onoff: 1,
+            l_linger: (linger.as_secs() + u64::from(linger.subsec_nanos() != 0))
+                .try_into()
+                .map_err(|_| io::Error::OVERFLOW)?,
+        },
+        None => Linger {
+            l_onoff: 0,
+            l_linger: 0,
+        },
+    };
+    setsockopt(
+        fd,
+        linux_raw_sys::general::SOL_SOCKET,
+        linux_raw_sys::general::SO_LINGER,
+        linger,
+    )
+}
+
+#[inline]
+pub(crate) fn get_socket_linger(fd: BorrowedFd<'_>) -> io::Result<Option<Duration>> {
+    let linger: Linger = getsockopt(
+        fd,
+        linux_raw_sys::general::SOL_SOCKET,
+        linux_raw_sys::general::SO_LINGER,
+    )?;
+    Ok(if linger.l_onoff != 0 {
+        Some(Duration::from_secs(linger.l_linger as u64))
+    } else {
+        None
+    })
+}
+
+#[inline]
+pub(crate) fn set_keepalive(fd: BorrowedFd<'_>, keepalive: bool) -> io::Result<()> {
+    setsockopt(
+        fd,
+        linux_raw_sys::general::SOL_SOCKET,
+        linux_raw_sys::general::SO_KEEPALIVE,
+        c_int::from(keepalive),
+    )
+}
+
+#[inline]
+pub(crate) fn get_keepalive(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt::<c_int>(
+        fd,
+        linux_raw_sys::general::SOL_SOCKET,
+        linux_raw_sys::general::SO_KEEPALIVE,
+    )
+    .map(|keepalive| keepalive != 0)
+}
+
+#[inline]
+pub(crate) fn get_socket_error(fd: BorrowedFd<'_>) -> io::Result<Result<(), io::Error>> {
+    let err: c_int = getsockopt(
+        fd,
+        linux_raw_sys::general::SOL_SOCKET,
+        linux_raw_sys::general::SO_ERROR,
+    )?;
+    Ok(if err == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::from_raw_os_error(err))
+    })
+}
+
+#[inline]
+pub(crate) fn set_tcp_keepidle(fd: BorrowedFd<'_>, idle: Duration) -> io::Result<()> {
+    let idle = keepalive_secs(idle)?;
+    setsockopt(
+        fd,
+        linux_raw_sys::general::IPPROTO_TCP as u32,
+        TCP_KEEPIDLE,
+        idle,
+    )
+}
+
+#[inline]
+pub(crate) fn get_tcp_keepidle(fd: BorrowedFd<'_>) -> io::Result<Duration> {
+    getsockopt::<c_int>(fd, linux_raw_sys::general::IPPROTO_TCP as u32, TCP_KEEPIDLE)
+        .map(|idle| Duration::from_secs(idle as u64))
+}
+
+#[inline]
+pub(crate) fn set_tcp_keepintvl(fd: BorrowedFd<'_>, interval: Duration) -> io::Result<()> {
+    let interval = keepalive_secs(interval)?;
+    setsockopt(
+        fd,
+        linux_raw_sys::general::IPPROTO_TCP as u32,
+        TCP_KEEPINTVL,
+        interval,
+    )
+}
+
+#[inline]
+pub(crate) fn get_tcp_keepintvl(fd: BorrowedFd<'_>) -> io::Result<Duration> {
+    getsockopt::<c_int>(
+        fd,
+        linux_raw_sys::general::IPPROTO_TCP as u32,
+        TCP_KEEPINTVL,
+    )
+    .map(|interval| Duration::from_secs(interval as u64))
+}
+
+#[inline]
+pub(crate) fn set_tcp_keepcnt(fd: BorrowedFd<'_>, count: u32) -> io::Result<()> {
+    let count: c_int = count.try_into().map_err(|_| io::Error::OVERFLOW)?;
+    setsockopt(
+        fd,
+        linux_raw_sys::general::IPPROTO_TCP as u32,
+        TCP_KEEPCNT,
+        count,
+    )
+}
+
+#[inline]
+pub(crate) fn get_tcp_keepcnt(fd: BorrowedFd<'_>) -> io::Result<u32> {
+    getsockopt::<c_int>(fd, linux_raw_sys::general::IPPROTO_TCP as u32, TCP_KEEPCNT)
+        .map(|count| count as u32)
+}
+
+#[inline]
+pub(crate) fn set_ip_multicast_loop(fd: BorrowedFd<'_>, multicast_loop: bool) -> io::Result<()> {
+    setsockopt(
+        fd,
+        linux_raw_sys::general::IPPROTO_IP as u32,
+        linux_raw_sys::general::IP_MULTICAST_LOOP,
+        c_int::from(multicast_loop),
+    )
+}
+
+#[inline]
+pub(crate) fn get_ip_multicast_loop(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt::<c_int>(
+        fd,
+        linux_raw_sys::general::IPPROTO_IP as u32,
+        linux_raw_sys::general::IP_MULTICAST_LOOP,
+    )
+    .map(|multicast_loop| multicast_loop != 0)
+}
+
+#[inline]
+pub(crate) fn set_ip_multicast_ttl(fd: BorrowedFd<'_>, ttl: u32) -> io::Result<()> {
+    let ttl: c_int = ttl.try_into().map_err(|_| io::Error::OVERFLOW)?;
+    setsockopt(
+        fd,
+        linux_raw_sys::general::IPPROTO_IP as u32,
+        linux_raw_sys::general::IP_MULTICAST_TTL,
+        ttl,
+    )
+}
+
+#[inline]
+pub(crate) fn get_ip_multicast_ttl(fd: BorrowedFd<'_>) -> io::Result<u32> {
+    getsockopt::<c_int>(
+        fd,
+        linux_raw_sys::general::IPPROTO_IP as u32,
+        linux_raw_sys::general::IP_MULTICAST_TTL,
+    )
+    .map(|ttl| ttl as u32)
+}
+
+#[inline]
+pub(crate) fn join_multicast_v4(
+    fd: BorrowedFd<'_>,
+    multiaddr: &Ipv4Addr,
+    interface: &Ipv4Addr,
+) -> io::Result<()> {
+    let mreq = linux_raw_sys::general::ip_mreq {
+        imr_multiaddr: multiaddr.0,
+        imr_interface: interface.0,
+    };
+    setsockopt(
+        fd,
+        linux_raw_sys::general::IPPROTO_IP as u32,
+        linux_raw_sys::general::IP_ADD_MEMBERSHIP,
+        mreq,
+    )
+}
+
+#[inline]
+pub(crate) fn leave_multicast_v4(
+    fd: BorrowedFd<'_>,
+    multiaddr: &Ipv4Addr,
+    interface: &Ipv4Addr,
+) -> io::Result<()> {
+    let mreq = linux_raw_sys::general::ip_mreq {
+        imr_multiaddr: multiaddr.0,
+        imr_interface: interface.0,
+    };
+    setsockopt(
+        fd,
+        linux_raw_sys::general::IPPROTO_IP as u32,
+        linux_raw_sys::general::IP_DROP_MEMBERSHIP,
+        mreq,
+    )
+}
+
+#[inline]
+pub(crate) fn join_multicast_v6(
+    fd: BorrowedFd<'_>,
+    multiaddr: &Ipv6Addr,
+    interface: u32,
+) -> io::Result<()> {
+    let mreq = linux_raw_sys::general::ipv6_mreq {
+        ipv6mr_multiaddr: multiaddr.0,
+        ipv6mr_ifindex: interface.try_into().map_err(|_| io::Error::OVERFLOW)?,
+    };
+    setsockopt(
+        fd,
+        linux_raw_sys::general::IPPROTO_IPV6 as u32,
+        linux_raw_sys::general::IPV6_ADD_MEMBERSHIP,
+        mreq,
+    )
+}
+
+#[inline]
+pub(crate) fn leave_multicast_v6(
+    fd: BorrowedFd<'_>,
+    multiaddr: &Ipv6Addr,
+    interface: u32,
+) -> io::Result<()> {
+    let mreq = linux_raw_sys::general::ipv6_mreq {
+        ipv6mr_multiaddr: multiaddr.0,
+        ipv6mr_ifindex: interface.try_into().map_err(|_| io::Error::OVERFLOW)?,
+    };
+    setsockopt(
+        fd,
+        linux_raw_sys::general::IPPROTO_IPV6 as u32,
+        linux_raw_sys::general::IPV6_DROP_MEMBERSHIP,
+        mreq,
+    )
+}
+
+#[inline]
+pub(crate) fn getsockopt_socket_type(fd: BorrowedFd<'_>) -> io::Result<SocketType> {
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
         let mut type_ = MaybeUninit::<u32>::uninit();
         let mut optlen = std::mem::size_of::<u32>();
         ret(syscall5(
@@ -1880,7 +2632,7 @@ pub(crate) fn sendto_unix(
             buf_len,
             c_uint(flags.bits()),
             by_ref(&addr.encode()),
-            size_of::<sockaddr_un, _>(),
+            socklen_t(addr.addr_len()),
         ))
     }
     #[cfg(target_arch = "x86")]
@@ -1894,7 +2646,7 @@ pub(crate) fn sendto_unix(
                 buf_len,
                 c_uint(flags.bits()),
                 by_ref(&addr.encode()),
-                size_of::<sockaddr_un, _>(),
+                socklen_t(addr.addr_len()),
             ]),
         ))
     }
@@ -1902,6 +2654,25 @@ pub(crate) fn sendto_unix(
 
 #[inline]
 pub(crate) fn recv(fd: BorrowedFd<'_>, buf: &mut [u8], flags: RecvFlags) -> io::Result<usize> {
+    _recv(fd, buf, flags)
+}
+
+#[inline]
+pub(crate) fn recv_uninit<'buf>(
+    fd: BorrowedFd<'_>,
+    buf: &'buf mut [MaybeUninit<u8>],
+    flags: RecvFlags,
+) -> io::Result<(&'buf mut [u8], &'buf mut [MaybeUninit<u8>])> {
+    // With `MSG_TRUNC`, the kernel may report more bytes than it wrote.
+    let nread = _recv(fd, buf, flags)?.min(buf.len());
+
+    // Safety: `recv` initialized the first `nread` bytes of `buf`.
+    Ok(unsafe { io::split_init(buf, nread) })
+}
+
+/// `recv` into a buffer of `u8` or `MaybeUninit<u8>`.
+#[inline]
+fn _recv<T>(fd: BorrowedFd<'_>, buf: &mut [T], flags: RecvFlags) -> io::Result<usize> {
     let (buf_addr_mut, buf_len) = slice_mut(buf);
 
     #[cfg(not(any(
@@ -1993,6 +2764,137 @@ pub(crate) fn recvfrom(
     }
 }
 
+#[inline]
+pub(crate) fn sendmsg(
+    fd: BorrowedFd<'_>,
+    iov: &[IoSlice<'_>],
+    control: &[u8],
+    flags: SendFlags,
+) -> io::Result<usize> {
+    let msg = MsgHdr {
+        msg_name: null_mut(),
+        msg_namelen: 0,
+        msg_iov: iov.as_ptr() as *mut c_void,
+        msg_iovlen: iov.len(),
+        msg_control: control.as_ptr() as *mut c_void,
+        msg_controllen: control.len(),
+        msg_flags: 0,
+    };
+
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret_usize(syscall3_readonly(
+            nr(__NR_sendmsg),
+            borrowed_fd(fd),
+            by_ref(&msg),
+            c_uint(flags.bits()),
+        ))
+    }
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret_usize(syscall2_readonly(
+            nr(__NR_socketcall),
+            x86_sys(SYS_SENDMSG),
+            slice_just_addr::<ArgReg<SocketArg>, _>(&[
+                borrowed_fd(fd),
+                by_ref(&msg),
+                c_uint(flags.bits()),
+            ]),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn recvmsg(
+    fd: BorrowedFd<'_>,
+    iov: &mut [IoSliceMut<'_>],
+    control: &mut [u8],
+    flags: RecvFlags,
+) -> io::Result<(usize, usize, ReturnFlags)> {
+    let mut msg = MsgHdr {
+        msg_name: null_mut(),
+        msg_namelen: 0,
+        msg_iov: iov.as_mut_ptr() as *mut c_void,
+        msg_iovlen: iov.len(),
+        msg_control: control.as_mut_ptr() as *mut c_void,
+        msg_controllen: control.len(),
+        msg_flags: 0,
+    };
+
+    let nread = unsafe { _recvmsg(fd, &mut msg, flags)? };
+    Ok((
+        nread,
+        msg.msg_controllen,
+        ReturnFlags::from_bits_truncate(msg.msg_flags),
+    ))
+}
+
+#[inline]
+pub(crate) fn recvfrom_flags(
+    fd: BorrowedFd<'_>,
+    buf: &mut [u8],
+    flags: RecvFlags,
+) -> io::Result<(usize, ReturnFlags, Option<SocketAddr>)> {
+    let mut storage = MaybeUninit::<__kernel_sockaddr_storage>::uninit();
+    let mut iov = [IoSliceMut::new(buf)];
+    let mut msg = MsgHdr {
+        msg_name: storage.as_mut_ptr() as *mut c_void,
+        msg_namelen: std::mem::size_of::<__kernel_sockaddr_storage>() as c_int,
+        msg_iov: iov.as_mut_ptr() as *mut c_void,
+        msg_iovlen: iov.len(),
+        msg_control: null_mut(),
+        msg_controllen: 0,
+        msg_flags: 0,
+    };
+
+    unsafe {
+        let nread = _recvmsg(fd, &mut msg, flags)?;
+        // Connected sockets may not report an address.
+        let addr = if msg.msg_namelen == 0 {
+            None
+        } else {
+            // The kernel reports the address's full length, even if it only
+            // copied as much as fits in `storage`.
+            let len = min(
+                msg.msg_namelen as usize,
+                std::mem::size_of::<__kernel_sockaddr_storage>(),
+            );
+            Some(decode_sockaddr(storage.as_ptr().cast(), len as socklen_t))
+        };
+        Ok((nread, ReturnFlags::from_bits_truncate(msg.msg_flags), addr))
+    }
+}
+
+/// `recvmsg(fd, msg, flags)`, on a `MsgHdr` the caller has set up.
+///
+/// # Safety
+///
+/// The pointers in `msg` must be valid for the lengths given with them.
+#[inline]
+unsafe fn _recvmsg(fd: BorrowedFd<'_>, msg: &mut MsgHdr, flags: RecvFlags) -> io::Result<usize> {
+    #[cfg(not(target_arch = "x86"))]
+    {
+        ret_usize(syscall3(
+            nr(__NR_recvmsg),
+            borrowed_fd(fd),
+            by_mut(msg),
+            c_uint(flags.bits()),
+        ))
+    }
+    #[cfg(target_arch = "x86")]
+    {
+        ret_usize(syscall2(
+            nr(__NR_socketcall),
+            x86_sys(SYS_RECVMSG),
+            slice_just_addr::<ArgReg<SocketArg>, _>(&[
+                borrowed_fd(fd),
+                by_mut(msg),
+                c_uint(flags.bits()),
+            ]),
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn getpeername(fd: BorrowedFd<'_>) -> io::Result<SocketAddr> {
     #[cfg(not(target_arch = "x86"))]
@@ -2113,7 +3015,7 @@ pub(crate) fn bind_unix(fd: BorrowedFd<'_>, addr: &SocketAddrUnix) -> io::Result
             nr(__NR_bind),
             borrowed_fd(fd),
             by_ref(&addr.encode()),
-            size_of::<sockaddr_un, _>(),
+            socklen_t(addr.addr_len()),
         ))
     }
     #[cfg(target_arch = "x86")]
@@ -2124,7 +3026,7 @@ pub(crate) fn bind_unix(fd: BorrowedFd<'_>, addr: &SocketAddrUnix) -> io::Result
             slice_just_addr::<ArgReg<SocketArg>, _>(&[
                 borrowed_fd(fd),
                 by_ref(&addr.encode()),
-                size_of::<sockaddr_un, _>(),
+                socklen_t(addr.addr_len()),
             ]),
         ))
     }
@@ -2188,7 +3090,7 @@ pub(crate) fn connect_unix(fd: BorrowedFd<'_>, addr: &SocketAddrUnix) -> io::Res
             nr(__NR_connect),
             borrowed_fd(fd),
             by_ref(&addr.encode()),
-            size_of::<sockaddr_un, _>(),
+            socklen_t(addr.addr_len()),
         ))
     }
     #[cfg(target_arch = "x86")]
@@ -2199,7 +3101,7 @@ pub(crate) fn connect_unix(fd: BorrowedFd<'_>, addr: &SocketAddrUnix) -> io::Res
             slice_just_addr::<ArgReg<SocketArg>, _>(&[
                 borrowed_fd(fd),
                 by_ref(&addr.encode()),
-                size_of::<sockaddr_un, _>(),
+                socklen_t(addr.addr_len()),
             ]),
         ))
     }
@@ -2225,6 +3127,68 @@ pub(crate) fn listen(fd: BorrowedFd<'_>, backlog: c_int) -> io::Result<()> {
     }
 }
 
+#[inline]
+pub(crate) fn kill_process(pid: Pid, sig: c_int) -> io::Result<()> {
+    unsafe {
+        ret(syscall2_readonly(
+            nr(__NR_kill),
+            c_int(pid.as_raw() as __kernel_pid_t),
+            c_int(sig),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn kill_process_group(pid: Pid, sig: c_int) -> io::Result<()> {
+    unsafe {
+        ret(syscall2_readonly(
+            nr(__NR_kill),
+            c_int(-(pid.as_raw() as __kernel_pid_t)),
+            c_int(sig),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn kill_current_process_group(sig: c_int) -> io::Result<()> {
+    unsafe { ret(syscall2_readonly(nr(__NR_kill), zero(), c_int(sig))) }
+}
+
+#[inline]
+pub(crate) fn waitpid(pid: Option<Pid>, waitopts: WaitOptions) -> io::Result<Option<(Pid, c_int)>> {
+    let pid = pid.map_or(-1, |pid| pid.as_raw() as __kernel_pid_t);
+    let mut status: c_int = 0;
+    unsafe {
+        let pid = ret_c_int(syscall4(
+            nr(__NR_wait4),
+            c_int(pid),
+            by_mut(&mut status),
+            c_uint(waitopts.bits()),
+            zero(),
+        ))?;
+        Ok(if pid == 0 {
+            None
+        } else {
+            Some((Pid::from_raw(pid as u32), status))
+        })
+    }
+}
+
+#[inline]
+pub(crate) fn sigprocmask(how: SigmaskHow, set: Option<&SigSet>) -> io::Result<SigSet> {
+    let mut old = SigSet::new();
+    unsafe {
+        ret(syscall4(
+            nr(__NR_rt_sigprocmask),
+            c_uint(how as c_uint),
+            opt_ref(set),
+            by_mut(&mut old),
+            size_of::<SigSet, _>(),
+        ))?;
+    }
+    Ok(old)
+}
+
 #[inline]
 pub(crate) fn sched_yield() {
     unsafe {
@@ -2232,6 +3196,34 @@ pub(crate) fn sched_yield() {
     }
 }
 
+#[inline]
+pub(crate) fn sched_getscheduler(pid: Option<Pid>) -> io::Result<c_int> {
+    unsafe {
+        ret_c_int(syscall1_readonly(
+            nr(__NR_sched_getscheduler),
+            c_uint(pid.map_or(0, Pid::as_raw)),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn sched_setscheduler(
+    pid: Option<Pid>,
+    policy: SchedPolicy,
+    flags: SchedFlags,
+    priority: c_int,
+) -> io::Result<()> {
+    // The kernel's `struct sched_param` holds just the priority.
+    unsafe {
+        ret(syscall3_readonly(
+            nr(__NR_sched_setscheduler),
+            c_uint(pid.map_or(0, Pid::as_raw)),
+            c_int(policy as c_int | flags.bits()),
+            by_ref(&priority),
+        ))
+    }
+}
+
 /// # Safety
 ///
 /// `mmap` is primarily unsafe due to the `addr` parameter, as anything working
@@ -2615,31 +3607,351 @@ pub(crate) fn clock_nanosleep_absolute(id: ClockId, req: &__kernel_timespec) ->
 }
 
 #[inline]
-pub(crate) fn getcwd(buf: &mut [c_char]) -> io::Result<usize> {
-    let (buf_addr_mut, buf_len) = slice_mut(buf);
-
-    unsafe { ret_usize(syscall2(nr(__NR_getcwd), buf_addr_mut, buf_len)) }
-}
-
-#[inline]
-pub(crate) fn chdir(filename: &CStr) -> io::Result<()> {
-    unsafe { ret(syscall1_readonly(nr(__NR_chdir), c_str(filename))) }
-}
-
-#[inline]
-pub(crate) fn ioctl_fionread(fd: BorrowedFd) -> io::Result<u64> {
+pub(crate) fn clock_settime(which_clock: ClockId, timespec: Timespec) -> io::Result<()> {
+    #[cfg(target_pointer_width = "32")]
     unsafe {
-        let mut result = MaybeUninit::<c_int>::uninit();
-        ret(syscall3(
-            nr(__NR_ioctl),
-            borrowed_fd(fd),
-            c_uint(FIONREAD),
-            out(&mut result),
+        ret(syscall2_readonly(
+            nr(__NR_clock_settime64),
+            clockid_t(which_clock),
+            by_ref(&timespec),
         ))
-        .map(|()| result.assume_init() as u64)
-    }
-}
-
+        .or_else(|err| {
+            // See the comments in `rsix_clock_gettime_via_syscall` about
+            // emulation.
+            if err == io::Error::NOSYS {
+                let old_timespec = __kernel_old_timespec {
+                    tv_sec: timespec
+                        .tv_sec
+                        .try_into()
+                        .map_err(|_| io::Error::OVERFLOW)?,
+                    tv_nsec: timespec.tv_nsec.try_into().map_err(|_| io::Error::INVAL)?,
+                };
+                ret(syscall2_readonly(
+                    nr(__NR_clock_settime),
+                    clockid_t(which_clock),
+                    by_ref(&old_timespec),
+                ))
+            } else {
+                Err(err)
+            }
+        })
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall2_readonly(
+            nr(__NR_clock_settime),
+            clockid_t(which_clock),
+            by_ref(&timespec),
+        ))
+    }
+}
+
+/// Convert a `Timex` into a `KernelTimex`. Only the fields which `Timex`
+/// exposes are filled in; the kernel ignores the others on input.
+fn timex_to_raw(timex: &Timex) -> KernelTimex {
+    let mut raw = KernelTimex::default();
+    raw.modes = timex.modes.bits();
+    raw.offset = timex.offset;
+    raw.freq = timex.freq;
+    raw.maxerror = timex.maxerror;
+    raw.esterror = timex.esterror;
+    raw.status = timex.status.bits();
+    raw.constant = timex.constant;
+    raw.tick = timex.tick;
+    raw
+}
+
+fn timex_from_raw(raw: &KernelTimex, timex: &mut Timex) {
+    timex.modes = TimexModes::from_bits_truncate(raw.modes);
+    timex.offset = raw.offset;
+    timex.freq = raw.freq;
+    timex.maxerror = raw.maxerror;
+    timex.esterror = raw.esterror;
+    timex.status = TimexStatus::from_bits_truncate(raw.status);
+    timex.constant = raw.constant;
+    timex.precision = raw.precision;
+    timex.tolerance = raw.tolerance;
+    timex.tick = raw.tick;
+    timex.tai = raw.tai;
+}
+
+#[inline]
+pub(crate) fn clock_adjtime(which_clock: ClockId, timex: &mut Timex) -> io::Result<AdjtimeState> {
+    let mut raw = timex_to_raw(timex);
+
+    // On 32-bit platforms, this requires Linux >= 5.1, as the older
+    // `clock_adjtime` uses a `struct timex` with 32-bit fields.
+    #[cfg(target_pointer_width = "32")]
+    let state = unsafe {
+        ret_c_int(syscall2(
+            nr(__NR_clock_adjtime64),
+            clockid_t(which_clock),
+            by_mut(&mut raw),
+        ))?
+    };
+    #[cfg(target_pointer_width = "64")]
+    let state = unsafe {
+        ret_c_int(syscall2(
+            nr(__NR_clock_adjtime),
+            clockid_t(which_clock),
+            by_mut(&mut raw),
+        ))?
+    };
+
+    timex_from_raw(&raw, timex);
+    AdjtimeState::from_raw(state).ok_or(io::Error::INVAL)
+}
+
+fn sigevent_to_raw(event: &Sigevent) -> KernelSigevent {
+    let (notify, signo, value, tid) = match *event {
+        Sigevent::None => (SIGEV_NONE, 0, 0, 0),
+        Sigevent::Signal { signo, value } => (SIGEV_SIGNAL, signo.as_raw(), value, 0),
+        Sigevent::ThreadSignal { signo, value, tid } => (
+            SIGEV_THREAD_ID,
+            signo.as_raw(),
+            value,
+            tid.as_raw() as c_int,
+        ),
+    };
+    KernelSigevent {
+        sigev_value: value,
+        sigev_signo: signo,
+        sigev_notify: notify as c_int,
+        sigev_tid: tid,
+        pad: Default::default(),
+    }
+}
+
+#[inline]
+pub(crate) fn timer_create(id: ClockId, event: &Sigevent) -> io::Result<RawTimer> {
+    let event = sigevent_to_raw(event);
+    let mut timer = MaybeUninit::<RawTimer>::uninit();
+    unsafe {
+        ret(syscall3(
+            nr(__NR_timer_create),
+            clockid_t(id),
+            by_ref(&event),
+            out(&mut timer),
+        ))?;
+        Ok(timer.assume_init())
+    }
+}
+
+#[inline]
+pub(crate) fn timer_settime(
+    timer: RawTimer,
+    flags: TimerFlags,
+    new_value: &Itimerspec,
+) -> io::Result<Itimerspec> {
+    let mut old_value = MaybeUninit::<Itimerspec>::uninit();
+
+    // On 32-bit platforms, this requires Linux >= 5.1.
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        ret(syscall4(
+            nr(__NR_timer_settime64),
+            c_int(timer),
+            c_int(flags.bits()),
+            by_ref(new_value),
+            out(&mut old_value),
+        ))?;
+        Ok(old_value.assume_init())
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall4(
+            nr(__NR_timer_settime),
+            c_int(timer),
+            c_int(flags.bits()),
+            by_ref(new_value),
+            out(&mut old_value),
+        ))?;
+        Ok(old_value.assume_init())
+    }
+}
+
+#[inline]
+pub(crate) fn timer_gettime(timer: RawTimer) -> io::Result<Itimerspec> {
+    let mut curr_value = MaybeUninit::<Itimerspec>::uninit();
+
+    // On 32-bit platforms, this requires Linux >= 5.1.
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        ret(syscall2(
+            nr(__NR_timer_gettime64),
+            c_int(timer),
+            out(&mut curr_value),
+        ))?;
+        Ok(curr_value.assume_init())
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall2(
+            nr(__NR_timer_gettime),
+            c_int(timer),
+            out(&mut curr_value),
+        ))?;
+        Ok(curr_value.assume_init())
+    }
+}
+
+#[inline]
+pub(crate) fn timer_getoverrun(timer: RawTimer) -> io::Result<c_int> {
+    unsafe { ret_c_int(syscall1_readonly(nr(__NR_timer_getoverrun), c_int(timer))) }
+}
+
+#[inline]
+pub(crate) fn timer_delete(timer: RawTimer) -> io::Result<()> {
+    unsafe { ret(syscall1_readonly(nr(__NR_timer_delete), c_int(timer))) }
+}
+
+#[inline]
+pub(crate) fn timerfd_create(id: ClockId, flags: TimerfdFlags) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall2(
+            nr(__NR_timerfd_create),
+            clockid_t(id),
+            c_uint(flags.bits()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn timerfd_settime(
+    fd: BorrowedFd<'_>,
+    flags: TimerfdTimerFlags,
+    new_value: &Itimerspec,
+) -> io::Result<Itimerspec> {
+    let mut old_value = MaybeUninit::<Itimerspec>::uninit();
+
+    // On 32-bit platforms, this requires Linux >= 5.1.
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        ret(syscall4(
+            nr(__NR_timerfd_settime64),
+            borrowed_fd(fd),
+            c_uint(flags.bits()),
+            by_ref(new_value),
+            out(&mut old_value),
+        ))?;
+        Ok(old_value.assume_init())
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall4(
+            nr(__NR_timerfd_settime),
+            borrowed_fd(fd),
+            c_uint(flags.bits()),
+            by_ref(new_value),
+            out(&mut old_value),
+        ))?;
+        Ok(old_value.assume_init())
+    }
+}
+
+#[inline]
+pub(crate) fn timerfd_gettime(fd: BorrowedFd<'_>) -> io::Result<Itimerspec> {
+    let mut curr_value = MaybeUninit::<Itimerspec>::uninit();
+
+    // On 32-bit platforms, this requires Linux >= 5.1.
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        ret(syscall2(
+            nr(__NR_timerfd_gettime64),
+            borrowed_fd(fd),
+            out(&mut curr_value),
+        ))?;
+        Ok(curr_value.assume_init())
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall2(
+            nr(__NR_timerfd_gettime),
+            borrowed_fd(fd),
+            out(&mut curr_value),
+        ))?;
+        Ok(curr_value.assume_init())
+    }
+}
+
+#[inline]
+pub(crate) fn getcwd(buf: &mut [u8]) -> io::Result<()> {
+    let (buf_addr_mut, buf_len) = slice_mut(buf);
+
+    unsafe { ret_usize(syscall2(nr(__NR_getcwd), buf_addr_mut, buf_len)).map(|_len| ()) }
+}
+
+#[inline]
+pub(crate) fn chdir(filename: &CStr) -> io::Result<()> {
+    unsafe { ret(syscall1_readonly(nr(__NR_chdir), c_str(filename))) }
+}
+
+#[inline]
+pub(crate) fn fchdir(fd: BorrowedFd<'_>) -> io::Result<()> {
+    unsafe { ret(syscall1_readonly(nr(__NR_fchdir), borrowed_fd(fd))) }
+}
+
+#[inline]
+pub(crate) fn chroot(filename: &CStr) -> io::Result<()> {
+    unsafe { ret(syscall1_readonly(nr(__NR_chroot), c_str(filename))) }
+}
+
+#[inline]
+pub(crate) fn pivot_root(new_root: &CStr, put_old: &CStr) -> io::Result<()> {
+    unsafe {
+        ret(syscall2_readonly(
+            nr(__NR_pivot_root),
+            c_str(new_root),
+            c_str(put_old),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn mount(
+    source: &CStr,
+    target: &CStr,
+    fstype: &CStr,
+    flags: MountFlags,
+    data: Option<&CStr>,
+) -> io::Result<()> {
+    unsafe {
+        ret(syscall5_readonly(
+            nr(__NR_mount),
+            c_str(source),
+            c_str(target),
+            c_str(fstype),
+            c_uint(flags.bits()),
+            opt_c_str(data),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn unmount(target: &CStr, flags: UnmountFlags) -> io::Result<()> {
+    unsafe {
+        ret(syscall2_readonly(
+            nr(__NR_umount2),
+            c_str(target),
+            c_uint(flags.bits()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn ioctl_fionread(fd: BorrowedFd) -> io::Result<u64> {
+    unsafe {
+        let mut result = MaybeUninit::<c_int>::uninit();
+        ret(syscall3(
+            nr(__NR_ioctl),
+            borrowed_fd(fd),
+            c_uint(FIONREAD),
+            out(&mut result),
+        ))
+        .map(|()| result.assume_init() as u64)
+    }
+}
+
 #[inline]
 pub(crate) fn ioctl_fionbio(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
     unsafe {
@@ -2667,6 +3979,28 @@ pub(crate) fn ioctl_tiocgwinsz(fd: BorrowedFd) -> io::Result<Winsize> {
     }
 }
 
+#[inline]
+pub(crate) fn ioctl_tiocswinsz(fd: BorrowedFd, size: &Winsize) -> io::Result<()> {
+    unsafe {
+        ret(syscall3_readonly(
+            nr(__NR_ioctl),
+            borrowed_fd(fd),
+            c_uint(TIOCSWINSZ),
+            by_ref(size),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn ioctl_fioclex(fd: BorrowedFd) -> io::Result<()> {
+    unsafe { ret(syscall2(nr(__NR_ioctl), borrowed_fd(fd), c_uint(FIOCLEX))) }
+}
+
+#[inline]
+pub(crate) fn ioctl_fionclex(fd: BorrowedFd) -> io::Result<()> {
+    unsafe { ret(syscall2(nr(__NR_ioctl), borrowed_fd(fd), c_uint(FIONCLEX))) }
+}
+
 #[inline]
 pub(crate) fn ioctl_tiocexcl(fd: BorrowedFd) -> io::Result<()> {
     unsafe { ret(syscall2(nr(__NR_ioctl), borrowed_fd(fd), c_uint(TIOCEXCL))) }
@@ -2691,6 +4025,92 @@ pub(crate) fn ioctl_tcgets(fd: BorrowedFd) -> io::Result<Termios> {
     }
 }
 
+#[inline]
+pub(crate) fn tcsetattr(
+    fd: BorrowedFd,
+    optional_actions: OptionalActions,
+    termios: &Termios,
+) -> io::Result<()> {
+    let request = match optional_actions {
+        OptionalActions::Now => TCSETS,
+        OptionalActions::Drain => TCSETSW,
+        OptionalActions::Flush => TCSETSF,
+    };
+    unsafe {
+        ret(syscall3_readonly(
+            nr(__NR_ioctl),
+            borrowed_fd(fd),
+            c_uint(request),
+            by_ref(termios),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn tcflush(fd: BorrowedFd, queue_selector: QueueSelector) -> io::Result<()> {
+    unsafe {
+        ret(syscall3(
+            nr(__NR_ioctl),
+            borrowed_fd(fd),
+            c_uint(TCFLSH),
+            c_uint(queue_selector as u32),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn tcdrain(fd: BorrowedFd) -> io::Result<()> {
+    // `TCSBRK` with a non-zero argument waits for output to drain instead of
+    // sending a break.
+    unsafe {
+        ret(syscall3(
+            nr(__NR_ioctl),
+            borrowed_fd(fd),
+            c_uint(TCSBRK),
+            c_uint(1),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn tcsendbreak(fd: BorrowedFd) -> io::Result<()> {
+    unsafe {
+        ret(syscall3(
+            nr(__NR_ioctl),
+            borrowed_fd(fd),
+            c_uint(TCSBRK),
+            c_uint(0),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn tcgetpgrp(fd: BorrowedFd) -> io::Result<Pid> {
+    unsafe {
+        let mut result = MaybeUninit::<__kernel_pid_t>::uninit();
+        ret(syscall3(
+            nr(__NR_ioctl),
+            borrowed_fd(fd),
+            c_uint(TIOCGPGRP),
+            out(&mut result),
+        ))?;
+        Ok(Pid::from_raw(result.assume_init() as u32))
+    }
+}
+
+#[inline]
+pub(crate) fn tcsetpgrp(fd: BorrowedFd, pid: Pid) -> io::Result<()> {
+    let pid = pid.as_raw() as __kernel_pid_t;
+    unsafe {
+        ret(syscall3_readonly(
+            nr(__NR_ioctl),
+            borrowed_fd(fd),
+            c_uint(TIOCSPGRP),
+            by_ref(&pid),
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn dup(fd: BorrowedFd) -> io::Result<OwnedFd> {
     unsafe { ret_owned_fd(syscall1_readonly(nr(__NR_dup), borrowed_fd(fd))) }
@@ -2725,6 +4145,16 @@ pub(crate) fn dup2_with(fd: BorrowedFd, new: &OwnedFd, flags: DupFlags) -> io::R
     }
 }
 
+#[inline]
+pub(crate) unsafe fn dup2_to(fd: BorrowedFd, new: RawFd) -> io::Result<OwnedFd> {
+    ret_owned_fd(syscall3_readonly(
+        nr(__NR_dup3),
+        borrowed_fd(fd),
+        raw_fd(new),
+        c_uint(0),
+    ))
+}
+
 pub(crate) fn accessat(
     dirfd: BorrowedFd<'_>,
     path: &CStr,
@@ -2739,90 +4169,395 @@ pub(crate) fn accessat(
         return _accessat(dirfd, path, access.bits());
     }
 
-    if flags.bits() != linux_raw_sys::v5_11::general::AT_EACCESS {
-        return Err(io::Error::INVAL);
+    // `faccessat` has no flags argument; `faccessat2` was added in Linux 5.8
+    // to support them.
+    match _accessat2(dirfd, path, access.bits(), flags.bits()) {
+        Err(err) if err == io::Error::NOSYS => {}
+        otherwise => return otherwise,
+    }
+
+    if flags.bits() != linux_raw_sys::v5_11::general::AT_EACCESS {
+        return Err(io::Error::INVAL);
+    }
+
+    // Without `faccessat2`, there's no way to check against the effective IDs
+    // when they differ from the real IDs.
+    Err(io::Error::NOSYS)
+}
+
+#[inline]
+fn _accessat(dirfd: BorrowedFd<'_>, pathname: &CStr, mode: c_uint) -> io::Result<()> {
+    unsafe {
+        ret(syscall3_readonly(
+            nr(__NR_faccessat),
+            borrowed_fd(dirfd),
+            c_str(pathname),
+            c_uint(mode),
+        ))
+    }
+}
+
+#[inline]
+fn _accessat2(
+    dirfd: BorrowedFd<'_>,
+    pathname: &CStr,
+    mode: c_uint,
+    flags: c_uint,
+) -> io::Result<()> {
+    unsafe {
+        ret(syscall4_readonly(
+            nr(__NR_faccessat2),
+            borrowed_fd(dirfd),
+            c_str(pathname),
+            c_uint(mode),
+            c_uint(flags),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn copy_file_range(
+    fd_in: BorrowedFd<'_>,
+    off_in: Option<&mut u64>,
+    fd_out: BorrowedFd<'_>,
+    off_out: Option<&mut u64>,
+    len: u64,
+) -> io::Result<u64> {
+    let len: usize = len.try_into().unwrap_or(usize::MAX);
+    _copy_file_range(fd_in, off_in, fd_out, off_out, len, 0).map(|result| result as u64)
+}
+
+#[inline]
+fn _copy_file_range(
+    fd_in: BorrowedFd<'_>,
+    off_in: Option<&mut u64>,
+    fd_out: BorrowedFd<'_>,
+    off_out: Option<&mut u64>,
+    len: usize,
+    flags: c_uint,
+) -> io::Result<usize> {
+    unsafe {
+        ret_usize(syscall6(
+            nr(__NR_copy_file_range),
+            borrowed_fd(fd_in),
+            opt_mut(off_in),
+            borrowed_fd(fd_out),
+            opt_mut(off_out),
+            pass_usize(len),
+            c_uint(flags),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn splice(
+    fd_in: BorrowedFd<'_>,
+    off_in: Option<&mut u64>,
+    fd_out: BorrowedFd<'_>,
+    off_out: Option<&mut u64>,
+    len: usize,
+    flags: SpliceFlags,
+) -> io::Result<usize> {
+    unsafe {
+        ret_usize(syscall6(
+            nr(__NR_splice),
+            borrowed_fd(fd_in),
+            opt_mut(off_in),
+            borrowed_fd(fd_out),
+            opt_mut(off_out),
+            pass_usize(len),
+            c_uint(flags.bits()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn tee(
+    fd_in: BorrowedFd<'_>,
+    fd_out: BorrowedFd<'_>,
+    len: usize,
+    flags: SpliceFlags,
+) -> io::Result<usize> {
+    unsafe {
+        ret_usize(syscall4(
+            nr(__NR_tee),
+            borrowed_fd(fd_in),
+            borrowed_fd(fd_out),
+            pass_usize(len),
+            c_uint(flags.bits()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) unsafe fn vmsplice(
+    fd: BorrowedFd<'_>,
+    bufs: &[IoSlice],
+    flags: SpliceFlags,
+) -> io::Result<usize> {
+    let (bufs_addr, bufs_len) = slice(&bufs[..min(bufs.len(), UIO_MAXIOV as usize)]);
+
+    ret_usize(syscall4(
+        nr(__NR_vmsplice),
+        borrowed_fd(fd),
+        bufs_addr,
+        bufs_len,
+        c_uint(flags.bits()),
+    ))
+}
+
+#[inline]
+pub(crate) fn poll(fds: &mut [PollFd<'_>], timeout: c_int) -> io::Result<usize> {
+    #[cfg(any(target_arch = "aarch64", target_arch = "riscv64"))]
+    {
+        let timeout = if timeout >= 0 {
+            Some(Timespec {
+                tv_sec: (timeout as i64) / 1000,
+                tv_nsec: (timeout as i64) % 1000 * 1000000,
+            })
+        } else {
+            None
+        };
+        ppoll(fds, timeout.as_ref(), None)
+    }
+    #[cfg(not(any(target_arch = "aarch64", target_arch = "riscv64")))]
+    unsafe {
+        let (fds_addr_mut, fds_len) = slice_mut(fds);
+        ret_usize(syscall3(
+            nr(__NR_poll),
+            fds_addr_mut,
+            fds_len,
+            c_int(timeout),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn ppoll(
+    fds: &mut [PollFd<'_>],
+    timeout: Option<&Timespec>,
+    sigmask: Option<&SigSet>,
+) -> io::Result<usize> {
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        let (fds_addr_mut, fds_len) = slice_mut(fds);
+        ret_usize(syscall5(
+            nr(__NR_ppoll_time64),
+            fds_addr_mut,
+            fds_len,
+            opt_ref(timeout),
+            opt_ref(sigmask),
+            size_of::<SigSet, _>(),
+        ))
+        .or_else(|err| {
+            // See the comments in `rsix_clock_gettime_via_syscall` about
+            // emulation.
+            if err == io::Error::NOSYS {
+                let old_timeout = match timeout {
+                    Some(timeout) => Some(__kernel_old_timespec {
+                        tv_sec: timeout.tv_sec.try_into().map_err(|_| io::Error::INVAL)?,
+                        tv_nsec: timeout.tv_nsec.try_into().map_err(|_| io::Error::INVAL)?,
+                    }),
+                    None => None,
+                };
+                let (fds_addr_mut, fds_len) = slice_mut(fds);
+                ret_usize(syscall5(
+                    nr(__NR_ppoll),
+                    fds_addr_mut,
+                    fds_len,
+                    opt_ref(old_timeout.as_ref()),
+                    opt_ref(sigmask),
+                    size_of::<SigSet, _>(),
+                ))
+            } else {
+                Err(err)
+            }
+        })
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        let (fds_addr_mut, fds_len) = slice_mut(fds);
+        ret_usize(syscall5(
+            nr(__NR_ppoll),
+            fds_addr_mut,
+            fds_len,
+            opt_ref(timeout),
+            opt_ref(sigmask),
+            size_of::<SigSet, _>(),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn getxattr(path: &CStr, name: &CStr, value: &mut [u8]) -> io::Result<usize> {
+    let (value_addr_mut, value_len) = slice_mut(value);
+    unsafe {
+        ret_usize(syscall4(
+            nr(__NR_getxattr),
+            c_str(path),
+            c_str(name),
+            value_addr_mut,
+            value_len,
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn lgetxattr(path: &CStr, name: &CStr, value: &mut [u8]) -> io::Result<usize> {
+    let (value_addr_mut, value_len) = slice_mut(value);
+    unsafe {
+        ret_usize(syscall4(
+            nr(__NR_lgetxattr),
+            c_str(path),
+            c_str(name),
+            value_addr_mut,
+            value_len,
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn fgetxattr(fd: BorrowedFd<'_>, name: &CStr, value: &mut [u8]) -> io::Result<usize> {
+    let (value_addr_mut, value_len) = slice_mut(value);
+    unsafe {
+        ret_usize(syscall4(
+            nr(__NR_fgetxattr),
+            borrowed_fd(fd),
+            c_str(name),
+            value_addr_mut,
+            value_len,
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn setxattr(
+    path: &CStr,
+    name: &CStr,
+    value: &[u8],
+    flags: XattrFlags,
+) -> io::Result<()> {
+    let (value_addr, value_len) = slice(value);
+    unsafe {
+        ret(syscall5_readonly(
+            nr(__NR_setxattr),
+            c_str(path),
+            c_str(name),
+            value_addr,
+            value_len,
+            c_uint(flags.bits()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn lsetxattr(
+    path: &CStr,
+    name: &CStr,
+    value: &[u8],
+    flags: XattrFlags,
+) -> io::Result<()> {
+    let (value_addr, value_len) = slice(value);
+    unsafe {
+        ret(syscall5_readonly(
+            nr(__NR_lsetxattr),
+            c_str(path),
+            c_str(name),
+            value_addr,
+            value_len,
+            c_uint(flags.bits()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn fsetxattr(
+    fd: BorrowedFd<'_>,
+    name: &CStr,
+    value: &[u8],
+    flags: XattrFlags,
+) -> io::Result<()> {
+    let (value_addr, value_len) = slice(value);
+    unsafe {
+        ret(syscall5_readonly(
+            nr(__NR_fsetxattr),
+            borrowed_fd(fd),
+            c_str(name),
+            value_addr,
+            value_len,
+            c_uint(flags.bits()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn listxattr(path: &CStr, list: &mut [u8]) -> io::Result<usize> {
+    let (list_addr_mut, list_len) = slice_mut(list);
+    unsafe {
+        ret_usize(syscall3(
+            nr(__NR_listxattr),
+            c_str(path),
+            list_addr_mut,
+            list_len,
+        ))
     }
-
-    // TODO: Use faccessat2 in newer Linux versions.
-    Err(io::Error::NOSYS)
 }
 
 #[inline]
-fn _accessat(dirfd: BorrowedFd<'_>, pathname: &CStr, mode: c_uint) -> io::Result<()> {
+pub(crate) fn llistxattr(path: &CStr, list: &mut [u8]) -> io::Result<usize> {
+    let (list_addr_mut, list_len) = slice_mut(list);
     unsafe {
-        ret(syscall3_readonly(
-            nr(__NR_faccessat),
-            borrowed_fd(dirfd),
-            c_str(pathname),
-            c_uint(mode),
+        ret_usize(syscall3(
+            nr(__NR_llistxattr),
+            c_str(path),
+            list_addr_mut,
+            list_len,
         ))
     }
 }
 
 #[inline]
-pub(crate) fn copy_file_range(
-    fd_in: BorrowedFd<'_>,
-    off_in: Option<&mut u64>,
-    fd_out: BorrowedFd<'_>,
-    off_out: Option<&mut u64>,
-    len: u64,
-) -> io::Result<u64> {
-    let len: usize = len.try_into().unwrap_or(usize::MAX);
-    _copy_file_range(fd_in, off_in, fd_out, off_out, len, 0).map(|result| result as u64)
+pub(crate) fn flistxattr(fd: BorrowedFd<'_>, list: &mut [u8]) -> io::Result<usize> {
+    let (list_addr_mut, list_len) = slice_mut(list);
+    unsafe {
+        ret_usize(syscall3(
+            nr(__NR_flistxattr),
+            borrowed_fd(fd),
+            list_addr_mut,
+            list_len,
+        ))
+    }
 }
 
 #[inline]
-fn _copy_file_range(
-    fd_in: BorrowedFd<'_>,
-    off_in: Option<&mut u64>,
-    fd_out: BorrowedFd<'_>,
-    off_out: Option<&mut u64>,
-    len: usize,
-    flags: c_uint,
-) -> io::Result<usize> {
+pub(crate) fn removexattr(path: &CStr, name: &CStr) -> io::Result<()> {
     unsafe {
-        ret_usize(syscall6(
-            nr(__NR_copy_file_range),
-            borrowed_fd(fd_in),
-            opt_mut(off_in),
-            borrowed_fd(fd_out),
-            opt_mut(off_out),
-            pass_usize(len),
-            c_uint(flags),
+        ret(syscall2_readonly(
+            nr(__NR_removexattr),
+            c_str(path),
+            c_str(name),
         ))
     }
 }
 
 #[inline]
-pub(crate) fn poll(fds: &mut [PollFd<'_>], timeout: c_int) -> io::Result<usize> {
-    let (fds_addr_mut, fds_len) = slice_mut(fds);
-
-    #[cfg(any(target_arch = "aarch64", target_arch = "riscv64"))]
+pub(crate) fn lremovexattr(path: &CStr, name: &CStr) -> io::Result<()> {
     unsafe {
-        let timeout = if timeout >= 0 {
-            Some(Timespec {
-                tv_sec: (timeout as i64) / 1000,
-                tv_nsec: (timeout as i64) % 1000 * 1000000,
-            })
-        } else {
-            None
-        };
-        ret_usize(syscall5(
-            nr(__NR_ppoll),
-            fds_addr_mut,
-            fds_len,
-            opt_ref(timeout.as_ref()),
-            zero(),
-            size_of::<sigset_t, _>(),
+        ret(syscall2_readonly(
+            nr(__NR_lremovexattr),
+            c_str(path),
+            c_str(name),
         ))
     }
-    #[cfg(not(any(target_arch = "aarch64", target_arch = "riscv64")))]
+}
+
+#[inline]
+pub(crate) fn fremovexattr(fd: BorrowedFd<'_>, name: &CStr) -> io::Result<()> {
     unsafe {
-        ret_usize(syscall3(
-            nr(__NR_poll),
-            fds_addr_mut,
-            fds_len,
-            c_int(timeout),
+        ret(syscall2_readonly(
+            nr(__NR_fremovexattr),
+            borrowed_fd(fd),
+            c_str(name),
         ))
     }
 }
@@ -2849,6 +4584,64 @@ pub(crate) fn eventfd(initval: u32, flags: EventfdFlags) -> io::Result<OwnedFd>
     }
 }
 
+#[inline]
+pub(crate) fn signalfd(mask: &SigSet, flags: SignalfdFlags) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall4(
+            nr(__NR_signalfd4),
+            no_fd(),
+            by_ref(mask),
+            size_of::<SigSet, _>(),
+            c_uint(flags.bits()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn signalfd_set_mask(fd: BorrowedFd<'_>, mask: &SigSet) -> io::Result<()> {
+    unsafe {
+        ret_discarded_fd(syscall4(
+            nr(__NR_signalfd4),
+            borrowed_fd(fd),
+            by_ref(mask),
+            size_of::<SigSet, _>(),
+            zero(),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn inotify_init1(flags: InotifyFlags) -> io::Result<OwnedFd> {
+    unsafe { ret_owned_fd(syscall1(nr(__NR_inotify_init1), c_uint(flags.bits()))) }
+}
+
+#[inline]
+pub(crate) fn inotify_add_watch(
+    fd: BorrowedFd<'_>,
+    path: &CStr,
+    mask: WatchFlags,
+) -> io::Result<c_int> {
+    unsafe {
+        ret_c_int(syscall3(
+            nr(__NR_inotify_add_watch),
+            borrowed_fd(fd),
+            c_str(path),
+            c_uint(mask.bits()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn inotify_rm_watch(fd: BorrowedFd<'_>, wd: c_int) -> io::Result<()> {
+    unsafe {
+        ret(syscall2(
+            nr(__NR_inotify_rm_watch),
+            borrowed_fd(fd),
+            c_int(wd),
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn sendfile(
     out_fd: BorrowedFd<'_>,
@@ -2899,6 +4692,146 @@ pub(crate) fn getppid() -> Pid {
     }
 }
 
+#[inline]
+pub(crate) fn setsid() -> io::Result<Pid> {
+    unsafe {
+        let pid = ret_c_int(syscall0_readonly(nr(__NR_setsid)))?;
+        Ok(Pid::from_raw(pid as u32))
+    }
+}
+
+#[inline]
+pub(crate) fn setpgid(pid: Option<Pid>, pgid: Option<Pid>) -> io::Result<()> {
+    unsafe {
+        ret(syscall2_readonly(
+            nr(__NR_setpgid),
+            c_uint(pid.map_or(0, Pid::as_raw)),
+            c_uint(pgid.map_or(0, Pid::as_raw)),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn getpgid(pid: Option<Pid>) -> io::Result<Pid> {
+    unsafe {
+        let pgid = ret_c_int(syscall1_readonly(
+            nr(__NR_getpgid),
+            c_uint(pid.map_or(0, Pid::as_raw)),
+        ))?;
+        Ok(Pid::from_raw(pgid as u32))
+    }
+}
+
+#[inline]
+pub(crate) fn getpgrp() -> Pid {
+    // Not all architectures have `__NR_getpgrp`, so use `getpgid(0)`, which
+    // can't fail.
+    unsafe {
+        let pgid: i32 =
+            ret_usize_infallible(syscall1_readonly(nr(__NR_getpgid), c_uint(0))) as __kernel_pid_t;
+        Pid::from_raw(pgid as u32)
+    }
+}
+
+#[inline]
+pub(crate) fn getsid(pid: Option<Pid>) -> io::Result<Pid> {
+    unsafe {
+        let sid = ret_c_int(syscall1_readonly(
+            nr(__NR_getsid),
+            c_uint(pid.map_or(0, Pid::as_raw)),
+        ))?;
+        Ok(Pid::from_raw(sid as u32))
+    }
+}
+
+#[inline]
+pub(crate) fn getresuid() -> ResUid {
+    let mut real = MaybeUninit::<Uid>::uninit();
+    let mut effective = MaybeUninit::<Uid>::uninit();
+    let mut saved = MaybeUninit::<Uid>::uninit();
+    unsafe {
+        ret_usize_infallible(syscall3(
+            nr(__NR_getresuid),
+            out(&mut real),
+            out(&mut effective),
+            out(&mut saved),
+        ));
+        ResUid {
+            real: real.assume_init(),
+            effective: effective.assume_init(),
+            saved: saved.assume_init(),
+        }
+    }
+}
+
+#[inline]
+pub(crate) fn getresgid() -> ResGid {
+    let mut real = MaybeUninit::<Gid>::uninit();
+    let mut effective = MaybeUninit::<Gid>::uninit();
+    let mut saved = MaybeUninit::<Gid>::uninit();
+    unsafe {
+        ret_usize_infallible(syscall3(
+            nr(__NR_getresgid),
+            out(&mut real),
+            out(&mut effective),
+            out(&mut saved),
+        ));
+        ResGid {
+            real: real.assume_init(),
+            effective: effective.assume_init(),
+            saved: saved.assume_init(),
+        }
+    }
+}
+
+#[inline]
+pub(crate) fn setresuid_thread(real: Uid, effective: Uid, saved: Uid) -> io::Result<()> {
+    unsafe {
+        ret(syscall3_readonly(
+            nr(__NR_setresuid),
+            c_uint(real.as_raw()),
+            c_uint(effective.as_raw()),
+            c_uint(saved.as_raw()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn setresgid_thread(real: Gid, effective: Gid, saved: Gid) -> io::Result<()> {
+    unsafe {
+        ret(syscall3_readonly(
+            nr(__NR_setresgid),
+            c_uint(real.as_raw()),
+            c_uint(effective.as_raw()),
+            c_uint(saved.as_raw()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn getgroups(buf: &mut [Gid]) -> io::Result<usize> {
+    let len = buf.len().try_into().map_err(|_| io::Error::INVAL)?;
+    unsafe {
+        ret_usize(syscall2(
+            nr(__NR_getgroups),
+            c_int(len),
+            void_star(buf.as_mut_ptr().cast::<c_void>()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn setgroups_thread(groups: &[Gid]) -> io::Result<()> {
+    let len = groups.len().try_into().map_err(|_| io::Error::INVAL)?;
+    unsafe {
+        ret(syscall2_readonly(
+            nr(__NR_setgroups),
+            c_int(len),
+            slice_just_addr(groups),
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn getgid() -> Gid {
     #[cfg(any(target_arch = "x86", target_arch = "sparc", target_arch = "arm"))]
@@ -2967,6 +4900,90 @@ pub(crate) fn gettid() -> Pid {
     }
 }
 
+// `linux_raw_sys` doesn't include the `prctl` constants, so define the ones
+// we need here.
+const PR_SET_NAME: c_uint = 15;
+const PR_GET_NAME: c_uint = 16;
+
+#[inline]
+pub(crate) fn set_thread_name(name: &CStr) -> io::Result<()> {
+    unsafe {
+        ret(syscall2_readonly(
+            nr(__NR_prctl),
+            c_uint(PR_SET_NAME),
+            c_str(name),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn thread_name(buf: &mut [u8; 16]) -> io::Result<()> {
+    unsafe { ret(syscall2(nr(__NR_prctl), c_uint(PR_GET_NAME), by_mut(buf))) }
+}
+
+#[inline]
+pub(crate) fn futex_wait(
+    uaddr: &AtomicU32,
+    flags: FutexFlags,
+    val: u32,
+    timeout: Option<&Timespec>,
+) -> io::Result<()> {
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        ret(syscall4(
+            nr(__NR_futex_time64),
+            by_ref(uaddr),
+            c_uint(FUTEX_WAIT | flags.bits()),
+            c_uint(val),
+            opt_ref(timeout),
+        ))
+        .or_else(|err| {
+            // See the comments in `rsix_clock_gettime_via_syscall` about
+            // emulation.
+            if err == io::Error::NOSYS {
+                let old_timeout = match timeout {
+                    Some(timeout) => Some(__kernel_old_timespec {
+                        tv_sec: timeout.tv_sec.try_into().map_err(|_| io::Error::INVAL)?,
+                        tv_nsec: timeout.tv_nsec.try_into().map_err(|_| io::Error::INVAL)?,
+                    }),
+                    None => None,
+                };
+                ret(syscall4(
+                    nr(__NR_futex),
+                    by_ref(uaddr),
+                    c_uint(FUTEX_WAIT | flags.bits()),
+                    c_uint(val),
+                    opt_ref(old_timeout.as_ref()),
+                ))
+            } else {
+                Err(err)
+            }
+        })
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall4(
+            nr(__NR_futex),
+            by_ref(uaddr),
+            c_uint(FUTEX_WAIT | flags.bits()),
+            c_uint(val),
+            opt_ref(timeout),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn futex_wake(uaddr: &AtomicU32, flags: FutexFlags, count: u32) -> io::Result<usize> {
+    unsafe {
+        ret_usize(syscall3(
+            nr(__NR_futex),
+            by_ref(uaddr),
+            c_uint(FUTEX_WAKE | flags.bits()),
+            c_uint(count),
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn isatty(fd: BorrowedFd<'_>) -> bool {
     // On error, Linux will return either `EINVAL` (2.6.32) or `ENOTTY`
@@ -3100,6 +5117,40 @@ pub(crate) fn uname() -> RawUname {
     }
 }
 
+#[inline]
+pub(crate) fn sysinfo() -> io::Result<RawSysinfo> {
+    let mut info = MaybeUninit::<RawSysinfo>::uninit();
+    unsafe { ret(syscall1(nr(__NR_sysinfo), out(&mut info))).map(|()| info.assume_init()) }
+}
+
+#[inline]
+pub(crate) fn getrusage(who: RusageWho) -> io::Result<RawRusage> {
+    let mut usage = MaybeUninit::<RawRusage>::uninit();
+    unsafe {
+        ret(syscall2(
+            nr(__NR_getrusage),
+            c_int(who as c_int),
+            out(&mut usage),
+        ))
+        .map(|()| usage.assume_init())
+    }
+}
+
+#[inline]
+pub(crate) fn sethostname(name: &[u8]) -> io::Result<()> {
+    let (name_addr, name_len) = slice(name);
+    unsafe { ret(syscall2_readonly(nr(__NR_sethostname), name_addr, name_len)) }
+}
+
+#[inline]
+pub(crate) fn umask(mask: Mode) -> Mode {
+    unsafe {
+        Mode::from_bits_truncate(
+            ret_usize_infallible(syscall1(nr(__NR_umask), mode_as(mask))) as c_uint,
+        )
+    }
+}
+
 #[inline]
 pub(crate) fn nice(inc: i32) -> io::Result<i32> {
     let priority = if inc > -40 && inc < 40 {
@@ -0,0 +1,4 @@
+mod types;
+
+pub use types::FutexFlags;
+pub(crate) use types::{FUTEX_WAIT, FUTEX_WAKE};
@@ -0,0 +1,17 @@
+use bitflags::bitflags;
+
+bitflags! {
+    /// `FUTEX_*` flags for use with [`futex_wait`] and [`futex_wake`].
+    ///
+    /// [`futex_wait`]: crate::thread::futex_wait
+    /// [`futex_wake`]: crate::thread::futex_wake
+    pub struct FutexFlags: u32 {
+        /// `FUTEX_PRIVATE_FLAG`
+        const PRIVATE = linux_raw_sys::general::FUTEX_PRIVATE_FLAG;
+        /// `FUTEX_CLOCK_REALTIME`
+        const REALTIME = linux_raw_sys::general::FUTEX_CLOCK_REALTIME;
+    }
+}
+
+pub(crate) const FUTEX_WAIT: u32 = linux_raw_sys::general::FUTEX_WAIT;
+pub(crate) const FUTEX_WAKE: u32 = linux_raw_sys::general::FUTEX_WAKE;
@@ -1,3 +1,7 @@
 mod types;
 
-pub use types::{ClockId, DynamicClockId, Nsecs, Secs, Timespec};
+pub use types::{
+    AdjtimeState, ClockId, DynamicClockId, Itimerspec, Nsecs, RawTimer, Secs, TimerFlags,
+    TimerfdFlags, TimerfdTimerFlags, Timespec, TimexModes, TimexStatus,
+};
+pub(crate) use types::{KernelSigevent, KernelTimex};
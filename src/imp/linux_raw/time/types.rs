@@ -1,3 +1,4 @@
+use bitflags::bitflags;
 use io_lifetimes::BorrowedFd;
 
 /// `struct timespec`
@@ -68,3 +69,212 @@ pub enum DynamicClockId<'a> {
     /// `CLOCK_BOOTTIME_ALARM`, available on Linux >= 2.6.39
     BoottimeAlarm,
 }
+
+// linux_raw_sys doesn't define the `ADJ_*` and `STA_*` constants, which are
+// in the kernel's linux/timex.h, so define them here.
+bitflags! {
+    /// `ADJ_*` flags for use in [`Timex::modes`].
+    ///
+    /// [`Timex::modes`]: crate::time::Timex::modes
+    pub struct TimexModes: u32 {
+        /// `ADJ_OFFSET`
+        const OFFSET = 0x0001;
+        /// `ADJ_FREQUENCY`
+        const FREQUENCY = 0x0002;
+        /// `ADJ_MAXERROR`
+        const MAXERROR = 0x0004;
+        /// `ADJ_ESTERROR`
+        const ESTERROR = 0x0008;
+        /// `ADJ_STATUS`
+        const STATUS = 0x0010;
+        /// `ADJ_TIMECONST`
+        const TIMECONST = 0x0020;
+        /// `ADJ_TAI`
+        const TAI = 0x0080;
+        /// `ADJ_SETOFFSET`
+        const SETOFFSET = 0x0100;
+        /// `ADJ_MICRO`
+        const MICRO = 0x1000;
+        /// `ADJ_NANO`
+        const NANO = 0x2000;
+        /// `ADJ_TICK`
+        const TICK = 0x4000;
+    }
+}
+
+bitflags! {
+    /// `STA_*` flags for use in [`Timex::status`].
+    ///
+    /// [`Timex::status`]: crate::time::Timex::status
+    pub struct TimexStatus: i32 {
+        /// `STA_PLL`
+        const PLL = 0x0001;
+        /// `STA_PPSFREQ`
+        const PPSFREQ = 0x0002;
+        /// `STA_PPSTIME`
+        const PPSTIME = 0x0004;
+        /// `STA_FLL`
+        const FLL = 0x0008;
+        /// `STA_INS`
+        const INS = 0x0010;
+        /// `STA_DEL`
+        const DEL = 0x0020;
+        /// `STA_UNSYNC`
+        const UNSYNC = 0x0040;
+        /// `STA_FREQHOLD`
+        const FREQHOLD = 0x0080;
+        /// `STA_PPSSIGNAL`
+        const PPSSIGNAL = 0x0100;
+        /// `STA_PPSJITTER`
+        const PPSJITTER = 0x0200;
+        /// `STA_PPSWANDER`
+        const PPSWANDER = 0x0400;
+        /// `STA_PPSERROR`
+        const PPSERROR = 0x0800;
+        /// `STA_CLOCKERR`
+        const CLOCKERR = 0x1000;
+        /// `STA_NANO`
+        const NANO = 0x2000;
+        /// `STA_MODE`
+        const MODE = 0x4000;
+        /// `STA_CLK`
+        const CLK = 0x8000;
+    }
+}
+
+/// `TIME_*` clock states returned by [`clock_adjtime`].
+///
+/// [`clock_adjtime`]: crate::time::clock_adjtime
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(i32)]
+pub enum AdjtimeState {
+    /// `TIME_OK`
+    Ok = 0,
+
+    /// `TIME_INS`
+    Insert = 1,
+
+    /// `TIME_DEL`
+    Delete = 2,
+
+    /// `TIME_OOP`
+    InProgress = 3,
+
+    /// `TIME_WAIT`
+    Wait = 4,
+
+    /// `TIME_ERROR`
+    Error = 5,
+}
+
+impl AdjtimeState {
+    pub(crate) fn from_raw(raw: i32) -> Option<Self> {
+        match raw {
+            0 => Some(Self::Ok),
+            1 => Some(Self::Insert),
+            2 => Some(Self::Delete),
+            3 => Some(Self::InProgress),
+            4 => Some(Self::Wait),
+            5 => Some(Self::Error),
+            _ => None,
+        }
+    }
+}
+
+/// `struct __kernel_timex`, which has the same layout as the 64-bit
+/// `struct timex`.
+#[repr(C)]
+#[derive(Default)]
+#[allow(dead_code)] // Some fields are only ever written by the kernel.
+pub(crate) struct KernelTimex {
+    pub(crate) modes: u32,
+    _pad0: i32,
+    pub(crate) offset: i64,
+    pub(crate) freq: i64,
+    pub(crate) maxerror: i64,
+    pub(crate) esterror: i64,
+    pub(crate) status: i32,
+    _pad1: i32,
+    pub(crate) constant: i64,
+    pub(crate) precision: i64,
+    pub(crate) tolerance: i64,
+    pub(crate) time_sec: i64,
+    pub(crate) time_usec: i64,
+    pub(crate) tick: i64,
+    pub(crate) ppsfreq: i64,
+    pub(crate) jitter: i64,
+    pub(crate) shift: i32,
+    _pad2: i32,
+    pub(crate) stabil: i64,
+    pub(crate) jitcnt: i64,
+    pub(crate) calcnt: i64,
+    pub(crate) errcnt: i64,
+    pub(crate) stbcnt: i64,
+    pub(crate) tai: i32,
+    _pad3: [i32; 11],
+}
+
+// linux_raw_sys's older bindings don't define `struct __kernel_itimerspec`,
+// so define it here, in terms of `Timespec`.
+/// `struct itimerspec`
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct Itimerspec {
+    /// The period of subsequent expirations.
+    pub it_interval: Timespec,
+    /// The time until the next expiration.
+    pub it_value: Timespec,
+}
+
+/// The raw `timer_t` identifier of a POSIX timer.
+pub type RawTimer = linux_raw_sys::general::__kernel_timer_t;
+
+bitflags! {
+    /// `TIMER_*` flags for use with [`Timer::set_time`].
+    ///
+    /// [`Timer::set_time`]: crate::time::Timer::set_time
+    pub struct TimerFlags: std::os::raw::c_int {
+        /// `TIMER_ABSTIME`
+        const ABSTIME = linux_raw_sys::general::TIMER_ABSTIME as std::os::raw::c_int;
+    }
+}
+
+bitflags! {
+    /// `TFD_*` flags for use with [`timerfd_create`].
+    ///
+    /// [`timerfd_create`]: crate::time::timerfd_create
+    pub struct TimerfdFlags: std::os::raw::c_uint {
+        /// `TFD_CLOEXEC`
+        const CLOEXEC = linux_raw_sys::general::O_CLOEXEC;
+        /// `TFD_NONBLOCK`
+        const NONBLOCK = linux_raw_sys::general::O_NONBLOCK;
+    }
+}
+
+bitflags! {
+    /// `TFD_TIMER_*` flags for use with [`timerfd_settime`].
+    ///
+    /// [`timerfd_settime`]: crate::time::timerfd_settime
+    pub struct TimerfdTimerFlags: std::os::raw::c_uint {
+        /// `TFD_TIMER_ABSTIME`
+        const ABSTIME = TFD_TIMER_ABSTIME;
+        /// `TFD_TIMER_CANCEL_ON_SET`
+        const CANCEL_ON_SET = TFD_TIMER_CANCEL_ON_SET;
+    }
+}
+
+// linux_raw_sys doesn't define the `TFD_TIMER_*` constants, which are in the
+// kernel's linux/timerfd.h, so define them here.
+const TFD_TIMER_ABSTIME: std::os::raw::c_uint = 0x1;
+const TFD_TIMER_CANCEL_ON_SET: std::os::raw::c_uint = 0x2;
+
+/// `struct sigevent`, with the `_tid` member of the union, and padded to
+/// the kernel's fixed size of 64 bytes.
+#[repr(C)]
+pub(crate) struct KernelSigevent {
+    pub(crate) sigev_value: usize,
+    pub(crate) sigev_signo: std::os::raw::c_int,
+    pub(crate) sigev_notify: std::os::raw::c_int,
+    pub(crate) sigev_tid: std::os::raw::c_int,
+    pub(crate) pad: [std::os::raw::c_int; (64 - 3 * 4 - std::mem::size_of::<usize>()) / 4],
+}
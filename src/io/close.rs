@@ -1,8 +1,11 @@
-//! The unsafe `close` for raw file descriptors.
+//! The unsafe `close` and `close_range` for raw file descriptors.
 #![allow(unsafe_code)]
 
 use crate::imp;
-use crate::io::RawFd;
+use crate::io::{self, RawFd};
+
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub use imp::io::CloseRangeFlags;
 
 /// `close(raw_fd)`—Closes a `RawFd` directly.
 ///
@@ -17,3 +20,25 @@ use crate::io::RawFd;
 pub unsafe fn close(raw_fd: RawFd) {
     imp::syscalls::close(raw_fd)
 }
+
+/// `close_range(first, last, flags)`—Closes all the file descriptors from
+/// `first` to `last`, inclusive.
+///
+/// With [`CloseRangeFlags::CLOEXEC`], the file descriptors are instead
+/// marked close-on-exec, and left open. On kernels older than Linux 5.9,
+/// this fails with [`io::Error::NOSYS`].
+///
+/// # Safety
+///
+/// Unless [`CloseRangeFlags::CLOEXEC`] is used, every file descriptor in the
+/// range must be valid before the call, and is not valid after the call.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/close_range.2.html
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[inline]
+pub unsafe fn close_range(first: RawFd, last: RawFd, flags: CloseRangeFlags) -> io::Result<()> {
+    imp::syscalls::close_range(first, last, flags)
+}
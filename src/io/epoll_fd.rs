@@ -0,0 +1,206 @@
+//! An epoll interface which borrows the file descriptors registered with it,
+//! and identifies each one by a `u64` of the caller's choosing.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # #![cfg_attr(io_lifetimes_use_std, feature(io_safety))]
+//! # fn main() -> std::io::Result<()> {
+//! use rsix::io::{epoll, pipe, write, Epoll, EventVec};
+//!
+//! let (reader, writer) = pipe()?;
+//!
+//! let epoll = Epoll::new(epoll::CreateFlags::CLOEXEC)?;
+//! epoll.add(&reader, 1, epoll::EventFlags::IN)?;
+//!
+//! write(&writer, b"hello")?;
+//!
+//! let mut events = EventVec::with_capacity(4);
+//! epoll.wait(&mut events, -1)?;
+//! for (_event_flags, data) in &events {
+//!     assert_eq!(data, 1);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+#![allow(unsafe_code)]
+
+use crate::imp;
+use crate::imp::io::epoll::Event;
+use crate::io::epoll::{CreateFlags, EventFlags};
+use crate::io::{self, AsRawFd, OwnedFd};
+use io_lifetimes::{AsFd, BorrowedFd};
+use std::marker::PhantomData;
+use std::os::raw::c_int;
+
+/// An epoll object in which each registered file descriptor is identified by
+/// a `u64` of the caller's choosing.
+///
+/// File descriptors are borrowed, not owned, so they must outlive the `'fd`
+/// lifetime of the `Epoll` they're added to. To have the epoll object own
+/// them instead, see [`epoll::Epoll`].
+///
+/// [`epoll::Epoll`]: crate::io::epoll::Epoll
+pub struct Epoll<'fd> {
+    epoll_fd: OwnedFd,
+    _fds: PhantomData<BorrowedFd<'fd>>,
+}
+
+impl<'fd> Epoll<'fd> {
+    /// `epoll_create1(flags)`—Creates a new `Epoll`.
+    ///
+    /// Use the [`CreateFlags::CLOEXEC`] flag to prevent the resulting file
+    /// descriptor from being implicity passed across `exec` boundaries.
+    #[inline]
+    #[doc(alias = "epoll_create1")]
+    pub fn new(flags: CreateFlags) -> io::Result<Self> {
+        Ok(Self {
+            epoll_fd: imp::syscalls::epoll_create(flags)?,
+            _fds: PhantomData,
+        })
+    }
+
+    /// `epoll_ctl(self, EPOLL_CTL_ADD, fd, event)`—Adds a file descriptor to
+    /// this `Epoll`.
+    ///
+    /// Events of interest on `fd` are reported with `data`.
+    #[doc(alias = "epoll_ctl")]
+    pub fn add<Fd: AsFd>(&self, fd: &'fd Fd, data: u64, event_flags: EventFlags) -> io::Result<()> {
+        let event = Event {
+            event_flags,
+            encoded: data,
+        };
+
+        // Safety: `Event` matches the layout of `epoll_event`, and `fd` is
+        // borrowed for `'fd`, so it stays open while it's registered.
+        unsafe {
+            imp::syscalls::epoll_add(
+                self.epoll_fd.as_fd(),
+                fd.as_fd().as_raw_fd(),
+                &*(&event as *const Event).cast(),
+            )
+        }
+    }
+
+    /// `epoll_ctl(self, EPOLL_CTL_MOD, fd, event)`—Modifies the events of
+    /// interest, and the `data` they're reported with, for a file descriptor
+    /// in this `Epoll`.
+    #[doc(alias = "epoll_ctl")]
+    pub fn modify<Fd: AsFd>(&self, fd: &Fd, data: u64, event_flags: EventFlags) -> io::Result<()> {
+        let event = Event {
+            event_flags,
+            encoded: data,
+        };
+
+        // Safety: `Event` matches the layout of `epoll_event`, and
+        // `EPOLL_CTL_MOD` fails with `ENOENT` unless `fd` was added with
+        // `add`.
+        unsafe {
+            imp::syscalls::epoll_mod(
+                self.epoll_fd.as_fd(),
+                fd.as_fd().as_raw_fd(),
+                &*(&event as *const Event).cast(),
+            )
+        }
+    }
+
+    /// `epoll_ctl(self, EPOLL_CTL_DEL, fd, NULL)`—Removes a file descriptor
+    /// from this `Epoll`.
+    #[doc(alias = "epoll_ctl")]
+    pub fn delete<Fd: AsFd>(&self, fd: &Fd) -> io::Result<()> {
+        // Safety: Removing a file descriptor doesn't affect its ownership.
+        unsafe { imp::syscalls::epoll_del(self.epoll_fd.as_fd(), fd.as_fd().as_raw_fd()) }
+    }
+
+    /// `epoll_wait(self, events, timeout)`—Waits for registered events of
+    /// interest.
+    ///
+    /// For each event of interest, an element is written to `events`,
+    /// replacing its previous contents.
+    #[doc(alias = "epoll_wait")]
+    pub fn wait(&self, events: &mut EventVec, timeout: c_int) -> io::Result<()> {
+        // Safety: `Event` matches the layout of `epoll_event`, and the kernel
+        // initializes the first `nfds` elements.
+        unsafe {
+            events.events.set_len(0);
+            let nfds = imp::syscalls::epoll_wait(
+                self.epoll_fd.as_fd(),
+                events.events.as_mut_ptr().cast(),
+                events.events.capacity(),
+                timeout,
+            )?;
+            events.events.set_len(nfds);
+        }
+
+        Ok(())
+    }
+}
+
+/// A reusable buffer of events filled in by [`Epoll::wait`].
+pub struct EventVec {
+    events: Vec<Event>,
+}
+
+impl EventVec {
+    /// Constructs an `EventVec` which can hold up to `capacity` events.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            events: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the maximum number of events [`Epoll::wait`] will report at
+    /// once.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.events.capacity()
+    }
+
+    /// Reserves room for at least `additional` more events.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.events.reserve(additional);
+    }
+
+    /// Removes all the events.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.events.clear();
+    }
+
+    /// Returns an iterator over the `(event_flags, data)` pairs of the events
+    /// that occurred.
+    #[inline]
+    pub fn iter(&self) -> EventIter<'_> {
+        EventIter {
+            iter: self.events.iter(),
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a EventVec {
+    type IntoIter = EventIter<'a>;
+    type Item = (EventFlags, u64);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the events in an [`EventVec`].
+pub struct EventIter<'a> {
+    iter: std::slice::Iter<'a, Event>,
+}
+
+impl<'a> Iterator for EventIter<'a> {
+    type Item = (EventFlags, u64);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|event| (event.event_flags, event.encoded))
+    }
+}
@@ -1,5 +1,7 @@
 use crate::imp;
 use crate::io::{self, OwnedFd};
+use io_lifetimes::AsFd;
+use std::mem::size_of;
 
 pub use imp::io::EventfdFlags;
 
@@ -14,3 +16,41 @@ pub use imp::io::EventfdFlags;
 pub fn eventfd(initval: u32, flags: EventfdFlags) -> io::Result<OwnedFd> {
     imp::syscalls::eventfd(initval, flags)
 }
+
+/// `eventfd_read(fd, &value)`—Reads the counter of an eventfd.
+///
+/// Normally this returns the counter's value and resets it to zero. If the
+/// eventfd was created with [`EventfdFlags::SEMAPHORE`], this returns 1 and
+/// decrements the counter by 1 instead. If the counter is zero, this blocks,
+/// or fails with [`io::Error::WOULDBLOCK`] if the eventfd was created with
+/// [`EventfdFlags::NONBLOCK`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/eventfd.2.html
+#[inline]
+pub fn eventfd_read<Fd: AsFd>(fd: &Fd) -> io::Result<u64> {
+    let mut bytes = [0_u8; size_of::<u64>()];
+    let nread = io::read(fd, &mut bytes)?;
+    if nread != bytes.len() {
+        return Err(io::Error::IO);
+    }
+    Ok(u64::from_ne_bytes(bytes))
+}
+
+/// `eventfd_write(fd, value)`—Adds to the counter of an eventfd.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/eventfd.2.html
+#[inline]
+pub fn eventfd_write<Fd: AsFd>(fd: &Fd, value: u64) -> io::Result<()> {
+    let bytes = value.to_ne_bytes();
+    let nwritten = io::write(fd, &bytes)?;
+    if nwritten != bytes.len() {
+        return Err(io::Error::IO);
+    }
+    Ok(())
+}
@@ -2,6 +2,8 @@
 
 use crate::imp;
 use crate::io::{self, OwnedFd};
+#[cfg(not(target_os = "wasi"))]
+use crate::io::{AsRawFd, RawFd};
 use io_lifetimes::AsFd;
 #[cfg(all(libc, not(any(target_os = "wasi", target_os = "fuchsia"))))]
 use std::ffi::OsString;
@@ -121,6 +123,46 @@ pub fn dup2_with<Fd: AsFd>(fd: &Fd, new: &OwnedFd, flags: DupFlags) -> io::Resul
     imp::syscalls::dup2_with(fd, new, flags)
 }
 
+/// `dup2(fd, new)`—Creates a new `OwnedFd` with the file descriptor number
+/// `new`, which shares the same underlying [file description] as `fd`.
+///
+/// This is for when the new file descriptor needs a specific number, such as
+/// when remapping stdio before an `exec`. If `new` is already open, it's
+/// closed first.
+///
+/// The new file descriptor doesn't have the `FD_CLOEXEC` flag set, even if
+/// `fd` does, so it's inherited across `exec`. To keep it from being
+/// inherited, set `FD_CLOEXEC` with [`fcntl_setfd`].
+///
+/// If `fd` is `new`, `dup2` itself would succeed without doing anything, and
+/// the returned `OwnedFd` would close `fd` out from under its owner. Instead,
+/// this fails with [`io::Error::INVAL`], as `dup3` does.
+///
+/// # Safety
+///
+/// If `new` is open, it's closed, so nothing else may own it or use it
+/// afterward.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [file description]: https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/V1_chap03.html#tag_03_258
+/// [`fcntl_setfd`]: crate::fs::fcntl_setfd
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/dup2.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/dup2.2.html
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+#[doc(alias = "dup2")]
+#[allow(unsafe_code)]
+pub unsafe fn dup2_to<Fd: AsFd>(fd: &Fd, new: RawFd) -> io::Result<OwnedFd> {
+    let fd = fd.as_fd();
+    if fd.as_raw_fd() == new {
+        return Err(io::Error::INVAL);
+    }
+    imp::syscalls::dup2_to(fd, new)
+}
+
 /// `ttyname_r(fd)`
 ///
 /// If `reuse` is non-empty, reuse its buffer to store the result if possible.
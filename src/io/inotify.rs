@@ -0,0 +1,175 @@
+//! inotify support.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # #![cfg_attr(io_lifetimes_use_std, feature(io_safety))]
+//! # fn main() -> std::io::Result<()> {
+//! use rsix::io::inotify::{self, CreateFlags, WatchFlags};
+//!
+//! let fd = inotify::inotify_init1(CreateFlags::CLOEXEC)?;
+//! inotify::inotify_add_watch(&fd, "/tmp", WatchFlags::CREATE | WatchFlags::DELETE)?;
+//!
+//! let mut buf = [0_u8; 4096];
+//! loop {
+//!     for event in inotify::read_events(&fd, &mut buf)? {
+//!         println!("{:?}", event);
+//!     }
+//! }
+//! # }
+//! ```
+
+use crate::io::{self, OwnedFd};
+use crate::{imp, path};
+use io_lifetimes::AsFd;
+use std::convert::TryInto;
+use std::ffi::CString;
+use std::mem::size_of;
+use std::os::raw::c_int;
+
+pub use imp::io::{InotifyFlags as CreateFlags, WatchFlags};
+
+/// The size of the fixed-size part of a `struct inotify_event`: the `wd`,
+/// `mask`, `cookie`, and `len` fields.
+const HEADER_LEN: usize = size_of::<c_int>() + 3 * size_of::<u32>();
+
+/// A watch descriptor, as returned by [`inotify_add_watch`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct WatchDescriptor(c_int);
+
+impl WatchDescriptor {
+    /// Return the raw integer value of this watch descriptor.
+    #[inline]
+    pub const fn as_raw(self) -> c_int {
+        self.0
+    }
+}
+
+/// `inotify_init1(flags)`—Creates a new inotify instance.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/inotify_init1.2.html
+#[inline]
+pub fn inotify_init1(flags: CreateFlags) -> io::Result<OwnedFd> {
+    imp::syscalls::inotify_init1(flags)
+}
+
+/// `inotify_add_watch(fd, path, mask)`—Adds a watch for `path` to an
+/// inotify instance, or modifies the existing watch for it.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/inotify_add_watch.2.html
+#[inline]
+pub fn inotify_add_watch<Fd: AsFd, P: path::Arg>(
+    fd: &Fd,
+    path: P,
+    mask: WatchFlags,
+) -> io::Result<WatchDescriptor> {
+    let fd = fd.as_fd();
+    path.into_with_c_str(|path| imp::syscalls::inotify_add_watch(fd, path, mask))
+        .map(WatchDescriptor)
+}
+
+/// `inotify_rm_watch(fd, wd)`—Removes a watch from an inotify instance.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/inotify_rm_watch.2.html
+#[inline]
+pub fn inotify_rm_watch<Fd: AsFd>(fd: &Fd, wd: WatchDescriptor) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::inotify_rm_watch(fd, wd.0)
+}
+
+/// Reads from an inotify instance into `buf` and returns an iterator over
+/// the events that were read.
+///
+/// `buf` should be at least `size_of::<inotify_event>() + NAME_MAX + 1`
+/// bytes long, or reads of events with long names fail with
+/// [`io::Error::INVAL`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/inotify.7.html
+#[inline]
+pub fn read_events<'buf, Fd: AsFd>(fd: &Fd, buf: &'buf mut [u8]) -> io::Result<Events<'buf>> {
+    let nread = io::read(fd, buf)?;
+    Ok(Events::new(&buf[..nread]))
+}
+
+/// An event read from an inotify instance.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct InotifyEvent {
+    /// The watch this event is for.
+    pub wd: WatchDescriptor,
+
+    /// The kind of event, along with `IN_ISDIR`, `IN_IGNORED`, and the
+    /// other flags the kernel reports alongside it.
+    pub mask: WatchFlags,
+
+    /// A cookie connecting related events, such as the `IN_MOVED_FROM` and
+    /// `IN_MOVED_TO` halves of a rename.
+    pub cookie: u32,
+
+    /// The name of the file within a watched directory that this event is
+    /// for, or `None` if the event is for the watched object itself.
+    pub name: Option<CString>,
+}
+
+/// An iterator over the events in a buffer of bytes read from an inotify
+/// instance.
+#[derive(Clone, Debug)]
+pub struct Events<'buf> {
+    buf: &'buf [u8],
+}
+
+impl<'buf> Events<'buf> {
+    /// Construct an iterator over the events in `buf`, which holds bytes
+    /// read from an inotify instance.
+    #[inline]
+    pub fn new(buf: &'buf [u8]) -> Self {
+        Self { buf }
+    }
+}
+
+impl<'buf> Iterator for Events<'buf> {
+    type Item = InotifyEvent;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.buf.len() < HEADER_LEN {
+            return None;
+        }
+
+        let (header, rest) = self.buf.split_at(HEADER_LEN);
+        let wd = c_int::from_ne_bytes(header[0..4].try_into().unwrap());
+        let mask = u32::from_ne_bytes(header[4..8].try_into().unwrap());
+        let cookie = u32::from_ne_bytes(header[8..12].try_into().unwrap());
+        let len = u32::from_ne_bytes(header[12..16].try_into().unwrap()) as usize;
+        if rest.len() < len {
+            return None;
+        }
+
+        // The name is padded with NULs out to `len`.
+        let (name, rest) = rest.split_at(len);
+        let name = &name[..name.iter().position(|b| *b == b'\0').unwrap_or(len)];
+        let name = if name.is_empty() {
+            None
+        } else {
+            Some(CString::new(name).unwrap())
+        };
+
+        self.buf = rest;
+        Some(InotifyEvent {
+            wd: WatchDescriptor(wd),
+            mask: WatchFlags::from_bits_truncate(mask),
+            cookie,
+            name,
+        })
+    }
+}
@@ -23,14 +23,55 @@ pub fn ioctl_tcgets<Fd: AsFd>(fd: &Fd) -> io::Result<Termios> {
 
 /// `ioctl(fd, FIOCLEX)`—Set the close-on-exec flag.
 ///
-/// Also known as `fcntl(fd, F_SETFD, FD_CLOEXEC)`.
-#[cfg(any(target_os = "ios", target_os = "macos"))]
+/// Also known as `fcntl(fd, F_SETFD, FD_CLOEXEC)`, and equivalent to
+/// [`fcntl_setfd`] with [`FdFlags::CLOEXEC`], though it doesn't need to know
+/// the current flags.
+///
+/// [`fcntl_setfd`]: crate::fs::fcntl_setfd
+/// [`FdFlags::CLOEXEC`]: crate::fs::FdFlags::CLOEXEC
+#[cfg(any(
+    linux_raw,
+    all(
+        libc,
+        not(any(
+            target_os = "emscripten",
+            target_os = "fuchsia",
+            target_os = "redox",
+            target_os = "wasi"
+        ))
+    )
+))]
 #[inline]
 pub fn ioctl_fioclex<Fd: AsFd>(fd: &Fd) -> io::Result<()> {
     let fd = fd.as_fd();
     imp::syscalls::ioctl_fioclex(fd)
 }
 
+/// `ioctl(fd, FIONCLEX)`—Clear the close-on-exec flag.
+///
+/// Also known as `fcntl(fd, F_SETFD, 0)`, and equivalent to [`fcntl_setfd`]
+/// with [`FdFlags::empty()`].
+///
+/// [`fcntl_setfd`]: crate::fs::fcntl_setfd
+/// [`FdFlags::empty()`]: crate::fs::FdFlags::empty
+#[cfg(any(
+    linux_raw,
+    all(
+        libc,
+        not(any(
+            target_os = "emscripten",
+            target_os = "fuchsia",
+            target_os = "redox",
+            target_os = "wasi"
+        ))
+    )
+))]
+#[inline]
+pub fn ioctl_fionclex<Fd: AsFd>(fd: &Fd) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::ioctl_fionclex(fd)
+}
+
 /// `ioctl(fd, TIOCGWINSZ)`—Get the current terminal window size.
 ///
 /// # References
@@ -44,6 +85,22 @@ pub fn ioctl_tiocgwinsz(fd: BorrowedFd) -> io::Result<Winsize> {
     imp::syscalls::ioctl_tiocgwinsz(fd)
 }
 
+/// `ioctl(fd, TIOCSWINSZ, &size)`—Set the terminal window size.
+///
+/// When `fd` is a pty master, this sets the size of the pty, and `SIGWINCH`
+/// is sent to the foreground process group of the pty if the size changes.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man4/tty_ioctl.4.html
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub fn ioctl_tiocswinsz<Fd: AsFd>(fd: &Fd, size: Winsize) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::ioctl_tiocswinsz(fd, &size)
+}
+
 /// `ioctl(fd, FIONBIO, &value)`—Enables or disables non-blocking mode.
 #[inline]
 pub fn ioctl_fionbio<Fd: AsFd>(fd: &Fd, value: bool) -> io::Result<()> {
@@ -12,10 +12,14 @@ pub(crate) use std::os::unix::io::{AsRawFd, FromRawFd, IntoRawFd, RawFd};
 pub(crate) use std::os::wasi::io::{AsRawFd, FromRawFd, IntoRawFd, RawFd};
 
 mod close;
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+mod epoll_fd;
 mod error;
 #[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
 mod eventfd;
 mod fd;
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub mod inotify;
 mod ioctl;
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 mod madvise;
@@ -28,14 +32,25 @@ mod poll;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 mod procfs;
 mod read_write;
+mod retry;
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+mod signalfd;
 mod stdio;
+#[cfg(not(target_os = "wasi"))]
+mod sync;
+#[cfg(not(target_os = "wasi"))]
+mod termios;
 #[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
 mod userfaultfd;
 
 pub use close::close;
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub use close::{close_range, CloseRangeFlags};
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub use epoll_fd::{Epoll, EventIter, EventVec};
 pub use error::{Error, Result};
 #[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
-pub use eventfd::{eventfd, EventfdFlags};
+pub use eventfd::{eventfd, eventfd_read, eventfd_write, EventfdFlags};
 #[cfg(not(target_os = "redox"))]
 pub use fd::ioctl_fionread;
 #[cfg(not(target_os = "redox"))]
@@ -44,14 +59,25 @@ pub use fd::isatty;
 #[cfg(all(libc, not(any(target_os = "fuchsia", target_os = "wasi"))))]
 pub use fd::ttyname;
 #[cfg(not(target_os = "wasi"))]
-pub use fd::{dup, dup2, dup2_with, DupFlags};
+pub use fd::{dup, dup2, dup2_to, dup2_with, DupFlags};
 #[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
 pub use imp::io::epoll;
-#[cfg(any(target_os = "ios", target_os = "macos"))]
-pub use ioctl::ioctl_fioclex;
 pub use ioctl::ioctl_fionbio;
+#[cfg(any(
+    linux_raw,
+    all(
+        libc,
+        not(any(
+            target_os = "emscripten",
+            target_os = "fuchsia",
+            target_os = "redox",
+            target_os = "wasi"
+        ))
+    )
+))]
+pub use ioctl::{ioctl_fioclex, ioctl_fionclex};
 #[cfg(not(target_os = "wasi"))]
-pub use ioctl::{ioctl_tcgets, ioctl_tiocgwinsz};
+pub use ioctl::{ioctl_tcgets, ioctl_tiocgwinsz, ioctl_tiocswinsz};
 #[cfg(any(
     linux_raw,
     all(libc, not(any(target_os = "redox", target_os = "wasi")))
@@ -68,17 +94,32 @@ pub use mmap::{mlock_with, MlockFlags};
 pub use owned_fd::OwnedFd;
 #[cfg(not(target_os = "wasi"))]
 pub use pipe::pipe;
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub use pipe::{fcntl_getpipe_size, fcntl_setpipe_size, splice, tee, vmsplice, SpliceFlags};
 #[cfg(not(any(target_os = "ios", target_os = "macos", target_os = "wasi")))]
 pub use pipe::{pipe_with, PipeFlags};
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub use poll::ppoll;
 pub use poll::{poll, PollFd, PollFlags};
 #[cfg(any(target_os = "android", target_os = "linux"))]
 pub use procfs::proc_self_fd;
-pub use read_write::{pread, pwrite, read, readv, write, writev};
+pub(crate) use read_write::split_init;
+pub use read_write::{pread, pwrite, read, read_uninit, readv, write, writev};
 #[cfg(not(target_os = "redox"))]
 pub use read_write::{preadv, pwritev};
 #[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
 pub use read_write::{preadv2, pwritev2, ReadWriteFlags};
+pub use retry::{read_exact, read_uninterrupted, retry_on_intr, write_all, write_uninterrupted};
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub use signalfd::{signalfd, signalfd_read, signalfd_set_mask, SignalfdFlags, SignalfdSiginfo};
 pub use stdio::{stderr, stdin, stdout, take_stderr, take_stdin, take_stdout};
+#[cfg(not(target_os = "wasi"))]
+pub use sync::sync;
+#[cfg(not(target_os = "wasi"))]
+pub use termios::{
+    tcdrain, tcflush, tcgetattr, tcgetpgrp, tcsendbreak, tcsetattr, tcsetpgrp, ControlFlags,
+    InputFlags, LocalFlags, OptionalActions, OutputFlags, QueueSelector, TermiosExt,
+};
 #[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
 pub use userfaultfd::{userfaultfd, UserfaultfdFlags};
 
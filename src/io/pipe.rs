@@ -1,8 +1,16 @@
 use crate::imp;
 use crate::io::{self, OwnedFd};
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+use io_lifetimes::AsFd;
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+use std::convert::TryInto;
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+use std::io::IoSlice;
 
 #[cfg(any(linux_raw, all(libc, not(any(target_os = "ios", target_os = "macos")))))]
 pub use imp::io::PipeFlags;
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub use imp::io::SpliceFlags;
 
 /// `pipe()`—Creates a pipe.
 ///
@@ -35,3 +43,114 @@ pub fn pipe() -> io::Result<(OwnedFd, OwnedFd)> {
 pub fn pipe_with(flags: PipeFlags) -> io::Result<(OwnedFd, OwnedFd)> {
     imp::syscalls::pipe_with(flags)
 }
+
+/// `fcntl(fd, F_GETPIPE_SZ)`—Returns the capacity of the pipe `fd`, in
+/// bytes.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[inline]
+#[doc(alias = "F_GETPIPE_SZ")]
+pub fn fcntl_getpipe_size<Fd: AsFd>(fd: &Fd) -> io::Result<usize> {
+    let fd = fd.as_fd();
+    imp::syscalls::fcntl_getpipe_sz(fd)
+}
+
+/// `fcntl(fd, F_SETPIPE_SZ, size)`—Sets the capacity of the pipe `fd`, and
+/// returns the capacity actually granted.
+///
+/// The kernel rounds `size` up, to at least a page and to a power-of-two
+/// number of pages, so the returned capacity may be larger than `size`.
+/// Unprivileged processes can't raise it above `/proc/sys/fs/pipe-max-size`,
+/// and it can't be made smaller than the data currently in the pipe, which
+/// fails with [`io::Error::BUSY`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[inline]
+#[doc(alias = "F_SETPIPE_SZ")]
+pub fn fcntl_setpipe_size<Fd: AsFd>(fd: &Fd, size: usize) -> io::Result<usize> {
+    let fd = fd.as_fd();
+    let size = size.try_into().map_err(|_| io::Error::INVAL)?;
+    imp::syscalls::fcntl_setpipe_sz(fd, size)
+}
+
+/// `splice(fd_in, off_in, fd_out, off_out, len, flags)`—Transfers data
+/// between a file descriptor and a pipe without copying through user space.
+///
+/// At least one of `fd_in` and `fd_out` must be a pipe. If `off_in` or
+/// `off_out` is `Some`, the corresponding non-pipe file descriptor is
+/// accessed at that offset, and the offset is advanced by the number of bytes
+/// transferred. With [`SpliceFlags::NONBLOCK`], this fails with
+/// [`io::Error::WOULDBLOCK`] rather than blocking on the pipe.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/splice.2.html
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[inline]
+pub fn splice<InFd: AsFd, OutFd: AsFd>(
+    fd_in: &InFd,
+    off_in: Option<&mut u64>,
+    fd_out: &OutFd,
+    off_out: Option<&mut u64>,
+    len: usize,
+    flags: SpliceFlags,
+) -> io::Result<usize> {
+    let fd_in = fd_in.as_fd();
+    let fd_out = fd_out.as_fd();
+    imp::syscalls::splice(fd_in, off_in, fd_out, off_out, len, flags)
+}
+
+/// `tee(fd_in, fd_out, len, flags)`—Copies data from one pipe to another
+/// without consuming it.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/tee.2.html
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[inline]
+pub fn tee<InFd: AsFd, OutFd: AsFd>(
+    fd_in: &InFd,
+    fd_out: &OutFd,
+    len: usize,
+    flags: SpliceFlags,
+) -> io::Result<usize> {
+    let fd_in = fd_in.as_fd();
+    let fd_out = fd_out.as_fd();
+    imp::syscalls::tee(fd_in, fd_out, len, flags)
+}
+
+/// `vmsplice(fd, bufs, flags)`—Transfers data from user memory into a pipe.
+///
+/// # Safety
+///
+/// `fd` must be the write end of a pipe. If it's the read end, the kernel
+/// writes into `bufs`, which are shared and immutable.
+///
+/// With [`SpliceFlags::GIFT`], the pages of `bufs` are handed to the kernel,
+/// and must not be modified afterward.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/vmsplice.2.html
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[inline]
+#[allow(unsafe_code)]
+pub unsafe fn vmsplice<Fd: AsFd>(
+    fd: &Fd,
+    bufs: &[IoSlice],
+    flags: SpliceFlags,
+) -> io::Result<usize> {
+    let fd = fd.as_fd();
+    imp::syscalls::vmsplice(fd, bufs, flags)
+}
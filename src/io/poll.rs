@@ -1,8 +1,15 @@
 use crate::{imp, io};
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+use crate::{process::SigSet, time::Timespec};
 
 pub use imp::io::{PollFd, PollFlags};
 
-/// `poll(self.fds, timeout)`
+/// `poll(self.fds, timeout)`—Waits for events on a set of file descriptors.
+///
+/// `timeout` is in milliseconds; a negative value waits indefinitely, and
+/// zero returns immediately. On success, this returns the number of entries
+/// in `fds` with non-empty [`PollFd::revents`], which is zero if the timeout
+/// expired.
 ///
 /// # References
 ///  - [POSIX]
@@ -14,3 +21,25 @@ pub use imp::io::{PollFd, PollFlags};
 pub fn poll(fds: &mut [PollFd], timeout: i32) -> io::Result<usize> {
     imp::syscalls::poll(fds, timeout)
 }
+
+/// `ppoll(self.fds, timeout, sigmask)`—Waits for events on a set of file
+/// descriptors, with a precise timeout and a temporary signal mask.
+///
+/// This is like [`poll`], except that `timeout` is a [`Timespec`], and `None`
+/// waits indefinitely. If `sigmask` is `Some`, the calling thread's signal
+/// mask is atomically replaced with it for the duration of the wait, so that
+/// signals which are otherwise blocked can interrupt it without a race.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ppoll.2.html
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[inline]
+pub fn ppoll(
+    fds: &mut [PollFd],
+    timeout: Option<&Timespec>,
+    sigmask: Option<&SigSet>,
+) -> io::Result<usize> {
+    imp::syscalls::ppoll(fds, timeout, sigmask)
+}
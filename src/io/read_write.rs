@@ -3,6 +3,7 @@
 use crate::{imp, io};
 use io_lifetimes::AsFd;
 use std::io::{IoSlice, IoSliceMut};
+use std::mem::MaybeUninit;
 
 /// `RWF_*` constants for use with [`preadv2`] and [`pwritev2`].
 #[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
@@ -22,6 +23,46 @@ pub fn read<Fd: AsFd>(fd: &Fd, buf: &mut [u8]) -> io::Result<usize> {
     imp::syscalls::read(fd, buf)
 }
 
+/// `read(fd, buf)`—Reads from a stream into a buffer that may be
+/// uninitialized.
+///
+/// This avoids having to zero `buf` before reading into it. On success, it
+/// returns the part of `buf` that was read into, which is now initialized,
+/// followed by the rest of `buf`, which is left untouched.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/read.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/read.2.html
+#[inline]
+#[doc(alias = "read")]
+pub fn read_uninit<'buf, Fd: AsFd>(
+    fd: &Fd,
+    buf: &'buf mut [MaybeUninit<u8>],
+) -> io::Result<(&'buf mut [u8], &'buf mut [MaybeUninit<u8>])> {
+    let fd = fd.as_fd();
+    imp::syscalls::read_uninit(fd, buf)
+}
+
+/// Splits `buf` into its first `len` bytes, which a `read`-like call has
+/// initialized, and the rest, which it hasn't touched.
+///
+/// # Safety
+///
+/// The first `len` bytes of `buf` must be initialized.
+#[allow(unsafe_code)]
+#[inline]
+pub(crate) unsafe fn split_init(
+    buf: &mut [MaybeUninit<u8>],
+    len: usize,
+) -> (&mut [u8], &mut [MaybeUninit<u8>]) {
+    let (init, uninit) = buf.split_at_mut(len);
+    let init = &mut *(init as *mut [MaybeUninit<u8>] as *mut [u8]);
+    (init, uninit)
+}
+
 /// `write(fd, buf)`—Writes to a stream.
 ///
 /// # References
@@ -122,6 +163,15 @@ pub fn pwritev<Fd: AsFd>(fd: &Fd, bufs: &[IoSlice], offset: u64) -> io::Result<u
 
 /// `preadv2(fd, bufs, offset, flags)`—Reads data, with several options.
 ///
+/// If `offset` is `Some`, this reads from that position and leaves the file
+/// position unchanged, like [`preadv`]. If it's `None`, which is passed to
+/// the OS as an offset of -1, this reads from the current file position and
+/// advances it, like [`readv`].
+///
+/// [`ReadWriteFlags::NOWAIT`] makes this fail with [`io::Error::AGAIN`]
+/// rather than block waiting for data that isn't immediately available, even
+/// on a regular file.
+///
 /// # References
 ///  - [Linux]
 ///
@@ -131,7 +181,7 @@ pub fn pwritev<Fd: AsFd>(fd: &Fd, bufs: &[IoSlice], offset: u64) -> io::Result<u
 pub fn preadv2<Fd: AsFd>(
     fd: &Fd,
     bufs: &[IoSliceMut],
-    offset: u64,
+    offset: Option<u64>,
     flags: ReadWriteFlags,
 ) -> io::Result<usize> {
     let fd = fd.as_fd();
@@ -140,6 +190,14 @@ pub fn preadv2<Fd: AsFd>(
 
 /// `pwritev2(fd, bufs, offset, flags)`—Writes data, with several options.
 ///
+/// If `offset` is `Some`, this writes at that position and leaves the file
+/// position unchanged, like [`pwritev`]. If it's `None`, which is passed to
+/// the OS as an offset of -1, this writes at the current file position and
+/// advances it, like [`writev`].
+///
+/// With [`ReadWriteFlags::APPEND`], the data is appended atomically to the
+/// end of the file and `offset` is ignored.
+///
 /// # References
 ///  - [Linux]
 ///
@@ -149,7 +207,7 @@ pub fn preadv2<Fd: AsFd>(
 pub fn pwritev2<Fd: AsFd>(
     fd: &Fd,
     bufs: &[IoSlice],
-    offset: u64,
+    offset: Option<u64>,
     flags: ReadWriteFlags,
 ) -> io::Result<usize> {
     let fd = fd.as_fd();
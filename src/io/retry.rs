@@ -0,0 +1,78 @@
+//! Helpers for retrying calls that fail with `EINTR` or that transfer less
+//! than requested.
+
+use crate::io::{self, read, write};
+use io_lifetimes::AsFd;
+
+/// Calls `f` until it returns something other than
+/// [`io::Error::INTR`].
+///
+/// A blocking call fails with `EINTR` when a signal handler runs while it's
+/// waiting, unless the handler was installed with `SA_RESTART`. Calls which
+/// have no effect when they fail, such as [`read`], [`write`], [`poll`], and
+/// most other blocking calls, can be retried blindly.
+///
+/// Don't use this with [`close`]: on Linux the file descriptor is closed even
+/// when `close` fails with `EINTR`, so retrying could close an unrelated file
+/// descriptor opened by another thread in the meantime. And a [`nanosleep`]
+/// retried this way starts the full interval over; use
+/// [`nanosleep_uninterrupted`] to sleep for just the remaining time.
+///
+/// [`poll`]: crate::io::poll
+/// [`close`]: crate::io::close
+/// [`nanosleep`]: crate::time::nanosleep
+/// [`nanosleep_uninterrupted`]: crate::time::nanosleep_uninterrupted
+pub fn retry_on_intr<T, F: FnMut() -> io::Result<T>>(mut f: F) -> io::Result<T> {
+    loop {
+        match f() {
+            Err(io::Error::INTR) => (),
+            result => return result,
+        }
+    }
+}
+
+/// `read(fd, buf)`, retried on `EINTR`.
+///
+/// This is [`read`] wrapped in [`retry_on_intr`].
+#[inline]
+pub fn read_uninterrupted<Fd: AsFd>(fd: &Fd, buf: &mut [u8]) -> io::Result<usize> {
+    retry_on_intr(|| read(fd, buf))
+}
+
+/// `write(fd, buf)`, retried on `EINTR`.
+///
+/// This is [`write`] wrapped in [`retry_on_intr`].
+#[inline]
+pub fn write_uninterrupted<Fd: AsFd>(fd: &Fd, buf: &[u8]) -> io::Result<usize> {
+    retry_on_intr(|| write(fd, buf))
+}
+
+/// Reads from `fd` until `buf` is full.
+///
+/// This calls [`read`] repeatedly, retrying on `EINTR` and continuing after
+/// short reads. If end-of-file is reached before `buf` is full, this fails
+/// with [`io::Error::IO`], and the contents of `buf` are unspecified.
+pub fn read_exact<Fd: AsFd>(fd: &Fd, mut buf: &mut [u8]) -> io::Result<()> {
+    while !buf.is_empty() {
+        match read_uninterrupted(fd, buf)? {
+            0 => return Err(io::Error::IO),
+            n => buf = &mut buf[n..],
+        }
+    }
+    Ok(())
+}
+
+/// Writes all of `buf` to `fd`.
+///
+/// This calls [`write`] repeatedly, retrying on `EINTR` and continuing after
+/// short writes. If `write` makes no progress, this fails with
+/// [`io::Error::IO`].
+pub fn write_all<Fd: AsFd>(fd: &Fd, mut buf: &[u8]) -> io::Result<()> {
+    while !buf.is_empty() {
+        match write_uninterrupted(fd, buf)? {
+            0 => return Err(io::Error::IO),
+            n => buf = &buf[n..],
+        }
+    }
+    Ok(())
+}
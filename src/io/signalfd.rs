@@ -0,0 +1,148 @@
+use crate::imp;
+use crate::io::{self, OwnedFd};
+use crate::process::SigSet;
+use io_lifetimes::AsFd;
+use std::convert::TryInto;
+
+pub use imp::io::SignalfdFlags;
+
+/// `signalfd(fd, mask, flags)`—Creates a file descriptor for accepting
+/// signals.
+///
+/// Signals in `mask` are reported through the returned file descriptor,
+/// which can be read with [`signalfd_read`]. They should usually also be
+/// blocked with [`sigprocmask`] so that they aren't delivered in the usual
+/// way as well.
+///
+/// If `fd` is `None`, a new signalfd is created. Otherwise, `fd` must be an
+/// existing signalfd; its mask is replaced with `mask`, and a new file
+/// descriptor referring to it is returned, since `fd` remains owned by the
+/// caller.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/signalfd.2.html
+/// [`sigprocmask`]: crate::process::sigprocmask
+#[inline]
+pub fn signalfd<Fd: AsFd>(
+    fd: Option<&Fd>,
+    mask: &SigSet,
+    flags: SignalfdFlags,
+) -> io::Result<OwnedFd> {
+    match fd {
+        None => imp::syscalls::signalfd(mask, flags),
+        Some(fd) => {
+            let fd = fd.as_fd();
+            imp::syscalls::signalfd_set_mask(fd, mask)?;
+            if flags.contains(SignalfdFlags::CLOEXEC) {
+                imp::syscalls::fcntl_dupfd_cloexec(fd, 0)
+            } else {
+                imp::syscalls::dup(fd)
+            }
+        }
+    }
+}
+
+/// `signalfd(fd, mask, 0)`—Replaces the set of signals accepted by an
+/// existing signalfd.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/signalfd.2.html
+#[inline]
+pub fn signalfd_set_mask<Fd: AsFd>(fd: &Fd, mask: &SigSet) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::signalfd_set_mask(fd, mask)
+}
+
+/// Reads one `signalfd_siginfo` record from a signalfd.
+///
+/// If no signals are pending, this blocks, or fails with
+/// [`io::Error::WOULDBLOCK`] if the signalfd was created with
+/// [`SignalfdFlags::NONBLOCK`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/signalfd.2.html
+#[inline]
+pub fn signalfd_read<Fd: AsFd>(fd: &Fd) -> io::Result<SignalfdSiginfo> {
+    let mut bytes = [0_u8; SignalfdSiginfo::SIZE];
+    let nread = io::read(fd, &mut bytes)?;
+    if nread != bytes.len() {
+        return Err(io::Error::IO);
+    }
+    Ok(SignalfdSiginfo::from_bytes(&bytes))
+}
+
+/// `struct signalfd_siginfo`—A signal read from a signalfd.
+///
+/// Which fields are meaningful depends on the signal and on how it was
+/// sent; see the `sigaction` documentation for details.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct SignalfdSiginfo {
+    /// The signal number.
+    pub ssi_signo: u32,
+    /// An error number; generally unused.
+    pub ssi_errno: i32,
+    /// The signal code, such as `SI_USER`.
+    pub ssi_code: i32,
+    /// The process ID of the sender.
+    pub ssi_pid: u32,
+    /// The real user ID of the sender.
+    pub ssi_uid: u32,
+    /// The file descriptor, for `SIGIO`.
+    pub ssi_fd: i32,
+    /// The kernel timer ID, for POSIX timers.
+    pub ssi_tid: u32,
+    /// The band event, for `SIGIO`.
+    pub ssi_band: u32,
+    /// The overrun count, for POSIX timers.
+    pub ssi_overrun: u32,
+    /// The trap number that caused a hardware-generated signal.
+    pub ssi_trapno: u32,
+    /// The exit status or signal, for `SIGCHLD`.
+    pub ssi_status: i32,
+    /// The integer sent by `sigqueue`.
+    pub ssi_int: i32,
+    /// The pointer sent by `sigqueue`.
+    pub ssi_ptr: u64,
+    /// The user CPU time consumed, for `SIGCHLD`.
+    pub ssi_utime: u64,
+    /// The system CPU time consumed, for `SIGCHLD`.
+    pub ssi_stime: u64,
+    /// The address that generated a hardware-generated signal.
+    pub ssi_addr: u64,
+}
+
+impl SignalfdSiginfo {
+    /// The size of a `signalfd_siginfo` record, in bytes.
+    pub const SIZE: usize = 128;
+
+    /// Decode a `signalfd_siginfo` record read from a signalfd.
+    pub fn from_bytes(bytes: &[u8; Self::SIZE]) -> Self {
+        let u32_at = |at: usize| u32::from_ne_bytes(bytes[at..at + 4].try_into().unwrap());
+        let i32_at = |at: usize| i32::from_ne_bytes(bytes[at..at + 4].try_into().unwrap());
+        let u64_at = |at: usize| u64::from_ne_bytes(bytes[at..at + 8].try_into().unwrap());
+        Self {
+            ssi_signo: u32_at(0),
+            ssi_errno: i32_at(4),
+            ssi_code: i32_at(8),
+            ssi_pid: u32_at(12),
+            ssi_uid: u32_at(16),
+            ssi_fd: i32_at(20),
+            ssi_tid: u32_at(24),
+            ssi_band: u32_at(28),
+            ssi_overrun: u32_at(32),
+            ssi_trapno: u32_at(36),
+            ssi_status: i32_at(40),
+            ssi_int: i32_at(44),
+            ssi_ptr: u64_at(48),
+            ssi_utime: u64_at(56),
+            ssi_stime: u64_at(64),
+            ssi_addr: u64_at(72),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use crate::imp;
+
+/// `sync()`—Schedules the data and metadata of all files on all filesystems
+/// to be written to the underlying storage device.
+///
+/// POSIX allows this to return before the writes are done. On Linux, it
+/// waits for them to complete. To flush a single filesystem on Linux, use
+/// [`syncfs`], or [`fsync`] for a single file.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [`syncfs`]: crate::fs::syncfs
+/// [`fsync`]: crate::fs::fsync
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/sync.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/sync.2.html
+#[inline]
+pub fn sync() {
+    imp::syscalls::sync()
+}
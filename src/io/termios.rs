@@ -0,0 +1,342 @@
+use crate::imp;
+use crate::imp::io::Tcflag;
+use crate::io::{self, Termios};
+use crate::process::Pid;
+use io_lifetimes::AsFd;
+
+pub use imp::io::{
+    ControlFlags, InputFlags, LocalFlags, OptionalActions, OutputFlags, QueueSelector,
+};
+
+/// `tcgetattr(fd)`—Get terminal attributes.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/tcgetattr.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/tcgetattr.3.html
+#[inline]
+pub fn tcgetattr<Fd: AsFd>(fd: &Fd) -> io::Result<Termios> {
+    let fd = fd.as_fd();
+    imp::syscalls::ioctl_tcgets(fd)
+}
+
+/// `tcsetattr(fd, optional_actions, termios)`—Set terminal attributes.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/tcsetattr.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/tcsetattr.3.html
+#[inline]
+pub fn tcsetattr<Fd: AsFd>(
+    fd: &Fd,
+    optional_actions: OptionalActions,
+    termios: &Termios,
+) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::tcsetattr(fd, optional_actions, termios)
+}
+
+/// `tcflush(fd, queue_selector)`—Discard pending terminal input, output, or
+/// both.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux `ioctl_tty`]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/tcflush.html
+/// [Linux `ioctl_tty`]: https://man7.org/linux/man-pages/man4/tty_ioctl.4.html
+#[inline]
+#[doc(alias = "TCFLSH")]
+pub fn tcflush<Fd: AsFd>(fd: &Fd, queue_selector: QueueSelector) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::tcflush(fd, queue_selector)
+}
+
+/// `tcdrain(fd)`—Wait until all output written to a terminal has been
+/// transmitted.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux `ioctl_tty`]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/tcdrain.html
+/// [Linux `ioctl_tty`]: https://man7.org/linux/man-pages/man4/tty_ioctl.4.html
+#[inline]
+#[doc(alias = "TCSBRK")]
+pub fn tcdrain<Fd: AsFd>(fd: &Fd) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::tcdrain(fd)
+}
+
+/// `tcsendbreak(fd, 0)`—Transmit a break, a stream of zero bits lasting
+/// between a quarter and half a second, on a serial line.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux `ioctl_tty`]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/tcsendbreak.html
+/// [Linux `ioctl_tty`]: https://man7.org/linux/man-pages/man4/tty_ioctl.4.html
+#[inline]
+#[doc(alias = "TCSBRK")]
+pub fn tcsendbreak<Fd: AsFd>(fd: &Fd) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::tcsendbreak(fd)
+}
+
+/// `tcgetpgrp(fd)`—Returns the foreground process group of a terminal.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/tcgetpgrp.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/tcgetpgrp.3.html
+#[inline]
+#[doc(alias = "TIOCGPGRP")]
+pub fn tcgetpgrp<Fd: AsFd>(fd: &Fd) -> io::Result<Pid> {
+    let fd = fd.as_fd();
+    imp::syscalls::tcgetpgrp(fd)
+}
+
+/// `tcsetpgrp(fd, pgrp)`—Makes a process group the foreground process group
+/// of a terminal.
+///
+/// `fd` must refer to the calling process' controlling terminal, and `pgrp`
+/// must be a process group in the same session.
+///
+/// If the calling process is in a background process group of the terminal,
+/// it is sent `SIGTTOU`, which by default stops it, unless it blocks or
+/// ignores `SIGTTOU`. So a shell typically ignores `SIGTTOU` before calling
+/// this.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/tcsetpgrp.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/tcsetpgrp.3.html
+#[inline]
+#[doc(alias = "TIOCSPGRP")]
+pub fn tcsetpgrp<Fd: AsFd>(fd: &Fd, pgrp: Pid) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::tcsetpgrp(fd, pgrp)
+}
+
+/// Replaces the bits of `field` that `known` covers with `flags`, leaving
+/// any other bits, such as platform-specific ones, unchanged.
+#[inline]
+fn replace_flags(field: &mut Tcflag, known: Tcflag, flags: Tcflag) {
+    *field = (*field & !known) | flags;
+}
+
+/// Portable accessors for the fields of a [`Termios`].
+///
+/// `Termios` is the platform's `struct termios`, so the types of its fields,
+/// and the indices of its control characters, vary between platforms. These
+/// methods hide those differences.
+pub trait TermiosExt: private::Sealed {
+    /// Returns the input modes, from `c_iflag`.
+    fn input_flags(&self) -> InputFlags;
+
+    /// Sets the input modes in `c_iflag`.
+    ///
+    /// Bits that [`InputFlags`] doesn't know about are left unchanged.
+    fn set_input_flags(&mut self, flags: InputFlags);
+
+    /// Returns the output modes, from `c_oflag`.
+    fn output_flags(&self) -> OutputFlags;
+
+    /// Sets the output modes in `c_oflag`.
+    ///
+    /// Bits that [`OutputFlags`] doesn't know about are left unchanged.
+    fn set_output_flags(&mut self, flags: OutputFlags);
+
+    /// Returns the control modes, from `c_cflag`.
+    fn control_flags(&self) -> ControlFlags;
+
+    /// Sets the control modes in `c_cflag`.
+    ///
+    /// Bits that [`ControlFlags`] doesn't know about, such as the baud rate
+    /// on some platforms, are left unchanged.
+    fn set_control_flags(&mut self, flags: ControlFlags);
+
+    /// Returns the local modes, from `c_lflag`.
+    fn local_flags(&self) -> LocalFlags;
+
+    /// Sets the local modes in `c_lflag`.
+    ///
+    /// Bits that [`LocalFlags`] doesn't know about are left unchanged.
+    fn set_local_flags(&mut self, flags: LocalFlags);
+
+    /// Tests whether canonical mode, `ICANON`, is enabled.
+    fn is_canonical(&self) -> bool;
+
+    /// Enables or disables canonical mode, `ICANON`.
+    ///
+    /// In non-canonical mode, reads are governed by
+    /// [`TermiosExt::set_vmin`] and [`TermiosExt::set_vtime`] instead of by
+    /// lines.
+    fn set_canonical(&mut self, canonical: bool);
+
+    /// Tests whether echoing of input characters, `ECHO`, is enabled.
+    fn is_echo(&self) -> bool;
+
+    /// Enables or disables echoing of input characters, `ECHO`.
+    fn set_echo(&mut self, echo: bool);
+
+    /// Returns `c_cc[VMIN]`, the minimum number of bytes for a
+    /// non-canonical read.
+    fn vmin(&self) -> u8;
+
+    /// Sets `c_cc[VMIN]`, the minimum number of bytes for a non-canonical
+    /// read.
+    fn set_vmin(&mut self, vmin: u8);
+
+    /// Returns `c_cc[VTIME]`, the timeout for a non-canonical read, in
+    /// tenths of a second.
+    fn vtime(&self) -> u8;
+
+    /// Sets `c_cc[VTIME]`, the timeout for a non-canonical read, in tenths
+    /// of a second.
+    fn set_vtime(&mut self, vtime: u8);
+
+    /// `cfmakeraw(termios)`—Puts the terminal into "raw" mode: input is
+    /// available byte by byte, with no echoing, and no special processing
+    /// of input or output characters.
+    ///
+    /// # References
+    ///  - [Linux]
+    ///
+    /// [Linux]: https://man7.org/linux/man-pages/man3/cfmakeraw.3.html
+    #[doc(alias = "cfmakeraw")]
+    fn make_raw(&mut self);
+}
+
+impl TermiosExt for Termios {
+    #[inline]
+    fn input_flags(&self) -> InputFlags {
+        InputFlags::from_bits_truncate(self.c_iflag)
+    }
+
+    #[inline]
+    fn set_input_flags(&mut self, flags: InputFlags) {
+        replace_flags(&mut self.c_iflag, InputFlags::all().bits(), flags.bits());
+    }
+
+    #[inline]
+    fn output_flags(&self) -> OutputFlags {
+        OutputFlags::from_bits_truncate(self.c_oflag)
+    }
+
+    #[inline]
+    fn set_output_flags(&mut self, flags: OutputFlags) {
+        replace_flags(&mut self.c_oflag, OutputFlags::all().bits(), flags.bits());
+    }
+
+    #[inline]
+    fn control_flags(&self) -> ControlFlags {
+        ControlFlags::from_bits_truncate(self.c_cflag)
+    }
+
+    #[inline]
+    fn set_control_flags(&mut self, flags: ControlFlags) {
+        replace_flags(&mut self.c_cflag, ControlFlags::all().bits(), flags.bits());
+    }
+
+    #[inline]
+    fn local_flags(&self) -> LocalFlags {
+        LocalFlags::from_bits_truncate(self.c_lflag)
+    }
+
+    #[inline]
+    fn set_local_flags(&mut self, flags: LocalFlags) {
+        replace_flags(&mut self.c_lflag, LocalFlags::all().bits(), flags.bits());
+    }
+
+    #[inline]
+    fn is_canonical(&self) -> bool {
+        self.local_flags().contains(LocalFlags::ICANON)
+    }
+
+    #[inline]
+    fn set_canonical(&mut self, canonical: bool) {
+        let mut flags = self.local_flags();
+        flags.set(LocalFlags::ICANON, canonical);
+        self.set_local_flags(flags);
+    }
+
+    #[inline]
+    fn is_echo(&self) -> bool {
+        self.local_flags().contains(LocalFlags::ECHO)
+    }
+
+    #[inline]
+    fn set_echo(&mut self, echo: bool) {
+        let mut flags = self.local_flags();
+        flags.set(LocalFlags::ECHO, echo);
+        self.set_local_flags(flags);
+    }
+
+    #[inline]
+    fn vmin(&self) -> u8 {
+        self.c_cc[imp::io::VMIN]
+    }
+
+    #[inline]
+    fn set_vmin(&mut self, vmin: u8) {
+        self.c_cc[imp::io::VMIN] = vmin;
+    }
+
+    #[inline]
+    fn vtime(&self) -> u8 {
+        self.c_cc[imp::io::VTIME]
+    }
+
+    #[inline]
+    fn set_vtime(&mut self, vtime: u8) {
+        self.c_cc[imp::io::VTIME] = vtime;
+    }
+
+    fn make_raw(&mut self) {
+        self.set_input_flags(
+            self.input_flags()
+                - (InputFlags::IGNBRK
+                    | InputFlags::BRKINT
+                    | InputFlags::PARMRK
+                    | InputFlags::ISTRIP
+                    | InputFlags::INLCR
+                    | InputFlags::IGNCR
+                    | InputFlags::ICRNL
+                    | InputFlags::IXON),
+        );
+        self.set_output_flags(self.output_flags() - OutputFlags::OPOST);
+        self.set_local_flags(
+            self.local_flags()
+                - (LocalFlags::ECHO
+                    | LocalFlags::ECHONL
+                    | LocalFlags::ICANON
+                    | LocalFlags::ISIG
+                    | LocalFlags::IEXTEN),
+        );
+        self.set_control_flags(
+            (self.control_flags() - (ControlFlags::CSIZE | ControlFlags::PARENB))
+                | ControlFlags::CS8,
+        );
+        self.set_vmin(1);
+        self.set_vtime(0);
+    }
+}
+
+/// Seal `TermiosExt` using the technique documented [here].
+///
+/// [here]: https://rust-lang.github.io/api-guidelines/future-proofing.html
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for super::Termios {}
+}
@@ -2,18 +2,27 @@
 
 use crate::imp;
 
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+mod msg;
 mod send_recv;
 mod socket;
 #[cfg(not(target_os = "wasi"))]
 mod socketpair;
+pub mod sockopt;
 
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub use msg::{
+    cmsg_space, recvfrom_flags, recvmsg, sendmsg, AncillaryDrain, RecvAncillaryBuffer,
+    RecvAncillaryMessage, RecvMsgReturn, ReturnFlags, ScmRights, SendAncillaryBuffer,
+};
 pub use send_recv::{
-    recv, recvfrom, send, sendto_unix, sendto_v4, sendto_v6, RecvFlags, SendFlags,
+    recv, recv_uninit, recvfrom, send, sendto, sendto_unix, sendto_v4, sendto_v6, RecvFlags,
+    SendFlags,
 };
 pub use socket::{
-    accept, accept_with, acceptfrom, acceptfrom_with, bind_unix, bind_v4, bind_v6, connect_unix,
-    connect_v4, connect_v6, getpeername, getsockname, getsockopt_socket_type, listen, shutdown,
-    socket, AcceptFlags, AddressFamily, Protocol, SocketType,
+    accept, accept_with, acceptfrom, acceptfrom_with, bind, bind_unix, bind_v4, bind_v6, connect,
+    connect_unix, connect_v4, connect_v6, getpeername, getsockname, getsockopt_socket_type, listen,
+    shutdown, socket, AcceptFlags, AddressFamily, Protocol, Shutdown, SocketType,
 };
 #[cfg(not(target_os = "wasi"))]
 pub use socketpair::socketpair;
@@ -0,0 +1,314 @@
+//! `sendmsg` and `recvmsg`, and the ancillary data they carry.
+//!
+//! # Safety
+//!
+//! File descriptors received in `SCM_RIGHTS` messages are installed in the
+//! process by the kernel and belong to no one else, so we take ownership of
+//! them with `from_raw_fd`.
+#![allow(unsafe_code)]
+
+use crate::imp;
+use crate::io::{self, AsRawFd, FromRawFd, OwnedFd, RawFd};
+use crate::net::{RecvFlags, SendFlags, SocketAddr};
+use io_lifetimes::{AsFd, BorrowedFd};
+use std::convert::TryInto;
+use std::io::{IoSlice, IoSliceMut};
+use std::marker::PhantomData;
+use std::mem::size_of;
+use std::os::raw::c_int;
+
+pub use imp::net::ReturnFlags;
+
+/// `CMSG_ALIGN(len)`—Rounds `len` up to the alignment of a `cmsghdr`.
+const fn cmsg_align(len: usize) -> usize {
+    (len + size_of::<usize>() - 1) & !(size_of::<usize>() - 1)
+}
+
+/// The size of a `struct cmsghdr`: the `cmsg_len`, `cmsg_level`, and
+/// `cmsg_type` fields, padded out to the alignment of the data after it.
+const CMSG_HEADER_LEN: usize = cmsg_align(size_of::<usize>() + 2 * size_of::<c_int>());
+
+/// `CMSG_SPACE(len)`—Returns the number of bytes an ancillary message with
+/// `len` bytes of data occupies in an ancillary buffer, including its header
+/// and padding.
+#[inline]
+pub const fn cmsg_space(len: usize) -> usize {
+    CMSG_HEADER_LEN + cmsg_align(len)
+}
+
+/// A buffer of ancillary messages to send with [`sendmsg`].
+///
+/// The `'fd` lifetime ties the buffer to the file descriptors passed to
+/// [`SendAncillaryBuffer::push_fds`], so that they stay open until the
+/// buffer is sent.
+#[derive(Debug, Default)]
+pub struct SendAncillaryBuffer<'fd> {
+    buf: Vec<u8>,
+    _fds: PhantomData<BorrowedFd<'fd>>,
+}
+
+impl<'fd> SendAncillaryBuffer<'fd> {
+    /// Construct a new, empty, ancillary buffer.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends an `SCM_RIGHTS` message, which sends duplicates of `fds` to
+    /// the receiver.
+    pub fn push_fds(&mut self, fds: &[BorrowedFd<'fd>]) {
+        let len = fds.len() * size_of::<RawFd>();
+        let start = self.buf.len();
+
+        self.buf
+            .extend_from_slice(&(CMSG_HEADER_LEN + len).to_ne_bytes());
+        self.buf
+            .extend_from_slice(&imp::net::SOL_SOCKET.to_ne_bytes());
+        self.buf
+            .extend_from_slice(&imp::net::SCM_RIGHTS.to_ne_bytes());
+        self.buf.resize(start + CMSG_HEADER_LEN, 0);
+        for fd in fds {
+            self.buf.extend_from_slice(&fd.as_raw_fd().to_ne_bytes());
+        }
+        self.buf.resize(start + cmsg_space(len), 0);
+    }
+
+    /// Removes all the messages from the buffer.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.buf.clear();
+    }
+}
+
+/// A buffer for receiving ancillary messages with [`recvmsg`].
+///
+/// File descriptors received in `SCM_RIGHTS` messages are closed if they
+/// aren't taken out of the buffer with [`RecvAncillaryBuffer::drain`] before
+/// the buffer is dropped or reused.
+#[derive(Debug)]
+pub struct RecvAncillaryBuffer {
+    buf: Vec<u8>,
+    /// The start of the messages that haven't been drained yet.
+    read: usize,
+    /// The end of the messages received by the last `recvmsg`.
+    len: usize,
+}
+
+impl RecvAncillaryBuffer {
+    /// Construct a buffer which can hold `capacity` bytes of ancillary
+    /// messages, including their headers.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buf: vec![0; capacity],
+            read: 0,
+            len: 0,
+        }
+    }
+
+    /// Construct a buffer large enough to receive a single `SCM_RIGHTS`
+    /// message carrying up to `count` file descriptors.
+    #[inline]
+    pub fn for_fds(count: usize) -> Self {
+        Self::with_capacity(cmsg_space(count * size_of::<RawFd>()))
+    }
+
+    /// Returns an iterator over the messages received by the last
+    /// [`recvmsg`], which removes them from the buffer.
+    #[inline]
+    pub fn drain(&mut self) -> AncillaryDrain<'_> {
+        AncillaryDrain {
+            bytes: &self.buf[self.read..self.len],
+            read: &mut self.read,
+        }
+    }
+}
+
+impl Drop for RecvAncillaryBuffer {
+    fn drop(&mut self) {
+        self.drain().for_each(drop);
+    }
+}
+
+/// An ancillary message received with [`recvmsg`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum RecvAncillaryMessage<'buf> {
+    /// `SCM_RIGHTS`—File descriptors sent by the peer.
+    ScmRights(ScmRights<'buf>),
+}
+
+/// An iterator over the messages in a [`RecvAncillaryBuffer`], returned by
+/// [`RecvAncillaryBuffer::drain`].
+///
+/// Messages of types this iterator doesn't know about are skipped. Messages
+/// that aren't iterated over are dropped when the iterator is dropped.
+#[derive(Debug)]
+pub struct AncillaryDrain<'buf> {
+    bytes: &'buf [u8],
+    read: &'buf mut usize,
+}
+
+impl<'buf> Iterator for AncillaryDrain<'buf> {
+    type Item = RecvAncillaryMessage<'buf>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.bytes.len() < CMSG_HEADER_LEN {
+                return None;
+            }
+
+            let (header, _) = self.bytes.split_at(CMSG_HEADER_LEN);
+            let (len, rest) = header.split_at(size_of::<usize>());
+            let (level, rest) = rest.split_at(size_of::<c_int>());
+            let len = usize::from_ne_bytes(len.try_into().unwrap());
+            let level = c_int::from_ne_bytes(level.try_into().unwrap());
+            let type_ = c_int::from_ne_bytes(rest[..size_of::<c_int>()].try_into().unwrap());
+            if len < CMSG_HEADER_LEN || len > self.bytes.len() {
+                return None;
+            }
+
+            // Consume the message, and its padding, before handing it out, so
+            // that it isn't handed out again.
+            let data = &self.bytes[CMSG_HEADER_LEN..len];
+            let consumed = cmsg_align(len).min(self.bytes.len());
+            self.bytes = &self.bytes[consumed..];
+            *self.read += consumed;
+
+            if level == imp::net::SOL_SOCKET && type_ == imp::net::SCM_RIGHTS {
+                return Some(RecvAncillaryMessage::ScmRights(ScmRights { data }));
+            }
+        }
+    }
+}
+
+impl Drop for AncillaryDrain<'_> {
+    fn drop(&mut self) {
+        self.for_each(drop);
+    }
+}
+
+/// An iterator over the file descriptors in an `SCM_RIGHTS` message.
+///
+/// File descriptors that aren't iterated over are closed when the iterator
+/// is dropped.
+#[derive(Debug)]
+pub struct ScmRights<'buf> {
+    data: &'buf [u8],
+}
+
+impl Iterator for ScmRights<'_> {
+    type Item = OwnedFd;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.len() < size_of::<RawFd>() {
+            return None;
+        }
+
+        let (raw_fd, rest) = self.data.split_at(size_of::<RawFd>());
+        self.data = rest;
+        let raw_fd = RawFd::from_ne_bytes(raw_fd.try_into().unwrap());
+
+        // Safety: The kernel installed this file descriptor for us, and
+        // we've advanced past it so that it's never taken again.
+        Some(OwnedFd::from(unsafe {
+            io_lifetimes::OwnedFd::from_raw_fd(raw_fd)
+        }))
+    }
+}
+
+impl Drop for ScmRights<'_> {
+    fn drop(&mut self) {
+        self.for_each(drop);
+    }
+}
+
+/// The result of a successful [`recvmsg`] call.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct RecvMsgReturn {
+    /// The number of bytes received.
+    pub bytes: usize,
+
+    /// The flags the kernel returned in `msg_flags`.
+    ///
+    /// [`ReturnFlags::CTRUNC`] indicates that some ancillary messages didn't
+    /// fit in the ancillary buffer and were discarded; any file descriptors
+    /// they carried were closed. [`ReturnFlags::TRUNC`] indicates the same
+    /// for the data of a datagram.
+    pub flags: ReturnFlags,
+}
+
+/// `recvmsg(fd, msg, flags)`—Reads a datagram into `buf`, and returns its
+/// length, the flags the kernel returned, and the sender address.
+///
+/// This is like [`recvfrom`], but [`ReturnFlags::TRUNC`] in the returned
+/// flags indicates that the datagram was longer than `buf` and the rest of
+/// it was discarded. The address is `None` if the socket didn't report one,
+/// as on connected stream sockets.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/recvmsg.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/recvmsg.2.html
+/// [`recvfrom`]: crate::net::recvfrom
+#[inline]
+#[doc(alias = "recvmsg")]
+#[doc(alias = "recvfrom")]
+pub fn recvfrom_flags<Fd: AsFd>(
+    fd: &Fd,
+    buf: &mut [u8],
+    flags: RecvFlags,
+) -> io::Result<(usize, ReturnFlags, Option<SocketAddr>)> {
+    let fd = fd.as_fd();
+    imp::syscalls::recvfrom_flags(fd, buf, flags)
+}
+
+/// `sendmsg(fd, msg, flags)`—Sends the data in `iov`, along with the
+/// ancillary messages in `control`, on a connected socket.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/sendmsg.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/sendmsg.2.html
+#[inline]
+pub fn sendmsg<Fd: AsFd>(
+    fd: &Fd,
+    iov: &[IoSlice<'_>],
+    control: &mut SendAncillaryBuffer<'_>,
+    flags: SendFlags,
+) -> io::Result<usize> {
+    let fd = fd.as_fd();
+    imp::syscalls::sendmsg(fd, iov, &control.buf, flags)
+}
+
+/// `recvmsg(fd, msg, flags)`—Receives data into `iov`, and ancillary
+/// messages into `control`, from a socket.
+///
+/// Any messages left in `control` from a previous call are dropped first.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/recvmsg.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/recvmsg.2.html
+#[inline]
+pub fn recvmsg<Fd: AsFd>(
+    fd: &Fd,
+    iov: &mut [IoSliceMut<'_>],
+    control: &mut RecvAncillaryBuffer,
+    flags: RecvFlags,
+) -> io::Result<RecvMsgReturn> {
+    let fd = fd.as_fd();
+    control.drain().for_each(drop);
+    control.read = 0;
+    control.len = 0;
+
+    let (bytes, len, flags) = imp::syscalls::recvmsg(fd, iov, &mut control.buf, flags)?;
+    control.len = len.min(control.buf.len());
+    Ok(RecvMsgReturn { bytes, flags })
+}
@@ -3,11 +3,16 @@
 use crate::net::{SocketAddr, SocketAddrUnix, SocketAddrV4, SocketAddrV6};
 use crate::{imp, io};
 use io_lifetimes::AsFd;
+use std::mem::MaybeUninit;
 
 pub use imp::net::{RecvFlags, SendFlags};
 
 /// `recv(fd, buf, flags)`—Reads data from a socket.
 ///
+/// If a datagram is larger than `buf`, the rest of it is discarded. With
+/// [`RecvFlags::TRUNC`] in `flags`, this returns the datagram's full length
+/// in that case, which may be more than `buf.len()`.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -20,6 +25,32 @@ pub fn recv<Fd: AsFd>(fd: &Fd, buf: &mut [u8], flags: RecvFlags) -> io::Result<u
     imp::syscalls::recv(fd, buf, flags)
 }
 
+/// `recv(fd, buf, flags)`—Reads data from a socket into a buffer that may be
+/// uninitialized.
+///
+/// Like [`read_uninit`], this returns the part of `buf` that was read into,
+/// which is now initialized, followed by the rest of `buf`. With
+/// [`RecvFlags::TRUNC`], the first part is still limited to `buf`; use
+/// [`recv`] to learn a truncated datagram's full length.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/recv.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/recv.2.html
+/// [`read_uninit`]: crate::io::read_uninit
+#[inline]
+#[doc(alias = "recv")]
+pub fn recv_uninit<'buf, Fd: AsFd>(
+    fd: &Fd,
+    buf: &'buf mut [MaybeUninit<u8>],
+    flags: RecvFlags,
+) -> io::Result<(&'buf mut [u8], &'buf mut [MaybeUninit<u8>])> {
+    let fd = fd.as_fd();
+    imp::syscalls::recv_uninit(fd, buf, flags)
+}
+
 /// `send(fd, buf, flags)`—Writes data to a socket.
 ///
 /// # References
@@ -37,12 +68,17 @@ pub fn send<Fd: AsFd>(fd: &Fd, buf: &[u8], flags: SendFlags) -> io::Result<usize
 /// `recvfrom(fd, buf, flags, addr, len)`—Reads data from a socket and
 /// returns the sender address.
 ///
+/// As with [`recv`], [`RecvFlags::TRUNC`] makes this return the full length
+/// of a datagram that didn't fit in `buf`. To be told about truncation
+/// without that, use [`recvfrom_flags`].
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
 ///
 /// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/recvfrom.html
 /// [Linux]: https://man7.org/linux/man-pages/man2/recvfrom.2.html
+/// [`recvfrom_flags`]: crate::net::recvfrom_flags
 #[inline]
 pub fn recvfrom<Fd: AsFd>(
     fd: &Fd,
@@ -53,6 +89,32 @@ pub fn recvfrom<Fd: AsFd>(
     imp::syscalls::recvfrom(fd, buf, flags)
 }
 
+/// `sendto(fd, buf, flags, addr, addrlen)`—Writes data to a socket to a
+/// specific address.
+///
+/// This dispatches to [`sendto_v4`], [`sendto_v6`], or [`sendto_unix`]
+/// according to the kind of address.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/sendto.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/sendto.2.html
+#[inline]
+pub fn sendto<Fd: AsFd>(
+    fd: &Fd,
+    buf: &[u8],
+    flags: SendFlags,
+    addr: &SocketAddr,
+) -> io::Result<usize> {
+    match addr {
+        SocketAddr::V4(v4) => sendto_v4(fd, buf, flags, v4),
+        SocketAddr::V6(v6) => sendto_v6(fd, buf, flags, v6),
+        SocketAddr::Unix(unix) => sendto_unix(fd, buf, flags, unix),
+    }
+}
+
 /// `sendto(fd, buf, flags, addr, sizeof(struct sockaddr_in))`—Writes data to
 /// a socket to a specific IPv4 address.
 ///
@@ -115,5 +177,3 @@ pub fn sendto_unix<Fd: AsFd>(
     let fd = fd.as_fd();
     imp::syscalls::sendto_unix(fd, buf, flags, addr)
 }
-
-// TODO: `recvmsg`, `sendmsg`
@@ -2,6 +2,7 @@ use crate::imp;
 use crate::io::{self, OwnedFd};
 use crate::net::{SocketAddr, SocketAddrUnix, SocketAddrV4, SocketAddrV6};
 use io_lifetimes::AsFd;
+use std::fmt;
 
 pub use imp::net::{AcceptFlags, AddressFamily, Protocol, Shutdown, SocketType};
 
@@ -12,6 +13,38 @@ impl Default for Protocol {
     }
 }
 
+impl fmt::Debug for SocketType {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::STREAM => fmt.write_str("STREAM"),
+            Self::DGRAM => fmt.write_str("DGRAM"),
+            Self::SEQPACKET => fmt.write_str("SEQPACKET"),
+            Self::RAW => fmt.write_str("RAW"),
+            Self::RDM => fmt.write_str("RDM"),
+            Self(raw) => write!(fmt, "SocketType({})", raw),
+        }
+    }
+}
+
+impl fmt::Debug for AddressFamily {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::INET => fmt.write_str("INET"),
+            Self::INET6 => fmt.write_str("INET6"),
+            #[cfg(not(any(
+                target_os = "freebsd",
+                target_os = "ios",
+                target_os = "macos",
+                target_os = "netbsd",
+                target_os = "openbsd",
+            )))]
+            Self::NETLINK => fmt.write_str("NETLINK"),
+            Self::UNIX => fmt.write_str("UNIX"),
+            Self(raw) => write!(fmt, "AddressFamily({})", raw),
+        }
+    }
+}
+
 /// `socket(domain, type_, protocol)`—Creates a socket.
 ///
 /// POSIX guarantees that `socket` will use the lowest unused file descriptor,
@@ -29,6 +62,26 @@ pub fn socket(domain: AddressFamily, type_: SocketType, protocol: Protocol) -> i
     imp::syscalls::socket(domain, type_, protocol)
 }
 
+/// `bind(sockfd, addr, addrlen)`—Binds a socket to an address.
+///
+/// This dispatches to [`bind_v4`], [`bind_v6`], or [`bind_unix`] according
+/// to the kind of address.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/bind.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/bind.2.html
+#[inline]
+pub fn bind<Fd: AsFd>(sockfd: &Fd, addr: &SocketAddr) -> io::Result<()> {
+    match addr {
+        SocketAddr::V4(v4) => bind_v4(sockfd, v4),
+        SocketAddr::V6(v6) => bind_v6(sockfd, v6),
+        SocketAddr::Unix(unix) => bind_unix(sockfd, unix),
+    }
+}
+
 /// `bind(sockfd, addr, sizeof(struct sockaddr_in))`—Binds a socket to an
 /// address.
 ///
@@ -77,6 +130,26 @@ pub fn bind_unix<Fd: AsFd>(sockfd: &Fd, addr: &SocketAddrUnix) -> io::Result<()>
     imp::syscalls::bind_unix(sockfd, addr)
 }
 
+/// `connect(sockfd, addr, addrlen)`—Initiates a connection.
+///
+/// This dispatches to [`connect_v4`], [`connect_v6`], or [`connect_unix`]
+/// according to the kind of address.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/connect.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/connect.2.html
+#[inline]
+pub fn connect<Fd: AsFd>(sockfd: &Fd, addr: &SocketAddr) -> io::Result<()> {
+    match addr {
+        SocketAddr::V4(v4) => connect_v4(sockfd, v4),
+        SocketAddr::V6(v6) => connect_v6(sockfd, v6),
+        SocketAddr::Unix(unix) => connect_unix(sockfd, unix),
+    }
+}
+
 /// `connect(sockfd, addr, sizeof(struct sockaddr_in))`—Initiates a
 /// connection.
 ///
@@ -202,13 +275,15 @@ pub fn acceptfrom<Fd: AsFd>(sockfd: &Fd) -> io::Result<(OwnedFd, SocketAddr)> {
     imp::syscalls::acceptfrom(sockfd)
 }
 
-/// `accept4(fd, &addr, &len, flags)—Accepts an incoming connection and
+/// `accept4(fd, &addr, &len, flags)`—Accepts an incoming connection and
 /// returns the peer address, with flags.
 ///
 /// Use [`accept_with`] if the peer address isn't needed.
 ///
 /// `acceptfrom_with` is the same as `acceptfrom` but adds an additional flags
-/// operand.
+/// operand. The flags apply to the returned file descriptor, so for example
+/// [`AcceptFlags::NONBLOCK`] puts it in non-blocking mode; they don't affect
+/// the listening socket.
 ///
 /// # References
 ///  - [POSIX]
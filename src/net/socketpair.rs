@@ -2,7 +2,14 @@ use crate::imp;
 use crate::io::{self, OwnedFd};
 use crate::net::{AcceptFlags, AddressFamily, Protocol, SocketType};
 
-/// `socketpair(domain, type_ | accept_flags, protocol)`
+/// `socketpair(domain, type_ | accept_flags, protocol)`—Creates a pair of
+/// connected sockets.
+///
+/// `accept_flags` may contain [`AcceptFlags::CLOEXEC`] and
+/// [`AcceptFlags::NONBLOCK`], which apply to both returned sockets, as with
+/// [`accept_with`].
+///
+/// [`accept_with`]: crate::net::accept_with
 ///
 /// # References
 ///  - [POSIX]
@@ -0,0 +1,484 @@
+//! `getsockopt` and `setsockopt` functions.
+//!
+//! Each socket option has its own typed getter and setter here, rather than
+//! exposing the raw `getsockopt` and `setsockopt` calls, which take untyped
+//! buffers.
+
+use crate::net::{Ipv4Addr, Ipv6Addr};
+use crate::{imp, io};
+use io_lifetimes::AsFd;
+use std::time::Duration;
+
+/// `setsockopt(fd, SOL_SOCKET, SO_REUSEADDR, reuseaddr)`—Sets whether a
+/// socket may bind to an address that's still in use by a socket in
+/// `TIME_WAIT` state.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/setsockopt.html
+/// [Linux]: https://man7.org/linux/man-pages/man7/socket.7.html
+#[inline]
+#[doc(alias = "SO_REUSEADDR")]
+pub fn set_reuseaddr<Fd: AsFd>(fd: &Fd, reuseaddr: bool) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::set_reuseaddr(fd, reuseaddr)
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_REUSEADDR)`—Returns whether `SO_REUSEADDR`
+/// is enabled on a socket.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getsockopt.html
+/// [Linux]: https://man7.org/linux/man-pages/man7/socket.7.html
+#[inline]
+#[doc(alias = "SO_REUSEADDR")]
+pub fn get_reuseaddr<Fd: AsFd>(fd: &Fd) -> io::Result<bool> {
+    let fd = fd.as_fd();
+    imp::syscalls::get_reuseaddr(fd)
+}
+
+/// `setsockopt(fd, IPPROTO_TCP, TCP_NODELAY, nodelay)`—Sets whether a TCP
+/// socket sends small segments immediately, rather than coalescing them
+/// with Nagle's algorithm.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/setsockopt.html
+/// [Linux]: https://man7.org/linux/man-pages/man7/tcp.7.html
+#[inline]
+#[doc(alias = "TCP_NODELAY")]
+pub fn set_tcp_nodelay<Fd: AsFd>(fd: &Fd, nodelay: bool) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::set_tcp_nodelay(fd, nodelay)
+}
+
+/// `getsockopt(fd, IPPROTO_TCP, TCP_NODELAY)`—Returns whether `TCP_NODELAY`
+/// is enabled on a TCP socket.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getsockopt.html
+/// [Linux]: https://man7.org/linux/man-pages/man7/tcp.7.html
+#[inline]
+#[doc(alias = "TCP_NODELAY")]
+pub fn get_tcp_nodelay<Fd: AsFd>(fd: &Fd) -> io::Result<bool> {
+    let fd = fd.as_fd();
+    imp::syscalls::get_tcp_nodelay(fd)
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_RCVBUF, size)`—Sets the size of a
+/// socket's receive buffer.
+///
+/// Linux doubles `size` to make room for its own bookkeeping, and
+/// [`get_recv_buffer_size`] returns the doubled value.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/setsockopt.html
+/// [Linux]: https://man7.org/linux/man-pages/man7/socket.7.html
+#[inline]
+#[doc(alias = "SO_RCVBUF")]
+pub fn set_recv_buffer_size<Fd: AsFd>(fd: &Fd, size: usize) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::set_recv_buffer_size(fd, size)
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_RCVBUF)`—Returns the size of a socket's
+/// receive buffer.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getsockopt.html
+/// [Linux]: https://man7.org/linux/man-pages/man7/socket.7.html
+#[inline]
+#[doc(alias = "SO_RCVBUF")]
+pub fn get_recv_buffer_size<Fd: AsFd>(fd: &Fd) -> io::Result<usize> {
+    let fd = fd.as_fd();
+    imp::syscalls::get_recv_buffer_size(fd)
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_SNDBUF, size)`—Sets the size of a
+/// socket's send buffer.
+///
+/// Linux doubles `size` to make room for its own bookkeeping, and
+/// [`get_send_buffer_size`] returns the doubled value.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/setsockopt.html
+/// [Linux]: https://man7.org/linux/man-pages/man7/socket.7.html
+#[inline]
+#[doc(alias = "SO_SNDBUF")]
+pub fn set_send_buffer_size<Fd: AsFd>(fd: &Fd, size: usize) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::set_send_buffer_size(fd, size)
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_SNDBUF)`—Returns the size of a socket's
+/// send buffer.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getsockopt.html
+/// [Linux]: https://man7.org/linux/man-pages/man7/socket.7.html
+#[inline]
+#[doc(alias = "SO_SNDBUF")]
+pub fn get_send_buffer_size<Fd: AsFd>(fd: &Fd) -> io::Result<usize> {
+    let fd = fd.as_fd();
+    imp::syscalls::get_send_buffer_size(fd)
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_LINGER, linger)`—Sets how long `close`
+/// waits for unsent data to be sent, or disables lingering if `linger` is
+/// `None`.
+///
+/// The duration is rounded up to whole seconds.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/setsockopt.html
+/// [Linux]: https://man7.org/linux/man-pages/man7/socket.7.html
+#[inline]
+#[doc(alias = "SO_LINGER")]
+pub fn set_socket_linger<Fd: AsFd>(fd: &Fd, linger: Option<Duration>) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::set_socket_linger(fd, linger)
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_LINGER)`—Returns how long `close` waits
+/// for unsent data to be sent, or `None` if lingering is disabled.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getsockopt.html
+/// [Linux]: https://man7.org/linux/man-pages/man7/socket.7.html
+#[inline]
+#[doc(alias = "SO_LINGER")]
+pub fn get_socket_linger<Fd: AsFd>(fd: &Fd) -> io::Result<Option<Duration>> {
+    let fd = fd.as_fd();
+    imp::syscalls::get_socket_linger(fd)
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_KEEPALIVE, keepalive)`—Sets whether a
+/// connection-oriented socket sends keepalive probes.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/setsockopt.html
+/// [Linux]: https://man7.org/linux/man-pages/man7/socket.7.html
+#[inline]
+#[doc(alias = "SO_KEEPALIVE")]
+pub fn set_keepalive<Fd: AsFd>(fd: &Fd, keepalive: bool) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::set_keepalive(fd, keepalive)
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_KEEPALIVE)`—Returns whether `SO_KEEPALIVE`
+/// is enabled on a socket.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getsockopt.html
+/// [Linux]: https://man7.org/linux/man-pages/man7/socket.7.html
+#[inline]
+#[doc(alias = "SO_KEEPALIVE")]
+pub fn get_keepalive<Fd: AsFd>(fd: &Fd) -> io::Result<bool> {
+    let fd = fd.as_fd();
+    imp::syscalls::get_keepalive(fd)
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_ERROR)`—Returns and clears a socket's
+/// pending error.
+///
+/// The outer `Result` reports whether the `getsockopt` call itself failed;
+/// the inner one is `Err` if the socket had a pending error. This is how to
+/// find out whether a non-blocking [`connect`] succeeded, once the socket
+/// polls as writable.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getsockopt.html
+/// [Linux]: https://man7.org/linux/man-pages/man7/socket.7.html
+/// [`connect`]: crate::net::connect
+#[inline]
+#[doc(alias = "SO_ERROR")]
+pub fn get_socket_error<Fd: AsFd>(fd: &Fd) -> io::Result<Result<(), io::Error>> {
+    let fd = fd.as_fd();
+    imp::syscalls::get_socket_error(fd)
+}
+
+/// `setsockopt(fd, IPPROTO_TCP, TCP_KEEPIDLE, idle)`—Sets how long a TCP
+/// connection must be idle before keepalive probes are sent.
+///
+/// The kernel counts in whole seconds, so `idle` is rounded up to the next
+/// second. A zero duration fails with [`io::Error::INVAL`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/tcp.7.html
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[inline]
+#[doc(alias = "TCP_KEEPIDLE")]
+pub fn set_tcp_keepidle<Fd: AsFd>(fd: &Fd, idle: Duration) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::set_tcp_keepidle(fd, idle)
+}
+
+/// `getsockopt(fd, IPPROTO_TCP, TCP_KEEPIDLE)`—Returns how long a TCP
+/// connection must be idle before keepalive probes are sent.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/tcp.7.html
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[inline]
+#[doc(alias = "TCP_KEEPIDLE")]
+pub fn get_tcp_keepidle<Fd: AsFd>(fd: &Fd) -> io::Result<Duration> {
+    let fd = fd.as_fd();
+    imp::syscalls::get_tcp_keepidle(fd)
+}
+
+/// `setsockopt(fd, IPPROTO_TCP, TCP_KEEPINTVL, interval)`—Sets the time
+/// between keepalive probes.
+///
+/// The kernel counts in whole seconds, so `interval` is rounded up to the
+/// next second. A zero duration fails with [`io::Error::INVAL`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/tcp.7.html
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[inline]
+#[doc(alias = "TCP_KEEPINTVL")]
+pub fn set_tcp_keepintvl<Fd: AsFd>(fd: &Fd, interval: Duration) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::set_tcp_keepintvl(fd, interval)
+}
+
+/// `getsockopt(fd, IPPROTO_TCP, TCP_KEEPINTVL)`—Returns the time between
+/// keepalive probes.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/tcp.7.html
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[inline]
+#[doc(alias = "TCP_KEEPINTVL")]
+pub fn get_tcp_keepintvl<Fd: AsFd>(fd: &Fd) -> io::Result<Duration> {
+    let fd = fd.as_fd();
+    imp::syscalls::get_tcp_keepintvl(fd)
+}
+
+/// `setsockopt(fd, IPPROTO_TCP, TCP_KEEPCNT, count)`—Sets how many
+/// unanswered keepalive probes are sent before the connection is dropped.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/tcp.7.html
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[inline]
+#[doc(alias = "TCP_KEEPCNT")]
+pub fn set_tcp_keepcnt<Fd: AsFd>(fd: &Fd, count: u32) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::set_tcp_keepcnt(fd, count)
+}
+
+/// `getsockopt(fd, IPPROTO_TCP, TCP_KEEPCNT)`—Returns how many unanswered
+/// keepalive probes are sent before the connection is dropped.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/tcp.7.html
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[inline]
+#[doc(alias = "TCP_KEEPCNT")]
+pub fn get_tcp_keepcnt<Fd: AsFd>(fd: &Fd) -> io::Result<u32> {
+    let fd = fd.as_fd();
+    imp::syscalls::get_tcp_keepcnt(fd)
+}
+
+/// `setsockopt(fd, IPPROTO_IP, IP_MULTICAST_LOOP, multicast_loop)`—Sets
+/// whether multicast datagrams sent from a socket are looped back to local
+/// sockets.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/setsockopt.html
+/// [Linux]: https://man7.org/linux/man-pages/man7/ip.7.html
+#[inline]
+#[doc(alias = "IP_MULTICAST_LOOP")]
+pub fn set_ip_multicast_loop<Fd: AsFd>(fd: &Fd, multicast_loop: bool) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::set_ip_multicast_loop(fd, multicast_loop)
+}
+
+/// `getsockopt(fd, IPPROTO_IP, IP_MULTICAST_LOOP)`—Returns whether
+/// multicast datagrams sent from a socket are looped back to local sockets.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getsockopt.html
+/// [Linux]: https://man7.org/linux/man-pages/man7/ip.7.html
+#[inline]
+#[doc(alias = "IP_MULTICAST_LOOP")]
+pub fn get_ip_multicast_loop<Fd: AsFd>(fd: &Fd) -> io::Result<bool> {
+    let fd = fd.as_fd();
+    imp::syscalls::get_ip_multicast_loop(fd)
+}
+
+/// `setsockopt(fd, IPPROTO_IP, IP_MULTICAST_TTL, ttl)`—Sets the time-to-live
+/// of multicast datagrams sent from a socket.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/setsockopt.html
+/// [Linux]: https://man7.org/linux/man-pages/man7/ip.7.html
+#[inline]
+#[doc(alias = "IP_MULTICAST_TTL")]
+pub fn set_ip_multicast_ttl<Fd: AsFd>(fd: &Fd, ttl: u32) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::set_ip_multicast_ttl(fd, ttl)
+}
+
+/// `getsockopt(fd, IPPROTO_IP, IP_MULTICAST_TTL)`—Returns the time-to-live
+/// of multicast datagrams sent from a socket.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getsockopt.html
+/// [Linux]: https://man7.org/linux/man-pages/man7/ip.7.html
+#[inline]
+#[doc(alias = "IP_MULTICAST_TTL")]
+pub fn get_ip_multicast_ttl<Fd: AsFd>(fd: &Fd) -> io::Result<u32> {
+    let fd = fd.as_fd();
+    imp::syscalls::get_ip_multicast_ttl(fd)
+}
+
+/// `setsockopt(fd, IPPROTO_IP, IP_ADD_MEMBERSHIP, mreq)`—Joins the IPv4
+/// multicast group `multiaddr` on the local interface with the address
+/// `interface`.
+///
+/// `interface` may be [`Ipv4Addr::UNSPECIFIED`] to let the system choose an
+/// interface.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/setsockopt.html
+/// [Linux]: https://man7.org/linux/man-pages/man7/ip.7.html
+#[inline]
+#[doc(alias = "IP_ADD_MEMBERSHIP")]
+pub fn join_multicast_v4<Fd: AsFd>(
+    fd: &Fd,
+    multiaddr: &Ipv4Addr,
+    interface: &Ipv4Addr,
+) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::join_multicast_v4(fd, multiaddr, interface)
+}
+
+/// `setsockopt(fd, IPPROTO_IP, IP_DROP_MEMBERSHIP, mreq)`—Leaves the IPv4
+/// multicast group `multiaddr` on the local interface with the address
+/// `interface`.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/setsockopt.html
+/// [Linux]: https://man7.org/linux/man-pages/man7/ip.7.html
+#[inline]
+#[doc(alias = "IP_DROP_MEMBERSHIP")]
+pub fn leave_multicast_v4<Fd: AsFd>(
+    fd: &Fd,
+    multiaddr: &Ipv4Addr,
+    interface: &Ipv4Addr,
+) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::leave_multicast_v4(fd, multiaddr, interface)
+}
+
+/// `setsockopt(fd, IPPROTO_IPV6, IPV6_ADD_MEMBERSHIP, mreq)`—Joins the IPv6
+/// multicast group `multiaddr` on the interface with the index `interface`.
+///
+/// `interface` may be 0 to let the system choose an interface.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/setsockopt.html
+/// [Linux]: https://man7.org/linux/man-pages/man7/ipv6.7.html
+#[inline]
+#[doc(alias = "IPV6_ADD_MEMBERSHIP")]
+pub fn join_multicast_v6<Fd: AsFd>(
+    fd: &Fd,
+    multiaddr: &Ipv6Addr,
+    interface: u32,
+) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::join_multicast_v6(fd, multiaddr, interface)
+}
+
+/// `setsockopt(fd, IPPROTO_IPV6, IPV6_DROP_MEMBERSHIP, mreq)`—Leaves the
+/// IPv6 multicast group `multiaddr` on the interface with the index
+/// `interface`.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/setsockopt.html
+/// [Linux]: https://man7.org/linux/man-pages/man7/ipv6.7.html
+#[inline]
+#[doc(alias = "IPV6_DROP_MEMBERSHIP")]
+pub fn leave_multicast_v6<Fd: AsFd>(
+    fd: &Fd,
+    multiaddr: &Ipv6Addr,
+    interface: u32,
+) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::leave_multicast_v6(fd, multiaddr, interface)
+}
@@ -11,7 +11,9 @@ use std::os::vxworks::ext::ffi::{OsStrExt, OsStringExt};
 #[cfg(target_os = "wasi")]
 use std::os::wasi::ffi::{OsStrExt, OsStringExt};
 use std::path::{Component, Components, Iter, Path, PathBuf};
+use std::rc::Rc;
 use std::str;
+use std::sync::Arc;
 
 /// A trait for passing path arguments.
 ///
@@ -39,6 +41,14 @@ use std::str;
 /// Users can then call `touch("foo")`, `touch(cstr!("foo"))`,
 /// `touch(Path::new("foo"))`, or many other things.
 ///
+/// Byte-slice arguments, such as `&[u8]` and `Vec<u8>`, may already end with
+/// a single NUL, as in `touch(&b"foo\0"[..])`; the NUL is then taken as the
+/// terminator rather than as part of the path, and borrowed arguments are
+/// used in place without allocating. This lets callers that keep paths in
+/// NUL-terminated form pass them without a copy. A path can't contain a NUL,
+/// so such arguments were previously rejected with [`io::Error::INVAL`], and
+/// no valid path changes meaning.
+///
 /// [`AsRef`]: std::convert::AsRef
 pub trait Arg {
     /// Returns a view of this string as a string slice.
@@ -231,6 +241,165 @@ impl Arg for String {
     }
 }
 
+impl Arg for Box<str> {
+    #[inline]
+    fn as_str(&self) -> io::Result<&str> {
+        Ok(self)
+    }
+
+    #[inline]
+    fn to_string_lossy(&self) -> Cow<str> {
+        Cow::Borrowed(self)
+    }
+
+    #[cfg(not(windows))]
+    #[inline]
+    fn as_cow_c_str(&self) -> io::Result<Cow<CStr>> {
+        Ok(Cow::Owned(
+            CString::new(self.as_bytes()).map_err(|_cstr_err| io::Error::INVAL)?,
+        ))
+    }
+
+    #[cfg(not(windows))]
+    #[inline]
+    fn into_c_str<'b>(self) -> io::Result<Cow<'b, CStr>>
+    where
+        Self: 'b,
+    {
+        Ok(Cow::Owned(
+            CString::new(self.into_string()).map_err(|_cstr_err| io::Error::INVAL)?,
+        ))
+    }
+
+    #[cfg(not(windows))]
+    #[inline]
+    fn as_maybe_utf8_bytes(&self) -> &[u8] {
+        self.as_bytes()
+    }
+
+    #[cfg(windows)]
+    #[inline]
+    fn as_os_str(&self) -> io::Result<Cow<OsStr>> {
+        self.as_ref()
+    }
+
+    #[cfg(not(windows))]
+    #[inline]
+    fn into_with_c_str<T, F>(self, f: F) -> io::Result<T>
+    where
+        Self: Sized,
+        F: FnOnce(&CStr) -> io::Result<T>,
+    {
+        with_c_str(self.as_bytes(), f)
+    }
+}
+
+impl Arg for Rc<str> {
+    #[inline]
+    fn as_str(&self) -> io::Result<&str> {
+        Ok(self)
+    }
+
+    #[inline]
+    fn to_string_lossy(&self) -> Cow<str> {
+        Cow::Borrowed(self)
+    }
+
+    #[cfg(not(windows))]
+    #[inline]
+    fn as_cow_c_str(&self) -> io::Result<Cow<CStr>> {
+        Ok(Cow::Owned(
+            CString::new(self.as_bytes()).map_err(|_cstr_err| io::Error::INVAL)?,
+        ))
+    }
+
+    #[cfg(not(windows))]
+    #[inline]
+    fn into_c_str<'b>(self) -> io::Result<Cow<'b, CStr>>
+    where
+        Self: 'b,
+    {
+        Ok(Cow::Owned(
+            CString::new(self.as_bytes()).map_err(|_cstr_err| io::Error::INVAL)?,
+        ))
+    }
+
+    #[cfg(not(windows))]
+    #[inline]
+    fn as_maybe_utf8_bytes(&self) -> &[u8] {
+        self.as_bytes()
+    }
+
+    #[cfg(windows)]
+    #[inline]
+    fn as_os_str(&self) -> io::Result<Cow<OsStr>> {
+        self.as_ref()
+    }
+
+    #[cfg(not(windows))]
+    #[inline]
+    fn into_with_c_str<T, F>(self, f: F) -> io::Result<T>
+    where
+        Self: Sized,
+        F: FnOnce(&CStr) -> io::Result<T>,
+    {
+        with_c_str(self.as_bytes(), f)
+    }
+}
+
+impl Arg for Arc<str> {
+    #[inline]
+    fn as_str(&self) -> io::Result<&str> {
+        Ok(self)
+    }
+
+    #[inline]
+    fn to_string_lossy(&self) -> Cow<str> {
+        Cow::Borrowed(self)
+    }
+
+    #[cfg(not(windows))]
+    #[inline]
+    fn as_cow_c_str(&self) -> io::Result<Cow<CStr>> {
+        Ok(Cow::Owned(
+            CString::new(self.as_bytes()).map_err(|_cstr_err| io::Error::INVAL)?,
+        ))
+    }
+
+    #[cfg(not(windows))]
+    #[inline]
+    fn into_c_str<'b>(self) -> io::Result<Cow<'b, CStr>>
+    where
+        Self: 'b,
+    {
+        Ok(Cow::Owned(
+            CString::new(self.as_bytes()).map_err(|_cstr_err| io::Error::INVAL)?,
+        ))
+    }
+
+    #[cfg(not(windows))]
+    #[inline]
+    fn as_maybe_utf8_bytes(&self) -> &[u8] {
+        self.as_bytes()
+    }
+
+    #[cfg(windows)]
+    #[inline]
+    fn as_os_str(&self) -> io::Result<Cow<OsStr>> {
+        self.as_ref()
+    }
+
+    #[cfg(not(windows))]
+    #[inline]
+    fn into_with_c_str<T, F>(self, f: F) -> io::Result<T>
+    where
+        Self: Sized,
+        F: FnOnce(&CStr) -> io::Result<T>,
+    {
+        with_c_str(self.as_bytes(), f)
+    }
+}
+
 impl Arg for &OsStr {
     #[inline]
     fn as_str(&self) -> io::Result<&str> {
@@ -1037,8 +1206,11 @@ impl Arg for &[u8] {
     #[cfg(not(windows))]
     #[inline]
     fn as_cow_c_str(&self) -> io::Result<Cow<CStr>> {
+        if let Some(c_str) = nul_terminated(self) {
+            return Ok(Cow::Borrowed(c_str));
+        }
         Ok(Cow::Owned(
-            CString::new(*self).map_err(|_cstr_err| io::Error::INVAL)?,
+            CString::new(&self[..]).map_err(|_cstr_err| io::Error::INVAL)?,
         ))
     }
 
@@ -1048,8 +1220,11 @@ impl Arg for &[u8] {
     where
         Self: 'b,
     {
+        if let Some(c_str) = nul_terminated(self) {
+            return Ok(Cow::Borrowed(c_str));
+        }
         Ok(Cow::Owned(
-            CString::new(self).map_err(|_cstr_err| io::Error::INVAL)?,
+            CString::new(self.to_vec()).map_err(|_cstr_err| io::Error::INVAL)?,
         ))
     }
 
@@ -1072,7 +1247,10 @@ impl Arg for &[u8] {
         Self: Sized,
         F: FnOnce(&CStr) -> io::Result<T>,
     {
-        with_c_str(self, f)
+        match nul_terminated(self) {
+            Some(c_str) => f(c_str),
+            None => with_c_str(self, f),
+        }
     }
 }
 
@@ -1090,8 +1268,11 @@ impl Arg for &Vec<u8> {
     #[cfg(not(windows))]
     #[inline]
     fn as_cow_c_str(&self) -> io::Result<Cow<CStr>> {
+        if let Some(c_str) = nul_terminated(self) {
+            return Ok(Cow::Borrowed(c_str));
+        }
         Ok(Cow::Owned(
-            CString::new(self.as_slice()).map_err(|_cstr_err| io::Error::INVAL)?,
+            CString::new(&self[..]).map_err(|_cstr_err| io::Error::INVAL)?,
         ))
     }
 
@@ -1101,8 +1282,11 @@ impl Arg for &Vec<u8> {
     where
         Self: 'b,
     {
+        if let Some(c_str) = nul_terminated(self) {
+            return Ok(Cow::Borrowed(c_str));
+        }
         Ok(Cow::Owned(
-            CString::new(self.as_slice()).map_err(|_cstr_err| io::Error::INVAL)?,
+            CString::new(self.to_vec()).map_err(|_cstr_err| io::Error::INVAL)?,
         ))
     }
 
@@ -1125,7 +1309,10 @@ impl Arg for &Vec<u8> {
         Self: Sized,
         F: FnOnce(&CStr) -> io::Result<T>,
     {
-        with_c_str(self, f)
+        match nul_terminated(self) {
+            Some(c_str) => f(c_str),
+            None => with_c_str(self, f),
+        }
     }
 }
 
@@ -1143,8 +1330,11 @@ impl Arg for Vec<u8> {
     #[cfg(not(windows))]
     #[inline]
     fn as_cow_c_str(&self) -> io::Result<Cow<CStr>> {
+        if let Some(c_str) = nul_terminated(self) {
+            return Ok(Cow::Borrowed(c_str));
+        }
         Ok(Cow::Owned(
-            CString::new(self.as_slice()).map_err(|_cstr_err| io::Error::INVAL)?,
+            CString::new(&self[..]).map_err(|_cstr_err| io::Error::INVAL)?,
         ))
     }
 
@@ -1154,8 +1344,13 @@ impl Arg for Vec<u8> {
     where
         Self: 'b,
     {
+        let mut bytes = self;
+        // Reuse the allocation, dropping the NUL that `CString` will re-add.
+        if nul_terminated(&bytes).is_some() {
+            bytes.pop();
+        }
         Ok(Cow::Owned(
-            CString::new(self).map_err(|_cstr_err| io::Error::INVAL)?,
+            CString::new(bytes).map_err(|_cstr_err| io::Error::INVAL)?,
         ))
     }
 
@@ -1178,7 +1373,74 @@ impl Arg for Vec<u8> {
         Self: Sized,
         F: FnOnce(&CStr) -> io::Result<T>,
     {
-        f(&CString::new(self).map_err(|_cstr_err| io::Error::INVAL)?)
+        match nul_terminated(&self) {
+            Some(c_str) => f(c_str),
+            None => with_c_str(&self, f),
+        }
+    }
+}
+
+impl Arg for Box<[u8]> {
+    #[inline]
+    fn as_str(&self) -> io::Result<&str> {
+        str::from_utf8(self).map_err(|_utf8_err| io::Error::INVAL)
+    }
+
+    #[inline]
+    fn to_string_lossy(&self) -> Cow<str> {
+        String::from_utf8_lossy(self)
+    }
+
+    #[cfg(not(windows))]
+    #[inline]
+    fn as_cow_c_str(&self) -> io::Result<Cow<CStr>> {
+        if let Some(c_str) = nul_terminated(self) {
+            return Ok(Cow::Borrowed(c_str));
+        }
+        Ok(Cow::Owned(
+            CString::new(&self[..]).map_err(|_cstr_err| io::Error::INVAL)?,
+        ))
+    }
+
+    #[cfg(not(windows))]
+    #[inline]
+    fn into_c_str<'b>(self) -> io::Result<Cow<'b, CStr>>
+    where
+        Self: 'b,
+    {
+        let mut bytes = self.into_vec();
+        // Reuse the allocation, dropping the NUL that `CString` will re-add.
+        if nul_terminated(&bytes).is_some() {
+            bytes.pop();
+        }
+        Ok(Cow::Owned(
+            CString::new(bytes).map_err(|_cstr_err| io::Error::INVAL)?,
+        ))
+    }
+
+    #[cfg(not(windows))]
+    #[inline]
+    fn as_maybe_utf8_bytes(&self) -> &[u8] {
+        self
+    }
+
+    #[cfg(windows)]
+    #[inline]
+    fn as_os_str(&self) -> io::Result<Cow<OsStr>> {
+        self.as_ref()
+    }
+
+    #[cfg(not(windows))]
+    #[inline]
+    fn into_with_c_str<T, F>(self, f: F) -> io::Result<T>
+    where
+        Self: Sized,
+        F: FnOnce(&CStr) -> io::Result<T>,
+    {
+        match nul_terminated(&self) {
+            Some(c_str) => f(c_str),
+            None => with_c_str(&self, f),
+        }
     }
 }
 
@@ -1235,7 +1497,18 @@ impl Arg for DecInt {
     }
 }
 
+/// Returns `bytes` as a `&CStr` if it already ends with a NUL and contains
+/// no other NULs, so that it can be used without copying.
+#[inline]
+fn nul_terminated(bytes: &[u8]) -> Option<&CStr> {
+    CStr::from_bytes_with_nul(bytes).ok()
+}
+
 /// Runs a closure with `bytes` passed in as a `&CStr`.
+///
+/// This always copies `bytes` and appends a NUL. Byte-slice arguments that
+/// are already NUL-terminated are checked with `nul_terminated` first and
+/// passed through directly.
 #[inline]
 fn with_c_str<T, F>(bytes: &[u8], f: F) -> io::Result<T>
 where
@@ -40,6 +40,9 @@ pub struct DecInt {
 
 impl DecInt {
     /// Construct a new path component from an integer.
+    ///
+    /// This accepts any primitive integer type up to 64 bits, signed or
+    /// unsigned. Negative numbers are formatted with a leading `-`.
     #[inline]
     pub fn new<Int: Integer>(i: Int) -> Self {
         let mut me = DecIntWriter(Self {
@@ -62,7 +65,7 @@ impl DecInt {
         &self.buf[..self.len]
     }
 
-    /// Return the raw byte buffer.
+    /// Return the decimal representation as a NUL-terminated `CStr`.
     #[inline]
     pub fn as_c_str(&self) -> &CStr {
         let bytes_with_nul = &self.buf[..self.len + 1];
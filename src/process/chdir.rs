@@ -0,0 +1,67 @@
+use crate::{imp, io, path};
+use io_lifetimes::AsFd;
+use std::ffi::OsString;
+use std::os::unix::ffi::OsStringExt;
+use std::path::PathBuf;
+
+/// `chdir(path)`—Changes the current working directory.
+///
+/// The working directory is shared by all the threads in the process.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/chdir.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/chdir.2.html
+#[inline]
+pub fn chdir<P: path::Arg>(path: P) -> io::Result<()> {
+    path.into_with_c_str(imp::syscalls::chdir)
+}
+
+/// `fchdir(fd)`—Changes the current working directory to the directory
+/// open at `fd`.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/fchdir.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/fchdir.2.html
+#[inline]
+pub fn fchdir<Fd: AsFd>(fd: &Fd) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::fchdir(fd)
+}
+
+/// `getcwd()`—Returns the current working directory.
+///
+/// If the working directory has been unlinked, or is outside the process'
+/// root directory, Linux may return a path starting with `(unreachable)`
+/// rather than failing.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getcwd.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/getcwd.3.html
+pub fn getcwd() -> io::Result<PathBuf> {
+    // Most paths are less than this long. Longer ones fail with `ERANGE`,
+    // and we retry with a bigger buffer.
+    let mut buf = vec![0_u8; 256];
+    loop {
+        match imp::syscalls::getcwd(&mut buf) {
+            Ok(()) => break,
+            Err(err) if err == io::Error::RANGE => {
+                let len = buf.len() * 2;
+                buf.resize(len, 0_u8);
+            }
+            Err(err) => return Err(err),
+        }
+    }
+    // The OS NUL-terminates the path on success.
+    let len = buf.iter().position(|b| *b == 0).unwrap();
+    buf.truncate(len);
+    Ok(PathBuf::from(OsString::from_vec(buf)))
+}
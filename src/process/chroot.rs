@@ -0,0 +1,43 @@
+use crate::{imp, io, path};
+
+/// `chroot(path)`—Changes the process' root directory.
+///
+/// This only changes how absolute paths are resolved. It doesn't change the
+/// current working directory, which may be outside the new root and still
+/// reachable through relative paths, including `..`. To confine a process,
+/// follow this with `chdir("/")`, and close any open directory file
+/// descriptors that are outside the new root.
+///
+/// The root directory is shared by all the threads in the process. Calling
+/// this requires the `CAP_SYS_CHROOT` capability.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/chroot.2.html
+#[inline]
+pub fn chroot<P: path::Arg>(path: P) -> io::Result<()> {
+    path.into_with_c_str(imp::syscalls::chroot)
+}
+
+/// `pivot_root(new_root, put_old)`—Makes `new_root` the root mount of the
+/// calling process' mount namespace, and moves the old root mount to
+/// `put_old`.
+///
+/// `new_root` must be a mount point, and `put_old` must be at or under
+/// `new_root`. This is usually called in a new mount namespace, followed by
+/// `chdir("/")` and unmounting `put_old`. Calling this requires the
+/// `CAP_SYS_ADMIN` capability in the user namespace that owns the mount
+/// namespace.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/pivot_root.2.html
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[inline]
+pub fn pivot_root<P: path::Arg, Q: path::Arg>(new_root: P, put_old: Q) -> io::Result<()> {
+    new_root.into_with_c_str(|new_root| {
+        put_old.into_with_c_str(|put_old| imp::syscalls::pivot_root(new_root, put_old))
+    })
+}
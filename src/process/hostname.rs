@@ -0,0 +1,76 @@
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+use crate::process::uname;
+use crate::{imp, io};
+use std::ffi::CString;
+
+/// `gethostname()`—Returns the name of the host.
+///
+/// On Linux, this is the `nodename` field of [`uname`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/gethostname.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/gethostname.2.html
+/// [`uname`]: crate::process::uname
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub fn gethostname() -> io::Result<CString> {
+    Ok(uname().nodename().to_owned())
+}
+
+/// `gethostname()`—Returns the name of the host.
+///
+/// # References
+///  - [POSIX]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/gethostname.html
+#[cfg(not(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux")))))]
+pub fn gethostname() -> io::Result<CString> {
+    let mut buf = vec![0_u8; 256];
+    loop {
+        match imp::syscalls::gethostname(&mut buf) {
+            // POSIX leaves it unspecified whether a name that doesn't fit is
+            // NUL-terminated, so a NUL in the last byte may mean the name was
+            // truncated. Only accept a NUL that comes before it.
+            Ok(()) => {
+                if let Some(len) = buf[..buf.len() - 1].iter().position(|b| *b == 0) {
+                    buf.truncate(len);
+                    return Ok(CString::new(buf).unwrap());
+                }
+            }
+            Err(err) if err == io::Error::NAMETOOLONG || err == io::Error::INVAL => {}
+            Err(err) => return Err(err),
+        }
+        let len = buf.len() * 2;
+        buf.resize(len, 0_u8);
+    }
+}
+
+/// `getdomainname()`—Returns the NIS domain name of the host.
+///
+/// This is the `domainname` field of [`uname`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/getdomainname.2.html
+/// [`uname`]: crate::process::uname
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub fn getdomainname() -> io::Result<CString> {
+    Ok(uname().domainname().to_owned())
+}
+
+/// `sethostname(name, len)`—Sets the name of the host.
+///
+/// This requires the `CAP_SYS_ADMIN` capability in the UTS namespace.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/sethostname.2.html
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[inline]
+pub fn sethostname(name: &[u8]) -> io::Result<()> {
+    imp::syscalls::sethostname(name)
+}
@@ -8,7 +8,8 @@
 //! [`FromRawFd::from_raw_fd`]: https://doc.rust-lang.org/std/os/unix/io/trait.FromRawFd.html#tymethod.from_raw_fd
 #![allow(unsafe_code)]
 
-use crate::imp;
+use crate::{imp, io};
+use std::num::NonZeroU32;
 
 /// The raw integer value of a Unix user ID.
 pub use imp::process::RawUid;
@@ -97,6 +98,25 @@ impl Pid {
     pub const fn as_raw(self) -> RawPid {
         self.0
     }
+
+    /// Converts a `Pid` into a `NonZeroU32`, or `None` if it's
+    /// [`Pid::NONE`] or negative.
+    ///
+    /// Functions like [`kill_process`] need a real process ID, since passing
+    /// 0 to the underlying `kill` means the current process group instead,
+    /// and negative values mean process groups.
+    ///
+    /// [`kill_process`]: crate::process::kill_process
+    #[inline]
+    #[allow(clippy::unnecessary_cast)] // `RawPid` is unsigned on some platforms
+    pub fn as_raw_nonzero(self) -> Option<NonZeroU32> {
+        let raw = self.0 as i32;
+        if raw > 0 {
+            NonZeroU32::new(raw as u32)
+        } else {
+            None
+        }
+    }
 }
 
 /// `getuid()`—Returns the process' real user ID.
@@ -182,3 +202,173 @@ pub fn getpid() -> Pid {
 pub fn getppid() -> Pid {
     imp::syscalls::getppid()
 }
+
+/// Returns the parent process' ID.
+///
+/// This is [`getppid`], under a more descriptive name.
+#[inline]
+#[must_use]
+pub fn parent_process_id() -> Pid {
+    getppid()
+}
+
+/// `setsid()`—Creates a new session, with the calling process as its
+/// leader, and returns the new session ID.
+///
+/// The calling process also becomes the leader of a new process group, and
+/// has no controlling terminal. This fails with [`io::Error::PERM`] if the
+/// calling process is already a process group leader.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/setsid.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/setsid.2.html
+#[inline]
+pub fn setsid() -> io::Result<Pid> {
+    imp::syscalls::setsid()
+}
+
+/// `setpgid(pid, pgid)`—Moves a process into a process group.
+///
+/// If `pid` is `None`, this moves the calling process. If `pgid` is `None`,
+/// the process group ID is the same as the process ID of the process being
+/// moved, which makes it the leader of a new process group.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/setpgid.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/setpgid.2.html
+#[inline]
+pub fn setpgid(pid: Option<Pid>, pgid: Option<Pid>) -> io::Result<()> {
+    imp::syscalls::setpgid(pid, pgid)
+}
+
+/// `getpgid(pid)`—Returns the process group ID of a process.
+///
+/// If `pid` is `None`, this returns the process group ID of the calling
+/// process.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getpgid.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/getpgid.2.html
+#[inline]
+pub fn getpgid(pid: Option<Pid>) -> io::Result<Pid> {
+    imp::syscalls::getpgid(pid)
+}
+
+/// `getpgrp()`—Returns the process group ID of the calling process.
+///
+/// This is equivalent to `getpgid(None)`, but can't fail.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getpgrp.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/getpgrp.2.html
+#[inline]
+#[must_use]
+pub fn getpgrp() -> Pid {
+    imp::syscalls::getpgrp()
+}
+
+/// `getsid(pid)`—Returns the session ID of a process.
+///
+/// If `pid` is `None`, this returns the session ID of the calling process.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getsid.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/getsid.2.html
+#[inline]
+pub fn getsid(pid: Option<Pid>) -> io::Result<Pid> {
+    imp::syscalls::getsid(pid)
+}
+
+/// The real, effective, and saved user IDs of a process, as returned by
+/// [`getresuid`].
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub struct ResUid {
+    /// The real user ID.
+    pub real: Uid,
+    /// The effective user ID.
+    pub effective: Uid,
+    /// The saved set-user-ID.
+    pub saved: Uid,
+}
+
+/// The real, effective, and saved group IDs of a process, as returned by
+/// [`getresgid`].
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub struct ResGid {
+    /// The real group ID.
+    pub real: Gid,
+    /// The effective group ID.
+    pub effective: Gid,
+    /// The saved set-group-ID.
+    pub saved: Gid,
+}
+
+/// `getresuid(&ruid, &euid, &suid)`—Returns the process' real, effective,
+/// and saved user IDs.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/getresuid.2.html
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[inline]
+#[must_use]
+pub fn getresuid() -> ResUid {
+    imp::syscalls::getresuid()
+}
+
+/// `getresgid(&rgid, &egid, &sgid)`—Returns the process' real, effective,
+/// and saved group IDs.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/getresgid.2.html
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[inline]
+#[must_use]
+pub fn getresgid() -> ResGid {
+    imp::syscalls::getresgid()
+}
+
+/// `getgroups()`—Returns the process' supplementary group IDs.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getgroups.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/getgroups.2.html
+pub fn getgroups() -> io::Result<Vec<Gid>> {
+    loop {
+        // Ask for the number of groups, and then fetch them.
+        let count = imp::syscalls::getgroups(&mut [])?;
+        let mut groups = vec![Gid::ROOT; count];
+        match imp::syscalls::getgroups(&mut groups) {
+            Ok(count) => {
+                groups.truncate(count);
+                return Ok(groups);
+            }
+            // More groups were added in between; try again.
+            Err(err) if err == io::Error::INVAL => continue,
+            Err(err) => return Err(err),
+        }
+    }
+}
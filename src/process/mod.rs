@@ -3,24 +3,64 @@
 use crate::imp;
 
 mod auxv;
+#[cfg(not(target_os = "wasi"))] // WASI doesn't have chdir.
+mod chdir;
+#[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))]
+mod chroot;
 mod exit;
+#[cfg(not(target_os = "wasi"))] // WASI doesn't have gethostname.
+mod hostname;
 #[cfg(not(target_os = "wasi"))] // WASI doesn't have get[gpu]id.
 mod id;
+#[cfg(not(target_os = "wasi"))] // WASI doesn't have kill.
+mod kill;
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+mod mount;
 #[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))] // WASI doesn't have [gs]etpriority.
 mod priority;
+#[cfg(not(target_os = "wasi"))] // WASI doesn't have getrusage.
+mod rusage;
 mod sched;
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+mod signal;
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+mod sysinfo;
+#[cfg(not(target_os = "wasi"))] // WASI doesn't have umask.
+mod umask;
 #[cfg(not(target_os = "wasi"))] // WASI doesn't have uname.
 mod uname;
+#[cfg(not(target_os = "wasi"))] // WASI doesn't have waitpid.
+mod wait;
 
 #[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
 pub use auxv::linux_hwcap;
 pub use auxv::page_size;
+#[cfg(not(target_os = "wasi"))]
+pub use chdir::{chdir, fchdir, getcwd};
+#[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))]
+pub use chroot::chroot;
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub use chroot::pivot_root;
 #[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
 pub use exit::exit_group;
 #[cfg(not(target_os = "wasi"))]
+pub use hostname::gethostname;
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub use hostname::{getdomainname, sethostname};
+#[cfg(not(target_os = "wasi"))]
 pub use id::{
-    getegid, geteuid, getgid, getpid, getppid, getuid, Gid, Pid, RawGid, RawPid, RawUid, Uid,
+    getegid, geteuid, getgid, getgroups, getpgid, getpgrp, getpid, getppid, getsid, getuid,
+    parent_process_id, setpgid, setsid, Gid, Pid, RawGid, RawPid, RawUid, Uid,
+};
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub use id::{getresgid, getresuid, ResGid, ResUid};
+#[cfg(not(target_os = "wasi"))]
+pub use kill::{
+    kill_current_process_group, kill_process, kill_process_group, test_kill_process,
+    test_kill_process_group, Signal,
 };
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub use mount::{mount, unmount, MountFlags, UnmountFlags};
 #[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))]
 pub use priority::nice;
 #[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
@@ -28,9 +68,23 @@ pub use priority::{
     getpriority_pgrp, getpriority_process, getpriority_user, setpriority_pgrp, setpriority_process,
     setpriority_user,
 };
+#[cfg(not(target_os = "wasi"))]
+pub use rusage::{getrusage, Rusage, RusageWho};
 pub use sched::sched_yield;
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub use sched::{
+    sched_getscheduler, sched_setscheduler, sched_setscheduler_with, SchedFlags, SchedPolicy,
+};
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+pub use signal::{sigprocmask, SigSet, SigmaskHow};
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub use sysinfo::{sysinfo, Sysinfo, SI_LOAD_SHIFT};
+#[cfg(not(target_os = "wasi"))]
+pub use umask::umask;
 #[cfg(not(target_os = "wasi"))]
 pub use uname::{uname, Uname};
+#[cfg(not(target_os = "wasi"))]
+pub use wait::{wait, waitpid, WaitOptions, WaitStatus};
 
 /// `EXIT_SUCCESS` for use with [`exit`].
 ///
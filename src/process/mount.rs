@@ -0,0 +1,47 @@
+use crate::{imp, io, path};
+use std::ffi::CStr;
+
+pub use imp::process::{MountFlags, UnmountFlags};
+
+/// `mount(source, target, fstype, flags, data)`—Mounts a filesystem.
+///
+/// The meaning of `source` and `data` depends on `fstype`. For a bind mount,
+/// with [`MountFlags::BIND`], `source` is the directory or file to mount and
+/// `fstype` and `data` are ignored. Calling this requires the `CAP_SYS_ADMIN`
+/// capability in the user namespace that owns the mount namespace.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/mount.2.html
+#[inline]
+pub fn mount<Src: path::Arg, Tgt: path::Arg, Ty: path::Arg>(
+    source: Src,
+    target: Tgt,
+    fstype: Ty,
+    flags: MountFlags,
+    data: Option<&CStr>,
+) -> io::Result<()> {
+    source.into_with_c_str(|source| {
+        target.into_with_c_str(|target| {
+            fstype
+                .into_with_c_str(|fstype| imp::syscalls::mount(source, target, fstype, flags, data))
+        })
+    })
+}
+
+/// `umount2(target, flags)`—Unmounts the filesystem mounted at `target`.
+///
+/// With [`UnmountFlags::DETACH`], the mount is removed from the namespace
+/// immediately and cleaned up once it's no longer busy.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/umount2.2.html
+#[inline]
+#[doc(alias = "umount")]
+#[doc(alias = "umount2")]
+pub fn unmount<P: path::Arg>(target: P, flags: UnmountFlags) -> io::Result<()> {
+    target.into_with_c_str(|target| imp::syscalls::unmount(target, flags))
+}
@@ -0,0 +1,59 @@
+use crate::time::Timespec;
+use crate::{imp, io};
+
+pub use imp::process::RusageWho;
+
+/// `struct rusage`—Resource usage, as returned by [`getrusage`].
+#[derive(Debug, Copy, Clone)]
+#[non_exhaustive]
+pub struct Rusage {
+    /// User CPU time used.
+    pub utime: Timespec,
+    /// System CPU time used.
+    pub stime: Timespec,
+    /// Maximum resident set size. This is in kilobytes on most platforms,
+    /// but in bytes on macOS and iOS.
+    pub maxrss: u64,
+    /// Page faults serviced without any I/O activity.
+    pub minflt: u64,
+    /// Page faults that required I/O activity.
+    pub majflt: u64,
+    /// Voluntary context switches, usually from waiting for a resource.
+    pub nvcsw: u64,
+    /// Involuntary context switches, from a time slice running out or a
+    /// higher-priority process becoming runnable.
+    pub nivcsw: u64,
+}
+
+/// `getrusage(who, &usage)`—Returns the resource usage of the calling
+/// process, its terminated and waited-for children, or the calling thread.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getrusage.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/getrusage.2.html
+pub fn getrusage(who: RusageWho) -> io::Result<Rusage> {
+    let raw = imp::syscalls::getrusage(who)?;
+
+    // The kernel reports CPU times as `struct timeval`s.
+    let utime = Timespec {
+        tv_sec: raw.ru_utime.tv_sec as _,
+        tv_nsec: (raw.ru_utime.tv_usec * 1000) as _,
+    };
+    let stime = Timespec {
+        tv_sec: raw.ru_stime.tv_sec as _,
+        tv_nsec: (raw.ru_stime.tv_usec * 1000) as _,
+    };
+
+    Ok(Rusage {
+        utime,
+        stime,
+        maxrss: raw.ru_maxrss as u64,
+        minflt: raw.ru_minflt as u64,
+        majflt: raw.ru_majflt as u64,
+        nvcsw: raw.ru_nvcsw as u64,
+        nivcsw: raw.ru_nivcsw as u64,
+    })
+}
@@ -1,4 +1,9 @@
 use crate::imp;
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+use crate::{io, process::Pid};
+
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub use imp::process::{SchedFlags, SchedPolicy};
 
 /// `sched_yield()`—Hints to the OS that other processes should run.
 ///
@@ -12,3 +17,73 @@ use crate::imp;
 pub fn sched_yield() {
     imp::syscalls::sched_yield()
 }
+
+/// `sched_getscheduler(pid)`—Returns the scheduling policy of a thread.
+///
+/// If `pid` is `None`, this returns the policy of the calling thread. Whether
+/// [`SchedFlags::RESET_ON_FORK`] is set isn't reported. This fails with
+/// [`io::Error::NOTSUP`] if the thread uses a policy not listed in
+/// [`SchedPolicy`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/sched_getscheduler.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/sched_getscheduler.2.html
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[inline]
+pub fn sched_getscheduler(pid: Option<Pid>) -> io::Result<SchedPolicy> {
+    let raw = imp::syscalls::sched_getscheduler(pid)?;
+    SchedPolicy::from_raw(raw & !SchedFlags::RESET_ON_FORK.bits()).ok_or(io::Error::NOTSUP)
+}
+
+/// `sched_setscheduler(pid, policy, {priority})`—Sets the scheduling policy
+/// and static priority of a thread.
+///
+/// If `pid` is `None`, this sets the policy of the calling thread. `priority`
+/// must be 0 for [`SchedPolicy::Other`], [`SchedPolicy::Batch`], and
+/// [`SchedPolicy::Idle`], and between 1 and 99 for the real-time policies,
+/// [`SchedPolicy::Fifo`] and [`SchedPolicy::RoundRobin`]. Otherwise this fails
+/// with [`io::Error::INVAL`].
+///
+/// Switching to a real-time policy, or raising a real-time priority, requires
+/// the `CAP_SYS_NICE` capability or a sufficient `RLIMIT_RTPRIO` limit, and
+/// fails with [`io::Error::PERM`] without one. [`SchedPolicy::Deadline`]
+/// needs parameters this call can't pass, so this always fails with
+/// [`io::Error::INVAL`] for it.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/sched_setscheduler.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/sched_setscheduler.2.html
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[inline]
+pub fn sched_setscheduler(pid: Option<Pid>, policy: SchedPolicy, priority: i32) -> io::Result<()> {
+    sched_setscheduler_with(pid, policy, priority, SchedFlags::empty())
+}
+
+/// `sched_setscheduler(pid, policy | flags, {priority})`—Sets the scheduling
+/// policy and static priority of a thread, with flags.
+///
+/// This is [`sched_setscheduler`] with the ability to set
+/// [`SchedFlags::RESET_ON_FORK`], which makes children created by `fork`
+/// start with [`SchedPolicy::Other`] instead of inheriting a real-time
+/// policy.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/sched.7.html
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[inline]
+pub fn sched_setscheduler_with(
+    pid: Option<Pid>,
+    policy: SchedPolicy,
+    priority: i32,
+    flags: SchedFlags,
+) -> io::Result<()> {
+    imp::syscalls::sched_setscheduler(pid, policy, flags, priority)
+}
@@ -0,0 +1,24 @@
+use crate::{imp, io};
+
+pub use imp::process::{SigSet, SigmaskHow};
+
+/// `sigprocmask(how, set, &oldset)`—Examines and changes the calling
+/// thread's signal mask.
+///
+/// If `set` is `None`, the mask is left unchanged. Either way, this returns
+/// the mask as it was before the call.
+///
+/// In a multi-threaded program this only affects the calling thread, so it
+/// behaves like `pthread_sigmask`.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/pthread_sigmask.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/sigprocmask.2.html
+#[inline]
+#[doc(alias = "pthread_sigmask")]
+pub fn sigprocmask(how: SigmaskHow, set: Option<&SigSet>) -> io::Result<SigSet> {
+    imp::syscalls::sigprocmask(how, set)
+}
@@ -0,0 +1,69 @@
+use crate::{imp, io};
+
+/// The number of bits [`Sysinfo::loads`] are shifted left by.
+///
+/// Divide a load by `1 << SI_LOAD_SHIFT` to get the load as a fraction.
+pub const SI_LOAD_SHIFT: u32 = 16;
+
+/// `struct sysinfo`—System statistics, as returned by [`sysinfo`].
+///
+/// Memory sizes are in bytes; the kernel's `mem_unit` has already been
+/// applied to them.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub struct Sysinfo {
+    /// Seconds since boot.
+    pub uptime: u64,
+    /// The 1, 5, and 15 minute load averages, shifted left by
+    /// [`SI_LOAD_SHIFT`].
+    pub loads: [u64; 3],
+    /// Total usable main memory.
+    pub totalram: u64,
+    /// Available memory.
+    pub freeram: u64,
+    /// Memory used by shared memory.
+    pub sharedram: u64,
+    /// Memory used by buffers.
+    pub bufferram: u64,
+    /// Total swap space.
+    pub totalswap: u64,
+    /// Available swap space.
+    pub freeswap: u64,
+    /// The number of current processes.
+    pub procs: u16,
+    /// The unit the kernel reported memory sizes in, in bytes.
+    pub mem_unit: u32,
+}
+
+/// `sysinfo()`—Returns system statistics, such as the uptime, load
+/// averages, and memory usage.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/sysinfo.2.html
+pub fn sysinfo() -> io::Result<Sysinfo> {
+    let raw = imp::syscalls::sysinfo()?;
+
+    // Linux versions before 2.3.23 report sizes in bytes and leave `mem_unit`
+    // zero.
+    let mem_unit = raw.mem_unit.max(1);
+    let bytes = |size: u64| u64::from(mem_unit).saturating_mul(size);
+
+    Ok(Sysinfo {
+        uptime: raw.uptime as u64,
+        loads: [
+            raw.loads[0] as u64,
+            raw.loads[1] as u64,
+            raw.loads[2] as u64,
+        ],
+        totalram: bytes(raw.totalram as u64),
+        freeram: bytes(raw.freeram as u64),
+        sharedram: bytes(raw.sharedram as u64),
+        bufferram: bytes(raw.bufferram as u64),
+        totalswap: bytes(raw.totalswap as u64),
+        freeswap: bytes(raw.freeswap as u64),
+        procs: raw.procs,
+        mem_unit,
+    })
+}
@@ -0,0 +1,30 @@
+use crate::fs::Mode;
+use crate::imp;
+
+/// `umask(mask)`—Sets the process' file mode creation mask, and returns the
+/// previous mask.
+///
+/// There's no way to read the mask without also setting it, so to just read
+/// it, set it to any value and then set it back:
+///
+/// ```rust
+/// use rsix::fs::Mode;
+/// use rsix::process::umask;
+///
+/// let mask = umask(Mode::empty());
+/// umask(mask);
+/// ```
+///
+/// The mask is shared by all the threads in the process, so other threads
+/// creating files in between may see the temporary value.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/umask.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/umask.2.html
+#[inline]
+pub fn umask(mask: Mode) -> Mode {
+    imp::syscalls::umask(mask)
+}
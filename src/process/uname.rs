@@ -1,36 +1,58 @@
 //! Uname support.
-//!
-//! # Safety
-//!
-//! This function converts from `struct utsname` fields provided from the
-//! kernel into `&str` references, which assumes that they're NUL-terminated.
-#![allow(unsafe_code)]
 
 use crate::imp;
-use std::ffi::{CStr, OsStr};
+use std::ffi::{CStr, CString};
 use std::fmt;
 use std::os::raw::c_char;
-#[cfg(unix)]
-use std::os::unix::ffi::OsStrExt;
-#[cfg(target_os = "wasi")]
-use std::os::wasi::ffi::OsStrExt;
 
 /// `uname()`—Returns high-level information about the runtime OS and
 /// hardware.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/uname.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/uname.2.html
 #[inline]
 pub fn uname() -> Uname {
-    Uname(imp::syscalls::uname())
+    Uname::new(&imp::syscalls::uname())
 }
 
 /// `struct utsname`—Return type for [`uname`].
+///
+/// Each field is copied out of the fixed-size array the OS fills in, up to
+/// its terminating NUL. If the OS leaves a field unterminated, the whole
+/// array is taken as its value.
 #[doc(alias = "utsname")]
-pub struct Uname(imp::process::RawUname);
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct Uname {
+    sysname: CString,
+    nodename: CString,
+    release: CString,
+    version: CString,
+    machine: CString,
+    #[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+    domainname: CString,
+}
 
 impl Uname {
+    fn new(raw: &imp::process::RawUname) -> Self {
+        Self {
+            sysname: Self::to_cstring(&raw.sysname),
+            nodename: Self::to_cstring(&raw.nodename),
+            release: Self::to_cstring(&raw.release),
+            version: Self::to_cstring(&raw.version),
+            machine: Self::to_cstring(&raw.machine),
+            #[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+            domainname: Self::to_cstring(&raw.domainname),
+        }
+    }
+
     /// `sysname`—Operating system release name
     #[inline]
-    pub fn sysname(&self) -> &OsStr {
-        Self::to_os_str(self.0.sysname.as_ptr())
+    pub fn sysname(&self) -> &CStr {
+        &self.sysname
     }
 
     /// `nodename`—Name with vague meaning
@@ -39,39 +61,44 @@ impl Uname {
     /// information about hosts that have multiple names, or any information
     /// about where the names are visible.
     #[inline]
-    pub fn nodename(&self) -> &OsStr {
-        Self::to_os_str(self.0.nodename.as_ptr())
+    pub fn nodename(&self) -> &CStr {
+        &self.nodename
     }
 
     /// `release`—Operating system release version string
     #[inline]
-    pub fn release(&self) -> &OsStr {
-        Self::to_os_str(self.0.release.as_ptr())
+    pub fn release(&self) -> &CStr {
+        &self.release
     }
 
     /// `version`—Operating system build identifiers
     #[inline]
-    pub fn version(&self) -> &OsStr {
-        Self::to_os_str(self.0.version.as_ptr())
+    pub fn version(&self) -> &CStr {
+        &self.version
     }
 
     /// `machine`—Hardware architecture identifier
     #[inline]
-    pub fn machine(&self) -> &OsStr {
-        Self::to_os_str(self.0.machine.as_ptr())
+    pub fn machine(&self) -> &CStr {
+        &self.machine
     }
 
     /// `domainname`—NIS or YP domain identifer
     #[inline]
     #[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
-    pub fn domainname(&self) -> &OsStr {
-        Self::to_os_str(self.0.domainname.as_ptr())
+    pub fn domainname(&self) -> &CStr {
+        &self.domainname
     }
 
-    #[inline]
-    fn to_os_str<'a>(ptr: *const c_char) -> &'a OsStr {
-        // Safety: Strings returned from the kernel are always NUL-terminated.
-        OsStr::from_bytes(unsafe { CStr::from_ptr(ptr) }.to_bytes())
+    /// Copies `field` up to its first NUL, or all of it if it has none.
+    fn to_cstring(field: &[c_char]) -> CString {
+        let bytes = field
+            .iter()
+            .map(|c| *c as u8)
+            .take_while(|b| *b != 0)
+            .collect::<Vec<u8>>();
+        // `bytes` stops before the first NUL, so it can't contain one.
+        CString::new(bytes).unwrap()
     }
 }
 
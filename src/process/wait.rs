@@ -0,0 +1,81 @@
+use crate::process::Pid;
+use crate::{imp, io};
+use std::os::raw::c_int;
+
+pub use imp::process::WaitOptions;
+
+/// The status of a child process, as reported by [`waitpid`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum WaitStatus {
+    /// The child exited normally with the given exit code.
+    Exited(c_int),
+
+    /// The child was terminated by the given signal. The `bool` says
+    /// whether it produced a core dump.
+    Signaled(c_int, bool),
+
+    /// The child was stopped by the given signal.
+    Stopped(c_int),
+
+    /// The child was resumed by `SIGCONT`.
+    Continued,
+
+    /// A raw status that isn't any of the above, which `waitpid` shouldn't
+    /// produce.
+    Unknown(c_int),
+}
+
+impl WaitStatus {
+    /// Decode a raw wait status, as produced by `waitpid` in C.
+    ///
+    /// If `raw` isn't a status `waitpid` could produce, this returns
+    /// [`WaitStatus::Unknown`] holding it.
+    #[inline]
+    pub fn from_raw(raw: c_int) -> Self {
+        if imp::process::WIFEXITED(raw) {
+            Self::Exited(imp::process::WEXITSTATUS(raw))
+        } else if imp::process::WIFSIGNALED(raw) {
+            Self::Signaled(imp::process::WTERMSIG(raw), imp::process::WCOREDUMP(raw))
+        } else if imp::process::WIFSTOPPED(raw) {
+            Self::Stopped(imp::process::WSTOPSIG(raw))
+        } else if imp::process::WIFCONTINUED(raw) {
+            Self::Continued
+        } else {
+            Self::Unknown(raw)
+        }
+    }
+}
+
+/// `waitpid(pid, &status, options)`—Waits for a child process to change
+/// state.
+///
+/// If `pid` is `None`, this waits for any child process. If
+/// [`WaitOptions::NOHANG`] is set and no child has changed state, this
+/// returns `Ok(None)`.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/wait.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/waitpid.2.html
+#[inline]
+pub fn waitpid(pid: Option<Pid>, waitopts: WaitOptions) -> io::Result<Option<(Pid, WaitStatus)>> {
+    Ok(imp::syscalls::waitpid(pid, waitopts)?
+        .map(|(pid, status)| (pid, WaitStatus::from_raw(status))))
+}
+
+/// `wait(&status)`—Waits for any child process to change state.
+///
+/// This is equivalent to `waitpid(None, waitopts)`.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/wait.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/waitpid.2.html
+#[inline]
+pub fn wait(waitopts: WaitOptions) -> io::Result<Option<(Pid, WaitStatus)>> {
+    waitpid(None, waitopts)
+}
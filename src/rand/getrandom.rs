@@ -5,6 +5,15 @@ pub use imp::rand::GetRandomFlags;
 
 /// `getrandom(buf, flags)`—Reads a sequence of random bytes.
 ///
+/// This returns the number of bytes written to `buf`, which may be fewer
+/// than `buf.len()`, so callers wanting to fill the whole buffer should call
+/// it in a loop. Reads of up to 256 bytes from the default source aren't
+/// interrupted once the entropy pool has been initialized, but this isn't
+/// guaranteed for larger reads or for [`GetRandomFlags::RANDOM`].
+///
+/// Before the entropy pool is initialized this blocks, or, if
+/// [`GetRandomFlags::NONBLOCK`] is set, fails with [`io::Error::AGAIN`].
+///
 /// # References
 ///  - [Linux]
 ///
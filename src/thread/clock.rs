@@ -0,0 +1,50 @@
+use crate::time::{clock_gettime, ClockId, Timespec};
+#[cfg(not(any(
+    target_os = "emscripten",
+    target_os = "freebsd",
+    target_os = "ios",
+    target_os = "macos",
+    target_os = "openbsd",
+)))]
+use crate::time::{clock_nanosleep_relative, NanosleepRelativeResult};
+
+/// `clock_gettime(CLOCK_THREAD_CPUTIME_ID)`—Returns the CPU time the calling
+/// thread has used.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/clock_gettime.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/clock_gettime.2.html
+#[inline]
+#[must_use]
+pub fn clock_gettime_thread_cputime() -> Timespec {
+    clock_gettime(ClockId::ThreadCPUTime)
+}
+
+/// `clock_nanosleep(CLOCK_PROCESS_CPUTIME_ID, 0, request, remain)`—Sleeps
+/// until the calling process has used `relative` more CPU time.
+///
+/// The calling thread uses no CPU time while it sleeps, so this waits for
+/// the process' other threads to use it. Linux doesn't allow a thread to
+/// sleep on its own CPU-time clock. Sleeping on a CPU-time clock is unusual,
+/// and mainly useful for testing.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/clock_nanosleep.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/clock_nanosleep.2.html
+#[cfg(not(any(
+    target_os = "emscripten",
+    target_os = "freebsd",
+    target_os = "ios",
+    target_os = "macos",
+    target_os = "openbsd",
+)))]
+#[inline]
+pub fn clock_nanosleep_cpu(relative: Timespec) -> NanosleepRelativeResult {
+    clock_nanosleep_relative(ClockId::ProcessCPUTime, &relative)
+}
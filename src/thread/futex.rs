@@ -0,0 +1,48 @@
+use crate::time::Timespec;
+use crate::{imp, io};
+use std::sync::atomic::AtomicU32;
+
+pub use imp::thread::FutexFlags;
+
+/// `futex(uaddr, FUTEX_WAIT, val, timeout)`—Waits for a [`futex_wake`] on
+/// `uaddr`, if it still holds `val`.
+///
+/// If `uaddr` doesn't hold `val`, this fails with [`io::Error::AGAIN`]
+/// without waiting. If `timeout` is given, it's the maximum time to wait,
+/// relative to now, and this fails with [`io::Error::TIMEDOUT`] if it
+/// expires.
+///
+/// Like any futex wait, this may return without a corresponding wake, so
+/// callers should recheck the value at `uaddr` and wait again as needed. In
+/// particular, a wait interrupted by a signal handler returns `Ok(())`
+/// rather than [`io::Error::INTR`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/futex.2.html
+#[doc(alias = "futex")]
+pub fn futex_wait(
+    uaddr: &AtomicU32,
+    flags: FutexFlags,
+    val: u32,
+    timeout: Option<Timespec>,
+) -> io::Result<()> {
+    match imp::syscalls::futex_wait(uaddr, flags, val, timeout.as_ref()) {
+        Err(err) if err == io::Error::INTR => Ok(()),
+        otherwise => otherwise,
+    }
+}
+
+/// `futex(uaddr, FUTEX_WAKE, count)`—Wakes up to `count` threads waiting in
+/// [`futex_wait`] on `uaddr`, and returns the number woken.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/futex.2.html
+#[inline]
+#[doc(alias = "futex")]
+pub fn futex_wake(uaddr: &AtomicU32, flags: FutexFlags, count: u32) -> io::Result<usize> {
+    imp::syscalls::futex_wake(uaddr, flags, count)
+}
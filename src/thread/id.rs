@@ -1,5 +1,5 @@
-use crate::imp;
-use crate::process::Pid;
+use crate::process::{Gid, Pid, Uid};
+use crate::{imp, io};
 
 /// `gettid()`—Returns the thread ID.
 ///
@@ -12,3 +12,49 @@ use crate::process::Pid;
 pub fn gettid() -> Pid {
     imp::syscalls::gettid()
 }
+
+/// `setresuid(ruid, euid, suid)`—Sets the calling thread's real, effective,
+/// and saved user IDs.
+///
+/// This is the Linux system call, which only affects the calling thread.
+/// Unlike the libc `setresuid` function, it doesn't arrange for the other
+/// threads in the process to make the same change.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/setresuid.2.html
+#[inline]
+#[doc(alias = "setresuid")]
+pub fn set_thread_res_uid(real: Uid, effective: Uid, saved: Uid) -> io::Result<()> {
+    imp::syscalls::setresuid_thread(real, effective, saved)
+}
+
+/// `setresgid(rgid, egid, sgid)`—Sets the calling thread's real, effective,
+/// and saved group IDs.
+///
+/// As with [`set_thread_res_uid`], this only affects the calling thread.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/setresgid.2.html
+#[inline]
+#[doc(alias = "setresgid")]
+pub fn set_thread_res_gid(real: Gid, effective: Gid, saved: Gid) -> io::Result<()> {
+    imp::syscalls::setresgid_thread(real, effective, saved)
+}
+
+/// `setgroups(groups)`—Sets the calling thread's supplementary group IDs.
+///
+/// As with [`set_thread_res_uid`], this only affects the calling thread.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/setgroups.2.html
+#[inline]
+#[doc(alias = "setgroups")]
+pub fn set_thread_groups(groups: &[Gid]) -> io::Result<()> {
+    imp::syscalls::setgroups_thread(groups)
+}
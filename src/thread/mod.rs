@@ -1,7 +1,35 @@
 //! Thread-associated operations.
 
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+mod clock;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod futex;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 mod id;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod name;
+mod sched;
+#[cfg(not(target_os = "redox"))]
+mod sleep;
 
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+pub use clock::clock_gettime_thread_cputime;
+#[cfg(not(any(
+    target_os = "emscripten",
+    target_os = "freebsd",
+    target_os = "ios",
+    target_os = "macos",
+    target_os = "openbsd",
+    target_os = "redox",
+    target_os = "wasi",
+)))]
+pub use clock::clock_nanosleep_cpu;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use futex::{futex_wait, futex_wake, FutexFlags};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use id::{gettid, set_thread_groups, set_thread_res_gid, set_thread_res_uid};
 #[cfg(any(target_os = "android", target_os = "linux"))]
-pub use id::gettid;
+pub use name::{name, set_name};
+pub use sched::yield_now;
+#[cfg(not(target_os = "redox"))]
+pub use sleep::sleep;
@@ -0,0 +1,33 @@
+use crate::{imp, io};
+use std::ffi::{CStr, CString};
+
+/// `prctl(PR_SET_NAME, name)`—Sets the name of the calling thread.
+///
+/// Thread names are limited to 16 bytes including the terminating NUL.
+/// Longer names are silently truncated to their first 15 bytes, as the
+/// kernel does, rather than producing an error.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/prctl.2.html
+#[inline]
+#[doc(alias = "prctl")]
+pub fn set_name(name: &CStr) -> io::Result<()> {
+    imp::syscalls::set_thread_name(name)
+}
+
+/// `prctl(PR_GET_NAME, buf)`—Returns the name of the calling thread.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/prctl.2.html
+#[inline]
+#[doc(alias = "prctl")]
+pub fn name() -> io::Result<CString> {
+    let mut buf = [0_u8; 16];
+    imp::syscalls::thread_name(&mut buf)?;
+    let len = buf.iter().position(|b| *b == b'\0').unwrap_or(buf.len());
+    Ok(CString::new(&buf[..len]).unwrap())
+}
@@ -0,0 +1,24 @@
+use crate::imp;
+
+/// `sched_yield()`—Hints to the OS that other threads should run.
+///
+/// This is only a hint. The calling thread may keep running immediately,
+/// for example if no other thread is runnable on its CPU, so this can't be
+/// relied on for fairness or to wait for another thread to make progress. It
+/// is most useful after a short spin in a spin-then-block loop.
+///
+/// This makes the same call as [`process::sched_yield`]; either way, only
+/// the calling thread yields.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [`process::sched_yield`]: crate::process::sched_yield
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/sched_yield.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/sched_yield.2.html
+#[inline]
+#[doc(alias = "sched_yield")]
+pub fn yield_now() {
+    imp::syscalls::sched_yield()
+}
@@ -0,0 +1,24 @@
+use crate::io;
+use crate::time::{nanosleep_uninterrupted, timespec_from_duration};
+use std::time::Duration;
+
+/// `nanosleep(duration, remain)`—Sleeps for `duration`, resuming after any
+/// interruptions.
+///
+/// This is [`nanosleep_uninterrupted`] with a [`Duration`], so the calling
+/// thread sleeps for at least `duration` in total, even if signal handlers
+/// interrupt it. This fails with [`io::Error::OVERFLOW`] if `duration` is
+/// too long to represent as a [`Timespec`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [`Timespec`]: crate::time::Timespec
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/nanosleep.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/nanosleep.2.html
+#[doc(alias = "nanosleep")]
+pub fn sleep(duration: Duration) -> io::Result<()> {
+    let request = timespec_from_duration(duration).ok_or(io::Error::OVERFLOW)?;
+    nanosleep_uninterrupted(&request)
+}
@@ -2,8 +2,9 @@ use crate::time::Timespec;
 use crate::{imp, io};
 
 /// `clockid_t`
+pub use imp::time::ClockId;
 #[cfg(any(linux_raw, all(libc, not(target_os = "wasi"))))]
-pub use imp::time::{ClockId, DynamicClockId};
+pub use imp::time::DynamicClockId;
 
 /// `clock_getres(id)`—Returns the resolution of a clock.
 ///
@@ -27,13 +28,21 @@ pub fn clock_getres(id: ClockId) -> Timespec {
 /// For a greater set of clocks and dynamic clock support, see
 /// [`clock_gettime_dynamic`].
 ///
+/// On WASI, this uses WASI's `clock_time_get`.
+///
+/// # Panics
+///
+/// On WASI, this panics if the host doesn't support `id`, which is only
+/// possible for the CPU-time clocks.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
+///  - [WASI]
 ///
 /// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/clock_gettime.html
 /// [Linux]: https://man7.org/linux/man-pages/man2/clock_gettime.2.html
-#[cfg(any(linux_raw, all(libc, not(target_os = "wasi"))))]
+/// [WASI]: https://github.com/WebAssembly/WASI/blob/main/phases/snapshot/docs.md
 #[inline]
 #[must_use]
 pub fn clock_gettime(id: ClockId) -> Timespec {
@@ -54,12 +63,48 @@ pub fn clock_gettime_dynamic(id: DynamicClockId) -> io::Result<Timespec> {
     imp::syscalls::clock_gettime_dynamic(id)
 }
 
+/// `clock_settime(id, timespec)`—Sets the current value of a clock.
+///
+/// Only some clocks, such as [`ClockId::Realtime`], can be set; attempts to
+/// set other clocks fail with [`io::Error::INVAL`]. Setting a clock
+/// requires `CAP_SYS_TIME`.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/clock_settime.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/clock_settime.2.html
+#[cfg(any(
+    linux_raw,
+    all(
+        libc,
+        not(any(
+            target_os = "ios",
+            target_os = "macos",
+            target_os = "redox",
+            target_os = "wasi"
+        ))
+    )
+))]
+#[inline]
+pub fn clock_settime(id: ClockId, timespec: Timespec) -> io::Result<()> {
+    imp::syscalls::clock_settime(id, timespec)
+}
+
 /// `clock_nanosleep(id, 0, request, remain)`—Sleeps for a duration on a
 /// given clock.
 ///
 /// This is `clock_nanosleep` specialized for the case of a relative sleep
 /// interval. See [`clock_nanosleep_absolute`] for absolute intervals.
 ///
+/// On Linux, sleeping on [`ClockId::ThreadCPUTime`] fails, since the calling
+/// thread uses no CPU time while it sleeps. The kernel reports this with
+/// [`io::Error::OPNOTSUPP`], though some libc implementations report
+/// [`io::Error::INVAL`] instead. Sleeping on [`ClockId::ProcessCPUTime`]
+/// waits for the process' other threads to use the given amount of CPU time;
+/// this is unusual and is mainly useful for testing.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -86,6 +131,9 @@ pub fn clock_nanosleep_relative(id: ClockId, request: &Timespec) -> NanosleepRel
 /// This is `clock_nanosleep` specialized for the case of an absolute sleep
 /// interval. See [`clock_nanosleep_relative`] for relative intervals.
 ///
+/// As with [`clock_nanosleep_relative`], on Linux this fails for
+/// [`ClockId::ThreadCPUTime`].
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -121,6 +169,31 @@ pub fn nanosleep(request: &Timespec) -> NanosleepRelativeResult {
     imp::syscalls::nanosleep(request)
 }
 
+/// `nanosleep(request, remain)`—Sleeps for a duration, resuming after any
+/// interruptions.
+///
+/// Unlike [`nanosleep`], which returns the remaining time when a signal
+/// handler interrupts it, this sleeps again for the remaining time, so the
+/// calling thread sleeps for at least `request` in total.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/nanosleep.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/nanosleep.2.html
+#[doc(alias = "nanosleep")]
+pub fn nanosleep_uninterrupted(request: &Timespec) -> io::Result<()> {
+    let mut request = *request;
+    loop {
+        match nanosleep(&request) {
+            NanosleepRelativeResult::Ok => return Ok(()),
+            NanosleepRelativeResult::Interrupted(remaining) => request = remaining,
+            NanosleepRelativeResult::Err(err) => return Err(err),
+        }
+    }
+}
+
 /// A return type for `nanosleep` and `clock_nanosleep_relative`.
 #[derive(Debug, Clone)]
 #[must_use]
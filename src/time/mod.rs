@@ -4,13 +4,42 @@ use crate::imp;
 
 #[cfg(not(target_os = "redox"))]
 mod clock;
+#[cfg(any(linux_raw, all(libc, target_os = "linux")))]
+mod timer;
+#[cfg(any(linux_raw, all(libc, target_os = "linux")))]
+mod timerfd;
+mod timespec;
+#[cfg(any(linux_raw, all(libc, target_os = "linux")))]
+mod timex;
 
-// TODO: Convert WASI'S clock APIs to use handles rather than ambient
-// clock identifiers, update `wasi-libc`, and then add support in `rsix`.
+// TODO: WASI's `clock_gettime` uses ambient clock identifiers for now. Convert
+// WASI's clock APIs to use handles rather than ambient clock identifiers,
+// update `wasi-libc`, and then switch `clock_gettime` to handles and add
+// support for the rest of the clock functions in `rsix`.
+#[cfg(not(any(
+    target_os = "ios",
+    target_os = "macos",
+    target_os = "redox",
+    target_os = "wasi",
+)))]
+pub use clock::clock_settime;
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
-pub use clock::{clock_getres, clock_gettime, clock_gettime_dynamic, ClockId, DynamicClockId};
+pub use clock::{clock_getres, clock_gettime_dynamic, DynamicClockId};
+#[cfg(not(target_os = "redox"))]
+pub use clock::{clock_gettime, ClockId};
 #[cfg(not(target_os = "redox"))]
-pub use clock::{nanosleep, NanosleepRelativeResult};
+pub use clock::{nanosleep, nanosleep_uninterrupted, NanosleepRelativeResult};
+#[cfg(any(linux_raw, all(libc, target_os = "linux")))]
+pub use timer::{Itimerspec, Sigevent, Timer, TimerFlags};
+#[cfg(any(linux_raw, all(libc, target_os = "linux")))]
+pub use timerfd::{
+    timerfd_create, timerfd_gettime, timerfd_settime, TimerfdFlags, TimerfdTimerFlags,
+};
+pub use timespec::{
+    duration_from_timespec, timespec_checked_add, timespec_checked_sub, timespec_from_duration,
+};
+#[cfg(any(linux_raw, all(libc, target_os = "linux")))]
+pub use timex::{clock_adjtime, AdjtimeState, Timex, TimexModes, TimexStatus};
 
 #[cfg(not(any(
     target_os = "emscripten",
@@ -0,0 +1,132 @@
+use crate::process::{Pid, Signal};
+use crate::time::ClockId;
+use crate::{imp, io};
+use std::os::raw::c_int;
+
+pub use imp::time::{Itimerspec, TimerFlags};
+
+/// `struct sigevent`—How a [`Timer`] notifies its owner when it expires.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Sigevent {
+    /// `SIGEV_NONE`—Don't notify; the timer can be polled with
+    /// [`Timer::get_time`].
+    None,
+
+    /// `SIGEV_SIGNAL`—Send the signal `signo` to the process, with `value`
+    /// in the `si_value` field of its `siginfo_t`.
+    Signal {
+        /// The signal to send.
+        signo: Signal,
+        /// The value passed along with the signal.
+        value: usize,
+    },
+
+    /// `SIGEV_THREAD_ID`—Send the signal `signo` to the thread `tid`, with
+    /// `value` in the `si_value` field of its `siginfo_t`.
+    ThreadSignal {
+        /// The signal to send.
+        signo: Signal,
+        /// The value passed along with the signal.
+        value: usize,
+        /// The thread to signal, as returned by [`gettid`].
+        ///
+        /// [`gettid`]: crate::thread::gettid
+        tid: Pid,
+    },
+}
+
+/// A POSIX interval timer, created with `timer_create`.
+///
+/// The timer is deleted with `timer_delete` when this is dropped.
+#[derive(Debug)]
+pub struct Timer {
+    raw: imp::time::RawTimer,
+}
+
+impl Timer {
+    /// `timer_create(id, event, &timer)`—Creates a timer measured by the
+    /// clock `id`.
+    ///
+    /// The timer is disarmed until it's armed with [`Timer::set_time`].
+    ///
+    /// # References
+    ///  - [POSIX]
+    ///  - [Linux]
+    ///
+    /// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/timer_create.html
+    /// [Linux]: https://man7.org/linux/man-pages/man2/timer_create.2.html
+    #[inline]
+    #[doc(alias = "timer_create")]
+    pub fn new(id: ClockId, event: Sigevent) -> io::Result<Self> {
+        let raw = imp::syscalls::timer_create(id, &event)?;
+        Ok(Self { raw })
+    }
+
+    /// `timer_settime(timer, flags, new_value, &old_value)`—Arms or disarms
+    /// the timer, and returns its previous setting.
+    ///
+    /// `new_value.it_value` is the time until the first expiration, or an
+    /// absolute time on the timer's clock if `flags` contains
+    /// [`TimerFlags::ABSTIME`]; if it's zero, the timer is disarmed.
+    /// `new_value.it_interval` is the period of subsequent expirations, or
+    /// zero for a one-shot timer.
+    ///
+    /// # References
+    ///  - [POSIX]
+    ///  - [Linux]
+    ///
+    /// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/timer_settime.html
+    /// [Linux]: https://man7.org/linux/man-pages/man2/timer_settime.2.html
+    #[inline]
+    #[doc(alias = "timer_settime")]
+    pub fn set_time(&self, flags: TimerFlags, new_value: &Itimerspec) -> io::Result<Itimerspec> {
+        imp::syscalls::timer_settime(self.raw, flags, new_value)
+    }
+
+    /// `timer_gettime(timer, &curr_value)`—Returns the time until the timer
+    /// next expires, and its interval.
+    ///
+    /// # References
+    ///  - [POSIX]
+    ///  - [Linux]
+    ///
+    /// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/timer_gettime.html
+    /// [Linux]: https://man7.org/linux/man-pages/man2/timer_gettime.2.html
+    #[inline]
+    #[doc(alias = "timer_gettime")]
+    pub fn get_time(&self) -> io::Result<Itimerspec> {
+        imp::syscalls::timer_gettime(self.raw)
+    }
+
+    /// `timer_getoverrun(timer)`—Returns the number of expirations that
+    /// occurred between the last signal being generated and it being
+    /// delivered or accepted.
+    ///
+    /// # References
+    ///  - [POSIX]
+    ///  - [Linux]
+    ///
+    /// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/timer_getoverrun.html
+    /// [Linux]: https://man7.org/linux/man-pages/man2/timer_getoverrun.2.html
+    #[inline]
+    #[doc(alias = "timer_getoverrun")]
+    pub fn overrun_count(&self) -> io::Result<c_int> {
+        imp::syscalls::timer_getoverrun(self.raw)
+    }
+}
+
+// Safety: With libc, `RawTimer` is `timer_t`, which is a pointer, so `Timer`
+// isn't `Send` or `Sync` automatically. It's only an opaque identifier for a
+// timer owned by the process, though, and the `timer_*` functions may be
+// called with it from any thread.
+#[allow(unsafe_code)]
+unsafe impl Send for Timer {}
+#[allow(unsafe_code)]
+unsafe impl Sync for Timer {}
+
+impl Drop for Timer {
+    #[inline]
+    fn drop(&mut self) {
+        let _ = imp::syscalls::timer_delete(self.raw);
+    }
+}
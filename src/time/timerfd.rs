@@ -0,0 +1,62 @@
+use crate::imp;
+use crate::io::{self, OwnedFd};
+use crate::time::{ClockId, Itimerspec};
+use io_lifetimes::AsFd;
+
+pub use imp::time::{TimerfdFlags, TimerfdTimerFlags};
+
+/// `timerfd_create(clockid, flags)`—Creates a timer that notifies via a file
+/// descriptor.
+///
+/// Reading from the file descriptor yields the number of expirations since
+/// the last read, as a native-endian `u64`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/timerfd_create.2.html
+#[inline]
+pub fn timerfd_create(id: ClockId, flags: TimerfdFlags) -> io::Result<OwnedFd> {
+    imp::syscalls::timerfd_create(id, flags)
+}
+
+/// `timerfd_settime(fd, flags, new_value, &old_value)`—Arms or disarms a
+/// timerfd, and returns its previous setting.
+///
+/// `new_value` is interpreted as in [`Timer::set_time`], with
+/// [`TimerfdTimerFlags::ABSTIME`] selecting an absolute expiration time.
+///
+/// If `flags` contains both `ABSTIME` and
+/// [`TimerfdTimerFlags::CANCEL_ON_SET`], and the timer's clock is
+/// [`ClockId::Realtime`], then when the clock undergoes a discontinuous
+/// change, such as from [`clock_settime`], the timer is cancelled and reads
+/// from it fail with [`io::Error::CANCELED`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/timerfd_settime.2.html
+/// [`Timer::set_time`]: crate::time::Timer::set_time
+/// [`clock_settime`]: crate::time::clock_settime
+#[inline]
+pub fn timerfd_settime<Fd: AsFd>(
+    fd: &Fd,
+    flags: TimerfdTimerFlags,
+    new_value: &Itimerspec,
+) -> io::Result<Itimerspec> {
+    let fd = fd.as_fd();
+    imp::syscalls::timerfd_settime(fd, flags, new_value)
+}
+
+/// `timerfd_gettime(fd, &curr_value)`—Returns the time until a timerfd next
+/// expires, and its interval.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/timerfd_gettime.2.html
+#[inline]
+pub fn timerfd_gettime<Fd: AsFd>(fd: &Fd) -> io::Result<Itimerspec> {
+    let fd = fd.as_fd();
+    imp::syscalls::timerfd_gettime(fd)
+}
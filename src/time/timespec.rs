@@ -0,0 +1,79 @@
+//! Conversions and arithmetic for [`Timespec`].
+//!
+//! `Timespec` is an alias for the platform's `struct timespec`, which is
+//! defined outside this crate, so these are free functions rather than trait
+//! impls and methods.
+
+use crate::time::Timespec;
+use std::convert::TryInto;
+use std::time::Duration;
+
+/// Converts a `Duration` into a `Timespec`.
+///
+/// Returns `None` if the number of seconds in `duration` doesn't fit in
+/// [`Secs`].
+///
+/// [`Secs`]: crate::time::Secs
+#[inline]
+pub fn timespec_from_duration(duration: Duration) -> Option<Timespec> {
+    Some(Timespec {
+        tv_sec: duration.as_secs().try_into().ok()?,
+        tv_nsec: duration.subsec_nanos() as _,
+    })
+}
+
+/// Converts a `Timespec` into a `Duration`.
+///
+/// `Duration` can't represent negative times, so a `timespec` with a
+/// negative `tv_sec` or `tv_nsec` converts to a zero `Duration`.
+#[inline]
+pub fn duration_from_timespec(timespec: Timespec) -> Duration {
+    match (timespec.tv_sec.try_into(), timespec.tv_nsec.try_into()) {
+        (Ok(secs), Ok(nanos)) => Duration::new(secs, nanos),
+        _ => Duration::from_secs(0),
+    }
+}
+
+/// Tests whether `timespec`'s `tv_nsec` is in `0..1_000_000_000`.
+#[inline]
+fn is_normalized(timespec: &Timespec) -> bool {
+    (0..1_000_000_000).contains(&timespec.tv_nsec)
+}
+
+/// Adds two `Timespec`s, carrying nanoseconds into seconds.
+///
+/// Returns `None` if either argument has `tv_nsec` outside
+/// `0..1_000_000_000`, or if the seconds overflow.
+#[inline]
+pub fn timespec_checked_add(a: Timespec, b: Timespec) -> Option<Timespec> {
+    if !is_normalized(&a) || !is_normalized(&b) {
+        return None;
+    }
+    let mut tv_sec = a.tv_sec.checked_add(b.tv_sec)?;
+    // Both are less than a second, so this can't overflow even a 32-bit
+    // `tv_nsec`.
+    let mut tv_nsec = a.tv_nsec + b.tv_nsec;
+    if tv_nsec >= 1_000_000_000 {
+        tv_nsec -= 1_000_000_000;
+        tv_sec = tv_sec.checked_add(1)?;
+    }
+    Some(Timespec { tv_sec, tv_nsec })
+}
+
+/// Subtracts `b` from `a`, borrowing seconds for nanoseconds.
+///
+/// The result may be negative. Returns `None` if either argument has
+/// `tv_nsec` outside `0..1_000_000_000`, or if the seconds overflow.
+#[inline]
+pub fn timespec_checked_sub(a: Timespec, b: Timespec) -> Option<Timespec> {
+    if !is_normalized(&a) || !is_normalized(&b) {
+        return None;
+    }
+    let mut tv_sec = a.tv_sec.checked_sub(b.tv_sec)?;
+    let mut tv_nsec = a.tv_nsec - b.tv_nsec;
+    if tv_nsec < 0 {
+        tv_nsec += 1_000_000_000;
+        tv_sec = tv_sec.checked_sub(1)?;
+    }
+    Some(Timespec { tv_sec, tv_nsec })
+}
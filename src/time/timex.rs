@@ -0,0 +1,76 @@
+use crate::time::ClockId;
+use crate::{imp, io};
+
+pub use imp::time::{AdjtimeState, TimexModes, TimexStatus};
+
+/// `struct timex`—Clock adjustment parameters for use with
+/// [`clock_adjtime`].
+///
+/// This exposes the fields of `struct timex` that can be set, along with a
+/// few read-only ones. On input, `modes` selects which of the other fields
+/// the kernel should apply; on output, all the fields are filled in with
+/// the clock's current parameters.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Timex {
+    /// Which fields to apply.
+    pub modes: TimexModes,
+    /// The time offset, in microseconds, or nanoseconds with
+    /// [`TimexStatus::NANO`].
+    pub offset: i64,
+    /// The frequency offset, in parts per million with a 16-bit fractional
+    /// part.
+    pub freq: i64,
+    /// The maximum error, in microseconds.
+    pub maxerror: i64,
+    /// The estimated error, in microseconds.
+    pub esterror: i64,
+    /// The clock command and status bits.
+    pub status: TimexStatus,
+    /// The PLL time constant.
+    pub constant: i64,
+    /// The clock precision, in microseconds. Read-only.
+    pub precision: i64,
+    /// The maximum frequency error, in parts per million with a 16-bit
+    /// fractional part. Read-only.
+    pub tolerance: i64,
+    /// The number of microseconds between clock ticks.
+    pub tick: i64,
+    /// The offset between TAI and UTC, in seconds. Read-only.
+    pub tai: i32,
+}
+
+impl Default for Timex {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            modes: TimexModes::empty(),
+            offset: 0,
+            freq: 0,
+            maxerror: 0,
+            esterror: 0,
+            status: TimexStatus::empty(),
+            constant: 0,
+            precision: 0,
+            tolerance: 0,
+            tick: 0,
+            tai: 0,
+        }
+    }
+}
+
+/// `clock_adjtime(id, timex)`—Reads and optionally adjusts the parameters
+/// of a clock.
+///
+/// With `timex.modes` empty, this only reads the clock's parameters, which
+/// doesn't require any privileges. Adjusting them requires
+/// `CAP_SYS_TIME`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/clock_adjtime.2.html
+#[inline]
+#[doc(alias = "adjtimex")]
+pub fn clock_adjtime(id: ClockId, timex: &mut Timex) -> io::Result<AdjtimeState> {
+    imp::syscalls::clock_adjtime(id, timex)
+}
@@ -0,0 +1,98 @@
+use rsix::fs::{access, accessat, cwd, openat, Access, AtFlags, Mode, OFlags};
+
+#[test]
+fn test_access() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(&cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let _file = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::WRONLY,
+        Mode::IRUSR | Mode::IWUSR,
+    )
+    .unwrap();
+
+    access(tmp.path().join("file"), Access::EXISTS).unwrap();
+    access(tmp.path().join("file"), Access::READ_OK | Access::WRITE_OK).unwrap();
+    assert_eq!(
+        access(tmp.path().join("absent"), Access::EXISTS).unwrap_err(),
+        rsix::io::Error::NOENT
+    );
+}
+
+#[cfg(not(any(target_os = "android", target_os = "emscripten")))]
+#[test]
+fn test_accessat_eaccess() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(&cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let _file = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::WRONLY,
+        Mode::IRUSR | Mode::IWUSR,
+    )
+    .unwrap();
+
+    match accessat(&dir, "file", Access::READ_OK, AtFlags::EACCESS) {
+        Ok(()) => (),
+        // Linux before 5.8 can't do this when the real and effective IDs
+        // differ.
+        Err(err) if err == rsix::io::Error::NOSYS => (),
+        Err(err) => panic!("{:?}", err),
+    }
+    assert_eq!(
+        accessat(&dir, "absent", Access::EXISTS, AtFlags::EACCESS).unwrap_err(),
+        rsix::io::Error::NOENT
+    );
+}
+
+/// Returns the running kernel's major and minor version numbers.
+#[cfg(target_os = "linux")]
+fn kernel_version() -> (u32, u32) {
+    let uname = rsix::process::uname();
+    let release = uname.release().to_str().unwrap();
+    let mut parts = release
+        .split(|c: char| !c.is_ascii_digit())
+        .map(|part| part.parse::<u32>().unwrap());
+    (parts.next().unwrap(), parts.next().unwrap())
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_accessat_eaccess_faccessat2() {
+    // `faccessat2` was added in Linux 5.8.
+    if kernel_version() < (5, 8) {
+        return;
+    }
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(&cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let _file = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::WRONLY,
+        Mode::IRUSR | Mode::IWUSR,
+    )
+    .unwrap();
+
+    // This must not fail with `NOSYS`, whether or not the real and effective
+    // IDs differ.
+    accessat(&dir, "file", Access::EXISTS, AtFlags::EACCESS).unwrap();
+
+    // `AT_SYMLINK_NOFOLLOW` always needs `faccessat2`, so this exercises it
+    // even when the real and effective IDs are the same. A dangling symlink
+    // exists itself, though its target doesn't.
+    std::os::unix::fs::symlink("absent", tmp.path().join("link")).unwrap();
+    accessat(
+        &dir,
+        "link",
+        Access::EXISTS,
+        AtFlags::EACCESS | AtFlags::SYMLINK_NOFOLLOW,
+    )
+    .unwrap();
+    accessat(&dir, "link", Access::EXISTS, AtFlags::SYMLINK_NOFOLLOW).unwrap();
+    assert_eq!(
+        accessat(&dir, "link", Access::EXISTS, AtFlags::EACCESS).unwrap_err(),
+        rsix::io::Error::NOENT
+    );
+}
@@ -0,0 +1,23 @@
+#[test]
+fn test_canonicalize() {
+    use rsix::fs::{canonicalize, cwd, openat, symlinkat, Mode, OFlags};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(&cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let _ = openat(&dir, "file", OFlags::CREATE | OFlags::WRONLY, Mode::IRUSR).unwrap();
+
+    // "b" -> "a" -> "./file".
+    symlinkat("./file", &dir, "a").unwrap();
+    symlinkat("a", &dir, "b").unwrap();
+
+    let resolved = canonicalize(tmp.path().join("b")).unwrap();
+    assert!(resolved.is_absolute());
+    assert_eq!(
+        resolved,
+        std::fs::canonicalize(tmp.path()).unwrap().join("file")
+    );
+
+    // A dangling symlink can't be resolved.
+    symlinkat("missing", &dir, "dangling").unwrap();
+    assert!(canonicalize(tmp.path().join("dangling")).is_err());
+}
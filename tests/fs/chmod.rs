@@ -0,0 +1,22 @@
+use rsix::fs::{chmod, cwd, fstat, openat, Mode, OFlags};
+
+#[test]
+fn test_chmod() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(&cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::WRONLY,
+        Mode::IRUSR | Mode::IWUSR | Mode::IRGRP | Mode::IROTH,
+    )
+    .unwrap();
+
+    chmod(tmp.path().join("file"), Mode::IRUSR | Mode::IWUSR).unwrap();
+
+    let stat = fstat(&file).unwrap();
+    assert_eq!(
+        Mode::from_bits_truncate(stat.st_mode as _) - Mode::IFMT,
+        Mode::IRUSR | Mode::IWUSR
+    );
+}
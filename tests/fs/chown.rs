@@ -0,0 +1,47 @@
+use rsix::fs::{chownat, cwd, fchown, fstat, openat, AtFlags, Mode, OFlags};
+use rsix::process::{getegid, geteuid, Gid, Uid};
+
+#[test]
+fn test_chown() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(&cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::WRONLY,
+        Mode::IRUSR | Mode::IWUSR,
+    )
+    .unwrap();
+
+    // Leaving both IDs unchanged is always permitted.
+    let before = fstat(&file).unwrap();
+    fchown(&file, None, None).unwrap();
+    chownat(&dir, "file", None, None, AtFlags::empty()).unwrap();
+    let after = fstat(&file).unwrap();
+    assert_eq!(before.st_uid, after.st_uid);
+    assert_eq!(before.st_gid, after.st_gid);
+
+    // Setting the IDs we already have is also permitted.
+    fchown(&file, Some(geteuid()), Some(getegid())).unwrap();
+
+    // Changing the owner requires privileges.
+    if geteuid() != Uid::ROOT {
+        return;
+    }
+
+    let (uid, gid) = unsafe { (Uid::from_raw(1), Gid::from_raw(1)) };
+    match fchown(&file, Some(uid), None) {
+        Ok(()) => (),
+        // Within a user namespace, the IDs may not be mapped.
+        Err(rsix::io::Error::INVAL) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+    let stat = fstat(&file).unwrap();
+    assert_eq!(stat.st_uid, uid.as_raw());
+    assert_eq!(stat.st_gid, before.st_gid);
+
+    chownat(&dir, "file", None, Some(gid), AtFlags::empty()).unwrap();
+    let stat = fstat(&file).unwrap();
+    assert_eq!(stat.st_uid, uid.as_raw());
+    assert_eq!(stat.st_gid, gid.as_raw());
+}
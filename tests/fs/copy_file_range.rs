@@ -0,0 +1,50 @@
+use rsix::fs::{copy_file_range, cwd, fstat, openat, seek, tell, Mode, OFlags};
+use std::io::SeekFrom;
+
+#[test]
+fn test_copy_file_range() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(&cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let src = openat(
+        &dir,
+        "src",
+        OFlags::CREATE | OFlags::RDWR,
+        Mode::IRUSR | Mode::IWUSR,
+    )
+    .unwrap();
+    let dst = openat(
+        &dir,
+        "dst",
+        OFlags::CREATE | OFlags::RDWR,
+        Mode::IRUSR | Mode::IWUSR,
+    )
+    .unwrap();
+    assert_eq!(rsix::io::write(&src, b"hello world").unwrap(), 11);
+
+    // Copy "world" to offset 2 of `dst`, using explicit offsets.
+    let mut off_in = 6;
+    let mut off_out = 2;
+    let copied = match copy_file_range(&src, Some(&mut off_in), &dst, Some(&mut off_out), 5) {
+        Ok(copied) => copied,
+        Err(rsix::io::Error::NOSYS) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    assert_eq!(copied, 5);
+    assert_eq!(off_in, 11);
+    assert_eq!(off_out, 7);
+    assert_eq!(fstat(&dst).unwrap().st_size, 7);
+
+    // The file positions are unchanged.
+    assert_eq!(tell(&src).unwrap(), 11);
+    assert_eq!(tell(&dst).unwrap(), 0);
+
+    let mut buf = [0_u8; 7];
+    assert_eq!(rsix::io::pread(&dst, &mut buf, 0).unwrap(), 7);
+    assert_eq!(&buf, b"\0\0world");
+
+    // Without offsets, the file positions are used and advanced.
+    seek(&src, SeekFrom::Start(0)).unwrap();
+    assert_eq!(copy_file_range(&src, None, &dst, None, 5).unwrap(), 5);
+    assert_eq!(tell(&src).unwrap(), 5);
+    assert_eq!(tell(&dst).unwrap(), 5);
+}
@@ -0,0 +1,27 @@
+use rsix::fs::{current_dir_fd, cwd, fstat, openat, statat, AtFlags, Mode, OFlags};
+use std::os::unix::fs::MetadataExt;
+
+#[test]
+fn test_cwd_is_at_fdcwd() {
+    use std::os::unix::io::AsRawFd;
+
+    assert_eq!(cwd().as_raw_fd(), libc::AT_FDCWD);
+    assert_eq!(current_dir_fd().as_raw_fd(), libc::AT_FDCWD);
+}
+
+#[test]
+fn test_cwd_relative_paths() {
+    let meta = std::fs::metadata("Cargo.toml").unwrap();
+
+    let stat = statat(&cwd(), "Cargo.toml", AtFlags::empty()).unwrap();
+    assert_eq!(stat.st_dev as u64, meta.dev());
+    assert_eq!(stat.st_ino as u64, meta.ino());
+
+    let file = openat(&cwd(), "Cargo.toml", OFlags::RDONLY, Mode::empty()).unwrap();
+    let stat = fstat(&file).unwrap();
+    assert_eq!(stat.st_dev as u64, meta.dev());
+    assert_eq!(stat.st_ino as u64, meta.ino());
+
+    // `cwd()` isn't an open file descriptor.
+    assert_eq!(fstat(&cwd()).unwrap_err(), rsix::io::Error::BADF);
+}
@@ -0,0 +1,33 @@
+use rsix::fs::{cwd, fadvise, openat, Advice, Mode, OFlags};
+use rsix::io::{read, write};
+
+#[test]
+fn test_fadvise() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(&cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "foo",
+        OFlags::CREATE | OFlags::RDWR | OFlags::TRUNC,
+        Mode::IRUSR | Mode::IWUSR,
+    )
+    .unwrap();
+    write(&file, &[b'a'; 8192]).unwrap();
+    drop(file);
+
+    let file = openat(&dir, "foo", OFlags::RDONLY, Mode::empty()).unwrap();
+    fadvise(&file, 0, 0, Advice::Sequential).unwrap();
+    fadvise(&file, 0, 8192, Advice::WillNeed).unwrap();
+
+    let mut buf = [0_u8; 8192];
+    let mut total = 0;
+    loop {
+        let n = read(&file, &mut buf[total..]).unwrap();
+        if n == 0 {
+            break;
+        }
+        total += n;
+    }
+    assert_eq!(total, 8192);
+    assert!(buf.iter().all(|b| *b == b'a'));
+}
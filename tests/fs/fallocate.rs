@@ -0,0 +1,51 @@
+use rsix::fs::{cwd, fallocate, fstat, openat, FallocateFlags, Mode, OFlags};
+
+#[test]
+fn test_fallocate() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(&cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::WRONLY | OFlags::TRUNC,
+        Mode::IRUSR | Mode::IWUSR,
+    )
+    .unwrap();
+
+    fallocate(&file, FallocateFlags::empty(), 0, 4096).unwrap();
+    let stat = fstat(&file).unwrap();
+    assert_eq!(stat.st_size, 4096);
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_fallocate_punch_hole() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(&cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::WRONLY | OFlags::TRUNC,
+        Mode::IRUSR | Mode::IWUSR,
+    )
+    .unwrap();
+    fallocate(&file, FallocateFlags::empty(), 0, 8192).unwrap();
+
+    // `PUNCH_HOLE` requires `KEEP_SIZE`; the kernel rejects it otherwise.
+    assert_eq!(
+        fallocate(&file, FallocateFlags::PUNCH_HOLE, 0, 4096).unwrap_err(),
+        rsix::io::Error::OPNOTSUPP
+    );
+
+    // Not all filesystems support hole punching.
+    match fallocate(
+        &file,
+        FallocateFlags::PUNCH_HOLE | FallocateFlags::KEEP_SIZE,
+        0,
+        4096,
+    ) {
+        Ok(()) | Err(rsix::io::Error::OPNOTSUPP) => (),
+        Err(err) => panic!("{:?}", err),
+    }
+    assert_eq!(fstat(&file).unwrap().st_size, 8192);
+}
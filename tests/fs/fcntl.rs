@@ -0,0 +1,35 @@
+use io_lifetimes::AsFd;
+use rsix::fs::{
+    fcntl_dupfd_cloexec_from, fcntl_dupfd_from, fcntl_getfd, fcntl_getfl, fcntl_setfl, FdFlags,
+    OFlags,
+};
+use rsix::io::{self, pipe, read};
+use std::os::unix::io::AsRawFd;
+
+#[test]
+fn test_fcntl_dupfd_from() {
+    let (reader, _writer) = pipe().unwrap();
+
+    let dup = fcntl_dupfd_from(&reader, 20).unwrap();
+    assert!(dup.as_fd().as_raw_fd() >= 20);
+    assert!(!fcntl_getfd(&dup).unwrap().contains(FdFlags::CLOEXEC));
+
+    let dup = fcntl_dupfd_cloexec_from(&reader, 20).unwrap();
+    assert!(dup.as_fd().as_raw_fd() >= 20);
+    assert!(fcntl_getfd(&dup).unwrap().contains(FdFlags::CLOEXEC));
+}
+
+#[test]
+fn test_fcntl_setfl_nonblock() {
+    let (reader, _writer) = pipe().unwrap();
+    assert!(!fcntl_getfl(&reader).unwrap().contains(OFlags::NONBLOCK));
+
+    fcntl_setfl(&reader, fcntl_getfl(&reader).unwrap() | OFlags::NONBLOCK).unwrap();
+    assert!(fcntl_getfl(&reader).unwrap().contains(OFlags::NONBLOCK));
+
+    let mut buf = [0_u8; 1];
+    assert_eq!(read(&reader, &mut buf), Err(io::Error::WOULDBLOCK));
+
+    fcntl_setfl(&reader, fcntl_getfl(&reader).unwrap() - OFlags::NONBLOCK).unwrap();
+    assert!(!fcntl_getfl(&reader).unwrap().contains(OFlags::NONBLOCK));
+}
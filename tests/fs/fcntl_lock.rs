@@ -0,0 +1,72 @@
+use crate::fork::in_child;
+use rsix::fs::{cwd, fcntl_getlk, fcntl_setlk, openat, Flock, FlockType, Mode, OFlags};
+use rsix::io;
+use rsix::process::{getpid, Pid};
+use std::io::SeekFrom;
+
+#[test]
+fn test_fcntl_lock() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(&cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::RDWR,
+        Mode::IRUSR | Mode::IWUSR,
+    )
+    .unwrap();
+
+    let lock = Flock {
+        typ: FlockType::WriteLock,
+        start: SeekFrom::Start(0),
+        len: 100,
+        pid: Pid::NONE,
+    };
+
+    // Nothing conflicts yet.
+    let found = fcntl_getlk(&file, &lock).unwrap();
+    assert_eq!(found.typ, FlockType::Unlock);
+
+    fcntl_setlk(&file, &lock).unwrap();
+
+    // A process never conflicts with its own locks, so check from a child.
+    let parent = getpid();
+    in_child(|| {
+        let found = fcntl_getlk(&file, &lock).unwrap();
+        assert_eq!(found.typ, FlockType::WriteLock);
+        assert_eq!(found.start, SeekFrom::Start(0));
+        assert_eq!(found.len, 100);
+        assert_eq!(found.pid, parent);
+        assert!(fcntl_setlk(&file, &lock).is_err());
+    });
+
+    let unlock = Flock {
+        typ: FlockType::Unlock,
+        ..lock
+    };
+    fcntl_setlk(&file, &unlock).unwrap();
+}
+
+#[test]
+fn test_fcntl_lock_start_overflow() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(&cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::RDWR,
+        Mode::IRUSR | Mode::IWUSR,
+    )
+    .unwrap();
+
+    // A start offset that doesn't fit in an `off_t` is rejected the same way
+    // on every backend.
+    let lock = Flock {
+        typ: FlockType::WriteLock,
+        start: SeekFrom::Start(u64::MAX),
+        len: 100,
+        pid: Pid::NONE,
+    };
+    assert_eq!(fcntl_getlk(&file, &lock).unwrap_err(), io::Error::OVERFLOW);
+    assert_eq!(fcntl_setlk(&file, &lock).unwrap_err(), io::Error::OVERFLOW);
+}
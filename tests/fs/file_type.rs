@@ -0,0 +1,61 @@
+use rsix::fs::FileType;
+
+#[test]
+fn test_file_type_from_raw_mode() {
+    assert_eq!(
+        FileType::from_raw_mode(libc::S_IFREG | 0o644),
+        FileType::RegularFile
+    );
+    assert_eq!(
+        FileType::from_raw_mode(libc::S_IFDIR | 0o755),
+        FileType::Directory
+    );
+    assert_eq!(
+        FileType::from_raw_mode(libc::S_IFLNK | 0o777),
+        FileType::Symlink
+    );
+    assert_eq!(FileType::from_raw_mode(libc::S_IFIFO), FileType::Fifo);
+    assert_eq!(FileType::from_raw_mode(libc::S_IFSOCK), FileType::Socket);
+    assert_eq!(
+        FileType::from_raw_mode(libc::S_IFCHR),
+        FileType::CharacterDevice
+    );
+    assert_eq!(
+        FileType::from_raw_mode(libc::S_IFBLK),
+        FileType::BlockDevice
+    );
+    assert_eq!(FileType::from_raw_mode(0), FileType::Unknown);
+}
+
+#[test]
+fn test_file_type_from_dirent_d_type() {
+    assert_eq!(
+        FileType::from_dirent_d_type(libc::DT_REG),
+        FileType::RegularFile
+    );
+    assert_eq!(
+        FileType::from_dirent_d_type(libc::DT_DIR),
+        FileType::Directory
+    );
+    assert_eq!(
+        FileType::from_dirent_d_type(libc::DT_LNK),
+        FileType::Symlink
+    );
+    assert_eq!(FileType::from_dirent_d_type(libc::DT_FIFO), FileType::Fifo);
+    assert_eq!(
+        FileType::from_dirent_d_type(libc::DT_SOCK),
+        FileType::Socket
+    );
+    assert_eq!(
+        FileType::from_dirent_d_type(libc::DT_CHR),
+        FileType::CharacterDevice
+    );
+    assert_eq!(
+        FileType::from_dirent_d_type(libc::DT_BLK),
+        FileType::BlockDevice
+    );
+    assert_eq!(
+        FileType::from_dirent_d_type(libc::DT_UNKNOWN),
+        FileType::Unknown
+    );
+}
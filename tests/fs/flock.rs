@@ -32,3 +32,34 @@ fn test_flock() {
     drop(f);
     drop(g);
 }
+
+#[cfg(not(target_os = "redox"))]
+#[test]
+fn test_flock_nonblocking_conflict() {
+    use rsix::fs::{cwd, flock, openat, FlockOperation, Mode, OFlags};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(&cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let f = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::RDWR,
+        Mode::IRUSR | Mode::IWUSR,
+    )
+    .unwrap();
+    let g = openat(&dir, "file", OFlags::RDWR, Mode::empty()).unwrap();
+
+    flock(&f, FlockOperation::LockExclusive).unwrap();
+    assert_eq!(
+        flock(&g, FlockOperation::NonBlockingLockExclusive).unwrap_err(),
+        rsix::io::Error::WOULDBLOCK
+    );
+    assert_eq!(
+        flock(&g, FlockOperation::NonBlockingLockShared).unwrap_err(),
+        rsix::io::Error::WOULDBLOCK
+    );
+
+    flock(&f, FlockOperation::Unlock).unwrap();
+    flock(&g, FlockOperation::NonBlockingLockExclusive).unwrap();
+    flock(&g, FlockOperation::Unlock).unwrap();
+}
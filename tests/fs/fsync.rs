@@ -0,0 +1,84 @@
+use rsix::fs::{cwd, fsync, openat, Mode, OFlags};
+
+#[test]
+fn test_fsync() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(&cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::WRONLY | OFlags::TRUNC,
+        Mode::IRUSR | Mode::IWUSR,
+    )
+    .unwrap();
+
+    assert_eq!(rsix::io::write(&file, b"hello").unwrap(), 5);
+    fsync(&file).unwrap();
+}
+
+#[cfg(not(any(target_os = "ios", target_os = "macos", target_os = "redox")))]
+#[test]
+fn test_fdatasync() {
+    use rsix::fs::fdatasync;
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(&cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::WRONLY | OFlags::TRUNC,
+        Mode::IRUSR | Mode::IWUSR,
+    )
+    .unwrap();
+
+    assert_eq!(rsix::io::write(&file, b"hello").unwrap(), 5);
+    fdatasync(&file).unwrap();
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_sync_file_range() {
+    use rsix::fs::{sync_file_range, SyncFileRangeFlags};
+    use rsix::io::pwrite;
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(&cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::WRONLY | OFlags::TRUNC,
+        Mode::IRUSR | Mode::IWUSR,
+    )
+    .unwrap();
+
+    assert_eq!(pwrite(&file, &[b'a'; 8192], 4096).unwrap(), 8192);
+    sync_file_range(&file, 4096, 8192, SyncFileRangeFlags::WRITE).unwrap();
+    sync_file_range(
+        &file,
+        4096,
+        8192,
+        SyncFileRangeFlags::WAIT_BEFORE
+            | SyncFileRangeFlags::WRITE
+            | SyncFileRangeFlags::WAIT_AFTER,
+    )
+    .unwrap();
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_syncfs() {
+    use rsix::fs::syncfs;
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(&cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::WRONLY | OFlags::TRUNC,
+        Mode::IRUSR | Mode::IWUSR,
+    )
+    .unwrap();
+
+    assert_eq!(rsix::io::write(&file, b"hello").unwrap(), 5);
+    syncfs(&file).unwrap();
+}
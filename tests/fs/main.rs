@@ -1,9 +1,42 @@
 #![cfg_attr(target_os = "wasi", feature(wasi_ext))]
 #![cfg_attr(io_lifetimes_use_std, feature(io_safety))]
 
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[path = "../util/fork.rs"]
+mod fork;
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+mod access;
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+mod canonicalize;
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+mod chmod;
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+mod chown;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod copy_file_range;
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+mod cwd;
+#[cfg(not(any(
+    target_os = "ios",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "redox"
+)))]
+mod fadvise;
+#[cfg(not(any(target_os = "netbsd", target_os = "openbsd", target_os = "redox")))]
+mod fallocate;
+#[cfg(not(target_os = "wasi"))]
+mod fcntl;
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+mod fcntl_lock;
 mod file;
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+mod file_type;
 #[cfg(not(target_os = "wasi"))]
 mod flock;
+mod fsync;
 mod invalid_offset;
 mod long_paths;
 #[cfg(not(any(
@@ -16,10 +49,34 @@ mod long_paths;
     target_os = "wasi"
 )))]
 mod makedev;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod memfd_create;
 mod mkdirat;
 mod mknodat;
+#[cfg(not(target_os = "wasi"))]
+mod mode;
+#[cfg(not(target_os = "redox"))]
+mod open;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 mod openat2;
 mod readdir;
+#[cfg(not(target_os = "redox"))]
+mod readlinkat;
 mod renameat;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod seals;
+#[cfg(target_os = "linux")]
+mod sendfile;
+#[cfg(not(target_os = "wasi"))]
+mod stat;
 mod statfs;
+#[cfg(any(linux_raw, all(libc, target_os = "linux", target_env = "gnu")))]
+mod statx;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod tmpfile;
+#[cfg(not(target_os = "redox"))]
+mod truncate;
+#[cfg(not(target_os = "redox"))]
+mod utimes;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod xattr;
@@ -8,3 +8,33 @@ fn makedev_roundtrip() {
     assert_eq!(maj, major(dev));
     assert_eq!(min, minor(dev));
 }
+
+#[test]
+fn makedev_small_roundtrip() {
+    let dev = makedev(259, 3);
+    assert_eq!(major(dev), 259);
+    assert_eq!(minor(dev), 3);
+}
+
+#[test]
+fn makedev_mknodat() {
+    use rsix::fs::{cwd, mknodat, openat, statat, AtFlags, FileType, Mode, OFlags};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(&cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    // Creating device nodes requires privileges.
+    match mknodat(&dir, "null", Mode::IFCHR | Mode::IRUSR, makedev(1, 3)) {
+        Ok(()) => (),
+        Err(err) if err == rsix::io::Error::PERM => return,
+        Err(err) => panic!("{:?}", err),
+    }
+
+    let stat = statat(&dir, "null", AtFlags::empty()).unwrap();
+    assert_eq!(
+        FileType::from_raw_mode(stat.st_mode),
+        FileType::CharacterDevice
+    );
+    assert_eq!(major(stat.st_rdev), 1);
+    assert_eq!(minor(stat.st_rdev), 3);
+}
@@ -0,0 +1,30 @@
+#[test]
+fn test_memfd_create() {
+    use rsix::fs::{fstat, ftruncate, memfd_create, MemfdFlags};
+    use rsix::io::{mmap, munmap, pread, MapFlags, ProtFlags};
+    use std::ptr::null_mut;
+    use std::slice;
+
+    let fd = memfd_create("test", MemfdFlags::CLOEXEC).unwrap();
+    ftruncate(&fd, 4096).unwrap();
+    assert_eq!(fstat(&fd).unwrap().st_size, 4096);
+
+    unsafe {
+        let addr = mmap(
+            null_mut(),
+            4096,
+            ProtFlags::READ | ProtFlags::WRITE,
+            MapFlags::SHARED,
+            &fd,
+            0,
+        )
+        .unwrap();
+        let slice = slice::from_raw_parts_mut(addr.cast::<u8>(), 4096);
+        slice[1000..1005].copy_from_slice(b"hello");
+        munmap(addr, 4096).unwrap();
+    }
+
+    let mut buf = [0_u8; 5];
+    assert_eq!(pread(&fd, &mut buf, 1000).unwrap(), 5);
+    assert_eq!(&buf, b"hello");
+}
@@ -25,3 +25,50 @@ fn test_mknodat() {
     assert_eq!(FileType::from_raw_mode(stat.st_mode), FileType::Fifo);
     unlinkat(&dir, "foo", AtFlags::empty()).unwrap();
 }
+
+#[cfg(not(any(
+    target_os = "ios",
+    target_os = "macos",
+    target_os = "redox",
+    target_os = "wasi",
+)))]
+#[test]
+fn test_mkfifoat() {
+    use rsix::fs::{cwd, mkfifoat, openat, statat, AtFlags, FileType, Mode, OFlags};
+    use rsix::io::{read, write, Error};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(&cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    // A conflicting file type is rejected.
+    assert_eq!(
+        mkfifoat(&dir, "fifo", Mode::IFREG | Mode::IRUSR),
+        Err(Error::INVAL)
+    );
+
+    mkfifoat(&dir, "fifo", Mode::IRUSR | Mode::IWUSR).unwrap();
+    let stat = statat(&dir, "fifo", AtFlags::empty()).unwrap();
+    assert_eq!(FileType::from_raw_mode(stat.st_mode), FileType::Fifo);
+
+    // Open the read end first, so that opening the write end nonblockingly
+    // doesn't fail with `ENXIO`.
+    let reader = openat(
+        &dir,
+        "fifo",
+        OFlags::RDONLY | OFlags::NONBLOCK,
+        Mode::empty(),
+    )
+    .unwrap();
+    let writer = openat(
+        &dir,
+        "fifo",
+        OFlags::WRONLY | OFlags::NONBLOCK,
+        Mode::empty(),
+    )
+    .unwrap();
+
+    assert_eq!(write(&writer, b"x").unwrap(), 1);
+    let mut buf = [0_u8; 1];
+    assert_eq!(read(&reader, &mut buf).unwrap(), 1);
+    assert_eq!(&buf, b"x");
+}
@@ -0,0 +1,30 @@
+use rsix::fs::Mode;
+
+#[test]
+fn test_mode_raw_round_trip() {
+    let mode = Mode::from_raw_mode(0o4755);
+    assert_eq!(mode.as_raw_mode(), 0o4755);
+    assert!(mode.is_setuid());
+    assert!(!mode.is_setgid());
+    assert!(!mode.is_sticky());
+    assert_eq!(
+        mode,
+        Mode::ISUID | Mode::IRWXU | Mode::IRGRP | Mode::IXGRP | Mode::IROTH | Mode::IXOTH
+    );
+}
+
+#[test]
+fn test_mode_file_type() {
+    let dir = Mode::from_raw_mode(0o041777);
+    assert!(dir.is_dir());
+    assert!(dir.is_sticky());
+    assert!(!dir.is_reg());
+    assert!(!dir.is_symlink());
+
+    let file = Mode::IFREG | Mode::IRUSR | Mode::IWUSR;
+    assert!(file.is_reg());
+    assert!(!file.is_dir());
+
+    assert!(Mode::IFLNK.is_symlink());
+    assert!(!Mode::IFLNK.is_reg());
+}
@@ -0,0 +1,36 @@
+use rsix::fs::{fstat, open, Mode, OFlags};
+use rsix::io::{read, write};
+
+#[test]
+fn test_open() {
+    let tmp = tempfile::tempdir().unwrap();
+    let path = tmp.path().join("file");
+
+    let file = open(
+        &path,
+        OFlags::CREATE | OFlags::EXCL | OFlags::WRONLY,
+        Mode::IRUSR | Mode::IWUSR,
+    )
+    .unwrap();
+    assert_eq!(write(&file, b"hello").unwrap(), 5);
+    drop(file);
+
+    // With `EXCL`, creating the file again fails.
+    assert_eq!(
+        open(
+            &path,
+            OFlags::CREATE | OFlags::EXCL | OFlags::WRONLY,
+            Mode::IRUSR | Mode::IWUSR,
+        )
+        .unwrap_err(),
+        rsix::io::Error::EXIST
+    );
+
+    // Open the existing file read-only; `mode` is ignored without `CREATE`.
+    let file = open(&path, OFlags::RDONLY, Mode::empty()).unwrap();
+    assert_eq!(fstat(&file).unwrap().st_size, 5);
+    let mut buf = [0_u8; 5];
+    assert_eq!(read(&file, &mut buf).unwrap(), 5);
+    assert_eq!(&buf, b"hello");
+    assert_eq!(write(&file, b"x").unwrap_err(), rsix::io::Error::BADF);
+}
@@ -68,14 +68,17 @@ fn test_openat2() {
         ResolveFlags::NO_MAGICLINKS,
     )
     .unwrap();
-    let _ = openat2_more(
-        &dir,
-        "symlink.txt",
-        OFlags::RDONLY,
-        Mode::empty(),
-        ResolveFlags::NO_SYMLINKS,
-    )
-    .unwrap_err();
+    assert_eq!(
+        openat2_more(
+            &dir,
+            "symlink.txt",
+            OFlags::RDONLY,
+            Mode::empty(),
+            ResolveFlags::NO_SYMLINKS,
+        )
+        .unwrap_err(),
+        io::Error::LOOP
+    );
 
     // Test `NO_MAGICLINKS`.
     let test = openat2_more(
@@ -37,6 +37,31 @@ fn dir_entries() {
     assert_eq!(entries.len(), 2);
 }
 
+#[test]
+fn dir_entry_inodes() {
+    use rsix::fs::{cwd, openat, statat, AtFlags, Mode, OFlags};
+
+    let tmpdir = tempfile::tempdir().expect("construct tempdir");
+    let names = ["a", "bb", "a_much_longer_file_name_than_the_others"];
+    for name in &names {
+        let _ = std::fs::File::create(tmpdir.path().join(name)).expect("create file");
+    }
+
+    let dirfd = openat(&cwd(), tmpdir.path(), OFlags::RDONLY, Mode::empty()).expect("open tempdir");
+    let stats = names
+        .iter()
+        .map(|name| statat(&dirfd, *name, AtFlags::empty()).expect("stat file"))
+        .collect::<Vec<_>>();
+
+    let mut dir = Dir::from(dirfd).expect("construct Dir from dirfd");
+    let entries = read_entries(&mut dir);
+    assert_eq!(entries.len(), names.len());
+    for (name, stat) in names.iter().zip(stats.iter()) {
+        let entry = entries.get(*name).expect("entry for file");
+        assert_eq!(entry.ino(), stat.st_ino);
+    }
+}
+
 fn read_entries(dir: &mut Dir) -> HashMap<String, DirEntry> {
     dir.rewind();
     let mut out = HashMap::new();
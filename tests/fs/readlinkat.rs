@@ -0,0 +1,26 @@
+#[test]
+fn test_readlinkat_into() {
+    use rsix::fs::{cwd, openat, readlinkat, readlinkat_into, symlinkat, Mode, OFlags};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(&cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    symlinkat("some/target", &dir, "link").unwrap();
+
+    let mut buf = Vec::new();
+    let len = readlinkat_into(&dir, "link", &mut buf).unwrap();
+    assert_eq!(len, "some/target".len());
+    assert_eq!(buf, b"some/target");
+
+    // Reading it again reuses the buffer.
+    let capacity = buf.capacity();
+    let len = readlinkat_into(&dir, "link", &mut buf).unwrap();
+    assert_eq!(len, "some/target".len());
+    assert_eq!(buf, b"some/target");
+    assert_eq!(buf.capacity(), capacity);
+
+    assert_eq!(
+        readlinkat(&dir, "link", Default::default()).unwrap(),
+        "some/target"
+    );
+}
@@ -78,3 +78,27 @@ fn test_renameat_with() {
         assert!(same(&before, &orig));
     }
 }
+
+#[cfg(all(target_os = "linux", target_env = "gnu"))]
+#[test]
+fn test_rename_with() {
+    use rsix::fs::{rename_with, RenameFlags};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let red = tmp.path().join("red");
+    let green = tmp.path().join("green");
+    std::fs::write(&red, b"red").unwrap();
+    std::fs::write(&green, b"green").unwrap();
+
+    match rename_with(&red, &green, RenameFlags::NOREPLACE) {
+        Err(e) if e == rsix::io::Error::NOSYS => return,
+        Err(e) => assert_eq!(e, rsix::io::Error::EXIST),
+        Ok(()) => panic!("`NOREPLACE` replaced an existing file"),
+    }
+    assert_eq!(std::fs::read(&red).unwrap(), b"red");
+    assert_eq!(std::fs::read(&green).unwrap(), b"green");
+
+    rename_with(&red, &green, RenameFlags::EXCHANGE).unwrap();
+    assert_eq!(std::fs::read(&red).unwrap(), b"green");
+    assert_eq!(std::fs::read(&green).unwrap(), b"red");
+}
@@ -0,0 +1,37 @@
+use rsix::fs::{fcntl_add_seals, fcntl_get_seals, memfd_create, MemfdFlags, SealFlags};
+use rsix::io::{self, pread, write};
+
+#[test]
+fn test_seals() {
+    let fd = memfd_create("test", MemfdFlags::CLOEXEC | MemfdFlags::ALLOW_SEALING).unwrap();
+    assert_eq!(fcntl_get_seals(&fd).unwrap(), SealFlags::empty());
+
+    assert_eq!(write(&fd, b"hello").unwrap(), 5);
+
+    fcntl_add_seals(&fd, SealFlags::WRITE | SealFlags::SEAL).unwrap();
+    assert_eq!(
+        fcntl_get_seals(&fd).unwrap(),
+        SealFlags::WRITE | SealFlags::SEAL
+    );
+
+    // Writes are no longer allowed, but reads are.
+    assert_eq!(write(&fd, b"world"), Err(io::Error::PERM));
+    let mut buf = [0_u8; 5];
+    assert_eq!(pread(&fd, &mut buf, 0).unwrap(), 5);
+    assert_eq!(&buf, b"hello");
+
+    // Neither is adding more seals.
+    assert_eq!(
+        fcntl_add_seals(&fd, SealFlags::SHRINK),
+        Err(io::Error::PERM)
+    );
+}
+
+#[test]
+fn test_seals_not_allowed() {
+    let fd = memfd_create("test", MemfdFlags::CLOEXEC).unwrap();
+
+    // Without `ALLOW_SEALING`, the file starts out with `SEAL`.
+    assert_eq!(fcntl_get_seals(&fd).unwrap(), SealFlags::SEAL);
+    assert_eq!(fcntl_add_seals(&fd, SealFlags::WRITE), Err(io::Error::PERM));
+}
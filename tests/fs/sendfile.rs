@@ -0,0 +1,39 @@
+use rsix::fs::{cwd, openat, sendfile, tell, Mode, OFlags};
+use rsix::net::{socketpair, AcceptFlags, AddressFamily, Protocol, SocketType};
+
+#[test]
+fn test_sendfile() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(&cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::RDWR,
+        Mode::IRUSR | Mode::IWUSR,
+    )
+    .unwrap();
+    assert_eq!(rsix::io::pwrite(&file, b"hello world", 0).unwrap(), 11);
+
+    let (a, b) = socketpair(
+        AddressFamily::UNIX,
+        SocketType::STREAM,
+        AcceptFlags::CLOEXEC,
+        Protocol::default(),
+    )
+    .unwrap();
+
+    let mut offset = 6;
+    assert_eq!(sendfile(&a, &file, Some(&mut offset), 5).unwrap(), 5);
+    assert_eq!(offset, 11);
+    assert_eq!(tell(&file).unwrap(), 0);
+
+    assert_eq!(sendfile(&a, &file, None, 5).unwrap(), 5);
+    assert_eq!(tell(&file).unwrap(), 5);
+
+    let mut buf = [0_u8; 10];
+    let mut total = 0;
+    while total < buf.len() {
+        total += rsix::io::read(&b, &mut buf[total..]).unwrap();
+    }
+    assert_eq!(&buf, b"worldhello");
+}
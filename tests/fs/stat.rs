@@ -0,0 +1,33 @@
+use rsix::fs::{cwd, fstat, openat, FileType, Mode, OFlags, StatExt};
+use rsix::time::{clock_gettime, ClockId};
+
+#[test]
+fn test_stat_ext() {
+    let before = clock_gettime(ClockId::Realtime);
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(&cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::WRONLY,
+        Mode::IRUSR | Mode::IWUSR,
+    )
+    .unwrap();
+
+    let stat = fstat(&file).unwrap();
+    assert_eq!(stat.file_type(), FileType::RegularFile);
+    assert!(stat.mode().is_reg());
+    assert_eq!(stat.mode() - Mode::IFMT, Mode::IRUSR | Mode::IWUSR);
+
+    // Filesystem timestamps may be coarser than the clock, so allow some
+    // slack either way.
+    let mtime = stat.mtime();
+    let after = clock_gettime(ClockId::Realtime);
+    assert!(mtime.tv_sec >= before.tv_sec - 2);
+    assert!(mtime.tv_sec <= after.tv_sec + 2);
+    assert!(mtime.tv_nsec >= 0 && mtime.tv_nsec < 1_000_000_000);
+
+    let stat = fstat(&dir).unwrap();
+    assert_eq!(stat.file_type(), FileType::Directory);
+}
@@ -28,3 +28,28 @@ fn test_statx() {
 
     assert_eq!(PROC_SUPER_MAGIC, 0x0000_9fa0);
 }
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[test]
+fn test_statvfs() {
+    use rsix::fs::{cwd, fstatvfs, openat, statvfs, Mode, OFlags};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let by_path = statvfs(tmp.path()).unwrap();
+
+    let dir = openat(&cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let by_fd = fstatvfs(&dir).unwrap();
+
+    assert_ne!(by_path.f_frsize, 0);
+    assert_ne!(by_path.f_namemax, 0);
+    assert!(by_path.f_bfree <= by_path.f_blocks);
+    assert!(by_path.f_bavail <= by_path.f_bfree);
+    assert_eq!(by_path.f_frsize, by_fd.f_frsize);
+    assert_eq!(by_path.f_blocks, by_fd.f_blocks);
+
+    // The free space available to us can't exceed the size of the
+    // filesystem.
+    let avail = by_path.f_bavail.checked_mul(by_path.f_frsize).unwrap();
+    let total = by_path.f_blocks.checked_mul(by_path.f_frsize).unwrap();
+    assert!(avail <= total);
+}
@@ -0,0 +1,30 @@
+use rsix::fs::{cwd, openat, statx, AtFlags, Mode, OFlags, StatxFlags};
+
+#[test]
+fn test_statx() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(&cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::WRONLY,
+        Mode::IRUSR | Mode::IWUSR,
+    )
+    .unwrap();
+    assert_eq!(rsix::io::write(&file, b"hello").unwrap(), 5);
+
+    let stat = match statx(
+        &dir,
+        "file",
+        AtFlags::empty(),
+        StatxFlags::BTIME | StatxFlags::SIZE,
+    ) {
+        Ok(stat) => stat,
+        Err(rsix::io::Error::NOSYS) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+
+    // Not all filesystems record birth times, but all report sizes.
+    assert!(StatxFlags::from_bits_truncate(stat.stx_mask).contains(StatxFlags::SIZE));
+    assert_eq!(stat.stx_size, 5);
+}
@@ -0,0 +1,65 @@
+use rsix::fs::{cwd, fstat, linkat_fd, openat, openat_tmpfile, statat, AtFlags, Mode, OFlags};
+use rsix::io::{pread, write};
+
+#[test]
+fn test_linkat_fd() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(&cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = match openat_tmpfile(&dir, OFlags::RDWR, Mode::IRUSR | Mode::IWUSR) {
+        Ok(file) => file,
+        // Not all filesystems support `O_TMPFILE`.
+        Err(err) if err == rsix::io::Error::OPNOTSUPP => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    assert_eq!(write(&file, b"hello").unwrap(), 5);
+
+    match linkat_fd(&file, &dir, "named") {
+        Ok(()) => (),
+        // Linking by file descriptor requires `CAP_DAC_READ_SEARCH`.
+        Err(err) if err == rsix::io::Error::NOENT => return,
+        Err(err) => panic!("{:?}", err),
+    }
+
+    let stat = statat(&dir, "named", AtFlags::empty()).unwrap();
+    assert_eq!(stat.st_size, 5);
+    assert_eq!(stat.st_nlink, 1);
+}
+
+#[test]
+fn test_openat_tmpfile() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(&cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = match openat_tmpfile(&dir, OFlags::RDWR, Mode::IRUSR | Mode::IWUSR) {
+        Ok(file) => file,
+        // Not all filesystems support `O_TMPFILE`.
+        Err(err) if err == rsix::io::Error::OPNOTSUPP => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    assert_eq!(write(&file, b"hello").unwrap(), 5);
+
+    let mut buf = [0_u8; 5];
+    assert_eq!(pread(&file, &mut buf, 0).unwrap(), 5);
+    assert_eq!(&buf, b"hello");
+
+    // The file has no name.
+    let stat = fstat(&file).unwrap();
+    assert_eq!(stat.st_nlink, 0);
+}
+
+#[test]
+fn test_openat_tmpfile_notdir() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(&cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::WRONLY,
+        Mode::IRUSR | Mode::IWUSR,
+    )
+    .unwrap();
+
+    match openat_tmpfile(&file, OFlags::RDWR, Mode::IRUSR | Mode::IWUSR) {
+        Err(err) if err == rsix::io::Error::NOTDIR || err == rsix::io::Error::OPNOTSUPP => (),
+        otherwise => panic!("{:?}", otherwise),
+    }
+}
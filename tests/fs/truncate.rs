@@ -0,0 +1,41 @@
+use rsix::fs::{cwd, fstat, ftruncate, openat, truncate, Mode, OFlags};
+
+#[test]
+fn test_ftruncate() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(&cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::WRONLY,
+        Mode::IRUSR | Mode::IWUSR,
+    )
+    .unwrap();
+    assert_eq!(rsix::io::write(&file, b"hello world").unwrap(), 11);
+
+    ftruncate(&file, 5).unwrap();
+    assert_eq!(fstat(&file).unwrap().st_size, 5);
+
+    // Growing a file past its end is permitted.
+    ftruncate(&file, 1 << 20).unwrap();
+    assert_eq!(fstat(&file).unwrap().st_size, 1 << 20);
+}
+
+#[test]
+fn test_truncate() {
+    let tmp = tempfile::tempdir().unwrap();
+    let path = tmp.path().join("file");
+    std::fs::write(&path, b"hello world").unwrap();
+    let file = openat(&cwd(), &path, OFlags::RDONLY, Mode::empty()).unwrap();
+
+    truncate(&path, 5).unwrap();
+    assert_eq!(fstat(&file).unwrap().st_size, 5);
+
+    truncate(&path, 4096).unwrap();
+    assert_eq!(fstat(&file).unwrap().st_size, 4096);
+
+    assert_eq!(
+        truncate(tmp.path().join("missing"), 0).unwrap_err(),
+        rsix::io::Error::NOENT
+    );
+}
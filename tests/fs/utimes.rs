@@ -0,0 +1,47 @@
+use rsix::fs::{cwd, fstat, futimens, openat, utimes, Mode, OFlags, UTIME_OMIT};
+use rsix::time::Timespec;
+
+#[test]
+fn test_utimes() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(&cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::WRONLY | OFlags::TRUNC,
+        Mode::IRUSR | Mode::IWUSR,
+    )
+    .unwrap();
+
+    // Set only the modification time, leaving the access time alone.
+    let times = [
+        Timespec {
+            tv_sec: 0,
+            tv_nsec: UTIME_OMIT,
+        },
+        Timespec {
+            tv_sec: 1_000_000_000,
+            tv_nsec: 0,
+        },
+    ];
+    utimes(tmp.path().join("file"), &times).unwrap();
+
+    let stat = fstat(&file).unwrap();
+    assert_eq!(stat.st_mtime as u64, 1_000_000_000);
+    assert_eq!(stat.st_mtime_nsec as u64, 0);
+
+    let times = [
+        Timespec {
+            tv_sec: 0,
+            tv_nsec: UTIME_OMIT,
+        },
+        Timespec {
+            tv_sec: 2_000_000_000,
+            tv_nsec: 0,
+        },
+    ];
+    futimens(&file, &times).unwrap();
+
+    let stat = fstat(&file).unwrap();
+    assert_eq!(stat.st_mtime as u64, 2_000_000_000);
+}
@@ -0,0 +1,73 @@
+use rsix::fs::{
+    cwd, fgetxattr, flistxattr, fremovexattr, fsetxattr, getxattr, listxattr, openat, removexattr,
+    setxattr, Mode, OFlags, XattrFlags,
+};
+use rsix::io;
+use std::ffi::CString;
+
+#[test]
+fn test_xattr() {
+    let tmp = tempfile::tempdir().unwrap();
+    let path = tmp.path().join("file");
+    let _ = std::fs::File::create(&path).unwrap();
+
+    // Not all filesystems support user extended attributes.
+    match setxattr(&path, "user.rsix", b"hello", XattrFlags::CREATE) {
+        Ok(()) => (),
+        Err(io::Error::NOTSUP) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+
+    assert_eq!(getxattr(&path, "user.rsix").unwrap(), b"hello");
+    assert_eq!(
+        setxattr(&path, "user.rsix", b"again", XattrFlags::CREATE).unwrap_err(),
+        io::Error::EXIST
+    );
+    setxattr(&path, "user.rsix", b"goodbye", XattrFlags::REPLACE).unwrap();
+    assert_eq!(getxattr(&path, "user.rsix").unwrap(), b"goodbye");
+
+    let names = listxattr(&path).unwrap();
+    assert!(names.contains(&CString::new("user.rsix").unwrap()));
+
+    removexattr(&path, "user.rsix").unwrap();
+    assert_eq!(getxattr(&path, "user.rsix").unwrap_err(), io::Error::NODATA);
+    assert_eq!(
+        setxattr(&path, "user.rsix", b"missing", XattrFlags::REPLACE).unwrap_err(),
+        io::Error::NODATA
+    );
+}
+
+#[test]
+fn test_fxattr() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(&cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::WRONLY,
+        Mode::IRUSR | Mode::IWUSR,
+    )
+    .unwrap();
+
+    match fsetxattr(&file, "user.empty", b"", XattrFlags::empty()) {
+        Ok(()) => (),
+        Err(io::Error::NOTSUP) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+    let big = vec![0xa5_u8; 1000];
+    fsetxattr(&file, "user.big", &big, XattrFlags::empty()).unwrap();
+
+    assert_eq!(fgetxattr(&file, "user.empty").unwrap(), b"");
+    assert_eq!(fgetxattr(&file, "user.big").unwrap(), big);
+
+    let names = flistxattr(&file).unwrap();
+    assert!(names.contains(&CString::new("user.empty").unwrap()));
+    assert!(names.contains(&CString::new("user.big").unwrap()));
+
+    fremovexattr(&file, "user.big").unwrap();
+    assert_eq!(fgetxattr(&file, "user.big").unwrap_err(), io::Error::NODATA);
+    assert_eq!(
+        fremovexattr(&file, "user.big").unwrap_err(),
+        io::Error::NODATA
+    );
+}
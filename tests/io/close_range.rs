@@ -0,0 +1,32 @@
+#![cfg(any(target_os = "android", target_os = "linux"))]
+
+use io_lifetimes::AsFd;
+use rsix::fs::{fcntl_getfd, FdFlags};
+use rsix::io::{close_range, pipe, CloseRangeFlags, Error};
+use std::os::unix::io::AsRawFd;
+
+#[test]
+fn test_close_range_cloexec() {
+    let pipes = (0..4).map(|_| pipe().unwrap()).collect::<Vec<_>>();
+    let fds = pipes
+        .iter()
+        .flat_map(|(reader, writer)| vec![reader, writer])
+        .collect::<Vec<_>>();
+    for &fd in &fds {
+        assert!(!fcntl_getfd(fd).unwrap().contains(FdFlags::CLOEXEC));
+    }
+
+    let first = fds.iter().map(|fd| fd.as_fd().as_raw_fd()).min().unwrap();
+    let last = fds.iter().map(|fd| fd.as_fd().as_raw_fd()).max().unwrap();
+
+    // Safety: With `CLOEXEC`, no file descriptors are closed.
+    match unsafe { close_range(first, last, CloseRangeFlags::CLOEXEC) } {
+        // `close_range` is new in Linux 5.9, and `CLOEXEC` in 5.11.
+        Err(Error::NOSYS) | Err(Error::INVAL) => return,
+        result => result.unwrap(),
+    }
+
+    for &fd in &fds {
+        assert!(fcntl_getfd(fd).unwrap().contains(FdFlags::CLOEXEC));
+    }
+}
@@ -0,0 +1,32 @@
+use io_lifetimes::AsFd;
+use rsix::io::{dup2_to, pipe, read, write, Error};
+use std::os::unix::io::AsRawFd;
+
+#[test]
+fn test_dup2_to() {
+    let (reader, writer) = pipe().unwrap();
+
+    // Other tests open file descriptors concurrently in this process, so use
+    // a number well above the ones they're likely to be using, rather than
+    // a small one like 10.
+    let new = 500;
+
+    // Safety: Nothing else in this process uses this file descriptor.
+    let dup = unsafe { dup2_to(&reader, new) }.unwrap();
+    assert_eq!(dup.as_fd().as_raw_fd(), new);
+    drop(reader);
+
+    assert_eq!(write(&writer, b"hello").unwrap(), 5);
+    let mut buf = [0_u8; 5];
+    assert_eq!(read(&dup, &mut buf).unwrap(), 5);
+    assert_eq!(&buf, b"hello");
+}
+
+#[test]
+fn test_dup2_to_self() {
+    let (reader, _writer) = pipe().unwrap();
+    let raw = reader.as_fd().as_raw_fd();
+
+    // Safety: `reader` is `raw`, and this fails without closing it.
+    assert_eq!(unsafe { dup2_to(&reader, raw) }.unwrap_err(), Error::INVAL);
+}
@@ -95,3 +95,33 @@ fn test_epoll() {
         .unwrap();
     client.join().unwrap();
 }
+
+#[test]
+fn test_epoll_pipe() {
+    use rsix::io::{pipe, Epoll, EventVec};
+
+    let (reader, writer) = pipe().unwrap();
+
+    let epoll = Epoll::new(epoll::CreateFlags::CLOEXEC).unwrap();
+    epoll.add(&reader, 42, epoll::EventFlags::IN).unwrap();
+
+    // Nothing has been written yet.
+    let mut events = EventVec::with_capacity(4);
+    epoll.wait(&mut events, 0).unwrap();
+    assert_eq!(events.iter().count(), 0);
+
+    write(&writer, b"x").unwrap();
+    epoll.wait(&mut events, -1).unwrap();
+    let events_list = events.iter().collect::<Vec<_>>();
+    assert_eq!(events_list.len(), 1);
+    assert!(events_list[0].0.contains(epoll::EventFlags::IN));
+    assert_eq!(events_list[0].1, 42);
+
+    epoll.modify(&reader, 7, epoll::EventFlags::IN).unwrap();
+    epoll.wait(&mut events, -1).unwrap();
+    assert_eq!(events.iter().next().unwrap().1, 7);
+
+    epoll.delete(&reader).unwrap();
+    epoll.wait(&mut events, 0).unwrap();
+    assert_eq!(events.iter().count(), 0);
+}
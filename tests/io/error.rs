@@ -0,0 +1,46 @@
+use rsix::io::Error;
+
+#[test]
+fn test_raw_os_error_round_trip() {
+    assert_eq!(Error::INVAL.raw_os_error(), libc::EINVAL);
+    assert_eq!(Error::from_raw_os_error(libc::EINVAL), Error::INVAL);
+    assert_eq!(
+        Error::from_raw_os_error(Error::NOENT.raw_os_error()),
+        Error::NOENT
+    );
+}
+
+#[test]
+fn test_from_raw_os_error_out_of_range() {
+    const ZERO: Error = Error::from_raw_os_error(0);
+    assert_eq!(ZERO, Error::INVAL);
+    assert_eq!(Error::from_raw_os_error(-1), Error::INVAL);
+    assert_eq!(Error::from_raw_os_error(4096), Error::INVAL);
+    assert_eq!(Error::from_raw_os_error(i32::MAX), Error::INVAL);
+}
+
+#[test]
+fn test_error_display() {
+    let message = Error::INVAL.to_string();
+    assert_eq!(
+        message,
+        std::io::Error::from_raw_os_error(libc::EINVAL).to_string()
+    );
+    assert!(message.contains(&libc::EINVAL.to_string()));
+}
+
+#[test]
+fn test_into_std_io_error() {
+    fn open_missing() -> std::io::Result<()> {
+        Err(Error::NOENT)?;
+        Ok(())
+    }
+
+    let err = open_missing().unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::NotFound);
+    assert_eq!(err.raw_os_error(), Some(libc::ENOENT));
+    assert_eq!(Error::from_io_error(&err), Some(Error::NOENT));
+
+    let source: &dyn std::error::Error = &Error::NOENT;
+    assert_eq!(source.to_string(), err.to_string());
+}
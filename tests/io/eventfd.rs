@@ -22,3 +22,41 @@ fn test_eventfd() {
     let u = u64::from_ne_bytes(bytes);
     assert_eq!(u, 5021);
 }
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_eventfd_read_write() {
+    use rsix::io::{eventfd, eventfd_read, eventfd_write, EventfdFlags};
+
+    let efd = eventfd(0, EventfdFlags::CLOEXEC | EventfdFlags::NONBLOCK).unwrap();
+    eventfd_write(&efd, 3).unwrap();
+    assert_eq!(eventfd_read(&efd).unwrap(), 3);
+    assert_eq!(eventfd_read(&efd).unwrap_err(), rsix::io::Error::WOULDBLOCK);
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_eventfd_semaphore() {
+    use rsix::io::{eventfd, eventfd_read, eventfd_write, EventfdFlags};
+
+    let efd = eventfd(
+        0,
+        EventfdFlags::CLOEXEC | EventfdFlags::NONBLOCK | EventfdFlags::SEMAPHORE,
+    )
+    .unwrap();
+    eventfd_write(&efd, 2).unwrap();
+    assert_eq!(eventfd_read(&efd).unwrap(), 1);
+    assert_eq!(eventfd_read(&efd).unwrap(), 1);
+    assert_eq!(eventfd_read(&efd).unwrap_err(), rsix::io::Error::WOULDBLOCK);
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_eventfd_read_short() {
+    use rsix::io::{eventfd_read, pipe, write};
+
+    // A short read means `fd` isn't an eventfd; it's an error, not a panic.
+    let (reader, writer) = pipe().unwrap();
+    write(&writer, b"abc").unwrap();
+    assert_eq!(eventfd_read(&reader).unwrap_err(), rsix::io::Error::IO);
+}
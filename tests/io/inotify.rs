@@ -0,0 +1,63 @@
+#![cfg(any(target_os = "android", target_os = "linux"))]
+
+use rsix::io::inotify::{
+    inotify_add_watch, inotify_init1, inotify_rm_watch, read_events, CreateFlags, Events,
+    WatchFlags,
+};
+use std::ffi::CString;
+
+#[test]
+fn test_inotify_create() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let fd = inotify_init1(CreateFlags::CLOEXEC | CreateFlags::NONBLOCK).unwrap();
+    let wd = inotify_add_watch(&fd, tmpdir.path(), WatchFlags::CREATE).unwrap();
+
+    let mut buf = [0_u8; 4096];
+    assert_eq!(
+        read_events(&fd, &mut buf).map(|_| ()),
+        Err(rsix::io::Error::WOULDBLOCK)
+    );
+
+    std::fs::File::create(tmpdir.path().join("file")).unwrap();
+
+    let events = read_events(&fd, &mut buf).unwrap().collect::<Vec<_>>();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].wd, wd);
+    assert!(events[0].mask.contains(WatchFlags::CREATE));
+    assert_eq!(events[0].name, Some(CString::new("file").unwrap()));
+
+    inotify_rm_watch(&fd, wd).unwrap();
+}
+
+#[test]
+fn test_inotify_multiple_events() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let fd = inotify_init1(CreateFlags::CLOEXEC).unwrap();
+    let wd = inotify_add_watch(
+        &fd,
+        tmpdir.path(),
+        WatchFlags::CREATE | WatchFlags::DELETE | WatchFlags::ONLYDIR,
+    )
+    .unwrap();
+
+    let names = ["a", "a_longer_name_that_needs_more_padding"];
+    for name in &names {
+        std::fs::File::create(tmpdir.path().join(name)).unwrap();
+    }
+    std::fs::remove_file(tmpdir.path().join(names[0])).unwrap();
+
+    let mut buf = [0_u8; 4096];
+    let events = read_events(&fd, &mut buf).unwrap().collect::<Vec<_>>();
+    assert_eq!(events.len(), 3);
+    for event in &events {
+        assert_eq!(event.wd, wd);
+    }
+    assert!(events[0].mask.contains(WatchFlags::CREATE));
+    assert_eq!(events[0].name, Some(CString::new(names[0]).unwrap()));
+    assert!(events[1].mask.contains(WatchFlags::CREATE));
+    assert_eq!(events[1].name, Some(CString::new(names[1]).unwrap()));
+    assert!(events[2].mask.contains(WatchFlags::DELETE));
+    assert_eq!(events[2].name, Some(CString::new(names[0]).unwrap()));
+
+    assert_eq!(Events::new(&[]).count(), 0);
+}
@@ -0,0 +1,21 @@
+#![cfg(not(any(
+    target_os = "emscripten",
+    target_os = "fuchsia",
+    target_os = "redox",
+    target_os = "wasi"
+)))]
+
+use rsix::fs::{fcntl_getfd, FdFlags};
+use rsix::io::{ioctl_fioclex, ioctl_fionclex, pipe};
+
+#[test]
+fn test_ioctl_fioclex() {
+    let (reader, _writer) = pipe().unwrap();
+    assert!(!fcntl_getfd(&reader).unwrap().contains(FdFlags::CLOEXEC));
+
+    ioctl_fioclex(&reader).unwrap();
+    assert!(fcntl_getfd(&reader).unwrap().contains(FdFlags::CLOEXEC));
+
+    ioctl_fionclex(&reader).unwrap();
+    assert!(!fcntl_getfd(&reader).unwrap().contains(FdFlags::CLOEXEC));
+}
@@ -1,11 +1,31 @@
 #![cfg_attr(target_os = "wasi", feature(wasi_ext))]
 #![cfg_attr(io_lifetimes_use_std, feature(io_safety))]
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[path = "../util/fork.rs"]
+mod fork;
+
+mod close_range;
+#[cfg(not(target_os = "wasi"))]
+mod dup2_to;
 mod dup2_to_replace_stdio;
 mod epoll;
+mod error;
 mod eventfd;
+mod inotify;
+mod ioctl;
 mod isatty;
 mod mmap;
+#[cfg(not(target_os = "wasi"))]
+mod pipe;
+#[cfg(not(target_os = "wasi"))]
+mod poll;
+#[cfg(not(target_os = "wasi"))]
+mod read_exact;
 #[cfg(not(target_os = "redox"))] // redox doesn't have cwd/openat
 #[cfg(not(target_os = "wasi"))] // wasi support for S_IRUSR etc. submitted to libc in #2264
 mod readwrite;
+mod retry;
+mod signalfd;
+mod splice;
+mod termios;
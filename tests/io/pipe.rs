@@ -0,0 +1,19 @@
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_pipe_size() {
+    use rsix::io::{fcntl_getpipe_size, fcntl_setpipe_size, pipe_with, PipeFlags};
+
+    let (reader, writer) = pipe_with(PipeFlags::CLOEXEC).unwrap();
+    assert!(fcntl_getpipe_size(&reader).unwrap() > 0);
+
+    let size = 1024 * 1024;
+    let granted = match fcntl_setpipe_size(&writer, size) {
+        Ok(granted) => granted,
+        // The limit in `/proc/sys/fs/pipe-max-size` may be lower.
+        Err(err) if err == rsix::io::Error::PERM => return,
+        Err(err) => panic!("fcntl_setpipe_size failed: {:?}", err),
+    };
+    assert!(granted >= size);
+    assert_eq!(fcntl_getpipe_size(&reader).unwrap(), granted);
+    assert_eq!(fcntl_getpipe_size(&writer).unwrap(), granted);
+}
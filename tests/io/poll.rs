@@ -0,0 +1,44 @@
+use rsix::io::{pipe, poll, write, PollFd, PollFlags};
+
+#[test]
+fn test_poll() {
+    let (reader, writer) = pipe().unwrap();
+
+    // Nothing has been written yet, so the read end isn't ready.
+    let mut fds = [PollFd::new(&reader, PollFlags::IN)];
+    assert_eq!(poll(&mut fds, 0).unwrap(), 0);
+    assert!(fds[0].clone().revents().is_empty());
+
+    write(&writer, b"x").unwrap();
+    assert_eq!(poll(&mut fds, -1).unwrap(), 1);
+    assert_eq!(fds[0].clone().revents(), PollFlags::IN);
+
+    // Closing the write end reports a hangup.
+    drop(writer);
+    assert_eq!(poll(&mut fds, -1).unwrap(), 1);
+    let revents = fds[0].clone().revents();
+    assert!(revents.contains(PollFlags::IN | PollFlags::HUP));
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_ppoll_timeout() {
+    use rsix::io::ppoll;
+    use rsix::time::{clock_gettime, ClockId, Timespec};
+
+    let (reader, _writer) = pipe().unwrap();
+    let mut fds = [PollFd::new(&reader, PollFlags::IN)];
+
+    let timeout = Timespec {
+        tv_sec: 0,
+        tv_nsec: 10_000_000,
+    };
+    let start = clock_gettime(ClockId::Monotonic);
+    assert_eq!(ppoll(&mut fds, Some(&timeout), None).unwrap(), 0);
+    let end = clock_gettime(ClockId::Monotonic);
+    assert!(fds[0].clone().revents().is_empty());
+
+    let elapsed_ns =
+        (end.tv_sec - start.tv_sec) as i64 * 1_000_000_000 + (end.tv_nsec - start.tv_nsec) as i64;
+    assert!(elapsed_ns >= 10_000_000);
+}
@@ -0,0 +1,32 @@
+#[test]
+fn test_read_exact_write_all() {
+    use rsix::io::{pipe, read_exact, write_all};
+    use std::thread;
+
+    let data: Vec<u8> = (0..64 * 1024).map(|i| (i % 251) as u8).collect();
+    let (reader, writer) = pipe().unwrap();
+
+    // Shrink the pipe so that the data arrives in many small pieces.
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    rsix::io::fcntl_setpipe_size(&writer, 4096).unwrap();
+
+    let expected = data.clone();
+    let writer = thread::spawn(move || write_all(&writer, &data).unwrap());
+
+    let mut buf = vec![0_u8; expected.len()];
+    read_exact(&reader, &mut buf).unwrap();
+    assert_eq!(buf, expected);
+    writer.join().unwrap();
+}
+
+#[test]
+fn test_read_exact_eof() {
+    use rsix::io::{pipe, read_exact, write_all};
+
+    let (reader, writer) = pipe().unwrap();
+    write_all(&writer, b"short").unwrap();
+    drop(writer);
+
+    let mut buf = [0_u8; 16];
+    assert_eq!(read_exact(&reader, &mut buf), Err(rsix::io::Error::IO));
+}
@@ -112,3 +112,106 @@ fn test_readwrite() {
     read(&foo, &mut buf).unwrap();
     assert_eq!(&buf, b"world");
 }
+
+#[test]
+fn test_read_uninit() {
+    use rsix::io::{pipe, read_uninit, write};
+    use std::mem::MaybeUninit;
+
+    let (reader, writer) = pipe().unwrap();
+    assert_eq!(write(&writer, b"hello").unwrap(), 5);
+
+    let mut buf = [MaybeUninit::<u8>::uninit(); 16];
+    let (init, uninit) = read_uninit(&reader, &mut buf).unwrap();
+    assert_eq!(init, b"hello");
+    assert_eq!(uninit.len(), 11);
+}
+
+#[cfg(all(unix, not(target_os = "fuchsia")))]
+#[test]
+fn test_readv_writev() {
+    use rsix::io::{readv, writev};
+    use rsix::net::{socketpair, AcceptFlags, AddressFamily, Protocol, SocketType};
+
+    let (a, b) = socketpair(
+        AddressFamily::UNIX,
+        SocketType::STREAM,
+        AcceptFlags::CLOEXEC,
+        Protocol::default(),
+    )
+    .unwrap();
+
+    let nwritten = writev(
+        &a,
+        &[
+            IoSlice::new(b"hello"),
+            IoSlice::new(b", "),
+            IoSlice::new(b"world"),
+        ],
+    )
+    .unwrap();
+    assert_eq!(nwritten, 12);
+
+    let mut first = [0_u8; 4];
+    let mut second = [0_u8; 8];
+    let nread = readv(
+        &b,
+        &[IoSliceMut::new(&mut first), IoSliceMut::new(&mut second)],
+    )
+    .unwrap();
+    assert_eq!(nread, 12);
+    assert_eq!(&first, b"hell");
+    assert_eq!(&second, b"o, world");
+}
+
+#[cfg(any(linux_raw, all(libc, target_os = "linux", target_env = "gnu")))]
+#[test]
+fn test_readwrite_v2() {
+    use rsix::fs::{cwd, openat, seek, Mode, OFlags};
+    use rsix::io::{preadv2, pwritev2, ReadWriteFlags};
+    use std::io::SeekFrom;
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(&cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let foo = openat(
+        &dir,
+        "foo",
+        OFlags::RDWR | OFlags::CREATE | OFlags::TRUNC,
+        Mode::IRUSR | Mode::IWUSR,
+    )
+    .unwrap();
+
+    // Write at explicit positions, durably.
+    let nwritten = pwritev2(
+        &foo,
+        &[IoSlice::new(b"hello")],
+        Some(200),
+        ReadWriteFlags::DSYNC,
+    )
+    .unwrap();
+    assert_eq!(nwritten, 5);
+    assert_eq!(seek(&foo, SeekFrom::Current(0)).unwrap(), 0);
+
+    // Write at the current position, which advances it.
+    let nwritten = pwritev2(&foo, &[IoSlice::new(b"world")], None, ReadWriteFlags::DSYNC).unwrap();
+    assert_eq!(nwritten, 5);
+    assert_eq!(seek(&foo, SeekFrom::Current(0)).unwrap(), 5);
+
+    let mut buf = [0_u8; 5];
+    preadv2(
+        &foo,
+        &[IoSliceMut::new(&mut buf)],
+        Some(200),
+        ReadWriteFlags::empty(),
+    )
+    .unwrap();
+    assert_eq!(&buf, b"hello");
+    preadv2(
+        &foo,
+        &[IoSliceMut::new(&mut buf)],
+        Some(0),
+        ReadWriteFlags::empty(),
+    )
+    .unwrap();
+    assert_eq!(&buf, b"world");
+}
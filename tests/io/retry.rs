@@ -0,0 +1,79 @@
+#![cfg(target_os = "linux")]
+
+use rsix::io::{pipe, read, retry_on_intr, write, Error};
+use rsix::process::Signal;
+use rsix::thread::gettid;
+use rsix::time::{ClockId, Itimerspec, Sigevent, Timer, TimerFlags, Timespec};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static SIGNALS: AtomicUsize = AtomicUsize::new(0);
+
+extern "C" fn handler(_signo: libc::c_int) {
+    SIGNALS.fetch_add(1, Ordering::SeqCst);
+}
+
+#[test]
+fn test_retry_on_intr() {
+    // Install a handler without `SA_RESTART`, so that the signal interrupts
+    // a blocking `read` with `EINTR`.
+    let mut old_action = unsafe { std::mem::zeroed::<libc::sigaction>() };
+    unsafe {
+        let mut action: libc::sigaction = std::mem::zeroed();
+        action.sa_sigaction = handler as extern "C" fn(libc::c_int) as libc::sighandler_t;
+        assert_eq!(libc::sigaction(libc::SIGUSR2, &action, &mut old_action), 0);
+    }
+
+    // Signal this thread periodically, so that it's eventually interrupted
+    // while it's blocked, however long it takes to get there.
+    let timer = Timer::new(
+        ClockId::Monotonic,
+        Sigevent::ThreadSignal {
+            signo: Signal::Usr2,
+            value: 0,
+            tid: gettid(),
+        },
+    )
+    .unwrap();
+    let period = Timespec {
+        tv_sec: 0,
+        tv_nsec: 10_000_000,
+    };
+    timer
+        .set_time(
+            TimerFlags::empty(),
+            &Itimerspec {
+                it_interval: period,
+                it_value: period,
+            },
+        )
+        .unwrap();
+
+    // The first attempt blocks on the empty pipe until a signal interrupts
+    // it. Each later attempt supplies the data first, so it doesn't block.
+    let (reader, writer) = pipe().unwrap();
+    let mut buf = [0_u8; 16];
+    let mut results = Vec::new();
+    let nread = retry_on_intr(|| {
+        if !results.is_empty() {
+            write(&writer, b"hello").unwrap();
+        }
+        let result = read(&reader, &mut buf);
+        results.push(result);
+        result
+    })
+    .unwrap();
+
+    // Stop the signals before restoring the default action, which would
+    // terminate the process.
+    drop(timer);
+    unsafe {
+        assert_eq!(
+            libc::sigaction(libc::SIGUSR2, &old_action, std::ptr::null_mut()),
+            0
+        );
+    }
+
+    assert_eq!(&buf[..nread], b"hello");
+    assert_eq!(results, [Err(Error::INTR), Ok(5)]);
+    assert!(SIGNALS.load(Ordering::SeqCst) >= 1);
+}
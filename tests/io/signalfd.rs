@@ -0,0 +1,64 @@
+#![cfg(any(target_os = "android", target_os = "linux"))]
+
+use rsix::io::{signalfd, signalfd_read, signalfd_set_mask, OwnedFd, SignalfdFlags};
+use rsix::process::{getpid, sigprocmask, SigSet, SigmaskHow, Signal};
+
+#[test]
+fn test_sigset() {
+    let mut set = SigSet::new();
+    assert!(!set.contains(Signal::Usr1));
+    set.add(Signal::Usr1).unwrap();
+    assert!(set.contains(Signal::Usr1));
+    assert!(!set.contains(Signal::Usr2));
+    set.remove(Signal::Usr1).unwrap();
+    assert!(!set.contains(Signal::Usr1));
+}
+
+#[test]
+fn test_signalfd() {
+    let mut mask = SigSet::new();
+    mask.add(Signal::Usr1).unwrap();
+
+    // Block `SIGUSR1` in this thread so that it's only reported through the
+    // signalfd. `raise` sends the signal to the calling thread.
+    let old = sigprocmask(SigmaskHow::Block, Some(&mask)).unwrap();
+
+    let fd = signalfd(
+        None::<&OwnedFd>,
+        &mask,
+        SignalfdFlags::CLOEXEC | SignalfdFlags::NONBLOCK,
+    )
+    .unwrap();
+    assert_eq!(
+        signalfd_read(&fd).map(|_| ()),
+        Err(rsix::io::Error::WOULDBLOCK)
+    );
+
+    assert_eq!(unsafe { libc::raise(libc::SIGUSR1) }, 0);
+
+    let info = signalfd_read(&fd).unwrap();
+    assert_eq!(info.ssi_signo, libc::SIGUSR1 as u32);
+    assert_eq!(info.ssi_pid, getpid().as_raw() as u32);
+
+    // With `SIGUSR1` gone from the mask, nothing is reported.
+    signalfd_set_mask(&fd, &SigSet::new()).unwrap();
+    assert_eq!(unsafe { libc::raise(libc::SIGUSR1) }, 0);
+    assert_eq!(
+        signalfd_read(&fd).map(|_| ()),
+        Err(rsix::io::Error::WOULDBLOCK)
+    );
+
+    // Passing an existing signalfd replaces its mask, and the returned file
+    // descriptor refers to the same signalfd.
+    let dup = signalfd(Some(&fd), &mask, SignalfdFlags::CLOEXEC).unwrap();
+    let info = signalfd_read(&dup).unwrap();
+    assert_eq!(info.ssi_signo, libc::SIGUSR1 as u32);
+    assert_eq!(
+        signalfd_read(&fd).map(|_| ()),
+        Err(rsix::io::Error::WOULDBLOCK)
+    );
+
+    sigprocmask(SigmaskHow::SetMask, Some(&old)).unwrap();
+    let current = sigprocmask(SigmaskHow::Block, None).unwrap();
+    assert!(!current.contains(Signal::Usr1));
+}
@@ -0,0 +1,72 @@
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_splice() {
+    use rsix::fs::{cwd, openat, tell, Mode, OFlags};
+    use rsix::io::{pipe, pread, splice, write, SpliceFlags};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(&cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::RDWR,
+        Mode::IRUSR | Mode::IWUSR,
+    )
+    .unwrap();
+
+    let (reader, writer) = pipe().unwrap();
+    assert_eq!(write(&writer, b"hello world").unwrap(), 11);
+
+    let mut off_out = 3;
+    let n = splice(
+        &reader,
+        None,
+        &file,
+        Some(&mut off_out),
+        11,
+        SpliceFlags::empty(),
+    )
+    .unwrap();
+    assert_eq!(n, 11);
+    assert_eq!(off_out, 14);
+    assert_eq!(tell(&file).unwrap(), 0);
+
+    let mut buf = [0_u8; 11];
+    assert_eq!(pread(&file, &mut buf, 3).unwrap(), 11);
+    assert_eq!(&buf, b"hello world");
+
+    // The pipe is now empty.
+    assert_eq!(
+        splice(&reader, None, &file, None, 11, SpliceFlags::NONBLOCK).unwrap_err(),
+        rsix::io::Error::WOULDBLOCK
+    );
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_vmsplice_tee() {
+    use rsix::io::{pipe, read, tee, vmsplice, SpliceFlags};
+    use std::io::IoSlice;
+
+    let (reader, writer) = pipe().unwrap();
+    let (copy_reader, copy_writer) = pipe().unwrap();
+
+    let bufs = [IoSlice::new(b"hello "), IoSlice::new(b"world")];
+    // Safety: `writer` is the write end of the pipe, and we don't use `GIFT`.
+    assert_eq!(
+        unsafe { vmsplice(&writer, &bufs, SpliceFlags::empty()) }.unwrap(),
+        11
+    );
+    assert_eq!(
+        tee(&reader, &copy_writer, 11, SpliceFlags::empty()).unwrap(),
+        11
+    );
+
+    // `tee` doesn't consume the data, so both pipes have it.
+    let mut buf = [0_u8; 11];
+    assert_eq!(read(&reader, &mut buf).unwrap(), 11);
+    assert_eq!(&buf, b"hello world");
+    let mut buf = [0_u8; 11];
+    assert_eq!(read(&copy_reader, &mut buf).unwrap(), 11);
+    assert_eq!(&buf, b"hello world");
+}
@@ -0,0 +1,150 @@
+#![cfg(any(target_os = "android", target_os = "linux"))]
+
+use crate::fork::in_child;
+use io_lifetimes::AsFd;
+use rsix::fs::{cwd, openat, Mode, OFlags};
+use rsix::io::{
+    ioctl_fionbio, ioctl_tiocgwinsz, ioctl_tiocswinsz, read, tcdrain, tcflush, tcgetattr,
+    tcgetpgrp, tcsetattr, write, Error, LocalFlags, OptionalActions, OwnedFd, QueueSelector,
+    TermiosExt, Winsize,
+};
+use std::ffi::CStr;
+use std::os::unix::io::AsRawFd;
+
+/// Opens a pty, returning its master and slave sides.
+fn openpty() -> (OwnedFd, OwnedFd) {
+    let master = openat(
+        &cwd(),
+        "/dev/ptmx",
+        OFlags::RDWR | OFlags::NOCTTY | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+    .unwrap();
+
+    let mut name = [0_u8; 64];
+    unsafe {
+        let raw = master.as_fd().as_raw_fd();
+        assert_eq!(libc::unlockpt(raw), 0);
+        assert_eq!(
+            libc::ptsname_r(raw, name.as_mut_ptr().cast(), name.len()),
+            0
+        );
+    }
+    let name =
+        CStr::from_bytes_with_nul(&name[..=name.iter().position(|b| *b == 0).unwrap()]).unwrap();
+
+    let slave = openat(
+        &cwd(),
+        name,
+        OFlags::RDWR | OFlags::NOCTTY | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+    .unwrap();
+    (master, slave)
+}
+
+#[test]
+fn test_termios_echo() {
+    // On Linux, terminal attributes set through a pty master apply to the
+    // pty, so we don't need to open the slave side.
+    let pty = openat(
+        &cwd(),
+        "/dev/ptmx",
+        OFlags::RDWR | OFlags::NOCTTY | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+    .unwrap();
+
+    let orig = tcgetattr(&pty).unwrap();
+
+    let mut termios = orig;
+    termios.set_echo(false);
+    tcsetattr(&pty, OptionalActions::Now, &termios).unwrap();
+
+    let termios = tcgetattr(&pty).unwrap();
+    assert!(!termios.is_echo());
+    assert!(!termios.local_flags().contains(LocalFlags::ECHO));
+    assert_eq!(termios.is_canonical(), orig.is_canonical());
+
+    tcsetattr(&pty, OptionalActions::Now, &orig).unwrap();
+    assert_eq!(tcgetattr(&pty).unwrap().is_echo(), orig.is_echo());
+}
+
+#[test]
+fn test_termios_make_raw() {
+    let mut termios = tcgetattr(
+        &openat(
+            &cwd(),
+            "/dev/ptmx",
+            OFlags::RDWR | OFlags::NOCTTY | OFlags::CLOEXEC,
+            Mode::empty(),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    termios.make_raw();
+    assert!(!termios.is_canonical());
+    assert!(!termios.is_echo());
+    assert_eq!(termios.vmin(), 1);
+    assert_eq!(termios.vtime(), 0);
+}
+
+#[test]
+fn test_tcflush() {
+    let (master, slave) = openpty();
+
+    // Make the input available to `read` without waiting for a newline.
+    let mut termios = tcgetattr(&slave).unwrap();
+    termios.make_raw();
+    tcsetattr(&slave, OptionalActions::Now, &termios).unwrap();
+
+    write(&master, b"hello").unwrap();
+    tcdrain(&master).unwrap();
+    tcflush(&slave, QueueSelector::IFlush).unwrap();
+
+    ioctl_fionbio(&slave, true).unwrap();
+    let mut buf = [0_u8; 16];
+    assert_eq!(read(&slave, &mut buf), Err(Error::WOULDBLOCK));
+}
+
+#[test]
+fn test_tiocswinsz() {
+    let (master, _slave) = openpty();
+
+    ioctl_tiocswinsz(
+        &master,
+        Winsize {
+            ws_row: 24,
+            ws_col: 80,
+            ws_xpixel: 0,
+            ws_ypixel: 0,
+        },
+    )
+    .unwrap();
+
+    let size = ioctl_tiocgwinsz(master.as_fd()).unwrap();
+    assert_eq!(size.ws_row, 24);
+    assert_eq!(size.ws_col, 80);
+}
+
+#[test]
+fn test_tcgetpgrp() {
+    let (master, slave) = openpty();
+
+    // The pty isn't our controlling terminal, so it has no foreground process
+    // group for us.
+    assert_eq!(tcgetpgrp(&slave), Err(Error::NOTTY));
+
+    // Make the pty the controlling terminal of a new session in a child, so
+    // that the child's process group is in the foreground.
+    in_child(|| {
+        rsix::process::setsid().unwrap();
+        assert_eq!(
+            unsafe { libc::ioctl(slave.as_fd().as_raw_fd(), libc::TIOCSCTTY, 0) },
+            0
+        );
+        assert_eq!(tcgetpgrp(&slave), Ok(rsix::process::getpgrp()));
+        assert_eq!(tcgetpgrp(&master), Ok(rsix::process::getpgrp()));
+    });
+}
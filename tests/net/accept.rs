@@ -0,0 +1,51 @@
+//! Test accepting a connection with flags and retrieving the peer address.
+
+#![cfg(not(any(target_os = "ios", target_os = "macos")))]
+
+use rsix::fs::{fcntl_getfd, fcntl_getfl, FdFlags, OFlags};
+use rsix::net::{
+    acceptfrom_with, bind_v4, connect_v4, getsockname, listen, socket, AcceptFlags, AddressFamily,
+    Ipv4Addr, Protocol, SocketAddr, SocketAddrV4, SocketType,
+};
+
+#[test]
+fn test_acceptfrom_with() {
+    let listener = socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+    bind_v4(
+        &listener,
+        &SocketAddrV4::new(Ipv4Addr::new(127, 0, 0, 1), 0),
+    )
+    .unwrap();
+    listen(&listener, 1).unwrap();
+    let local = match getsockname(&listener).unwrap() {
+        SocketAddr::V4(addr) => addr,
+        _ => panic!(),
+    };
+
+    // The listen backlog completes the handshake, so connecting doesn't need
+    // a separate thread.
+    let client = socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+    connect_v4(&client, &local).unwrap();
+
+    let (accepted, peer) =
+        acceptfrom_with(&listener, AcceptFlags::NONBLOCK | AcceptFlags::CLOEXEC).unwrap();
+    match peer {
+        SocketAddr::V4(addr) => {
+            assert_eq!(
+                addr.address().clone().into_std(),
+                std::net::Ipv4Addr::LOCALHOST
+            );
+            match getsockname(&client).unwrap() {
+                SocketAddr::V4(client_addr) => assert_eq!(addr.port(), client_addr.port()),
+                _ => panic!(),
+            }
+        }
+        _ => panic!("expected an IPv4 peer address"),
+    }
+
+    assert!(fcntl_getfl(&accepted).unwrap().contains(OFlags::NONBLOCK));
+    assert!(fcntl_getfd(&accepted).unwrap().contains(FdFlags::CLOEXEC));
+
+    // The flags apply only to the accepted socket.
+    assert!(!fcntl_getfl(&listener).unwrap().contains(OFlags::NONBLOCK));
+}
@@ -0,0 +1,33 @@
+//! Test the functions that take a `SocketAddr` of any kind.
+
+use rsix::net::{
+    bind, connect, getsockname, recv, send, sendto, socket, AddressFamily, Ipv4Addr, Protocol,
+    RecvFlags, SendFlags, SocketAddr, SocketAddrV4, SocketType,
+};
+
+#[test]
+fn test_connect_to_getsockname() {
+    let server = socket(AddressFamily::INET, SocketType::DGRAM, Protocol::default()).unwrap();
+    let local = SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0));
+    bind(&server, &local).unwrap();
+
+    let addr = getsockname(&server).unwrap();
+    assert!(matches!(addr, SocketAddr::V4(_)));
+
+    let client = socket(AddressFamily::INET, SocketType::DGRAM, Protocol::default()).unwrap();
+    connect(&client, &addr).unwrap();
+    assert_eq!(send(&client, b"hello", SendFlags::empty()).unwrap(), 5);
+
+    let mut buf = [0_u8; 16];
+    let nread = recv(&server, &mut buf, RecvFlags::empty()).unwrap();
+    assert_eq!(&buf[..nread], b"hello");
+
+    let other = socket(AddressFamily::INET, SocketType::DGRAM, Protocol::default()).unwrap();
+    assert_eq!(
+        sendto(&other, b"world", SendFlags::empty(), &addr).unwrap(),
+        5
+    );
+
+    let nread = recv(&server, &mut buf, RecvFlags::empty()).unwrap();
+    assert_eq!(&buf[..nread], b"world");
+}
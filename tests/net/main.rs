@@ -2,6 +2,17 @@
 #![cfg(not(any(target_os = "redox", target_os = "wasi")))] // WASI doesn't support `net` yet.
 #![cfg_attr(io_lifetimes_use_std, feature(io_safety))]
 
+mod accept;
+mod addr;
+mod msg;
+mod shutdown;
+mod socket;
+#[cfg(not(any(target_os = "ios", target_os = "macos")))]
+mod socketpair;
+mod sockopt;
+mod udp;
 mod unix;
+mod unix_abstract;
+mod unix_peer;
 mod v4;
 mod v6;
@@ -0,0 +1,88 @@
+//! Test passing file descriptors over a Unix-domain socket with `sendmsg`
+//! and `recvmsg`.
+
+#![cfg(any(target_os = "android", target_os = "linux"))]
+
+use io_lifetimes::AsFd;
+use rsix::io::{pipe, read, write};
+use rsix::net::{
+    recvmsg, sendmsg, socketpair, AcceptFlags, AddressFamily, Protocol, RecvAncillaryBuffer,
+    RecvAncillaryMessage, RecvFlags, ReturnFlags, SendAncillaryBuffer, SendFlags, SocketType,
+};
+use std::io::{IoSlice, IoSliceMut};
+
+#[test]
+fn test_sendmsg_recvmsg_fds() {
+    let (a, b) = socketpair(
+        AddressFamily::UNIX,
+        SocketType::STREAM,
+        AcceptFlags::CLOEXEC,
+        Protocol::default(),
+    )
+    .unwrap();
+    let (reader, writer) = pipe().unwrap();
+
+    let mut control = SendAncillaryBuffer::new();
+    control.push_fds(&[writer.as_fd()]);
+    let nwritten = sendmsg(&a, &[IoSlice::new(b"fd")], &mut control, SendFlags::empty()).unwrap();
+    assert_eq!(nwritten, 2);
+    drop(control);
+    drop(writer);
+
+    let mut buf = [0_u8; 2];
+    let mut control = RecvAncillaryBuffer::for_fds(1);
+    let ret = recvmsg(
+        &b,
+        &mut [IoSliceMut::new(&mut buf)],
+        &mut control,
+        RecvFlags::CMSG_CLOEXEC,
+    )
+    .unwrap();
+    assert_eq!(ret.bytes, 2);
+    assert_eq!(&buf, b"fd");
+    assert!(!ret.flags.contains(ReturnFlags::CTRUNC));
+
+    let mut fds = Vec::new();
+    for message in control.drain() {
+        match message {
+            RecvAncillaryMessage::ScmRights(rights) => fds.extend(rights),
+            _ => panic!("unexpected ancillary message"),
+        }
+    }
+    assert_eq!(fds.len(), 1);
+
+    // The received descriptor refers to the write end of the pipe.
+    assert_eq!(write(&fds[0], b"hello").unwrap(), 5);
+    let mut buf = [0_u8; 5];
+    assert_eq!(read(&reader, &mut buf).unwrap(), 5);
+    assert_eq!(&buf, b"hello");
+}
+
+#[test]
+fn test_recvmsg_ctrunc() {
+    let (a, b) = socketpair(
+        AddressFamily::UNIX,
+        SocketType::STREAM,
+        AcceptFlags::CLOEXEC,
+        Protocol::default(),
+    )
+    .unwrap();
+    let (reader, writer) = pipe().unwrap();
+
+    let mut control = SendAncillaryBuffer::new();
+    control.push_fds(&[reader.as_fd(), writer.as_fd()]);
+    sendmsg(&a, &[IoSlice::new(b"x")], &mut control, SendFlags::empty()).unwrap();
+
+    let mut buf = [0_u8; 1];
+    let mut control = RecvAncillaryBuffer::with_capacity(0);
+    let ret = recvmsg(
+        &b,
+        &mut [IoSliceMut::new(&mut buf)],
+        &mut control,
+        RecvFlags::empty(),
+    )
+    .unwrap();
+    assert_eq!(ret.bytes, 1);
+    assert!(ret.flags.contains(ReturnFlags::CTRUNC));
+    assert_eq!(control.drain().count(), 0);
+}
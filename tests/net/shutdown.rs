@@ -0,0 +1,37 @@
+//! Test that `shutdown` is reflected in `is_read_write`.
+
+use rsix::io::is_read_write;
+use rsix::net::{shutdown, socketpair, AcceptFlags, AddressFamily, Protocol, Shutdown, SocketType};
+
+#[test]
+fn test_shutdown_write() {
+    let (a, b) = socketpair(
+        AddressFamily::UNIX,
+        SocketType::STREAM,
+        AcceptFlags::empty(),
+        Protocol::default(),
+    )
+    .unwrap();
+    assert_eq!(is_read_write(&a).unwrap(), (true, true));
+    assert_eq!(is_read_write(&b).unwrap(), (true, true));
+
+    shutdown(&a, Shutdown::Write).unwrap();
+
+    // `a` can no longer send, and `b` now sees end-of-stream.
+    assert_eq!(is_read_write(&a).unwrap(), (true, false));
+    assert_eq!(is_read_write(&b).unwrap(), (false, true));
+}
+
+#[test]
+fn test_shutdown_read_write() {
+    let (a, _b) = socketpair(
+        AddressFamily::UNIX,
+        SocketType::STREAM,
+        AcceptFlags::empty(),
+        Protocol::default(),
+    )
+    .unwrap();
+
+    shutdown(&a, Shutdown::ReadWrite).unwrap();
+    assert_eq!(is_read_write(&a).unwrap(), (false, false));
+}
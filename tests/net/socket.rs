@@ -0,0 +1,60 @@
+use rsix::io::{read, write};
+use rsix::net::{
+    accept, bind, connect, getpeername, getsockname, getsockopt_socket_type, listen, socket,
+    AddressFamily, Ipv4Addr, Protocol, SocketAddr, SocketAddrV4, SocketType,
+};
+
+#[test]
+fn test_socket_type_debug() {
+    assert_eq!(format!("{:?}", SocketType::DGRAM), "DGRAM");
+    assert_eq!(format!("{:?}", SocketType::STREAM), "STREAM");
+    assert_eq!(format!("{:?}", AddressFamily::INET6), "INET6");
+    assert_eq!(format!("{:?}", AddressFamily::UNIX), "UNIX");
+    assert_eq!(format!("{:?}", Protocol::Udp), "Udp");
+}
+
+#[test]
+fn test_socket_protocol_udp() {
+    let s = socket(AddressFamily::INET, SocketType::DGRAM, Protocol::Udp).unwrap();
+    assert_eq!(getsockopt_socket_type(&s).unwrap(), SocketType::DGRAM);
+
+    let s = socket(AddressFamily::INET, SocketType::STREAM, Protocol::Tcp).unwrap();
+    assert_eq!(getsockopt_socket_type(&s).unwrap(), SocketType::STREAM);
+}
+
+fn port(addr: &SocketAddr) -> u16 {
+    match addr {
+        SocketAddr::V4(addr) => addr.port(),
+        _ => panic!("expected an IPv4 address"),
+    }
+}
+
+#[test]
+fn test_tcp_server() {
+    let listener = socket(AddressFamily::INET, SocketType::STREAM, Protocol::Tcp).unwrap();
+    bind(
+        &listener,
+        &SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::new(127, 0, 0, 1), 0)),
+    )
+    .unwrap();
+    listen(&listener, 1).unwrap();
+    let local = getsockname(&listener).unwrap();
+    assert_ne!(port(&local), 0);
+
+    // The listen backlog completes the handshake, so connecting doesn't need
+    // a separate thread.
+    let client = socket(AddressFamily::INET, SocketType::STREAM, Protocol::Tcp).unwrap();
+    connect(&client, &local).unwrap();
+    assert_eq!(port(&getpeername(&client).unwrap()), port(&local));
+
+    let server = accept(&listener).unwrap();
+    assert_eq!(
+        port(&getpeername(&server).unwrap()),
+        port(&getsockname(&client).unwrap())
+    );
+
+    assert_eq!(write(&client, b"x").unwrap(), 1);
+    let mut buf = [0_u8; 1];
+    assert_eq!(read(&server, &mut buf).unwrap(), 1);
+    assert_eq!(&buf, b"x");
+}
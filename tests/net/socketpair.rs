@@ -0,0 +1,22 @@
+use rsix::fs::{fcntl_getfd, FdFlags};
+use rsix::io::{read, write};
+use rsix::net::{socketpair, AcceptFlags, AddressFamily, Protocol, SocketType};
+
+#[test]
+fn test_socketpair() {
+    let (a, b) = socketpair(
+        AddressFamily::UNIX,
+        SocketType::STREAM,
+        AcceptFlags::CLOEXEC,
+        Protocol::default(),
+    )
+    .unwrap();
+
+    assert!(fcntl_getfd(&a).unwrap().contains(FdFlags::CLOEXEC));
+    assert!(fcntl_getfd(&b).unwrap().contains(FdFlags::CLOEXEC));
+
+    assert_eq!(write(&a, b"hello").unwrap(), 5);
+    let mut buf = [0_u8; 5];
+    assert_eq!(read(&b, &mut buf).unwrap(), 5);
+    assert_eq!(&buf, b"hello");
+}
@@ -0,0 +1,143 @@
+use rsix::fs::{fcntl_setfl, OFlags};
+use rsix::io::{self, poll, PollFd, PollFlags};
+use rsix::net::{
+    bind_v4, connect_v4, getsockname, socket, sockopt, AddressFamily, Ipv4Addr, Protocol,
+    SocketAddr, SocketAddrV4, SocketType,
+};
+use std::time::Duration;
+
+#[test]
+fn test_sockopt_tcp_nodelay() {
+    let s = socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+
+    sockopt::set_tcp_nodelay(&s, true).unwrap();
+    assert!(sockopt::get_tcp_nodelay(&s).unwrap());
+
+    sockopt::set_tcp_nodelay(&s, false).unwrap();
+    assert!(!sockopt::get_tcp_nodelay(&s).unwrap());
+}
+
+#[test]
+fn test_sockopt_reuseaddr() {
+    let s = socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+
+    sockopt::set_reuseaddr(&s, true).unwrap();
+    assert!(sockopt::get_reuseaddr(&s).unwrap());
+
+    sockopt::set_reuseaddr(&s, false).unwrap();
+    assert!(!sockopt::get_reuseaddr(&s).unwrap());
+}
+
+#[test]
+fn test_sockopt_recv_buffer_size() {
+    let s = socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+
+    sockopt::set_recv_buffer_size(&s, 65536).unwrap();
+    let size = sockopt::get_recv_buffer_size(&s).unwrap();
+
+    // Linux doubles the requested size to leave room for bookkeeping, and
+    // other platforms may round it up.
+    assert!(size >= 65536);
+}
+
+#[test]
+fn test_sockopt_linger() {
+    let s = socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+
+    assert_eq!(sockopt::get_socket_linger(&s).unwrap(), None);
+
+    sockopt::set_socket_linger(&s, Some(Duration::from_secs(5))).unwrap();
+    assert_eq!(
+        sockopt::get_socket_linger(&s).unwrap(),
+        Some(Duration::from_secs(5))
+    );
+
+    // Sub-second durations round up rather than down to zero.
+    sockopt::set_socket_linger(&s, Some(Duration::from_millis(500))).unwrap();
+    assert_eq!(
+        sockopt::get_socket_linger(&s).unwrap(),
+        Some(Duration::from_secs(1))
+    );
+
+    sockopt::set_socket_linger(&s, None).unwrap();
+    assert_eq!(sockopt::get_socket_linger(&s).unwrap(), None);
+}
+
+#[test]
+fn test_sockopt_keepalive() {
+    let s = socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+
+    sockopt::set_keepalive(&s, true).unwrap();
+    assert!(sockopt::get_keepalive(&s).unwrap());
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    {
+        sockopt::set_tcp_keepidle(&s, Duration::from_secs(60)).unwrap();
+        assert_eq!(
+            sockopt::get_tcp_keepidle(&s).unwrap(),
+            Duration::from_secs(60)
+        );
+
+        // Durations are rounded up to whole seconds.
+        sockopt::set_tcp_keepintvl(&s, Duration::from_millis(1500)).unwrap();
+        assert_eq!(
+            sockopt::get_tcp_keepintvl(&s).unwrap(),
+            Duration::from_secs(2)
+        );
+
+        sockopt::set_tcp_keepcnt(&s, 4).unwrap();
+        assert_eq!(sockopt::get_tcp_keepcnt(&s).unwrap(), 4);
+
+        assert_eq!(
+            sockopt::set_tcp_keepidle(&s, Duration::from_secs(0)),
+            Err(rsix::io::Error::INVAL)
+        );
+    }
+}
+
+#[test]
+fn test_sockopt_socket_error() {
+    let s = socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+    assert_eq!(sockopt::get_socket_error(&s).unwrap(), Ok(()));
+
+    // Bind a socket to get a free port, but don't listen on it, so that
+    // connecting to it is refused.
+    let unused = socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+    bind_v4(&unused, &SocketAddrV4::new(Ipv4Addr::new(127, 0, 0, 1), 0)).unwrap();
+    let addr = match getsockname(&unused).unwrap() {
+        SocketAddr::V4(addr) => addr,
+        _ => panic!(),
+    };
+
+    fcntl_setfl(&s, OFlags::NONBLOCK).unwrap();
+    match connect_v4(&s, &addr) {
+        Err(err) if err == io::Error::INPROGRESS => (),
+        otherwise => panic!("unexpected connect result: {:?}", otherwise),
+    }
+
+    let mut fds = [PollFd::new(&s, PollFlags::OUT)];
+    assert_eq!(poll(&mut fds, 10_000).unwrap(), 1);
+
+    assert_eq!(
+        sockopt::get_socket_error(&s).unwrap(),
+        Err(io::Error::CONNREFUSED)
+    );
+
+    // Reading `SO_ERROR` clears it.
+    assert_eq!(sockopt::get_socket_error(&s).unwrap(), Ok(()));
+}
+
+#[test]
+fn test_sockopt_multicast_v4() {
+    let s = socket(AddressFamily::INET, SocketType::DGRAM, Protocol::default()).unwrap();
+
+    sockopt::set_ip_multicast_loop(&s, false).unwrap();
+    assert!(!sockopt::get_ip_multicast_loop(&s).unwrap());
+
+    sockopt::set_ip_multicast_ttl(&s, 4).unwrap();
+    assert_eq!(sockopt::get_ip_multicast_ttl(&s).unwrap(), 4);
+
+    let group = Ipv4Addr::new(239, 0, 0, 1);
+    sockopt::join_multicast_v4(&s, &group, &Ipv4Addr::LOCALHOST).unwrap();
+    sockopt::leave_multicast_v4(&s, &group, &Ipv4Addr::LOCALHOST).unwrap();
+}
@@ -0,0 +1,123 @@
+//! Test receiving UDP datagrams that are larger than the buffer.
+
+use rsix::io::OwnedFd;
+use rsix::net::{
+    bind_v4, getsockname, recv, recv_uninit, sendto_v4, socket, AddressFamily, Ipv4Addr, Protocol,
+    RecvFlags, SendFlags, SocketAddr, SocketAddrV4, SocketType,
+};
+use std::mem::MaybeUninit;
+
+fn udp_pair() -> (OwnedFd, OwnedFd, SocketAddrV4) {
+    let receiver = socket(AddressFamily::INET, SocketType::DGRAM, Protocol::default()).unwrap();
+    bind_v4(
+        &receiver,
+        &SocketAddrV4::new(Ipv4Addr::new(127, 0, 0, 1), 0),
+    )
+    .unwrap();
+    let addr = match getsockname(&receiver).unwrap() {
+        SocketAddr::V4(addr) => addr,
+        _ => panic!(),
+    };
+    let sender = socket(AddressFamily::INET, SocketType::DGRAM, Protocol::default()).unwrap();
+    (receiver, sender, addr)
+}
+
+#[test]
+fn test_recv_trunc() {
+    let (receiver, sender, addr) = udp_pair();
+    let data = [0x2a_u8; 100];
+
+    sendto_v4(&sender, &data, SendFlags::empty(), &addr).unwrap();
+    let mut buf = [0_u8; 10];
+    assert_eq!(recv(&receiver, &mut buf, RecvFlags::empty()).unwrap(), 10);
+    assert_eq!(buf, [0x2a_u8; 10]);
+
+    // With `MSG_TRUNC`, the full length is reported.
+    sendto_v4(&sender, &data, SendFlags::empty(), &addr).unwrap();
+    assert_eq!(recv(&receiver, &mut buf, RecvFlags::TRUNC).unwrap(), 100);
+}
+
+#[test]
+fn test_recv_uninit() {
+    let (receiver, sender, addr) = udp_pair();
+
+    sendto_v4(&sender, b"hello", SendFlags::empty(), &addr).unwrap();
+    let mut buf = [MaybeUninit::<u8>::uninit(); 16];
+    let (init, uninit) = recv_uninit(&receiver, &mut buf, RecvFlags::empty()).unwrap();
+    assert_eq!(init, b"hello");
+    assert_eq!(uninit.len(), 11);
+
+    // The initialized part never extends past the buffer, even when the
+    // datagram is truncated.
+    sendto_v4(&sender, &[0x2a_u8; 100], SendFlags::empty(), &addr).unwrap();
+    let (init, uninit) = recv_uninit(&receiver, &mut buf, RecvFlags::TRUNC).unwrap();
+    assert_eq!(init, &[0x2a_u8; 16][..]);
+    assert!(uninit.is_empty());
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_recvfrom_flags_trunc() {
+    use rsix::net::{recvfrom_flags, ReturnFlags};
+
+    let (receiver, sender, addr) = udp_pair();
+    let sender_port = {
+        bind_v4(&sender, &SocketAddrV4::new(Ipv4Addr::new(127, 0, 0, 1), 0)).unwrap();
+        match getsockname(&sender).unwrap() {
+            SocketAddr::V4(addr) => addr.port(),
+            _ => panic!(),
+        }
+    };
+
+    sendto_v4(&sender, &[0x2a_u8; 100], SendFlags::empty(), &addr).unwrap();
+    let mut buf = [0_u8; 10];
+    let (n, flags, from) = recvfrom_flags(&receiver, &mut buf, RecvFlags::empty()).unwrap();
+    assert_eq!(n, 10);
+    assert!(flags.contains(ReturnFlags::TRUNC));
+    match from {
+        Some(SocketAddr::V4(from)) => assert_eq!(from.port(), sender_port),
+        _ => panic!("expected an IPv4 sender address"),
+    }
+
+    sendto_v4(&sender, b"hello", SendFlags::empty(), &addr).unwrap();
+    let (n, flags, _from) = recvfrom_flags(&receiver, &mut buf, RecvFlags::empty()).unwrap();
+    assert_eq!(n, 5);
+    assert!(!flags.contains(ReturnFlags::TRUNC));
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_recvfrom_flags_v6() {
+    use rsix::net::{bind_v6, recvfrom_flags, sendto_v6, Ipv6Addr, SocketAddrV6};
+
+    let bind_addr = SocketAddrV6::new(Ipv6Addr::LOCALHOST, 0, 0, 0);
+    let receiver = socket(AddressFamily::INET6, SocketType::DGRAM, Protocol::default()).unwrap();
+    bind_v6(&receiver, &bind_addr).unwrap();
+    let addr = match getsockname(&receiver).unwrap() {
+        SocketAddr::V6(addr) => addr,
+        _ => panic!(),
+    };
+    let sender = socket(AddressFamily::INET6, SocketType::DGRAM, Protocol::default()).unwrap();
+    bind_v6(&sender, &bind_addr).unwrap();
+    let sender_port = match getsockname(&sender).unwrap() {
+        SocketAddr::V6(addr) => addr.port(),
+        _ => panic!(),
+    };
+
+    // A `sockaddr_in6` is larger than a plain `sockaddr`, so this checks
+    // that the whole sender address is decoded.
+    sendto_v6(&sender, b"hello", SendFlags::empty(), &addr).unwrap();
+    let mut buf = [0_u8; 16];
+    let (n, _flags, from) = recvfrom_flags(&receiver, &mut buf, RecvFlags::empty()).unwrap();
+    assert_eq!(&buf[..n], b"hello");
+    match from {
+        Some(SocketAddr::V6(from)) => {
+            assert_eq!(
+                from.address().clone().into_std(),
+                std::net::Ipv6Addr::LOCALHOST
+            );
+            assert_eq!(from.port(), sender_port);
+        }
+        _ => panic!("expected an IPv6 sender address"),
+    }
+}
@@ -0,0 +1,46 @@
+//! Test Unix-domain sockets bound to addresses in the abstract namespace.
+
+#![cfg(any(target_os = "android", target_os = "linux"))]
+
+use rsix::io::{read, write};
+use rsix::net::{
+    accept, bind_unix, connect_unix, listen, socket, AddressFamily, Protocol, SocketAddrUnix,
+    SocketType,
+};
+use rsix::process::getpid;
+
+#[test]
+fn test_abstract_unix_addr() {
+    let addr = SocketAddrUnix::new_abstract(b"rsix\0test").unwrap();
+    assert!(addr.is_abstract());
+    assert_eq!(addr.abstract_name(), Some(&b"rsix\0test"[..]));
+    assert_eq!(addr.path(), None);
+
+    let addr = SocketAddrUnix::new("/tmp/rsix").unwrap();
+    assert!(!addr.is_abstract());
+    assert_eq!(addr.abstract_name(), None);
+    assert_eq!(addr.path().unwrap().to_bytes(), b"/tmp/rsix");
+
+    assert!(SocketAddrUnix::new_abstract(&[b'x'; 108]).is_err());
+}
+
+#[test]
+fn test_abstract_unix_socket() {
+    // Abstract names are visible to the whole network namespace, so make
+    // this one unique to this process.
+    let name = format!("rsix-test-abstract-{}", getpid().as_raw());
+    let addr = SocketAddrUnix::new_abstract(name.as_bytes()).unwrap();
+
+    let listener = socket(AddressFamily::UNIX, SocketType::STREAM, Protocol::default()).unwrap();
+    bind_unix(&listener, &addr).unwrap();
+    listen(&listener, 1).unwrap();
+
+    let client = socket(AddressFamily::UNIX, SocketType::STREAM, Protocol::default()).unwrap();
+    connect_unix(&client, &addr).unwrap();
+    let server = accept(&listener).unwrap();
+
+    assert_eq!(write(&client, b"hello").unwrap(), 5);
+    let mut buf = [0_u8; 5];
+    assert_eq!(read(&server, &mut buf).unwrap(), 5);
+    assert_eq!(&buf, b"hello");
+}
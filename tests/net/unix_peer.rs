@@ -0,0 +1,93 @@
+//! Test decoding the addresses of Unix-domain peers.
+
+use rsix::net::{
+    acceptfrom, bind_unix, connect_unix, listen, socket, AddressFamily, Protocol, SocketAddr,
+    SocketAddrUnix, SocketType,
+};
+
+#[test]
+fn test_acceptfrom_unix_pathname_peer() {
+    let dir = tempfile::tempdir().unwrap();
+    let server_path = dir.path().join("server");
+    let client_path = dir.path().join("client");
+
+    let listener = socket(AddressFamily::UNIX, SocketType::STREAM, Protocol::default()).unwrap();
+    bind_unix(&listener, &SocketAddrUnix::new(&server_path).unwrap()).unwrap();
+    listen(&listener, 1).unwrap();
+
+    let client = socket(AddressFamily::UNIX, SocketType::STREAM, Protocol::default()).unwrap();
+    bind_unix(&client, &SocketAddrUnix::new(&client_path).unwrap()).unwrap();
+    connect_unix(&client, &SocketAddrUnix::new(&server_path).unwrap()).unwrap();
+
+    let (_server, peer) = acceptfrom(&listener).unwrap();
+    match peer {
+        SocketAddr::Unix(unix) => {
+            assert!(!unix.is_unnamed());
+            assert_eq!(
+                unix.path().unwrap().to_str().unwrap(),
+                client_path.to_str().unwrap()
+            );
+        }
+        other => panic!("unexpected address {:?}", other),
+    }
+}
+
+#[test]
+fn test_acceptfrom_unix_unnamed_peer() {
+    let dir = tempfile::tempdir().unwrap();
+    let server_path = dir.path().join("server");
+
+    let listener = socket(AddressFamily::UNIX, SocketType::STREAM, Protocol::default()).unwrap();
+    bind_unix(&listener, &SocketAddrUnix::new(&server_path).unwrap()).unwrap();
+    listen(&listener, 1).unwrap();
+
+    let client = socket(AddressFamily::UNIX, SocketType::STREAM, Protocol::default()).unwrap();
+    connect_unix(&client, &SocketAddrUnix::new(&server_path).unwrap()).unwrap();
+
+    let (_server, peer) = acceptfrom(&listener).unwrap();
+    match peer {
+        SocketAddr::Unix(unix) => {
+            assert!(unix.is_unnamed());
+            assert_eq!(unix.path(), None);
+        }
+        other => panic!("unexpected address {:?}", other),
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_acceptfrom_unix_full_length_peer() {
+    use std::os::unix::ffi::OsStrExt;
+
+    // Linux accepts a path that fills all 108 bytes of `sun_path`, with no
+    // NUL terminator, and reports it back that way.
+    let dir = tempfile::tempdir().unwrap();
+    let server_path = dir.path().join("server");
+    let prefix_len = dir.path().as_os_str().len() + 1;
+    let client_path = dir.path().join("c".repeat(108 - prefix_len));
+    assert_eq!(client_path.as_os_str().len(), 108);
+
+    let listener = socket(AddressFamily::UNIX, SocketType::STREAM, Protocol::default()).unwrap();
+    bind_unix(&listener, &SocketAddrUnix::new(&server_path).unwrap()).unwrap();
+    listen(&listener, 1).unwrap();
+
+    let client = socket(AddressFamily::UNIX, SocketType::STREAM, Protocol::default()).unwrap();
+    bind_unix(&client, &SocketAddrUnix::new(&client_path).unwrap()).unwrap();
+    connect_unix(&client, &SocketAddrUnix::new(&server_path).unwrap()).unwrap();
+
+    let (_server, peer) = acceptfrom(&listener).unwrap();
+    match peer {
+        SocketAddr::Unix(unix) => assert_eq!(
+            unix.path().unwrap().to_bytes(),
+            client_path.as_os_str().as_bytes()
+        ),
+        other => panic!("unexpected address {:?}", other),
+    }
+
+    // One more byte doesn't fit.
+    let too_long = dir.path().join("c".repeat(109 - prefix_len));
+    assert_eq!(
+        SocketAddrUnix::new(&too_long).unwrap_err(),
+        rsix::io::Error::NAMETOOLONG
+    );
+}
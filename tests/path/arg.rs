@@ -3,6 +3,8 @@ use rsix::path::{Arg, DecInt};
 use std::borrow::Cow;
 use std::ffi::{CStr, CString, OsStr, OsString};
 use std::path::{Component, Components, Iter, Path, PathBuf};
+use std::rc::Rc;
+use std::sync::Arc;
 
 #[test]
 fn test_arg() {
@@ -13,9 +15,15 @@ fn test_arg() {
     assert_eq!("hello", t.as_str().unwrap());
     assert_eq!("hello".to_owned(), Arg::to_string_lossy(&t));
     #[cfg(not(windows))]
-    assert_eq!(cstr!("hello"), Borrow::borrow(&t.as_cow_c_str().unwrap()));
+    assert_eq!(
+        cstr!("hello"),
+        Borrow::<CStr>::borrow(&t.as_cow_c_str().unwrap())
+    );
     #[cfg(not(windows))]
-    assert_eq!(cstr!("hello"), Borrow::borrow(&t.into_c_str().unwrap()));
+    assert_eq!(
+        cstr!("hello"),
+        Borrow::<CStr>::borrow(&t.into_c_str().unwrap())
+    );
     #[cfg(not(windows))]
     assert_eq!(b"hello", t.as_maybe_utf8_bytes());
     #[cfg(windows)]
@@ -28,11 +36,14 @@ fn test_arg() {
     assert_eq!("hello", Arg::as_str(&t).unwrap());
     assert_eq!("hello".to_owned(), Arg::to_string_lossy(&t));
     #[cfg(not(windows))]
-    assert_eq!(cstr!("hello"), Borrow::borrow(&t.as_cow_c_str().unwrap()));
+    assert_eq!(
+        cstr!("hello"),
+        Borrow::<CStr>::borrow(&t.as_cow_c_str().unwrap())
+    );
     #[cfg(not(windows))]
     assert_eq!(
         cstr!("hello"),
-        Borrow::borrow(&t.clone().into_c_str().unwrap())
+        Borrow::<CStr>::borrow(&t.clone().into_c_str().unwrap())
     );
     #[cfg(not(windows))]
     assert_eq!(b"hello", t.as_maybe_utf8_bytes());
@@ -46,9 +57,15 @@ fn test_arg() {
     assert_eq!("hello", t.as_str().unwrap());
     assert_eq!("hello".to_owned(), Arg::to_string_lossy(&t));
     #[cfg(not(windows))]
-    assert_eq!(cstr!("hello"), Borrow::borrow(&t.as_cow_c_str().unwrap()));
+    assert_eq!(
+        cstr!("hello"),
+        Borrow::<CStr>::borrow(&t.as_cow_c_str().unwrap())
+    );
     #[cfg(not(windows))]
-    assert_eq!(cstr!("hello"), Borrow::borrow(&t.into_c_str().unwrap()));
+    assert_eq!(
+        cstr!("hello"),
+        Borrow::<CStr>::borrow(&t.into_c_str().unwrap())
+    );
     #[cfg(not(windows))]
     assert_eq!(b"hello", t.as_maybe_utf8_bytes());
     #[cfg(windows)]
@@ -61,11 +78,14 @@ fn test_arg() {
     assert_eq!("hello", t.as_str().unwrap());
     assert_eq!("hello".to_owned(), Arg::to_string_lossy(&t));
     #[cfg(not(windows))]
-    assert_eq!(cstr!("hello"), Borrow::borrow(&t.as_cow_c_str().unwrap()));
+    assert_eq!(
+        cstr!("hello"),
+        Borrow::<CStr>::borrow(&t.as_cow_c_str().unwrap())
+    );
     #[cfg(not(windows))]
     assert_eq!(
         cstr!("hello"),
-        Borrow::borrow(&t.clone().into_c_str().unwrap())
+        Borrow::<CStr>::borrow(&t.clone().into_c_str().unwrap())
     );
     #[cfg(not(windows))]
     assert_eq!(b"hello", t.as_maybe_utf8_bytes());
@@ -79,9 +99,15 @@ fn test_arg() {
     assert_eq!("hello", t.as_str().unwrap());
     assert_eq!("hello".to_owned(), Arg::to_string_lossy(&t));
     #[cfg(not(windows))]
-    assert_eq!(cstr!("hello"), Borrow::borrow(&t.as_cow_c_str().unwrap()));
+    assert_eq!(
+        cstr!("hello"),
+        Borrow::<CStr>::borrow(&t.as_cow_c_str().unwrap())
+    );
     #[cfg(not(windows))]
-    assert_eq!(cstr!("hello"), Borrow::borrow(&t.into_c_str().unwrap()));
+    assert_eq!(
+        cstr!("hello"),
+        Borrow::<CStr>::borrow(&t.into_c_str().unwrap())
+    );
     #[cfg(not(windows))]
     assert_eq!(b"hello", t.as_maybe_utf8_bytes());
     #[cfg(windows)]
@@ -94,11 +120,14 @@ fn test_arg() {
     assert_eq!("hello", t.as_str().unwrap());
     assert_eq!("hello".to_owned(), Arg::to_string_lossy(&t));
     #[cfg(not(windows))]
-    assert_eq!(cstr!("hello"), Borrow::borrow(&t.as_cow_c_str().unwrap()));
+    assert_eq!(
+        cstr!("hello"),
+        Borrow::<CStr>::borrow(&t.as_cow_c_str().unwrap())
+    );
     #[cfg(not(windows))]
     assert_eq!(
         cstr!("hello"),
-        Borrow::borrow(&t.clone().into_c_str().unwrap())
+        Borrow::<CStr>::borrow(&t.clone().into_c_str().unwrap())
     );
     #[cfg(not(windows))]
     assert_eq!(b"hello", t.as_maybe_utf8_bytes());
@@ -112,9 +141,15 @@ fn test_arg() {
     assert_eq!("hello", t.as_str().unwrap());
     assert_eq!("hello".to_owned(), Arg::to_string_lossy(&t));
     #[cfg(not(windows))]
-    assert_eq!(cstr!("hello"), Borrow::borrow(&t.as_cow_c_str().unwrap()));
+    assert_eq!(
+        cstr!("hello"),
+        Borrow::<CStr>::borrow(&t.as_cow_c_str().unwrap())
+    );
     #[cfg(not(windows))]
-    assert_eq!(cstr!("hello"), Borrow::borrow(&t.into_c_str().unwrap()));
+    assert_eq!(
+        cstr!("hello"),
+        Borrow::<CStr>::borrow(&t.into_c_str().unwrap())
+    );
     #[cfg(not(windows))]
     assert_eq!(b"hello", t.as_maybe_utf8_bytes());
     #[cfg(windows)]
@@ -129,12 +164,12 @@ fn test_arg() {
     #[cfg(not(windows))]
     assert_eq!(
         cstr!("hello"),
-        Borrow::borrow(&Arg::as_cow_c_str(&t).unwrap())
+        Borrow::<CStr>::borrow(&Arg::as_cow_c_str(&t).unwrap())
     );
     #[cfg(not(windows))]
     assert_eq!(
         cstr!("hello"),
-        Borrow::borrow(&t.clone().into_c_str().unwrap())
+        Borrow::<CStr>::borrow(&t.clone().into_c_str().unwrap())
     );
     #[cfg(not(windows))]
     assert_eq!(b"hello", t.as_maybe_utf8_bytes());
@@ -148,11 +183,14 @@ fn test_arg() {
     assert_eq!("hello", t.as_str().unwrap());
     assert_eq!("hello".to_owned(), Arg::to_string_lossy(&t));
     #[cfg(not(windows))]
-    assert_eq!(cstr!("hello"), Borrow::borrow(&t.as_cow_c_str().unwrap()));
+    assert_eq!(
+        cstr!("hello"),
+        Borrow::<CStr>::borrow(&t.as_cow_c_str().unwrap())
+    );
     #[cfg(not(windows))]
     assert_eq!(
         cstr!("hello"),
-        Borrow::borrow(&t.clone().into_c_str().unwrap())
+        Borrow::<CStr>::borrow(&t.clone().into_c_str().unwrap())
     );
     #[cfg(not(windows))]
     assert_eq!(b"hello", t.as_maybe_utf8_bytes());
@@ -166,9 +204,15 @@ fn test_arg() {
     assert_eq!("hello", t.as_str().unwrap());
     assert_eq!("hello".to_owned(), Arg::to_string_lossy(&t));
     #[cfg(not(windows))]
-    assert_eq!(cstr!("hello"), Borrow::borrow(&t.as_cow_c_str().unwrap()));
+    assert_eq!(
+        cstr!("hello"),
+        Borrow::<CStr>::borrow(&t.as_cow_c_str().unwrap())
+    );
     #[cfg(not(windows))]
-    assert_eq!(cstr!("hello"), Borrow::borrow(&t.into_c_str().unwrap()));
+    assert_eq!(
+        cstr!("hello"),
+        Borrow::<CStr>::borrow(&t.into_c_str().unwrap())
+    );
     #[cfg(not(windows))]
     assert_eq!(b"hello", t.as_maybe_utf8_bytes());
     #[cfg(windows)]
@@ -181,11 +225,14 @@ fn test_arg() {
     assert_eq!("hello", t.as_str().unwrap());
     assert_eq!("hello".to_owned(), Arg::to_string_lossy(&t));
     #[cfg(not(windows))]
-    assert_eq!(cstr!("hello"), Borrow::borrow(&t.as_cow_c_str().unwrap()));
+    assert_eq!(
+        cstr!("hello"),
+        Borrow::<CStr>::borrow(&t.as_cow_c_str().unwrap())
+    );
     #[cfg(not(windows))]
     assert_eq!(
         cstr!("hello"),
-        Borrow::borrow(&t.clone().into_c_str().unwrap())
+        Borrow::<CStr>::borrow(&t.clone().into_c_str().unwrap())
     );
     #[cfg(not(windows))]
     assert_eq!(b"hello", t.as_maybe_utf8_bytes());
@@ -199,11 +246,14 @@ fn test_arg() {
     assert_eq!("hello", t.as_str().unwrap());
     assert_eq!("hello".to_owned(), Arg::to_string_lossy(&t));
     #[cfg(not(windows))]
-    assert_eq!(cstr!("hello"), Borrow::borrow(&t.as_cow_c_str().unwrap()));
+    assert_eq!(
+        cstr!("hello"),
+        Borrow::<CStr>::borrow(&t.as_cow_c_str().unwrap())
+    );
     #[cfg(not(windows))]
     assert_eq!(
         cstr!("hello"),
-        Borrow::borrow(&t.clone().into_c_str().unwrap())
+        Borrow::<CStr>::borrow(&t.clone().into_c_str().unwrap())
     );
     #[cfg(not(windows))]
     assert_eq!(b"hello", t.as_maybe_utf8_bytes());
@@ -217,11 +267,14 @@ fn test_arg() {
     assert_eq!("hello", t.as_str().unwrap());
     assert_eq!("hello".to_owned(), Arg::to_string_lossy(&t));
     #[cfg(not(windows))]
-    assert_eq!(cstr!("hello"), Borrow::borrow(&t.as_cow_c_str().unwrap()));
+    assert_eq!(
+        cstr!("hello"),
+        Borrow::<CStr>::borrow(&t.as_cow_c_str().unwrap())
+    );
     #[cfg(not(windows))]
     assert_eq!(
         cstr!("hello"),
-        Borrow::borrow(&t.clone().into_c_str().unwrap())
+        Borrow::<CStr>::borrow(&t.clone().into_c_str().unwrap())
     );
     #[cfg(not(windows))]
     assert_eq!(b"hello", t.as_maybe_utf8_bytes());
@@ -235,11 +288,14 @@ fn test_arg() {
     assert_eq!("hello", t.as_str().unwrap());
     assert_eq!("hello".to_owned(), Arg::to_string_lossy(&t));
     #[cfg(not(windows))]
-    assert_eq!(cstr!("hello"), Borrow::borrow(&t.as_cow_c_str().unwrap()));
+    assert_eq!(
+        cstr!("hello"),
+        Borrow::<CStr>::borrow(&t.as_cow_c_str().unwrap())
+    );
     #[cfg(not(windows))]
     assert_eq!(
         cstr!("hello"),
-        Borrow::borrow(&t.clone().into_c_str().unwrap())
+        Borrow::<CStr>::borrow(&t.clone().into_c_str().unwrap())
     );
     #[cfg(not(windows))]
     assert_eq!(b"hello", t.as_maybe_utf8_bytes());
@@ -253,11 +309,14 @@ fn test_arg() {
     assert_eq!("hello", t.as_str().unwrap());
     assert_eq!("hello".to_owned(), Arg::to_string_lossy(&t));
     #[cfg(not(windows))]
-    assert_eq!(cstr!("hello"), Borrow::borrow(&t.as_cow_c_str().unwrap()));
+    assert_eq!(
+        cstr!("hello"),
+        Borrow::<CStr>::borrow(&t.as_cow_c_str().unwrap())
+    );
     #[cfg(not(windows))]
     assert_eq!(
         cstr!("hello"),
-        Borrow::borrow(&t.clone().into_c_str().unwrap())
+        Borrow::<CStr>::borrow(&t.clone().into_c_str().unwrap())
     );
     #[cfg(not(windows))]
     assert_eq!(b"hello", t.as_maybe_utf8_bytes());
@@ -271,11 +330,14 @@ fn test_arg() {
     assert_eq!("hello", t.as_str().unwrap());
     assert_eq!("hello".to_owned(), Arg::to_string_lossy(&t));
     #[cfg(not(windows))]
-    assert_eq!(cstr!("hello"), Borrow::borrow(&t.as_cow_c_str().unwrap()));
+    assert_eq!(
+        cstr!("hello"),
+        Borrow::<CStr>::borrow(&t.as_cow_c_str().unwrap())
+    );
     #[cfg(not(windows))]
     assert_eq!(
         cstr!("hello"),
-        Borrow::borrow(&t.clone().into_c_str().unwrap())
+        Borrow::<CStr>::borrow(&t.clone().into_c_str().unwrap())
     );
     #[cfg(not(windows))]
     assert_eq!(b"hello", t.as_maybe_utf8_bytes());
@@ -289,11 +351,14 @@ fn test_arg() {
     assert_eq!("hello", t.as_str().unwrap());
     assert_eq!("hello".to_owned(), Arg::to_string_lossy(&t));
     #[cfg(not(windows))]
-    assert_eq!(cstr!("hello"), Borrow::borrow(&t.as_cow_c_str().unwrap()));
+    assert_eq!(
+        cstr!("hello"),
+        Borrow::<CStr>::borrow(&t.as_cow_c_str().unwrap())
+    );
     #[cfg(not(windows))]
     assert_eq!(
         cstr!("hello"),
-        Borrow::borrow(&t.clone().into_c_str().unwrap())
+        Borrow::<CStr>::borrow(&t.clone().into_c_str().unwrap())
     );
     #[cfg(not(windows))]
     assert_eq!(b"hello", t.as_maybe_utf8_bytes());
@@ -307,9 +372,15 @@ fn test_arg() {
     assert_eq!("hello", t.as_str().unwrap());
     assert_eq!("hello".to_owned(), Arg::to_string_lossy(&t));
     #[cfg(not(windows))]
-    assert_eq!(cstr!("hello"), Borrow::borrow(&t.as_cow_c_str().unwrap()));
+    assert_eq!(
+        cstr!("hello"),
+        Borrow::<CStr>::borrow(&t.as_cow_c_str().unwrap())
+    );
     #[cfg(not(windows))]
-    assert_eq!(cstr!("hello"), Borrow::borrow(&t.into_c_str().unwrap()));
+    assert_eq!(
+        cstr!("hello"),
+        Borrow::<CStr>::borrow(&t.into_c_str().unwrap())
+    );
     #[cfg(not(windows))]
     assert_eq!(b"hello", t.as_maybe_utf8_bytes());
     #[cfg(windows)]
@@ -322,11 +393,14 @@ fn test_arg() {
     assert_eq!("hello", t.as_str().unwrap());
     assert_eq!("hello".to_owned(), Arg::to_string_lossy(&t));
     #[cfg(not(windows))]
-    assert_eq!(cstr!("hello"), Borrow::borrow(&t.as_cow_c_str().unwrap()));
+    assert_eq!(
+        cstr!("hello"),
+        Borrow::<CStr>::borrow(&t.as_cow_c_str().unwrap())
+    );
     #[cfg(not(windows))]
     assert_eq!(
         cstr!("hello"),
-        Borrow::borrow(&t.clone().into_c_str().unwrap())
+        Borrow::<CStr>::borrow(&t.clone().into_c_str().unwrap())
     );
     #[cfg(not(windows))]
     assert_eq!(b"hello", t.as_maybe_utf8_bytes());
@@ -340,12 +414,15 @@ fn test_arg() {
     assert_eq!("43110", t.as_str().unwrap());
     assert_eq!("43110".to_owned(), Arg::to_string_lossy(&t));
     #[cfg(not(windows))]
-    assert_eq!(cstr!("43110"), Borrow::borrow(&t.as_cow_c_str().unwrap()));
+    assert_eq!(
+        cstr!("43110"),
+        Borrow::<CStr>::borrow(&t.as_cow_c_str().unwrap())
+    );
     assert_eq!(cstr!("43110"), t.as_c_str());
     #[cfg(not(windows))]
     assert_eq!(
         cstr!("43110"),
-        Borrow::borrow(&t.clone().into_c_str().unwrap())
+        Borrow::<CStr>::borrow(&t.clone().into_c_str().unwrap())
     );
     #[cfg(not(windows))]
     assert_eq!(b"43110", t.as_maybe_utf8_bytes());
@@ -356,6 +433,93 @@ fn test_arg() {
     );
 }
 
+#[cfg(not(windows))]
+#[test]
+fn test_arg_smart_pointers() {
+    use cstr::cstr;
+    use std::borrow::Borrow;
+
+    fn check<P: Arg>(path: P) {
+        assert_eq!("hello", path.as_str().unwrap());
+        assert_eq!("hello".to_owned(), path.to_string_lossy());
+        assert_eq!(
+            cstr!("hello"),
+            Borrow::<CStr>::borrow(&path.as_cow_c_str().unwrap())
+        );
+        assert_eq!(b"hello", path.as_maybe_utf8_bytes());
+        assert_eq!(
+            cstr!("hello"),
+            Borrow::<CStr>::borrow(&path.into_c_str().unwrap())
+        );
+    }
+
+    check(Box::<str>::from("hello"));
+    check(Rc::<str>::from("hello"));
+    check(Arc::<str>::from("hello"));
+    check(Box::<[u8]>::from(&b"hello"[..]));
+
+    let t: Arc<str> = Arc::from("hello");
+    t.into_with_c_str(|c_str| {
+        assert_eq!(cstr!("hello"), c_str);
+        Ok(())
+    })
+    .unwrap();
+
+    let t: Box<str> = Box::from("hello\0world");
+    assert_eq!(t.into_c_str().unwrap_err(), io::Error::INVAL);
+    let t: Box<[u8]> = Box::from(&b"hello\0world"[..]);
+    assert_eq!(t.into_c_str().unwrap_err(), io::Error::INVAL);
+}
+
+#[cfg(not(windows))]
+#[test]
+fn test_nul_terminated_bytes() {
+    use cstr::cstr;
+    use std::borrow::Borrow;
+
+    let t: &[u8] = b"hello\0";
+    assert!(matches!(t.as_cow_c_str().unwrap(), Cow::Borrowed(_)));
+    match t.into_c_str().unwrap() {
+        Cow::Borrowed(c_str) => assert_eq!(cstr!("hello"), c_str),
+        Cow::Owned(_) => panic!("expected a borrowed `CStr`"),
+    }
+    t.into_with_c_str(|c_str| {
+        assert_eq!(cstr!("hello"), c_str);
+        Ok(())
+    })
+    .unwrap();
+
+    let t: Vec<u8> = b"hello\0".to_vec();
+    assert!(matches!((&t).into_c_str().unwrap(), Cow::Borrowed(_)));
+    assert_eq!(
+        cstr!("hello"),
+        Borrow::<CStr>::borrow(&t.into_c_str().unwrap())
+    );
+
+    let t: Box<[u8]> = Box::from(&b"hello\0"[..]);
+    assert_eq!(
+        cstr!("hello"),
+        Borrow::<CStr>::borrow(&t.into_c_str().unwrap())
+    );
+
+    // Slices without a NUL are still copied, and interior NULs are still
+    // rejected.
+    let t: &[u8] = b"hello";
+    assert!(matches!(t.into_c_str().unwrap(), Cow::Owned(_)));
+    let t: &[u8] = b"hello\0world\0";
+    assert_eq!(t.into_c_str().unwrap_err(), io::Error::INVAL);
+
+    // A NUL-terminated path names the same file as the path without it.
+    #[cfg(not(windows))]
+    {
+        use rsix::fs::{cwd, statat, AtFlags};
+
+        let with_nul = statat(&cwd(), &b"Cargo.toml\0"[..], AtFlags::empty()).unwrap();
+        let without_nul = statat(&cwd(), "Cargo.toml", AtFlags::empty()).unwrap();
+        assert_eq!(with_nul.st_ino, without_nul.st_ino);
+    }
+}
+
 #[test]
 fn test_invalid() {
     use cstr::cstr;
@@ -367,12 +531,12 @@ fn test_invalid() {
     #[cfg(not(windows))]
     assert_eq!(
         cstr!(b"hello\xc0world"),
-        Borrow::borrow(&t.as_cow_c_str().unwrap())
+        Borrow::<CStr>::borrow(&t.as_cow_c_str().unwrap())
     );
     #[cfg(not(windows))]
     assert_eq!(
         cstr!(b"hello\xc0world"),
-        Borrow::borrow(&t.clone().into_c_str().unwrap())
+        Borrow::<CStr>::borrow(&t.into_c_str().unwrap())
     );
     #[cfg(not(windows))]
     assert_eq!(b"hello\xc0world", t.as_maybe_utf8_bytes());
@@ -394,7 +558,7 @@ fn test_embedded_nul() {
     #[cfg(not(windows))]
     assert_eq!(t.as_cow_c_str().unwrap_err(), io::Error::INVAL);
     #[cfg(not(windows))]
-    assert_eq!(t.clone().into_c_str().unwrap_err(), io::Error::INVAL);
+    assert_eq!(t.into_c_str().unwrap_err(), io::Error::INVAL);
     #[cfg(not(windows))]
     assert_eq!(b"hello\0world", t.as_maybe_utf8_bytes());
     #[cfg(windows)]
@@ -18,3 +18,26 @@ fn test_dec_int() {
         u64::MAX.to_string()
     );
 }
+
+#[test]
+fn test_dec_int_c_str() {
+    assert_eq!(
+        DecInt::new(-42_i64).as_c_str().to_bytes_with_nul(),
+        b"-42\0"
+    );
+    assert_eq!(DecInt::new(-42_i64).as_bytes(), b"-42");
+    assert_eq!(
+        DecInt::new(u64::MAX).as_c_str().to_bytes_with_nul(),
+        b"18446744073709551615\0"
+    );
+    assert_eq!(
+        DecInt::new(i64::MIN).as_c_str().to_bytes_with_nul(),
+        b"-9223372036854775808\0"
+    );
+    assert_eq!(DecInt::new(7_u8).as_c_str().to_bytes_with_nul(), b"7\0");
+    assert_eq!(DecInt::new(-7_i16).as_os_str(), "-7");
+    assert_eq!(
+        DecInt::new(usize::MAX).as_bytes(),
+        usize::MAX.to_string().as_bytes()
+    );
+}
@@ -0,0 +1,30 @@
+use crate::fork::in_child;
+use rsix::fs::{cwd, openat, Mode, OFlags};
+use rsix::process::{chdir, fchdir, getcwd};
+
+#[test]
+fn test_chdir_getcwd() {
+    let orig = getcwd().unwrap();
+    assert_eq!(orig, std::env::current_dir().unwrap());
+
+    let dir = tempfile::tempdir().unwrap();
+    // The temporary directory's path may go through a symlink.
+    let expected = dir.path().canonicalize().unwrap();
+
+    // The current directory is process-wide, so change it in a child, where
+    // it can't affect other tests that use relative paths.
+    in_child(|| {
+        chdir(dir.path()).unwrap();
+        assert_eq!(getcwd().unwrap(), expected);
+
+        let orig_dir = openat(
+            &cwd(),
+            &orig,
+            OFlags::RDONLY | OFlags::DIRECTORY | OFlags::CLOEXEC,
+            Mode::empty(),
+        )
+        .unwrap();
+        fchdir(&orig_dir).unwrap();
+        assert_eq!(getcwd().unwrap(), orig);
+    });
+}
@@ -0,0 +1,31 @@
+use crate::fork::in_child;
+use cstr::cstr;
+use rsix::fs::{cwd, statat, AtFlags};
+use rsix::process::{chdir, chroot};
+use std::ffi::CString;
+use std::os::unix::ffi::OsStrExt;
+
+#[test]
+#[ignore = "requires unprivileged user namespaces"]
+fn test_chroot() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("marker"), b"").unwrap();
+    let root = CString::new(dir.path().as_os_str().as_bytes()).unwrap();
+
+    // `chroot` affects the whole process, so do it in a child, in a new user
+    // namespace so that it has `CAP_SYS_CHROOT` there.
+    in_child(|| {
+        assert_eq!(
+            unsafe { libc::unshare(libc::CLONE_NEWUSER) },
+            0,
+            "unshare failed: {}",
+            std::io::Error::last_os_error()
+        );
+        chroot(root.as_c_str()).unwrap();
+        chdir(cstr!("/")).unwrap();
+        statat(&cwd(), cstr!("/marker"), AtFlags::empty()).unwrap();
+    });
+
+    // The parent's root is untouched.
+    assert!(statat(&cwd(), "/marker", AtFlags::empty()).is_err());
+}
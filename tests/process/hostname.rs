@@ -0,0 +1,8 @@
+use rsix::process::{gethostname, uname};
+
+#[test]
+fn test_gethostname() {
+    let name = gethostname().unwrap();
+    assert!(!name.as_bytes().is_empty());
+    assert_eq!(name.to_str().unwrap(), uname().nodename().to_str().unwrap());
+}
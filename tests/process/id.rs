@@ -1,3 +1,4 @@
+use crate::fork::in_child;
 use rsix::process;
 
 #[test]
@@ -29,4 +30,70 @@ fn test_getpid() {
 #[test]
 fn test_getppid() {
     assert_eq!(process::getppid(), process::getppid());
+    assert_ne!(process::getppid(), process::getpid());
+}
+
+#[test]
+fn test_parent_process_id() {
+    assert_eq!(process::parent_process_id(), process::getppid());
+    assert_ne!(process::parent_process_id(), process::getpid());
+    assert!(process::getpid().as_raw_nonzero().is_some());
+}
+
+#[test]
+fn test_pid_as_raw_nonzero() {
+    assert_eq!(process::Pid::NONE.as_raw_nonzero(), None);
+    assert_eq!(process::Pid::INIT.as_raw_nonzero().unwrap().get(), 1);
+
+    let pid = process::getpid();
+    assert_eq!(pid.as_raw_nonzero().unwrap().get(), pid.as_raw() as u32);
+
+    // All bits set is -1, which isn't a process ID.
+    let negative = unsafe { process::Pid::from_raw(!0) };
+    assert_eq!(negative.as_raw_nonzero(), None);
+}
+
+#[test]
+fn test_getpgid() {
+    let pgid = process::getpgid(None).unwrap();
+    assert_eq!(pgid.as_raw(), unsafe { libc::getpgrp() } as _);
+    assert_eq!(process::getpgid(Some(process::getpid())).unwrap(), pgid);
+    assert_eq!(process::getpgrp(), pgid);
+}
+
+#[test]
+fn test_getsid() {
+    let sid = process::getsid(None).unwrap();
+    assert_eq!(process::getsid(Some(process::getpid())).unwrap(), sid);
+}
+
+#[test]
+fn test_setsid() {
+    // `setsid` detaches the process from its session, so do it in a child.
+    // A freshly forked child is never a process group leader, so it succeeds.
+    in_child(|| {
+        let pid = process::getpid();
+        assert_eq!(process::setsid(), Ok(pid));
+        assert_eq!(process::getsid(None), Ok(pid));
+        assert_eq!(process::getpgid(None), Ok(pid));
+        assert!(process::setpgid(None, None).is_err());
+    });
+}
+
+#[test]
+fn test_getgroups() {
+    let groups = process::getgroups().unwrap();
+    assert_eq!(groups, process::getgroups().unwrap());
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_getresuid() {
+    let res = process::getresuid();
+    assert_eq!(res.real, process::getuid());
+    assert_eq!(res.effective, process::geteuid());
+
+    let res = process::getresgid();
+    assert_eq!(res.real, process::getgid());
+    assert_eq!(res.effective, process::getegid());
 }
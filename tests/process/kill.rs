@@ -0,0 +1,35 @@
+use rsix::process::{
+    getpid, kill_process, test_kill_process, waitpid, Pid, Signal, WaitOptions, WaitStatus,
+};
+use std::process::Command;
+
+#[test]
+fn test_kill_current_process() {
+    kill_process(getpid(), Signal::Cont).unwrap();
+    test_kill_process(getpid()).unwrap();
+}
+
+#[test]
+fn test_kill_child() {
+    // rsix's `waitpid` reaps the child, which clippy can't see.
+    #[allow(clippy::zombie_processes)]
+    let child = Command::new("sleep")
+        .arg("10")
+        .spawn()
+        .expect("failed to execute child");
+    let pid = unsafe { Pid::from_raw(child.id() as _) };
+
+    test_kill_process(pid).unwrap();
+    kill_process(pid, Signal::Term).unwrap();
+
+    // The wait status shows that the signal was delivered.
+    let (_, status) = waitpid(Some(pid), WaitOptions::empty()).unwrap().unwrap();
+    assert_eq!(status, WaitStatus::Signaled(Signal::Term.as_raw(), false));
+}
+
+#[test]
+fn test_signal_from_raw() {
+    assert_eq!(Signal::from_raw(libc::SIGUSR1), Some(Signal::Usr1));
+    assert_eq!(Signal::from_raw(Signal::Kill.as_raw()), Some(Signal::Kill));
+    assert_eq!(Signal::from_raw(0), None);
+}
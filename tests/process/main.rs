@@ -1,11 +1,37 @@
 #![cfg_attr(target_os = "wasi", feature(wasi_ext))]
 #![cfg_attr(io_lifetimes_use_std, feature(io_safety))]
 
+#[cfg(not(target_os = "wasi"))]
+#[path = "../util/fork.rs"]
+mod fork;
+
 mod auxv;
+#[cfg(not(target_os = "wasi"))] // WASI doesn't have chdir.
+mod chdir;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod chroot;
+#[cfg(not(target_os = "wasi"))] // WASI doesn't have gethostname.
+mod hostname;
 #[cfg(not(target_os = "wasi"))] // WASI doesn't have get[gpu]id.
 mod id;
+#[cfg(not(target_os = "wasi"))] // WASI doesn't have kill.
+mod kill;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod mount;
 #[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))] // WASI doesn't have [gs]etpriority.
 mod priority;
+#[cfg(not(target_os = "wasi"))] // WASI doesn't have getrusage.
+mod rusage;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod sched;
 mod sched_yield;
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+mod sigset;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod sysinfo;
+#[cfg(not(target_os = "wasi"))] // WASI doesn't have umask.
+mod umask;
 #[cfg(not(target_os = "wasi"))] // WASI doesn't have uname.
 mod uname;
+#[cfg(not(target_os = "wasi"))] // WASI doesn't have waitpid.
+mod wait;
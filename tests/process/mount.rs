@@ -0,0 +1,40 @@
+use crate::fork::in_child;
+use rsix::process::{mount, unmount, MountFlags, UnmountFlags};
+use std::ffi::CString;
+use std::os::unix::ffi::OsStrExt;
+
+#[test]
+#[ignore = "requires unprivileged user namespaces"]
+fn test_bind_mount() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    std::fs::write(source.path().join("marker"), b"").unwrap();
+    let source_path = CString::new(source.path().as_os_str().as_bytes()).unwrap();
+    let target_path = CString::new(target.path().as_os_str().as_bytes()).unwrap();
+    let marker = target.path().join("marker");
+
+    // Mount in a child, in new user and mount namespaces so that it has
+    // `CAP_SYS_ADMIN` there and the mount isn't visible outside.
+    in_child(|| {
+        assert_eq!(
+            unsafe { libc::unshare(libc::CLONE_NEWUSER | libc::CLONE_NEWNS) },
+            0,
+            "unshare failed: {}",
+            std::io::Error::last_os_error()
+        );
+        mount(
+            source_path.as_c_str(),
+            target_path.as_c_str(),
+            "",
+            MountFlags::BIND,
+            None,
+        )
+        .unwrap();
+        assert!(marker.exists());
+        unmount(target_path.as_c_str(), UnmountFlags::DETACH).unwrap();
+        assert!(!marker.exists());
+    });
+
+    // The parent's view of the target is untouched.
+    assert!(!marker.exists());
+}
@@ -0,0 +1,28 @@
+use rsix::process::{getrusage, RusageWho};
+use rsix::time::Timespec;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+fn nanos(ts: &Timespec) -> i128 {
+    ts.tv_sec as i128 * 1_000_000_000 + ts.tv_nsec as i128
+}
+
+#[test]
+fn test_getrusage() {
+    let before = getrusage(RusageWho::Self_).unwrap();
+
+    // Burn some CPU time.
+    let x = AtomicU64::new(0);
+    for i in 0..10_000_000_u64 {
+        x.fetch_add(i, Ordering::Relaxed);
+    }
+    assert_ne!(x.into_inner(), 0);
+
+    let after = getrusage(RusageWho::Self_).unwrap();
+    assert!(nanos(&after.utime) >= nanos(&before.utime));
+    assert!(after.maxrss > 0);
+}
+
+#[test]
+fn test_getrusage_children() {
+    getrusage(RusageWho::Children).unwrap();
+}
@@ -0,0 +1,61 @@
+use crate::fork::in_child;
+use rsix::io;
+use rsix::process::{
+    geteuid, sched_getscheduler, sched_setscheduler, sched_setscheduler_with, SchedFlags,
+    SchedPolicy, Uid,
+};
+use rsix::thread::set_thread_res_uid;
+
+#[test]
+fn test_sched_getscheduler() {
+    assert_eq!(sched_getscheduler(None).unwrap(), SchedPolicy::Other);
+}
+
+#[test]
+fn test_sched_setscheduler() {
+    // Setting the current policy doesn't require privileges.
+    sched_setscheduler(None, SchedPolicy::Other, 0).unwrap();
+    assert_eq!(sched_getscheduler(None).unwrap(), SchedPolicy::Other);
+
+    // Non-real-time policies only have priority 0.
+    assert_eq!(
+        sched_setscheduler(None, SchedPolicy::Other, 1),
+        Err(io::Error::INVAL)
+    );
+}
+
+#[test]
+fn test_sched_setscheduler_unprivileged() {
+    // Do this in a child, which drops its privileges if it has any.
+    in_child(|| {
+        if geteuid() == Uid::ROOT {
+            let nobody = unsafe { Uid::from_raw(65534) };
+            set_thread_res_uid(nobody, nobody, nobody).unwrap();
+        }
+        // An `RLIMIT_RTPRIO` of 0 disallows real-time policies for
+        // unprivileged processes, and lowering it needs no privileges.
+        let limit = libc::rlimit {
+            rlim_cur: 0,
+            rlim_max: 0,
+        };
+        assert_eq!(unsafe { libc::setrlimit(libc::RLIMIT_RTPRIO, &limit) }, 0);
+
+        assert_eq!(
+            sched_setscheduler(None, SchedPolicy::Fifo, 1),
+            Err(io::Error::PERM)
+        );
+        assert_eq!(sched_getscheduler(None).unwrap(), SchedPolicy::Other);
+    });
+}
+
+#[test]
+fn test_sched_setscheduler_reset_on_fork() {
+    // Clearing `SCHED_RESET_ON_FORK` requires privileges, so set it on a
+    // thread of its own.
+    std::thread::spawn(|| {
+        sched_setscheduler_with(None, SchedPolicy::Other, 0, SchedFlags::RESET_ON_FORK).unwrap();
+        assert_eq!(sched_getscheduler(None).unwrap(), SchedPolicy::Other);
+    })
+    .join()
+    .unwrap();
+}
@@ -0,0 +1,23 @@
+use rsix::process::{SigSet, Signal};
+use std::collections::HashSet;
+
+#[test]
+fn test_sigset_eq_hash() {
+    assert_eq!(SigSet::new(), SigSet::default());
+
+    let mut a = SigSet::new();
+    a.add(Signal::Usr1).unwrap();
+    a.add(Signal::Usr2).unwrap();
+    let mut b = SigSet::new();
+    b.add(Signal::Usr2).unwrap();
+    assert_ne!(a, b);
+    b.add(Signal::Usr1).unwrap();
+    assert_eq!(a, b);
+
+    a.remove(Signal::Usr1).unwrap();
+    assert!(!a.contains(Signal::Usr1));
+    assert!(a.contains(Signal::Usr2));
+
+    let sets: HashSet<SigSet> = [a, b, a].iter().copied().collect();
+    assert_eq!(sets.len(), 2);
+}
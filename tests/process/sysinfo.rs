@@ -0,0 +1,11 @@
+use rsix::process::sysinfo;
+
+#[test]
+fn test_sysinfo() {
+    let first = sysinfo().unwrap();
+    assert_ne!(first.totalram, 0);
+    assert!(first.freeram <= first.totalram);
+
+    let second = sysinfo().unwrap();
+    assert!(second.uptime >= first.uptime);
+}
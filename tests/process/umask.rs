@@ -0,0 +1,26 @@
+use crate::fork::in_child;
+use rsix::fs::{cwd, fstat, openat, Mode, OFlags};
+use rsix::process::umask;
+
+#[test]
+fn test_umask() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(&cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    // The umask is process-wide, so change it in a child, where it can't
+    // affect files other tests create.
+    in_child(|| {
+        let orig = umask(Mode::IWGRP | Mode::IWOTH);
+        let file = openat(
+            &dir,
+            "file",
+            OFlags::CREATE | OFlags::WRONLY,
+            Mode::IRUSR | Mode::IWUSR | Mode::IRGRP | Mode::IWGRP | Mode::IROTH | Mode::IWOTH,
+        )
+        .unwrap();
+        assert_eq!(umask(orig), Mode::IWGRP | Mode::IWOTH);
+
+        let stat = fstat(&file).unwrap();
+        assert_eq!(stat.st_mode & 0o777, 0o644);
+    });
+}
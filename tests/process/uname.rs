@@ -2,12 +2,18 @@
 fn test_uname() {
     let name: rsix::process::Uname = rsix::process::uname();
 
-    assert!(!name.sysname().is_empty());
-    assert!(!name.nodename().is_empty());
-    assert!(!name.release().is_empty());
-    assert!(!name.version().is_empty());
-    assert!(!name.machine().is_empty());
+    assert!(!name.sysname().to_bytes().is_empty());
+    assert!(!name.nodename().to_bytes().is_empty());
+    assert!(!name.release().to_bytes().is_empty());
+    assert!(!name.version().to_bytes().is_empty());
+    assert!(!name.machine().to_bytes().is_empty());
 
     #[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
-    assert!(!name.domainname().is_empty());
+    assert!(!name.domainname().to_bytes().is_empty());
+}
+
+#[cfg(linux_raw)]
+#[test]
+fn test_uname_sysname() {
+    assert_eq!(rsix::process::uname().sysname().to_bytes(), b"Linux");
 }
@@ -0,0 +1,53 @@
+use rsix::process::{waitpid, Pid, WaitOptions, WaitStatus};
+use std::process::Command;
+
+#[test]
+fn test_waitpid_exited() {
+    // rsix's `waitpid` reaps the child, which clippy can't see.
+    #[allow(clippy::zombie_processes)]
+    let child = Command::new("sh")
+        .args(["-c", "exit 7"])
+        .spawn()
+        .expect("failed to execute child");
+    let pid = unsafe { Pid::from_raw(child.id() as _) };
+
+    let (waited, status) = waitpid(Some(pid), WaitOptions::empty()).unwrap().unwrap();
+    assert_eq!(waited, pid);
+    assert_eq!(status, WaitStatus::Exited(7));
+}
+
+#[test]
+fn test_waitpid_nohang() {
+    // rsix's `waitpid` reaps the child, which clippy can't see.
+    #[allow(clippy::zombie_processes)]
+    let mut child = Command::new("sleep")
+        .arg("10")
+        .spawn()
+        .expect("failed to execute child");
+    let pid = unsafe { Pid::from_raw(child.id() as _) };
+
+    assert_eq!(waitpid(Some(pid), WaitOptions::NOHANG).unwrap(), None);
+
+    child.kill().unwrap();
+    let (waited, status) = waitpid(Some(pid), WaitOptions::empty()).unwrap().unwrap();
+    assert_eq!(waited, pid);
+    assert_eq!(status, WaitStatus::Signaled(libc::SIGKILL, false));
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_wait_status_from_raw() {
+    assert_eq!(WaitStatus::from_raw(7 << 8), WaitStatus::Exited(7));
+    assert_eq!(
+        WaitStatus::from_raw(libc::SIGKILL),
+        WaitStatus::Signaled(libc::SIGKILL, false)
+    );
+    assert_eq!(
+        WaitStatus::from_raw((libc::SIGSTOP << 8) | 0x7f),
+        WaitStatus::Stopped(libc::SIGSTOP)
+    );
+    assert_eq!(WaitStatus::from_raw(0xffff), WaitStatus::Continued);
+
+    // On Linux, a low byte of 0xff doesn't match any of the above.
+    assert_eq!(WaitStatus::from_raw(0xff), WaitStatus::Unknown(0xff));
+}
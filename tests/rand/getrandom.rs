@@ -5,3 +5,17 @@ fn test_getrandom() {
     let mut buf = [0_u8; 256];
     let _ = getrandom(&mut buf, GetRandomFlags::empty());
 }
+
+#[test]
+fn test_getrandom_fill() {
+    let mut buf = [0_u8; 32];
+    let mut filled = 0;
+    while filled < buf.len() {
+        match getrandom(&mut buf[filled..], GetRandomFlags::empty()) {
+            Ok(n) => filled += n,
+            Err(rsix::io::Error::INTR) => continue,
+            Err(err) => panic!("getrandom failed: {:?}", err),
+        }
+    }
+    assert!(buf.iter().any(|b| *b != 0));
+}
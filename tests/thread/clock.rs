@@ -0,0 +1,43 @@
+use rsix::thread::clock_gettime_thread_cputime;
+
+#[test]
+fn test_clock_gettime_thread_cputime() {
+    let start = clock_gettime_thread_cputime();
+    let mut now = start;
+    while now.tv_sec == start.tv_sec && now.tv_nsec - start.tv_nsec < 1_000_000 {
+        now = clock_gettime_thread_cputime();
+    }
+    assert!((now.tv_sec, now.tv_nsec) > (start.tv_sec, start.tv_nsec));
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_clock_nanosleep_cpu() {
+    use rsix::thread::clock_nanosleep_cpu;
+    use rsix::time::{NanosleepRelativeResult, Timespec};
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    // Spin on another thread, so that the process uses CPU time while this
+    // thread sleeps.
+    let done = Arc::new(AtomicBool::new(false));
+    let spinner = {
+        let done = Arc::clone(&done);
+        std::thread::spawn(move || {
+            while !done.load(Ordering::Relaxed) {
+                std::hint::spin_loop();
+            }
+        })
+    };
+
+    match clock_nanosleep_cpu(Timespec {
+        tv_sec: 0,
+        tv_nsec: 1_000_000,
+    }) {
+        NanosleepRelativeResult::Ok => (),
+        otherwise => panic!("unexpected result: {:?}", otherwise),
+    }
+
+    done.store(true, Ordering::Relaxed);
+    spinner.join().unwrap();
+}
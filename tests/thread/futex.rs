@@ -0,0 +1,58 @@
+use rsix::io;
+use rsix::thread::{futex_wait, futex_wake, FutexFlags};
+use rsix::time::Timespec;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+
+#[test]
+fn test_futex_wait_wake() {
+    let futex = Arc::new(AtomicU32::new(0));
+
+    let waker = {
+        let futex = Arc::clone(&futex);
+        std::thread::spawn(move || {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+            futex.store(1, Ordering::SeqCst);
+            futex_wake(&futex, FutexFlags::PRIVATE, 1).unwrap();
+        })
+    };
+
+    while futex.load(Ordering::SeqCst) == 0 {
+        match futex_wait(&futex, FutexFlags::PRIVATE, 0, None) {
+            Ok(()) => (),
+            Err(err) if err == io::Error::AGAIN => (),
+            Err(err) => panic!("futex_wait failed: {:?}", err),
+        }
+    }
+    assert_eq!(futex.load(Ordering::SeqCst), 1);
+
+    waker.join().unwrap();
+}
+
+#[test]
+fn test_futex_wait_changed() {
+    let futex = AtomicU32::new(1);
+    assert_eq!(
+        futex_wait(&futex, FutexFlags::PRIVATE, 0, None),
+        Err(io::Error::AGAIN)
+    );
+}
+
+#[test]
+fn test_futex_wait_timeout() {
+    let futex = AtomicU32::new(0);
+    let timeout = Timespec {
+        tv_sec: 0,
+        tv_nsec: 1_000_000,
+    };
+    assert_eq!(
+        futex_wait(&futex, FutexFlags::PRIVATE, 0, Some(timeout)),
+        Err(io::Error::TIMEDOUT)
+    );
+}
+
+#[test]
+fn test_futex_wake_none() {
+    let futex = AtomicU32::new(0);
+    assert_eq!(futex_wake(&futex, FutexFlags::PRIVATE, 1).unwrap(), 0);
+}
@@ -1,4 +1,6 @@
-use rsix::process::Pid;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::fork::in_child;
+use rsix::process::{self, Gid, Pid, Uid};
 use rsix::thread;
 
 #[cfg(any(target_os = "android", target_os = "linux"))]
@@ -7,3 +9,67 @@ fn test_gettid() {
     assert_ne!(thread::gettid(), Pid::NONE);
     assert_eq!(thread::gettid(), thread::gettid());
 }
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_set_thread_res_uid_unchanged() {
+    // Setting the IDs to their current values doesn't require privileges.
+    let res = process::getresuid();
+    thread::set_thread_res_uid(res.real, res.effective, res.saved).unwrap();
+    assert_eq!(process::getresuid(), res);
+
+    let res = process::getresgid();
+    thread::set_thread_res_gid(res.real, res.effective, res.saved).unwrap();
+    assert_eq!(process::getresgid(), res);
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_set_thread_ids_round_trip() {
+    // Changing to other IDs requires privileges, and do it in a child, so
+    // that the test harness's threads aren't affected either way.
+    in_child(|| {
+        if process::geteuid() != Uid::ROOT {
+            return;
+        }
+        let nobody = unsafe { Uid::from_raw(65534) };
+        let nogroup = unsafe { Gid::from_raw(65534) };
+        let res_uid = process::getresuid();
+        let res_gid = process::getresgid();
+        let groups = process::getgroups().unwrap();
+
+        let (changed_tx, changed_rx) = std::sync::mpsc::channel();
+        let (checked_tx, checked_rx) = std::sync::mpsc::channel();
+        let thread = std::thread::spawn(move || {
+            thread::set_thread_groups(&[nogroup]).unwrap();
+            assert_eq!(process::getgroups().unwrap(), [nogroup]);
+            thread::set_thread_res_gid(nogroup, nogroup, res_gid.saved).unwrap();
+            assert_eq!(process::getresgid().effective, nogroup);
+
+            // Keep root as the saved user ID, so that it can be restored.
+            thread::set_thread_res_uid(nobody, nobody, Uid::ROOT).unwrap();
+            let res = process::getresuid();
+            assert_eq!(
+                (res.real, res.effective, res.saved),
+                (nobody, nobody, Uid::ROOT)
+            );
+
+            changed_tx.send(()).unwrap();
+            checked_rx.recv().unwrap();
+
+            thread::set_thread_res_uid(res_uid.real, res_uid.effective, res_uid.saved).unwrap();
+            assert_eq!(process::getresuid(), res_uid);
+            thread::set_thread_res_gid(res_gid.real, res_gid.effective, res_gid.saved).unwrap();
+            assert_eq!(process::getresgid(), res_gid);
+            thread::set_thread_groups(&groups).unwrap();
+        });
+
+        // Only the thread that made the changes is affected.
+        if changed_rx.recv().is_ok() {
+            assert_eq!(process::getresuid(), res_uid);
+            assert_eq!(process::getresgid(), res_gid);
+            checked_tx.send(()).unwrap();
+        }
+        thread.join().unwrap();
+    });
+}
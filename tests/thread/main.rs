@@ -1,2 +1,15 @@
 #[cfg(any(target_os = "android", target_os = "linux"))]
+#[path = "../util/fork.rs"]
+mod fork;
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+mod clock;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod futex;
+#[cfg(any(target_os = "android", target_os = "linux"))]
 mod id;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod name;
+#[cfg(not(target_os = "redox"))]
+mod sleep;
+mod yield_now;
@@ -0,0 +1,23 @@
+use rsix::thread;
+use std::ffi::CString;
+
+#[test]
+fn test_thread_name() {
+    std::thread::spawn(|| {
+        thread::set_name(&CString::new("worker").unwrap()).unwrap();
+        assert_eq!(thread::name().unwrap(), CString::new("worker").unwrap());
+    })
+    .join()
+    .unwrap();
+}
+
+#[test]
+fn test_thread_name_truncated() {
+    std::thread::spawn(|| {
+        let long = CString::new("a_thread_name_longer_than_fifteen_bytes").unwrap();
+        thread::set_name(&long).unwrap();
+        assert_eq!(thread::name().unwrap().as_bytes(), &long.as_bytes()[..15]);
+    })
+    .join()
+    .unwrap();
+}
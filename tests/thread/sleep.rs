@@ -0,0 +1,15 @@
+use rsix::thread::sleep;
+use std::time::{Duration, Instant};
+
+#[test]
+fn test_sleep() {
+    let duration = Duration::from_millis(50);
+    let start = Instant::now();
+    sleep(duration).unwrap();
+    assert!(start.elapsed() >= duration);
+}
+
+#[test]
+fn test_sleep_zero() {
+    sleep(Duration::from_secs(0)).unwrap();
+}
@@ -0,0 +1,10 @@
+use rsix::thread::yield_now;
+
+#[test]
+fn test_yield_now() {
+    // There's nothing to observe about scheduling; just make sure repeated
+    // calls return.
+    for _ in 0..1000 {
+        yield_now();
+    }
+}
@@ -156,3 +156,20 @@ fn test_zero_nanosleep_relative() {
         otherwise => panic!("unexpected resut: {:?}", otherwise),
     }
 }
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_thread_cputime_nanosleep() {
+    // A thread can't sleep on its own CPU-time clock.
+    match clock_nanosleep_relative(
+        ClockId::ThreadCPUTime,
+        &Timespec {
+            tv_sec: 0,
+            tv_nsec: 1_000_000,
+        },
+    ) {
+        NanosleepRelativeResult::Err(io::Error::OPNOTSUPP)
+        | NanosleepRelativeResult::Err(io::Error::INVAL) => (),
+        otherwise => panic!("unexpected resut: {:?}", otherwise),
+    }
+}
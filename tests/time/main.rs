@@ -1,9 +1,15 @@
 #![cfg_attr(target_os = "wasi", feature(wasi_ext))]
 #![cfg_attr(io_lifetimes_use_std, feature(io_safety))]
 
+#[cfg(target_os = "linux")]
+mod adjtime;
 mod clocks;
 mod dynamic_clocks;
-#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[cfg(not(target_os = "redox"))]
 mod monotonic;
+#[cfg(target_os = "linux")]
+mod timer;
+#[cfg(target_os = "linux")]
+mod timerfd;
 mod timespec;
 mod y2038;
@@ -0,0 +1,78 @@
+use rsix::time::{clock_gettime, ClockId, Itimerspec, Sigevent, Timer, TimerFlags, Timespec};
+
+fn is_zero(ts: &Timespec) -> bool {
+    ts.tv_sec == 0 && ts.tv_nsec == 0
+}
+
+#[test]
+fn test_timer_sigev_none() {
+    let timer = Timer::new(ClockId::Monotonic, Sigevent::None).unwrap();
+    assert!(is_zero(&timer.get_time().unwrap().it_value));
+
+    let old = timer
+        .set_time(
+            TimerFlags::empty(),
+            &Itimerspec {
+                it_interval: Timespec {
+                    tv_sec: 0,
+                    tv_nsec: 0,
+                },
+                it_value: Timespec {
+                    tv_sec: 0,
+                    tv_nsec: 50_000_000,
+                },
+            },
+        )
+        .unwrap();
+    assert!(is_zero(&old.it_value));
+
+    // Poll until the timer counts down to zero.
+    let mut last = timer.get_time().unwrap().it_value;
+    assert!(last.tv_sec == 0 && last.tv_nsec <= 50_000_000);
+    while !is_zero(&last) {
+        let now = timer.get_time().unwrap().it_value;
+        assert!((now.tv_sec, now.tv_nsec) <= (last.tv_sec, last.tv_nsec));
+        last = now;
+    }
+
+    assert_eq!(timer.overrun_count().unwrap(), 0);
+}
+
+#[test]
+fn test_timer_abstime() {
+    let timer = Timer::new(ClockId::Monotonic, Sigevent::None).unwrap();
+
+    let mut deadline = clock_gettime(ClockId::Monotonic);
+    deadline.tv_sec += 10;
+    timer
+        .set_time(
+            TimerFlags::ABSTIME,
+            &Itimerspec {
+                it_interval: Timespec {
+                    tv_sec: 0,
+                    tv_nsec: 0,
+                },
+                it_value: deadline,
+            },
+        )
+        .unwrap();
+
+    let remaining = timer.get_time().unwrap().it_value;
+    assert!(remaining.tv_sec <= 10);
+    assert!(!is_zero(&remaining));
+}
+
+#[test]
+fn test_timer_send_sync() {
+    fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+    let timer = Timer::new(ClockId::Monotonic, Sigevent::None).unwrap();
+    assert_send_sync(&timer);
+
+    // The timer can be used and deleted from another thread.
+    std::thread::spawn(move || {
+        assert!(is_zero(&timer.get_time().unwrap().it_value));
+    })
+    .join()
+    .unwrap();
+}
@@ -0,0 +1,49 @@
+use rsix::time::{
+    clock_gettime, timerfd_create, timerfd_gettime, timerfd_settime, ClockId, Itimerspec,
+    TimerfdFlags, TimerfdTimerFlags, Timespec,
+};
+
+#[test]
+fn test_timerfd_cancel_on_set() {
+    let fd = timerfd_create(ClockId::Realtime, TimerfdFlags::CLOEXEC).unwrap();
+
+    let mut deadline = clock_gettime(ClockId::Realtime);
+    deadline.tv_sec += 60;
+    let old = timerfd_settime(
+        &fd,
+        TimerfdTimerFlags::ABSTIME | TimerfdTimerFlags::CANCEL_ON_SET,
+        &Itimerspec {
+            it_interval: Timespec {
+                tv_sec: 1,
+                tv_nsec: 0,
+            },
+            it_value: deadline,
+        },
+    )
+    .unwrap();
+    assert_eq!((old.it_value.tv_sec, old.it_value.tv_nsec), (0, 0));
+
+    // `timerfd_gettime` reports the time remaining, not the absolute deadline.
+    let curr = timerfd_gettime(&fd).unwrap();
+    assert_eq!((curr.it_interval.tv_sec, curr.it_interval.tv_nsec), (1, 0));
+    assert!(curr.it_value.tv_sec <= 60);
+    assert!(curr.it_value.tv_sec > 0 || curr.it_value.tv_nsec > 0);
+
+    // Disarming returns the previous setting.
+    let zero = Timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+    };
+    let old = timerfd_settime(
+        &fd,
+        TimerfdTimerFlags::empty(),
+        &Itimerspec {
+            it_interval: zero,
+            it_value: zero,
+        },
+    )
+    .unwrap();
+    assert_eq!((old.it_interval.tv_sec, old.it_interval.tv_nsec), (1, 0));
+    let curr = timerfd_gettime(&fd).unwrap();
+    assert_eq!((curr.it_value.tv_sec, curr.it_value.tv_nsec), (0, 0));
+}
@@ -24,3 +24,71 @@ fn test_timespec_layout() {
         tv_nsec: 999999999,
     };
 }
+
+#[test]
+fn test_timespec_duration_round_trip() {
+    use rsix::time::{duration_from_timespec, timespec_from_duration};
+    use std::time::Duration;
+
+    let duration = Duration::from_millis(1500);
+    let timespec = timespec_from_duration(duration).unwrap();
+    assert_eq!(timespec.tv_sec, 1);
+    assert_eq!(timespec.tv_nsec, 500_000_000);
+    assert_eq!(duration_from_timespec(timespec), duration);
+
+    // Negative times clamp to zero.
+    let negative = rsix::time::Timespec {
+        tv_sec: -1,
+        tv_nsec: 0,
+    };
+    assert_eq!(duration_from_timespec(negative), Duration::from_secs(0));
+}
+
+#[test]
+fn test_timespec_from_duration_overflow() {
+    use rsix::time::timespec_from_duration;
+    use std::time::Duration;
+
+    assert!(timespec_from_duration(Duration::from_secs(u64::MAX)).is_none());
+}
+
+#[test]
+fn test_timespec_checked_arithmetic() {
+    use rsix::time::{timespec_checked_add, timespec_checked_sub, Secs, Timespec};
+
+    let a = Timespec {
+        tv_sec: 1,
+        tv_nsec: 700_000_000,
+    };
+    let b = Timespec {
+        tv_sec: 0,
+        tv_nsec: 500_000_000,
+    };
+
+    let sum = timespec_checked_add(a, b).unwrap();
+    assert_eq!((sum.tv_sec, sum.tv_nsec), (2, 200_000_000));
+    let difference = timespec_checked_sub(b, a).unwrap();
+    assert_eq!((difference.tv_sec, difference.tv_nsec), (-2, 800_000_000));
+    let difference = timespec_checked_sub(sum, b).unwrap();
+    assert_eq!((difference.tv_sec, difference.tv_nsec), (1, 700_000_000));
+
+    let max = Timespec {
+        tv_sec: Secs::MAX,
+        tv_nsec: 0,
+    };
+    assert!(timespec_checked_add(max, a).is_none());
+
+    // Out-of-range nanoseconds are rejected rather than overflowing.
+    let bad = Timespec {
+        tv_sec: 0,
+        tv_nsec: 1_000_000_000,
+    };
+    assert!(timespec_checked_add(a, bad).is_none());
+    assert!(timespec_checked_sub(bad, a).is_none());
+    let negative = Timespec {
+        tv_sec: 0,
+        tv_nsec: -1,
+    };
+    assert!(timespec_checked_add(negative, a).is_none());
+    assert!(timespec_checked_sub(a, negative).is_none());
+}
@@ -0,0 +1,62 @@
+//! Running test code in a forked child process.
+//!
+//! This is shared by several test crates, each of which includes it with
+//! `#[path]`.
+
+use rsix::io::{pipe, read, write_all};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// Runs `f` in a forked child process and waits for it to finish.
+///
+/// This is for tests of functions that change process-wide state, which
+/// would otherwise interfere with other tests running in parallel. If `f`
+/// panics in the child, this panics in the parent with the same message.
+pub(crate) fn in_child<F: FnOnce()>(f: F) {
+    let (reader, writer) = pipe().unwrap();
+
+    match unsafe { libc::fork() } {
+        -1 => panic!("fork failed: {}", std::io::Error::last_os_error()),
+        0 => {
+            drop(reader);
+            let code = match catch_unwind(AssertUnwindSafe(f)) {
+                Ok(()) => 0,
+                Err(payload) => {
+                    let message = if let Some(message) = payload.downcast_ref::<&str>() {
+                        message
+                    } else if let Some(message) = payload.downcast_ref::<String>() {
+                        message.as_str()
+                    } else {
+                        "unknown panic payload"
+                    };
+                    let _ = write_all(&writer, message.as_bytes());
+                    1
+                }
+            };
+            unsafe { libc::_exit(code) }
+        }
+        child => {
+            drop(writer);
+            let mut message = Vec::new();
+            let mut buf = [0_u8; 256];
+            loop {
+                match read(&reader, &mut buf) {
+                    Ok(0) => break,
+                    Ok(n) => message.extend_from_slice(&buf[..n]),
+                    Err(rsix::io::Error::INTR) => (),
+                    Err(err) => panic!("reading from the child failed: {:?}", err),
+                }
+            }
+
+            let mut status = 0;
+            assert_eq!(unsafe { libc::waitpid(child, &mut status, 0) }, child);
+            assert!(
+                libc::WIFEXITED(status),
+                "child terminated abnormally: status {:#x}",
+                status
+            );
+            if libc::WEXITSTATUS(status) != 0 {
+                panic!("child panicked: {}", String::from_utf8_lossy(&message));
+            }
+        }
+    }
+}